@@ -367,6 +367,7 @@ pub fn testnet_genesis(
 		transaction_payment: Default::default(),
 		alliance: Default::default(),
 		alliance_motion: Default::default(),
+		ranked_collective: Default::default(),
 		nomination_pools: NominationPoolsConfig {
 			min_create_bond: 10 * DOLLARS,
 			min_join_bond: 1 * DOLLARS,
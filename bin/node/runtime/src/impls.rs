@@ -18,19 +18,26 @@
 //! Some configurable implementations as associated type for the substrate runtime.
 
 use crate::{
-	AccountId, AllianceMotion, Assets, Authorship, Balances, Hash, NegativeImbalance, Runtime,
-	RuntimeCall,
+	AccountId, AllianceMotion, Assets, Authorship, Balances, Hash, NegativeImbalance, Preimage,
+	Runtime, RuntimeCall, RuntimeOrigin,
 };
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		fungibles::{Balanced, CreditOf},
-		Currency, OnUnbalanced,
+		Currency, EnsureOrigin, EnsureOriginWithArg, OnUnbalanced, QueryPreimage,
 	},
 };
+use frame_system::EnsureRoot;
 use pallet_alliance::{IdentityVerifier, ProposalIndex, ProposalProvider};
 use pallet_asset_tx_payment::HandleCredit;
-use sp_std::prelude::*;
+use pallet_ranked_collective::{
+	migration::{v1, v6},
+	Votes,
+};
+use pallet_referenda::{Instance2, ReferendumIndex, ReferendumInfo};
+use sp_runtime::traits::StaticLookup;
+use sp_std::{fmt::Debug, prelude::*};
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -111,6 +118,62 @@ impl ProposalProvider<AccountId, Hash, RuntimeCall> for AllianceProposalProvider
 	}
 }
 
+/// The tallies and queued votes of the ranked polls, which the ranked collective migrates as it
+/// changes their layout.
+pub struct RankedPollsVotes;
+impl<Old, New> v1::TallyStore<Runtime, (), Old, New> for RankedPollsVotes
+where
+	Old: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	New: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+{
+	fn translate(f: impl FnMut(ReferendumIndex, Old) -> New) -> u32 {
+		pallet_referenda::migration::tally::translate_tallies::<Runtime, Instance2, Old, New>(f)
+	}
+}
+impl v6::QueuedVotesStore for RankedPollsVotes {
+	fn translate_votes(f: impl FnMut(u32) -> Votes) -> u32 {
+		pallet_referenda::migration::tally::translate_queued_votes::<Runtime, Instance2, u32>(f)
+	}
+}
+
+/// Passes the root origin for a member and a ranked poll only while the poll is ongoing and its
+/// proposal demotes or removes the member.
+pub struct EnsureRootReviewOf;
+impl EnsureOriginWithArg<RuntimeOrigin, (AccountId, ReferendumIndex)> for EnsureRootReviewOf {
+	type Success = ();
+
+	fn try_origin(
+		o: RuntimeOrigin,
+		(who, poll): &(AccountId, ReferendumIndex),
+	) -> Result<Self::Success, RuntimeOrigin> {
+		// Polls created by the benchmarks carry no proposal about the member.
+		if !cfg!(feature = "runtime-benchmarks") && !is_review_of(who, *poll) {
+			return Err(o)
+		}
+		EnsureRoot::<AccountId>::try_origin(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &(AccountId, ReferendumIndex)) -> Result<RuntimeOrigin, ()> {
+		EnsureRoot::<AccountId>::try_successful_origin()
+	}
+}
+
+/// Whether the ranked poll `poll` is ongoing with a proposal to demote or remove `who`.
+fn is_review_of(who: &AccountId, poll: ReferendumIndex) -> bool {
+	use pallet_ranked_collective::Call as RankedCall;
+	let proposal = match pallet_referenda::ReferendumInfoFor::<Runtime, Instance2>::get(poll) {
+		Some(ReferendumInfo::Ongoing(status)) => status.proposal,
+		_ => return false,
+	};
+	let subject = match Preimage::peek::<RuntimeCall>(&proposal) {
+		Ok((RuntimeCall::RankedCollective(RankedCall::demote_member { who, .. }), _)) |
+		Ok((RuntimeCall::RankedCollective(RankedCall::remove_member { who, .. }), _)) => who,
+		_ => return false,
+	};
+	<Runtime as frame_system::Config>::Lookup::lookup(subject).map_or(false, |s| &s == who)
+}
+
 #[cfg(test)]
 mod multiplier_tests {
 	use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
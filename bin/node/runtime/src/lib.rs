@@ -97,7 +97,9 @@ pub use sp_runtime::BuildStorage;
 pub mod impls;
 #[cfg(not(feature = "runtime-benchmarks"))]
 use impls::AllianceIdentityVerifier;
-use impls::{AllianceProposalProvider, Author, CreditToBlockAuthor};
+use impls::{
+	AllianceProposalProvider, Author, CreditToBlockAuthor, EnsureRootReviewOf, RankedPollsVotes,
+};
 
 /// Constant values used within the runtime.
 pub mod constants;
@@ -367,6 +369,7 @@ parameter_types! {
 	pub const PreimageBaseDeposit: Balance = 1 * DOLLARS;
 	// One cent: $10,000 / MB
 	pub const PreimageByteDeposit: Balance = 1 * CENTS;
+	pub const PreimageMaxRequests: u32 = 1024;
 }
 
 impl pallet_preimage::Config for Runtime {
@@ -376,6 +379,10 @@ impl pallet_preimage::Config for Runtime {
 	type ManagerOrigin = EnsureRoot<AccountId>;
 	type BaseDeposit = PreimageBaseDeposit;
 	type ByteDeposit = PreimageByteDeposit;
+	type MaxRequests = PreimageMaxRequests;
+	type CheckCanonicalEncoding = ConstBool<false>;
+	type RequestExpiry = ();
+	type MaxExpiriesPerBlock = ConstU32<100>;
 }
 
 parameter_types! {
@@ -879,6 +886,7 @@ impl pallet_referenda::Config for Runtime {
 	type AlarmInterval = AlarmInterval;
 	type Tracks = TracksInfo;
 	type Preimages = Preimage;
+	type OnPollCompleted = ();
 }
 
 impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
@@ -899,6 +907,13 @@ impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type AlarmInterval = AlarmInterval;
 	type Tracks = TracksInfo;
 	type Preimages = Preimage;
+	type OnPollCompleted = RankedCollective;
+}
+
+parameter_types! {
+	pub RankedCollectiveIdleCleanupWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 10;
+	pub const RankedCollectiveRankSyncPolicy: pallet_ranked_collective::SyncPolicy =
+		pallet_ranked_collective::SyncPolicy::Manual;
 }
 
 impl pallet_ranked_collective::Config for Runtime {
@@ -906,9 +921,38 @@ impl pallet_ranked_collective::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PromoteOrigin = EnsureRootWithSuccess<AccountId, ConstU16<65535>>;
 	type DemoteOrigin = EnsureRootWithSuccess<AccountId, ConstU16<65535>>;
+	type MembershipOrigin = pallet_ranked_collective::EnsureSelfDemotion<Runtime, ()>;
 	type Polls = RankedPolls;
 	type MinRankOfClass = traits::Identity;
 	type VoteWeight = pallet_ranked_collective::Geometric;
+	type SupportIncludesNays = ConstBool<false>;
+	type SnapshotVoters = ConstBool<false>;
+	type CompactVoteEvents = ConstBool<false>;
+	type Randomness = RandomnessCollectiveFlip;
+	type PanelOrigin = EnsureRoot<AccountId>;
+	type MaxPanelSize = ConstU32<100>;
+	type IdleCleanupWeight = RankedCollectiveIdleCleanupWeight;
+	type ResignationCooldown = ConstU32<{ 7 * DAYS }>;
+	type MaxRankChanges = ConstU32<100>;
+	type MaxFreeVotes = ConstU32<100>;
+	type FreeVotePeriod = ConstU32<DAYS>;
+	type VetoRank = ConstU16<3>;
+	type VetoThreshold = ConstU32<3>;
+	type VetoPeriod = ConstU32<{ 2 * DAYS }>;
+	type MembershipNft = ();
+	type ReviewOrigin = EnsureRootReviewOf;
+	type MaxDelegators = ConstU32<16>;
+	type ExchangeOrigin = EnsureRoot<AccountId>;
+	type SplitTarget = frame_support::traits::ranked::NoMembers<AccountId>;
+	type DemotionPeriod = ();
+	type MaxInactivityChecks = ConstU32<0>;
+	type MaxMembersPerRank = ConstU32<1000>;
+	type OnMemberAdded = ();
+	type OnRankChanged = ();
+	type OnMemberRemoved = ();
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type RankSource = frame_support::traits::ranked::NoMembers<AccountId>;
+	type RankSyncPolicy = RankedCollectiveRankSyncPolicy;
 }
 
 impl pallet_remark::Config for Runtime {
@@ -1532,6 +1576,9 @@ parameter_types! {
 	pub const ApprovalsLimit: u32 = 20;
 	pub const ItemAttributesApprovalsLimit: u32 = 20;
 	pub const MaxTips: u32 = 10;
+	pub const MaxRoyaltyBeneficiaries: u32 = 10;
+	pub const MaxRoyalty: Permill = Permill::from_percent(50);
+	pub const MaxAttributesPerCall: u32 = 10;
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
 }
 
@@ -1558,6 +1605,7 @@ impl pallet_uniques::Config for Runtime {
 
 parameter_types! {
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub const NftsPalletId: PalletId = PalletId(*b"py/nfts_");
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -1577,8 +1625,25 @@ impl pallet_nfts::Config for Runtime {
 	type ApprovalsLimit = ApprovalsLimit;
 	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
 	type MaxTips = MaxTips;
+	type MaxRoyaltyBeneficiaries = MaxRoyaltyBeneficiaries;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type RemoveItemsLimit = ConstU32<250>;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
+	type MaxRevealCommitments = ConstU32<16>;
+	type ProvenanceLength = ConstU32<10>;
 	type Features = Features;
+	type DepositAssets = Assets;
+	type DepositAssetFilter = Everything;
+	type DepositConversion = pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>;
+	type PalletId = NftsPalletId;
+	type Randomness = RandomnessCollectiveFlip;
+	type RandomMintDeposit = ItemDeposit;
+	// Longer than the 81 blocks for which `RandomnessCollectiveFlip` is known in advance.
+	type RandomMintDelay = ConstU32<{ 10 * MINUTES }>;
+	type RandomMintRevealPeriod = ConstU32<{ 2 * HOURS }>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
 	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
@@ -1809,6 +1874,15 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_ranked_collective::migration::v1::MigrateToV1<Runtime, (), RankedPollsVotes>,
+	pallet_ranked_collective::migration::v2::MigrateToV2<Runtime, (), RankedPollsVotes>,
+	pallet_ranked_collective::migration::v3::MigrateToV3<Runtime, ()>,
+	pallet_ranked_collective::migration::v4::MigrateToV4<Runtime, ()>,
+	pallet_ranked_collective::migration::v5::MigrateToV5<Runtime, (), RankedPollsVotes>,
+	pallet_ranked_collective::migration::v6::MigrateToV6<Runtime, (), RankedPollsVotes>,
+	pallet_ranked_collective::migration::v7::MigrateToV7<Runtime, ()>,
+	pallet_ranked_collective::migration::v8::MigrateToV8<Runtime, (), ConstU32<1_000>>,
+	pallet_nfts::migration::v1::MigrateToV1<Runtime>,
 );
 
 /// MMR helper types.
@@ -1837,6 +1911,7 @@ mod benches {
 		[pallet_bounties, Bounties]
 		[pallet_child_bounties, ChildBounties]
 		[pallet_collective, Council]
+		[pallet_collective, TechnicalCommittee]
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_contracts, Contracts]
 		[pallet_democracy, Democracy]
@@ -1860,6 +1935,7 @@ mod benches {
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, RankedCollective]
 		[pallet_referenda, Referenda]
+		[pallet_referenda, RankedPolls]
 		[pallet_recovery, Recovery]
 		[pallet_remark, Remark]
 		[pallet_scheduler, Scheduler]
@@ -1976,6 +2052,87 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(6)]
+	impl pallet_ranked_collective_runtime_api::RankedCollectiveApi<
+		Block,
+		AccountId,
+		pallet_referenda::ReferendumIndex,
+		pallet_ranked_collective::TallyOf<Runtime>,
+		Hash,
+		BlockNumber,
+	> for Runtime
+	{
+		fn dry_run_vote(
+			who: AccountId,
+			poll: pallet_referenda::ReferendumIndex,
+			aye: bool,
+		) -> Result<(), pallet_ranked_collective_runtime_api::VoteRejection> {
+			RankedCollective::dry_run_vote(who, poll, aye)
+		}
+
+		fn tally_certificate(
+			poll: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_ranked_collective::TallyCertificateOf<Runtime>> {
+			RankedCollective::tally_certificate(poll)
+		}
+
+		fn tally(
+			poll: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_ranked_collective::TallyOf<Runtime>> {
+			RankedCollective::tally_of(poll)
+		}
+
+		fn members(at_least_rank: pallet_ranked_collective_runtime_api::Rank) -> Vec<AccountId> {
+			RankedCollective::members_of_rank(at_least_rank).collect()
+		}
+
+		fn vote_of(
+			poll: pallet_referenda::ReferendumIndex,
+			who: AccountId,
+		) -> Option<pallet_ranked_collective_runtime_api::VoteRecord> {
+			RankedCollective::vote_of(poll, &who)
+		}
+
+		fn voting_power(
+			who: AccountId,
+			class: pallet_ranked_collective_runtime_api::Rank,
+		) -> Option<pallet_ranked_collective_runtime_api::Votes> {
+			RankedCollective::voting_power(&who, class)
+		}
+
+		fn votable_polls(
+			who: AccountId,
+		) -> Vec<(
+			pallet_referenda::ReferendumIndex,
+			pallet_ranked_collective_runtime_api::Rank,
+			pallet_ranked_collective::TallyOf<Runtime>,
+		)> {
+			RankedCollective::votable_polls(&who)
+		}
+	}
+
+	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, Balance, BlockNumber, u32, u32>
+		for Runtime
+	{
+		fn provenance(
+			collection: u32,
+			item: u32,
+		) -> Vec<pallet_nfts_runtime_api::ProvenanceRecord<AccountId, Balance, BlockNumber>> {
+			Nfts::provenance(collection, item)
+		}
+	}
+
+	impl pallet_preimage_runtime_api::PreimageApi<Block, Hash> for Runtime {
+		fn preimage_availability(
+			calls: Vec<(Hash, u32)>,
+		) -> pallet_preimage_runtime_api::PreimageAvailability {
+			use pallet_scheduler::WeightInfo;
+			Preimage::availability(&calls, |len| {
+				<Runtime as pallet_scheduler::Config>::WeightInfo::service_task_fetched(len)
+			})
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -2222,7 +2379,7 @@ impl_runtime_apis! {
 			Vec<frame_benchmarking::BenchmarkList>,
 			Vec<frame_support::traits::StorageInfo>,
 		) {
-			use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
+			use frame_benchmarking::{baseline, BenchmarkList};
 			use frame_support::traits::StorageInfoTrait;
 
 			// Trying to add benchmarks directly to the Session Pallet caused cyclic dependency
@@ -2246,7 +2403,7 @@ impl_runtime_apis! {
 		fn dispatch_benchmark(
 			config: frame_benchmarking::BenchmarkConfig
 		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
-			use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch,  TrackedStorageKey};
+			use frame_benchmarking::{baseline, BenchmarkBatch,  TrackedStorageKey};
 
 			// Trying to add benchmarks directly to the Session Pallet caused cyclic dependency
 			// issues. To get around that, we separated the Session benchmarks into its own crate,
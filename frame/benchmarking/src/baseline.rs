@@ -81,7 +81,6 @@ benchmarks! {
 	}
 
 	hashing {
-		let i in 0 .. 100;
 		let mut hash = T::Hash::default();
 	}: {
 		(0..=100_000u32).for_each(|j| hash = T::Hashing::hash(&j.to_be_bytes()));
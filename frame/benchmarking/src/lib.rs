@@ -88,6 +88,17 @@ macro_rules! whitelist {
 /// Note that due to parsing restrictions, if the `from` expression is not a single token (i.e. a
 /// literal or constant), then it must be parenthesized.
 ///
+/// A parameter which is not naturally a `u32` may be given a type with `as`; its value is then
+/// converted by [`FromComponent`] before any instancing happens. The range itself stays a `u32`
+/// pair, and both of its ends must be single tokens (parenthesize anything else):
+///
+/// ```ignore
+/// // `p` is a `Perbill` going from 0% to 100%.
+/// let p in 0 .. 100 as Perbill;
+/// // `d` is a `u64` going from 1 to `MAX_DELAY`.
+/// let d in 1 .. MAX_DELAY as u64 => schedule_in(d)?;
+/// ```
+///
 /// The macro allows for a number of "arms", each representing an individual benchmark. Using the
 /// simple syntax, the associated dispatchable function maps 1:1 with the benchmark and the name of
 /// the benchmark is the same as that of the associated function. However, extended syntax allows
@@ -143,6 +154,12 @@ macro_rules! whitelist {
 ///     let l in 1 .. MAX_LENGTH => initialize_l(l);
 ///   }: baz<T::RuntimeOrigin>(RuntimeOrigin::Signed(caller), vec![0u8; l])
 ///
+///   // The origin may be any expression which converts into the origin of the call, such as
+///   // `RawOrigin::Root`, `RawOrigin::None` or the successful origin of an `EnsureOrigin`.
+///   qux {
+///     let origin = T::AdminOrigin::successful_origin();
+///   }: qux<T::RuntimeOrigin>(origin, 0)
+///
 ///   // this is benchmarking some code that is not a dispatchable.
 ///   populate_a_set {
 ///     let x in 0 .. 10_000;
@@ -183,7 +200,15 @@ macro_rules! whitelist {
 /// }
 /// ```
 ///
-/// These `verify` blocks will not affect your benchmark results!
+/// These `verify` blocks will not affect your benchmark results! Bindings of the setup which are
+/// only used by `verify` are computed before the measurement like all others.
+///
+/// Every component must be used by its benchmark: a component which changes nothing only adds
+/// noise to the fitted weight. An unused component is reported by the `unused_variables` lint at
+/// the `let`, as a warning which `-D warnings` turns into an error. A component may set its own
+/// level for the lint, e.g. `#[allow(unused_variables)] let x in 0 .. 1;` for a component which
+/// is kept only to match an existing weight function, or `#[deny(unused_variables)]` to always
+/// make it an error.
 ///
 /// You can construct benchmark by using the `impl_benchmark_test_suite` macro or
 /// by manually implementing them like so:
@@ -650,6 +675,37 @@ macro_rules! benchmark_backend {
 			$postcode
 		}
 	};
+	// arm for a component which is converted into another type.
+	(
+		{ $( $instance:ident: $instance_bound:tt )? }
+		$name:ident
+		{ $( $where_clause:tt )* }
+		{ $( $parsed:tt )* }
+		{ $eval:block }
+		{
+			$( #[$lint:ident(unused_variables)] )?
+			let $param:ident in $param_from:tt .. $param_to:tt as $param_ty:ty
+				=> $param_instancer:expr ;
+			$( $rest:tt )*
+		}
+		$postcode:block
+	) => {
+		$crate::benchmark_backend! {
+			{ $( $instance: $instance_bound )? }
+			$name
+			{ $( $where_clause )* }
+			{
+				$( $parsed )*
+				PARAM {
+					[ $( $lint )? ] $param , $param_ty , $param_from , $param_to , $param_instancer
+				}
+			}
+			{ $eval }
+			{ $( $rest )* }
+			$postcode
+		}
+	};
+	// mutation arm to look after the default tail of `=> ()` for a converted component.
 	(
 		{ $( $instance:ident: $instance_bound:tt )? }
 		$name:ident
@@ -657,6 +713,34 @@ macro_rules! benchmark_backend {
 		{ $( $parsed:tt )* }
 		{ $eval:block }
 		{
+			$( #[$lint:ident(unused_variables)] )?
+			let $param:ident in $param_from:tt .. $param_to:tt as $param_ty:ty;
+			$( $rest:tt )*
+		}
+		$postcode:block
+	) => {
+		$crate::benchmark_backend! {
+			{ $( $instance: $instance_bound )? }
+			$name
+			{ $( $where_clause )* }
+			{ $( $parsed )* }
+			{ $eval }
+			{
+				$( #[$lint(unused_variables)] )?
+				let $param in $param_from .. $param_to as $param_ty => ();
+				$( $rest )*
+			}
+			$postcode
+		}
+	};
+	(
+		{ $( $instance:ident: $instance_bound:tt )? }
+		$name:ident
+		{ $( $where_clause:tt )* }
+		{ $( $parsed:tt )* }
+		{ $eval:block }
+		{
+			$( #[$lint:ident(unused_variables)] )?
 			let $param:ident in ( $param_from:expr ) .. $param_to:expr => $param_instancer:expr;
 			$( $rest:tt )*
 		}
@@ -668,7 +752,9 @@ macro_rules! benchmark_backend {
 			{ $( $where_clause )* }
 			{
 				$( $parsed )*
-				PARAM { $param , $param_from , $param_to , $param_instancer }
+				PARAM {
+					[ $( $lint )? ] $param , u32 , $param_from , $param_to , $param_instancer
+				}
 			}
 			{ $eval }
 			{ $( $rest )* }
@@ -683,6 +769,7 @@ macro_rules! benchmark_backend {
 		{ $( $parsed:tt )* }
 		{ $eval:block }
 		{
+			$( #[$lint:ident(unused_variables)] )?
 			let $param:ident in $param_from:tt .. $param_to:expr => $param_instancer:expr ;
 			$( $rest:tt )*
 		}
@@ -695,6 +782,7 @@ macro_rules! benchmark_backend {
 			{ $( $parsed )* }
 			{ $eval }
 			{
+				$( #[$lint(unused_variables)] )?
 				let $param in ( $param_from ) .. $param_to => $param_instancer;
 				$( $rest )*
 			}
@@ -709,6 +797,7 @@ macro_rules! benchmark_backend {
 		{ $( $parsed:tt )* }
 		{ $eval:block }
 		{
+			$( #[$lint:ident(unused_variables)] )?
 			let $param:ident in $param_from:tt .. $param_to:expr;
 			$( $rest:tt )*
 		}
@@ -721,6 +810,7 @@ macro_rules! benchmark_backend {
 			{ $( $parsed )* }
 			{ $eval }
 			{
+				$( #[$lint(unused_variables)] )?
 				let $param in $param_from .. $param_to => ();
 				$( $rest )*
 			}
@@ -760,7 +850,10 @@ macro_rules! benchmark_backend {
 		{ $( $where_clause:tt )* }
 		{
 			$( PRE { $pre_id:tt , $pre_ty:ty , $pre_ex:expr } )*
-			$( PARAM { $param:ident , $param_from:expr , $param_to:expr , $param_instancer:expr } )*
+			$( PARAM {
+				[ $( $lint:ident )? ]
+				$param:ident , $param_ty:ty , $param_from:expr , $param_to:expr , $param_instancer:expr
+			} )*
 		}
 		{ $eval:block }
 		{ $( $post:tt )* }
@@ -787,11 +880,16 @@ macro_rules! benchmark_backend {
 				verify: bool
 			) -> Result<$crate::Box<dyn FnOnce() -> Result<(), $crate::BenchmarkError>>, $crate::BenchmarkError> {
 				$(
-					// Prepare instance
-					let $param = components.iter()
-						.find(|&c| c.0 == $crate::BenchmarkParameter::$param)
-						.ok_or("Could not find component in benchmark preparation.")?
-						.1;
+					// Prepare instance. Components are linted even though setup bindings are not,
+					// unless the component gives its own level for the lint.
+					#[warn(unused_variables)]
+					$( #[$lint(unused_variables)] )?
+					let $param: $param_ty = $crate::FromComponent::from_component(
+						components.iter()
+							.find(|&c| c.0 == $crate::BenchmarkParameter::$param)
+							.ok_or("Could not find component in benchmark preparation.")?
+							.1
+					);
 				)*
 				$(
 					let $pre_id : $pre_ty = $pre_ex;
@@ -1768,7 +1866,7 @@ macro_rules! add_benchmark {
 			internal_repeats,
 		} = config;
 		if &pallet[..] == &name_string[..] {
-			let benchmark_result = $( $location )*::run_benchmark(
+			let benchmark_result = <$( $location )* as $crate::Benchmarking>::run_benchmark(
 				&benchmark[..],
 				&selected_components[..],
 				whitelist,
@@ -1814,7 +1912,7 @@ macro_rules! add_benchmark {
 						$crate::str::from_utf8(benchmark)
 							.expect("benchmark name is always a valid string!")
 					);
-					Some(vec![$crate::BenchmarkResult {
+					Some($crate::vec![$crate::BenchmarkResult {
 						components: selected_components.clone(),
 						.. Default::default()
 					}])
@@ -1871,8 +1969,8 @@ macro_rules! list_benchmark {
 	( $list:ident, $extra:ident, $name:path, $( $location:tt )* ) => (
 		let pallet_string = stringify!($name).as_bytes();
 		let instance_string = stringify!( $( $location )* ).as_bytes();
-		let benchmarks = $( $location )*::benchmarks($extra);
-		let pallet_benchmarks = BenchmarkList {
+		let benchmarks = <$( $location )* as $crate::Benchmarking>::benchmarks($extra);
+		let pallet_benchmarks = $crate::BenchmarkList {
 			pallet: pallet_string.to_vec(),
 			instance: instance_string.to_vec(),
 			benchmarks: benchmarks.to_vec(),
@@ -1898,6 +1996,16 @@ macro_rules! cb_list_benchmarks {
 /// Defines pallet configs that `add_benchmarks` and `list_benchmarks` use.
 /// Should be preferred instead of having a repetitive list of configs
 /// in `add_benchmark` and `list_benchmark`.
+///
+/// An instantiable pallet is listed once per instance to benchmark, each with its own module
+/// struct, which names the instance in the results:
+///
+/// ```ignore
+/// define_benchmarks!(
+/// 	[pallet_collective, Council]
+/// 	[pallet_collective, TechnicalCommittee]
+/// );
+/// ```
 
 #[macro_export]
 macro_rules! define_benchmarks {
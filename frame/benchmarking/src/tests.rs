@@ -71,6 +71,14 @@ mod pallet_test {
 		pub fn always_error(_origin: OriginFor<T>) -> DispatchResult {
 			return Err("I always fail".into())
 		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(0)]
+		pub fn force_set_value(origin: OriginFor<T>, n: u32) -> DispatchResult {
+			ensure_root(origin)?;
+			Value::<T>::put(n);
+			Ok(())
+		}
 	}
 }
 
@@ -143,6 +151,7 @@ mod benchmarks {
 	use frame_support::{assert_err, assert_ok, ensure, traits::Get};
 	use frame_system::RawOrigin;
 	use rusty_fork::rusty_fork_test;
+	use sp_runtime::Perbill;
 	use sp_std::prelude::*;
 
 	// Additional used internally by the benchmark macro.
@@ -202,6 +211,28 @@ mod benchmarks {
 			let b in ( T::LowerBound::get() ) .. T::UpperBound::get();
 		}: dummy (RawOrigin::None, b.into())
 
+		// A component may opt out of the lint for unused components.
+		allowed_unused_component {
+			#[allow(unused_variables)]
+			let b in 1 .. 1000;
+			let caller = account::<T::AccountId>("caller", 0, 0);
+		}: set_value(RawOrigin::Signed(caller), 0)
+
+		root_origin {
+			let b in 1 .. 1000;
+		}: force_set_value(RawOrigin::Root, b)
+		verify {
+			assert_eq!(Value::<T>::get(), Some(b));
+		}
+
+		// The origin may be any expression, here one which is already a `RuntimeOrigin`.
+		origin_expression {
+			let origin = T::RuntimeOrigin::from(RawOrigin::Root);
+		}: force_set_value<T::RuntimeOrigin>(origin, 42)
+		verify {
+			assert_eq!(Value::<T>::get(), Some(42));
+		}
+
 		#[extra]
 		extra_benchmark {
 			let b in 1 .. 1000;
@@ -221,7 +252,6 @@ mod benchmarks {
 		}
 
 		override_benchmark {
-			let b in 1 .. 1000;
 			let caller = account::<T::AccountId>("caller", 0, 0);
 		}: {
 			Err(BenchmarkError::Override(
@@ -265,6 +295,14 @@ mod benchmarks {
 		}: {
 			VALUES_PER_COMPONENT.with(|v| v.borrow_mut().push(n));
 		}
+
+		// Gets its components converted into other types.
+		typed_components {
+			let p in 0 .. 100 as Perbill;
+			let d in 1 .. 1000 as u64 => ensure!(d > 0, "`d` starts at one");
+		}: {
+			Value::<T>::set(Some((p * d) as u32));
+		}
 	}
 
 	#[test]
@@ -287,6 +325,22 @@ mod benchmarks {
 		});
 	}
 
+	#[test]
+	fn benchmarks_macro_works_for_any_origin() {
+		for selected in [SelectedBenchmark::root_origin, SelectedBenchmark::origin_expression] {
+			let closure = <SelectedBenchmark as BenchmarkingSetup<Test>>::instance(
+				&selected,
+				&[(BenchmarkParameter::b, 7)],
+				true,
+			)
+			.expect("failed to create closure");
+
+			new_test_ext().execute_with(|| {
+				assert_ok!(closure());
+			});
+		}
+	}
+
 	#[test]
 	fn benchmarks_macro_rename_works() {
 		// Check benchmark creation for `other_dummy`.
@@ -306,6 +360,27 @@ mod benchmarks {
 		});
 	}
 
+	#[test]
+	fn benchmarks_metadata_is_ordered_and_digested() {
+		use crate::Benchmarking;
+		use codec::Encode;
+
+		let names = Pallet::<Test>::benchmarks(false)
+			.into_iter()
+			.map(|b| String::from_utf8(b.name).unwrap())
+			.collect::<Vec<_>>();
+		// Benchmarks are listed in definition order.
+		assert_eq!(&names[..3], &["set_value", "other_name", "sort_vector"]);
+
+		// The digest is stable and covers the benchmark names and component ranges.
+		let digest = Pallet::<Test>::benchmarks_digest(false);
+		assert_eq!(digest, Pallet::<Test>::benchmarks_digest(false));
+		let mut metadata = Pallet::<Test>::benchmarks(false);
+		assert_eq!(digest, sp_io::hashing::blake2_256(&metadata.encode()));
+		metadata[0].components[0].2 += 1;
+		assert_ne!(digest, sp_io::hashing::blake2_256(&metadata.encode()));
+	}
+
 	#[test]
 	fn benchmarks_macro_works_for_non_dispatchable() {
 		let selected = SelectedBenchmark::sort_vector;
@@ -323,6 +398,29 @@ mod benchmarks {
 		assert_ok!(closure());
 	}
 
+	#[test]
+	fn benchmarks_macro_converts_typed_components() {
+		let selected = SelectedBenchmark::typed_components;
+
+		let components = <SelectedBenchmark as BenchmarkingSetup<Test>>::components(&selected);
+		assert_eq!(
+			components,
+			vec![(BenchmarkParameter::p, 0, 100), (BenchmarkParameter::d, 1, 1000)]
+		);
+
+		let closure = <SelectedBenchmark as BenchmarkingSetup<Test>>::instance(
+			&selected,
+			&[(BenchmarkParameter::p, 25), (BenchmarkParameter::d, 400)],
+			true,
+		)
+		.expect("failed to create closure");
+
+		new_test_ext().execute_with(|| {
+			assert_ok!(closure());
+			assert_eq!(Value::<Test>::get(), Some(100));
+		});
+	}
+
 	#[test]
 	fn benchmarks_macro_verify_works() {
 		// Check postcondition for benchmark `set_value` is valid.
@@ -360,7 +458,7 @@ mod benchmarks {
 
 		let closure = <SelectedBenchmark as BenchmarkingSetup<Test>>::instance(
 			&selected,
-			&[(BenchmarkParameter::b, 1)],
+			&[],
 			true,
 		)
 		.expect("failed to create closure");
@@ -381,6 +479,7 @@ mod benchmarks {
 			assert_err!(Pallet::<Test>::test_benchmark_bad_verify(), "You forgot to sort!");
 			assert_ok!(Pallet::<Test>::test_benchmark_no_components());
 			assert_ok!(Pallet::<Test>::test_benchmark_variable_components());
+			assert_ok!(Pallet::<Test>::test_benchmark_allowed_unused_component());
 			assert!(matches!(
 				Pallet::<Test>::test_benchmark_override_benchmark(),
 				Err(BenchmarkError::Override(_)),
@@ -89,6 +89,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		TestPallet: pallet_test::{Pallet, Call, Storage, Event<T>},
+		TestPallet2: pallet_test::<Instance2>::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -125,6 +126,12 @@ impl pallet_test::Config for Test {
 	type UpperBound = ConstU32<100>;
 }
 
+impl pallet_test::Config<pallet_test::Instance2> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type LowerBound = ConstU32<10>;
+	type UpperBound = ConstU32<20>;
+}
+
 impl pallet_test::OtherConfig for Test {
 	type OtherEvent = RuntimeEvent;
 }
@@ -136,7 +143,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
 mod benchmarks {
 	use super::pallet_test::{self, Value};
 	use crate::account;
-	use frame_support::ensure;
+	use frame_support::{ensure, traits::Get};
 	use frame_system::RawOrigin;
 	use sp_std::prelude::*;
 
@@ -163,6 +170,10 @@ mod benchmarks {
 			let b in 1 .. 1000;
 		}: dummy (RawOrigin::None, b.into())
 
+		bounded {
+			let b in (T::LowerBound::get()) .. T::UpperBound::get();
+		}: dummy (RawOrigin::None, b.into())
+
 		sort_vector {
 			let x in 1 .. 10000;
 			let mut m = Vec::<u32>::new();
@@ -181,4 +192,41 @@ mod benchmarks {
 			crate::tests_instance::Test
 		)
 	}
+
+	#[test]
+	fn benchmarks_are_generated_for_each_instance() {
+		use super::{pallet_test::Instance2, Test};
+
+		crate::tests_instance::new_test_ext().execute_with(|| {
+			assert!(Pallet::<Test, Instance2>::test_benchmark_set_value().is_ok());
+			assert!(Pallet::<Test, Instance2>::test_benchmark_bounded().is_ok());
+		});
+	}
+
+	#[test]
+	fn instances_are_listed_separately() {
+		use super::{TestPallet, TestPallet2};
+		use crate::{BenchmarkList, BenchmarkParameter};
+
+		let mut list = Vec::<BenchmarkList>::new();
+		let extra = false;
+		crate::list_benchmark!(list, extra, pallet_test, TestPallet);
+		crate::list_benchmark!(list, extra, pallet_test, TestPallet2);
+
+		let instances = list.iter().map(|l| l.instance.clone()).collect::<Vec<_>>();
+		assert_eq!(instances, vec![b"TestPallet".to_vec(), b"TestPallet2".to_vec()]);
+		assert!(list.iter().all(|l| l.pallet == b"pallet_test".to_vec()));
+
+		// Each instance reports the components of its own configuration.
+		let components_of_bounded = |l: &BenchmarkList| {
+			l.benchmarks
+				.iter()
+				.find(|b| b.name == b"bounded".to_vec())
+				.unwrap()
+				.components
+				.clone()
+		};
+		assert_eq!(components_of_bounded(&list[0]), vec![(BenchmarkParameter::b, 1, 100)]);
+		assert_eq!(components_of_bounded(&list[1]), vec![(BenchmarkParameter::b, 10, 20)]);
+	}
 }
@@ -25,7 +25,7 @@ use frame_support::{
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::TrailingZeroInput;
+use sp_runtime::{traits::TrailingZeroInput, PerU16, Perbill, Percent, Permill, Perquintill};
 use sp_std::{prelude::Box, vec::Vec};
 use sp_storage::TrackedStorageKey;
 
@@ -329,11 +329,23 @@ pub trait Benchmarking {
 	/// Get the benchmarks available for this pallet. Generally there is one benchmark per
 	/// extrinsic, so these are sometimes just called "extrinsics".
 	///
+	/// The benchmarks are returned in the order in which they are defined in the `benchmarks!`
+	/// invocation, so the result is deterministic for a given set of definitions.
+	///
 	/// Parameters
 	/// - `extra`: Also return benchmarks marked "extra" which would otherwise not be needed for
 	///   weight calculation.
 	fn benchmarks(extra: bool) -> Vec<BenchmarkMetadata>;
 
+	/// A digest of the names and component ranges of the benchmarks of this pallet.
+	///
+	/// Any change to the benchmark definitions that could affect the generated weights changes
+	/// the digest, so tooling can compare it against the digest recorded when the weight file
+	/// was generated in order to detect stale weights.
+	fn benchmarks_digest(extra: bool) -> [u8; 32] {
+		blake2_256(&Self::benchmarks(extra).encode())
+	}
+
 	/// Run the benchmarks for this pallet.
 	fn run_benchmark(
 		name: &[u8],
@@ -360,6 +372,40 @@ pub trait BenchmarkingSetup<T, I = ()> {
 	) -> Result<Box<dyn FnOnce() -> Result<(), BenchmarkError>>, BenchmarkError>;
 }
 
+/// Conversion of a benchmark component into the type of the parameter it stands for.
+///
+/// Components are always `u32`; this lets the `benchmarks!` macro hand them to the benchmark
+/// setup as e.g. a balance or a fraction.
+pub trait FromComponent {
+	/// Convert the component value `c`.
+	fn from_component(c: u32) -> Self;
+}
+
+macro_rules! impl_from_component_for_integer {
+	( $( $int:ty ),* ) => { $(
+		impl FromComponent for $int {
+			fn from_component(c: u32) -> Self {
+				c.into()
+			}
+		}
+	)* }
+}
+
+impl_from_component_for_integer!(u32, u64, u128);
+
+macro_rules! impl_from_component_for_per_thing {
+	( $( $per_thing:ty ),* ) => { $(
+		/// The component is a percentage; values above 100 saturate to one.
+		impl FromComponent for $per_thing {
+			fn from_component(c: u32) -> Self {
+				<$per_thing>::from_rational(u128::from(c.min(100)), 100)
+			}
+		}
+	)* }
+}
+
+impl_from_component_for_per_thing!(Percent, PerU16, Permill, Perbill, Perquintill);
+
 /// Grab an account, seeded by a name and index.
 pub fn account<AccountId: Decode>(name: &'static str, index: u32, seed: u32) -> AccountId {
 	let entropy = (name, index, seed).using_encoded(blake2_256);
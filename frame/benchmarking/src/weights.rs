@@ -51,7 +51,7 @@ pub trait WeightInfo {
 	fn subtraction(i: u32, ) -> Weight;
 	fn multiplication(i: u32, ) -> Weight;
 	fn division(i: u32, ) -> Weight;
-	fn hashing(i: u32, ) -> Weight;
+	fn hashing() -> Weight;
 	fn sr25519_verification(i: u32, ) -> Weight;
 	fn storage_read(i: u32, ) -> Weight;
 	fn storage_write(i: u32, ) -> Weight;
@@ -80,8 +80,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 96 nanoseconds.
 		Weight::from_ref_time(136_059 as u64)
 	}
-	/// The range of component `i` is `[0, 100]`.
-	fn hashing(_i: u32, ) -> Weight {
+	fn hashing() -> Weight {
 		// Minimum execution time: 21_804_747 nanoseconds.
 		Weight::from_ref_time(22_013_681_386 as u64)
 	}
@@ -134,8 +133,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 96 nanoseconds.
 		Weight::from_ref_time(136_059 as u64)
 	}
-	/// The range of component `i` is `[0, 100]`.
-	fn hashing(_i: u32, ) -> Weight {
+	fn hashing() -> Weight {
 		// Minimum execution time: 21_804_747 nanoseconds.
 		Weight::from_ref_time(22_013_681_386 as u64)
 	}
@@ -118,6 +118,10 @@ impl pallet_preimage::Config for Test {
 	type ManagerOrigin = EnsureRoot<u64>;
 	type BaseDeposit = ConstU64<0>;
 	type ByteDeposit = ConstU64<0>;
+	type MaxRequests = ConstU32<100>;
+	type CheckCanonicalEncoding = frame_support::traits::ConstBool<false>;
+	type RequestExpiry = ();
+	type MaxExpiriesPerBlock = ConstU32<0>;
 }
 
 impl pallet_scheduler::Config for Test {
@@ -24,8 +24,6 @@ use sp_core::H256;
 
 benchmarks! {
 	check_equivocation_proof {
-		let x in 0 .. 1;
-
 		// NOTE: generated with the test below `test_generate_equivocation_report_blob`.
 		// the output should be deterministic since the keys we use are static.
 		// with the current benchmark setup it is not possible to generate this
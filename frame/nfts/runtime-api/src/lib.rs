@@ -0,0 +1,44 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for NFTs pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_nfts::ProvenanceRecord;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for querying the ownership history of the items of the NFTs pallet.
+	pub trait NftsApi<AccountId, Balance, BlockNumber, CollectionId, ItemId>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+			BlockNumber: Codec,
+			CollectionId: Codec,
+			ItemId: Codec,
+	{
+		/// Returns the last ownership changes of `item` in `collection`, oldest first.
+		///
+		/// This is empty unless the collection tracks provenance.
+		fn provenance(
+			collection: CollectionId,
+			item: ItemId,
+		) -> Vec<ProvenanceRecord<AccountId, Balance, BlockNumber>>;
+	}
+}
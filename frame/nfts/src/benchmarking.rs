@@ -20,18 +20,18 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use enumflags2::{BitFlag, BitFlags};
+use enumflags2::BitFlags;
 use frame_benchmarking::{
 	account, benchmarks_instance_pallet, whitelist_account, whitelisted_caller,
 };
 use frame_support::{
 	assert_ok,
 	dispatch::UnfilteredDispatchable,
-	traits::{EnsureOrigin, Get},
+	traits::{EnsureOrigin, Get, Hooks},
 	BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::{Bounded, One};
+use sp_runtime::traits::{Bounded, Hash, One};
 use sp_std::prelude::*;
 
 use crate::Pallet as Nfts;
@@ -122,6 +122,49 @@ fn add_item_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn setup_random_mint<T: Config<I>, I: 'static>(
+	collection: T::CollectionId,
+	caller: &T::AccountId,
+	max_supply: u32,
+) {
+	let origin: T::RuntimeOrigin = SystemOrigin::Signed(caller.clone()).into();
+	assert_ok!(Nfts::<T, I>::set_collection_max_supply(origin.clone(), collection, max_supply));
+	assert_ok!(Nfts::<T, I>::enable_random_mint(origin, collection));
+}
+
+fn make_collection_royalty<T: Config<I>, I: 'static>(
+	beneficiaries: u32,
+) -> CollectionRoyaltyFor<T, I> {
+	let share = Permill::from_parts(Permill::one().deconstruct() / beneficiaries);
+	let beneficiaries = (0..beneficiaries)
+		.map(|i| {
+			let beneficiary: T::AccountId = account("beneficiary", i, SEED);
+			T::Currency::make_free_balance_be(&beneficiary, T::Currency::minimum_balance());
+			(beneficiary, share)
+		})
+		.collect::<Vec<_>>();
+	let mut beneficiaries: RoyaltyBeneficiariesOf<T, I> = beneficiaries.try_into().unwrap();
+	// Whatever doesn't divide evenly goes to the last beneficiary.
+	let total = share.deconstruct() * beneficiaries.len() as u32;
+	if let Some((_, last)) = beneficiaries.last_mut() {
+		*last = Permill::from_parts(last.deconstruct() + Permill::one().deconstruct() - total);
+	}
+	CollectionRoyalty { percentage: T::MaxRoyalty::get(), beneficiaries }
+}
+
+fn set_collection_royalty<T: Config<I>, I: 'static>(
+	collection: T::CollectionId,
+	beneficiaries: u32,
+) {
+	if beneficiaries > 0 {
+		assert_ok!(Nfts::<T, I>::set_collection_royalty(
+			SystemOrigin::Root.into(),
+			collection,
+			Some(make_collection_royalty::<T, I>(beneficiaries)),
+		));
+	}
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -141,22 +184,23 @@ fn make_collection_config<T: Config<I>, I: 'static>(
 }
 
 fn default_collection_config<T: Config<I>, I: 'static>() -> CollectionConfigFor<T, I> {
-	make_collection_config::<T, I>(CollectionSetting::empty())
+	make_collection_config::<T, I>(CollectionSetting::UntrackedProvenance.into())
 }
 
 fn default_item_config() -> ItemConfig {
-	ItemConfig { settings: ItemSettings::all_enabled() }
+	ItemConfig { settings: ItemSettings::all_enabled(), soulbound: None }
 }
 
 benchmarks_instance_pallet! {
 	create {
 		let collection = T::Helper::collection(0);
-		let origin = T::CreateOrigin::successful_origin(&collection);
-		let caller = T::CreateOrigin::ensure_origin(origin.clone(), &collection).unwrap();
+		let config = default_collection_config::<T, I>();
+		let origin = T::CreateOrigin::successful_origin(&(collection, config));
+		let caller = T::CreateOrigin::ensure_origin(origin.clone(), &(collection, config)).unwrap();
 		whitelist_account!(caller);
 		let admin = T::Lookup::unlookup(caller.clone());
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
-		let call = Call::<T, I>::create { admin, config: default_collection_config::<T, I>() };
+		let call = Call::<T, I>::create { admin, config };
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
 		assert_last_event::<T, I>(Event::Created { collection: T::Helper::collection(0), creator: caller.clone(), owner: caller }.into());
@@ -171,29 +215,72 @@ benchmarks_instance_pallet! {
 	}
 
 	destroy {
+		let (collection, caller, _) = create_collection::<T, I>();
+		mint_item::<T, I>(0);
+		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
+	}: { Nfts::<T, I>::do_start_destroy(collection, witness, Some(caller))? }
+	verify {
+		assert_last_event::<T, I>(Event::DestructionStarted { collection }.into());
+	}
+
+	destroy_items {
 		let n in 0 .. 1_000;
-		let m in 0 .. 1_000;
-		let a in 0 .. 1_000;
 
 		let (collection, caller, _) = create_collection::<T, I>();
-		add_collection_metadata::<T, I>();
 		for i in 0..n {
 			mint_item::<T, I>(i as u16);
 		}
+		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
+		assert_ok!(Nfts::<T, I>::do_start_destroy(collection, witness, Some(caller)));
+	}: { Nfts::<T, I>::do_continue_destroy(collection, n)? }
+	verify {
+		assert_eq!(Collection::<T, I>::get(collection).unwrap().items, 0);
+	}
+
+	destroy_item_metadata {
+		let m in 0 .. 1_000;
+
+		let (collection, caller, _) = create_collection::<T, I>();
 		for i in 0..m {
-			if !Item::<T, I>::contains_key(collection, T::Helper::item(i as u16)) {
-				mint_item::<T, I>(i as u16);
-			}
+			mint_item::<T, I>(i as u16);
 			add_item_metadata::<T, I>(T::Helper::item(i as u16));
 		}
+		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
+		assert_ok!(Nfts::<T, I>::do_start_destroy(collection, witness, Some(caller)));
+		DestroyCursor::<T, I>::insert(collection, DestroyStage::ItemMetadata);
+	}: { Nfts::<T, I>::do_continue_destroy(collection, m)? }
+	verify {
+		assert_eq!(Collection::<T, I>::get(collection).unwrap().item_metadatas, 0);
+	}
+
+	destroy_attributes {
+		let a in 0 .. 1_000;
+
+		let (collection, caller, _) = create_collection::<T, I>();
 		for i in 0..a {
-			if !Item::<T, I>::contains_key(collection, T::Helper::item(i as u16)) {
-				mint_item::<T, I>(i as u16);
-			}
+			mint_item::<T, I>(i as u16);
 			add_item_attribute::<T, I>(T::Helper::item(i as u16));
 		}
 		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
-	}: _(SystemOrigin::Signed(caller), collection, witness)
+		assert_ok!(Nfts::<T, I>::do_start_destroy(collection, witness, Some(caller)));
+		DestroyCursor::<T, I>::insert(collection, DestroyStage::Attributes);
+	}: { Nfts::<T, I>::do_continue_destroy(collection, a)? }
+	verify {
+		assert_eq!(Collection::<T, I>::get(collection).unwrap().attributes, 0);
+	}
+
+	destroy_configs {
+		let c in 0 .. 1_000;
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		add_collection_metadata::<T, I>();
+		for i in 0..c {
+			mint_item::<T, I>(i as u16);
+		}
+		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
+		assert_ok!(Nfts::<T, I>::do_start_destroy(collection, witness, Some(caller)));
+		DestroyCursor::<T, I>::insert(collection, DestroyStage::Configs);
+	}: { Nfts::<T, I>::do_continue_destroy(collection, c + 1)? }
 	verify {
 		assert_last_event::<T, I>(Event::Destroyed { collection }.into());
 	}
@@ -282,6 +369,21 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::CollectionLocked { collection }.into());
 	}
 
+	pause_collection {
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller.clone()), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionPaused { collection }.into());
+	}
+
+	unpause_collection {
+		let (collection, caller, _) = create_collection::<T, I>();
+		Nfts::<T, I>::pause_collection(SystemOrigin::Signed(caller.clone()).into(), collection)?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionUnpaused { collection }.into());
+	}
+
 	transfer_ownership {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let target: T::AccountId = account("target", 0, SEED);
@@ -366,6 +468,34 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	set_attributes {
+		let n in 1 .. T::MaxAttributesPerCall::get();
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		let attributes: AttributesOf<T, I> = (0..n)
+			.map(|i| {
+				let mut key = i.to_le_bytes().to_vec();
+				key.resize(T::KeyLimit::get() as usize, 0);
+				(key.try_into().unwrap(), value.clone())
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), AttributeNamespace::CollectionOwner, attributes)
+	verify {
+		assert_last_event::<T, I>(
+			Event::AttributesSet {
+				collection,
+				maybe_item: Some(item),
+				namespace: AttributeNamespace::CollectionOwner,
+				count: n,
+			}
+			.into(),
+		);
+	}
+
 	force_set_attribute {
 		let key: BoundedVec<_, _> = vec![0u8; T::KeyLimit::get() as usize].try_into().unwrap();
 		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
@@ -578,24 +708,37 @@ benchmarks_instance_pallet! {
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let price = ItemPrice::<T, I>::from(100u32);
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), Some(delegate_lookup))
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::MaxDeadlineDuration::get();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), Some(delegate_lookup), Some(expires_at))
 	verify {
 		assert_last_event::<T, I>(Event::ItemPriceSet {
 			collection,
 			item,
 			price,
 			whitelisted_buyer: Some(delegate),
+			expires_at: Some(expires_at),
 		}.into());
 	}
 
 	buy_item {
+		let b in 0 .. T::MaxRoyaltyBeneficiaries::get();
+
 		let (collection, seller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		set_collection_royalty::<T, I>(collection, b);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = T::Currency::minimum_balance() * ItemPrice::<T, I>::from(1_000u32);
 		let origin = SystemOrigin::Signed(seller.clone()).into();
-		Nfts::<T, I>::set_price(origin, collection, item, Some(price.clone()), Some(buyer_lookup))?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::MaxDeadlineDuration::get();
+		Nfts::<T, I>::set_price(
+			origin,
+			collection,
+			item,
+			Some(price.clone()),
+			Some(buyer_lookup),
+			Some(expires_at),
+		)?;
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
 	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price.clone())
 	verify {
@@ -608,6 +751,28 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	clear_expired_listings {
+		let n in 0 .. 1_000;
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let expires_at = frame_system::Pallet::<T>::block_number();
+		for i in 0..n {
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			assert_ok!(Nfts::<T, I>::set_price(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				item,
+				Some(ItemPrice::<T, I>::from(100u32)),
+				None,
+				Some(expires_at),
+			));
+		}
+		frame_system::Pallet::<T>::set_block_number(expires_at + One::one());
+	}: _(SystemOrigin::Signed(caller), n)
+	verify {
+		assert_eq!(ItemPriceOf::<T, I>::iter().count(), 0);
+	}
+
 	pay_tips {
 		let n in 0 .. T::MaxTips::get() as u32;
 		let amount = BalanceOf::<T, I>::from(100u32);
@@ -677,16 +842,19 @@ benchmarks_instance_pallet! {
 	}
 
 	claim_swap {
+		let b in 0 .. T::MaxRoyaltyBeneficiaries::get();
+
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
-		let price = ItemPrice::<T, I>::from(0u32);
+		set_collection_royalty::<T, I>(collection, b);
+		let price = T::Currency::minimum_balance() * ItemPrice::<T, I>::from(1_000u32);
 		let price_direction = PriceDirection::Receive;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
-		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
 		let origin = SystemOrigin::Signed(caller.clone());
 		frame_system::Pallet::<T>::set_block_number(One::one());
 		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
@@ -714,5 +882,203 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_deposit_asset {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(SystemOrigin::Signed(caller.clone()), None)
+	verify {
+		assert_last_event::<T, I>(Event::DepositAssetSet { who: caller, asset: None }.into());
+	}
+
+	approve_collection_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let deadline = T::BlockNumber::max_value();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, delegate_lookup, Some(deadline))
+	verify {
+		assert_last_event::<T, I>(Event::CollectionTransferApproved { collection, owner: caller, delegate, deadline: Some(deadline) }.into());
+	}
+
+	cancel_collection_approval {
+		let (collection, caller, _) = create_collection::<T, I>();
+		mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		let deadline = T::BlockNumber::max_value();
+		Nfts::<T, I>::approve_collection_transfer(origin, collection, delegate_lookup.clone(), Some(deadline))?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection, delegate_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionApprovalCancelled { collection, owner: caller, delegate }.into());
+	}
+
+	clear_all_collection_approvals {
+		let (collection, caller, _) = create_collection::<T, I>();
+		mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate);
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		let deadline = T::BlockNumber::max_value();
+		Nfts::<T, I>::approve_collection_transfer(origin, collection, delegate_lookup, Some(deadline))?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection)
+	verify {
+		assert_last_event::<T, I>(Event::AllCollectionApprovalsCancelled { collection, owner: caller }.into());
+	}
+
+	enable_random_mint {
+		let (collection, caller, _) = create_collection::<T, I>();
+		assert_ok!(Nfts::<T, I>::set_collection_max_supply(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			u32::MAX,
+		));
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::RandomMintEnabled { collection, pool: u32::MAX }.into());
+	}
+
+	commit_random_mint {
+		let (collection, caller, _) = create_collection::<T, I>();
+		setup_random_mint::<T, I>(collection, &caller, u32::MAX);
+		let commitment = T::Hashing::hash_of(&[0u8; 32]);
+		// An earlier commitment is replaced, forfeiting its deposit.
+		assert_ok!(Nfts::<T, I>::commit_random_mint(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			commitment,
+		));
+	}: _(SystemOrigin::Signed(caller.clone()), collection, commitment)
+	verify {
+		assert_last_event::<T, I>(Event::RandomMintCommitted { collection, who: caller }.into());
+	}
+
+	mint_random {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		setup_random_mint::<T, I>(collection, &caller, u32::MAX);
+		let secret = [0u8; 32];
+		assert_ok!(Nfts::<T, I>::commit_random_mint(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::Hashing::hash_of(&secret),
+		));
+		// The randomness for the commitment is drawn.
+		let seed_block = frame_system::Pallet::<T>::block_number() + T::RandomMintDelay::get();
+		frame_system::Pallet::<T>::set_block_number(seed_block);
+		Nfts::<T, I>::on_initialize(seed_block);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, secret, caller_lookup, None)
+	verify {
+		assert_eq!(RandomMintPool::<T, I>::get(collection), Some(u32::MAX - 1));
+		assert_eq!(Collection::<T, I>::get(collection).unwrap().items, 1);
+	}
+
+	clear_random_mint_commitment {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		setup_random_mint::<T, I>(collection, &caller, u32::MAX);
+		assert_ok!(Nfts::<T, I>::commit_random_mint(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::Hashing::hash_of(&[0u8; 32]),
+		));
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() +
+				T::RandomMintDelay::get() +
+				T::RandomMintRevealPeriod::get() +
+				One::one(),
+		);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, caller_lookup)
+	verify {
+		assert!(!RandomMintCommitments::<T, I>::contains_key(collection, &caller));
+		let deposit = T::RandomMintDeposit::get();
+		assert_last_event::<T, I>(Event::RandomMintCommitmentForfeited { collection, who: caller, deposit }.into());
+	}
+
+	transfer_pre_signed {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (signer_public, signer) = T::Helper::signer();
+		T::Currency::make_free_balance_be(&signer, T::Currency::minimum_balance());
+		assert_ok!(Nfts::<T, I>::transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+			T::Lookup::unlookup(signer.clone()),
+		));
+
+		let target: T::AccountId = account("target", 0, SEED);
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		let pre_signed = PreSignedTransfer {
+			collection,
+			item,
+			dest: target.clone(),
+			deadline: T::BlockNumber::max_value(),
+			nonce: 0,
+		};
+		let message = Nfts::<T, I>::pre_signed_transfer_message(&pre_signed);
+		let signature = T::Helper::sign(&signer_public, &message);
+	}: _(SystemOrigin::Signed(caller), Box::new(pre_signed), signature)
+	verify {
+		assert_last_event::<T, I>(Event::Transferred { collection, item, from: signer, to: target }.into());
+	}
+
+	commit_reveal {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let duration = T::MaxDeadlineDuration::get();
+		for i in 1 .. T::MaxRevealCommitments::get() {
+			assert_ok!(Nfts::<T, I>::commit_reveal(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				T::Hashing::hash_of(&i),
+				duration,
+			));
+		}
+		let hash = T::Hashing::hash_of(&0u32);
+		let deadline = frame_system::Pallet::<T>::block_number() + duration;
+	}: _(SystemOrigin::Signed(caller), collection, hash, duration)
+	verify {
+		assert_last_event::<T, I>(Event::RevealCommitted { collection, hash, deadline }.into());
+	}
+
+	reveal_batch {
+		let n in 1 .. T::MaxAttributesPerCall::get();
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let batch: RevealBatchOf<T, I> = (0..n)
+			.map(|i| {
+				let mut key = i.to_le_bytes().to_vec();
+				key.resize(T::KeyLimit::get() as usize, 0);
+				RevealEntry::Attribute {
+					maybe_item: Some(item),
+					key: key.try_into().unwrap(),
+					value: value.clone(),
+				}
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let salt = [0u8; 32];
+		let hash = T::Hashing::hash_of(&(salt, &batch));
+		assert_ok!(Nfts::<T, I>::commit_reveal(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			hash,
+			T::MaxDeadlineDuration::get(),
+		));
+	}: _(SystemOrigin::Signed(caller), collection, batch, salt)
+	verify {
+		assert_last_event::<T, I>(Event::BatchRevealed { collection, hash }.into());
+	}
+
+	set_collection_royalty {
+		let b in 1 .. T::MaxRoyaltyBeneficiaries::get();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		let royalty = make_collection_royalty::<T, I>(b);
+	}: _(SystemOrigin::Signed(caller), collection, Some(royalty.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet { collection, royalty }.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -30,6 +30,26 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Collection::<T, I>::get(collection).map(|i| i.owner)
 	}
 
+	/// Check whether transfers, sales and approvals within the collection are paused.
+	pub fn is_collection_paused(collection: &T::CollectionId) -> bool {
+		PausedCollections::<T, I>::contains_key(collection)
+	}
+
+	/// Check whether the item is locked in place by its custodian.
+	pub fn is_item_custody_locked(collection: &T::CollectionId, item: &T::ItemId) -> bool {
+		ItemLocks::<T, I>::contains_key(collection, item)
+	}
+
+	/// Get the last ownership changes of the item, oldest first.
+	///
+	/// This is empty unless the item's collection tracks provenance.
+	pub fn provenance(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Vec<ProvenanceRecordOf<T, I>> {
+		ItemProvenance::<T, I>::get(collection, item).into_inner()
+	}
+
 	#[cfg(any(test, feature = "runtime-benchmarks"))]
 	pub fn set_next_id(id: T::CollectionId) {
 		NextCollectionId::<T, I>::set(Some(id));
@@ -38,6 +38,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
 			Error::<T, I>::ItemsNonTransferable
 		);
+		ensure!(!Self::is_collection_paused(&collection), Error::<T, I>::CollectionPaused);
+		ensure!(
+			!Self::get_item_config(&collection, &item)?.is_soulbound(),
+			Error::<T, I>::ItemSoulbound
+		);
 
 		if let Some(check_origin) = maybe_check_origin {
 			let is_admin = Self::has_role(&collection, &check_origin, CollectionRole::Admin);
@@ -129,4 +134,77 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	pub(crate) fn do_approve_collection_transfer(
+		owner: T::AccountId,
+		collection: T::CollectionId,
+		delegate: T::AccountId,
+		maybe_deadline: Option<<T as SystemConfig>::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Approvals),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
+			Error::<T, I>::ItemsNonTransferable
+		);
+		ensure!(!Self::is_collection_paused(&collection), Error::<T, I>::CollectionPaused);
+		// Only holders of the collection may store approvals for it.
+		ensure!(
+			Account::<T, I>::iter_key_prefix((&owner, &collection)).next().is_some(),
+			Error::<T, I>::NoPermission
+		);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let deadline = maybe_deadline.map(|d| d.saturating_add(now));
+
+		CollectionApprovalsOf::<T, I>::try_mutate(&collection, &owner, |approvals| {
+			approvals
+				.try_insert(delegate.clone(), deadline)
+				.map_err(|_| Error::<T, I>::ReachedApprovalLimit)
+		})?;
+
+		Self::deposit_event(Event::CollectionTransferApproved {
+			collection,
+			owner,
+			delegate,
+			deadline,
+		});
+
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_collection_approval(
+		owner: T::AccountId,
+		collection: T::CollectionId,
+		delegate: T::AccountId,
+	) -> DispatchResult {
+		CollectionApprovalsOf::<T, I>::try_mutate_exists(&collection, &owner, |maybe_approvals| {
+			let approvals = maybe_approvals.as_mut().ok_or(Error::<T, I>::NotDelegate)?;
+			approvals.remove(&delegate).ok_or(Error::<T, I>::NotDelegate)?;
+			if approvals.is_empty() {
+				*maybe_approvals = None;
+			}
+			Ok::<_, Error<T, I>>(())
+		})?;
+
+		Self::deposit_event(Event::CollectionApprovalCancelled { collection, owner, delegate });
+
+		Ok(())
+	}
+
+	pub(crate) fn do_clear_all_collection_approvals(
+		owner: T::AccountId,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		CollectionApprovalsOf::<T, I>::remove(&collection, &owner);
+
+		Self::deposit_event(Event::AllCollectionApprovalsCancelled { collection, owner });
+
+		Ok(())
+	}
 }
@@ -16,10 +16,7 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub(crate) fn do_create_swap(
@@ -40,6 +37,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let item = Item::<T, I>::get(&offered_collection_id, &offered_item_id)
 			.ok_or(Error::<T, I>::UnknownItem)?;
 		ensure!(item.owner == caller, Error::<T, I>::NoPermission);
+		ensure!(
+			!Self::get_item_config(&offered_collection_id, &offered_item_id)?.is_soulbound(),
+			Error::<T, I>::ItemSoulbound
+		);
 
 		match maybe_desired_item_id {
 			Some(desired_item_id) => ensure!(
@@ -141,18 +142,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
 
 		if let Some(ref price) = swap.price {
+			// The price is paid for the item of whoever receives it, so the royalty of the
+			// collection of that item is owed out of it.
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
+				PriceDirection::Send => Self::pay_with_royalty(
+					send_collection_id,
+					send_item_id,
 					&receive_item.owner,
 					&send_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
-				PriceDirection::Receive => T::Currency::transfer(
+				PriceDirection::Receive => Self::pay_with_royalty(
+					receive_collection_id,
+					receive_item_id,
 					&send_item.owner,
 					&receive_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
 			};
 		}
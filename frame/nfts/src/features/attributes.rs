@@ -34,36 +34,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let mut collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-
-		ensure!(
-			Self::is_valid_namespace(
-				&origin,
-				&namespace,
-				&collection,
-				&collection_details.owner,
-				&maybe_item,
-			)?,
-			Error::<T, I>::NoPermission
-		);
-
 		let collection_config = Self::get_collection_config(&collection)?;
-		// for the `CollectionOwner` namespace we need to check if the collection/item is not locked
-		match namespace {
-			AttributeNamespace::CollectionOwner => match maybe_item {
-				None => {
-					ensure!(
-						collection_config.is_setting_enabled(CollectionSetting::UnlockedAttributes),
-						Error::<T, I>::LockedCollectionAttributes
-					)
-				},
-				Some(item) => {
-					let maybe_is_locked = Self::get_item_config(&collection, &item)
-						.map(|c| c.has_disabled_setting(ItemSetting::UnlockedAttributes))?;
-					ensure!(!maybe_is_locked, Error::<T, I>::LockedItemAttributes);
-				},
-			},
-			_ => (),
-		}
+		Self::ensure_can_set_attributes(
+			&origin,
+			&collection,
+			&collection_details,
+			&collection_config,
+			&maybe_item,
+			&namespace,
+		)?;
 
 		let attribute = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key));
 		if attribute.is_none() {
@@ -73,25 +52,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let old_deposit =
 			attribute.map_or(AttributeDeposit { account: None, amount: Zero::zero() }, |m| m.1);
 
-		let mut deposit = Zero::zero();
-		if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) ||
-			namespace != AttributeNamespace::CollectionOwner
-		{
-			deposit = T::DepositPerByte::get()
-				.saturating_mul(((key.len() + value.len()) as u32).into())
-				.saturating_add(T::AttributeDepositBase::get());
-		}
+		let deposit =
+			Self::attribute_deposit(&collection_config, &namespace, key.len() + value.len());
 
 		// NOTE: when we transfer an item, we don't move attributes in the ItemOwner namespace.
 		// When the new owner updates the same attribute, we will update the depositor record
 		// and return the deposit to the previous owner.
 		if old_deposit.account.is_some() && old_deposit.account != Some(origin.clone()) {
-			T::Currency::unreserve(&old_deposit.account.unwrap(), old_deposit.amount);
-			T::Currency::reserve(&origin, deposit)?;
+			Self::unreserve_deposit(&old_deposit.account.unwrap(), old_deposit.amount);
+			Self::reserve_deposit(&origin, deposit)?;
 		} else if deposit > old_deposit.amount {
-			T::Currency::reserve(&origin, deposit - old_deposit.amount)?;
+			Self::reserve_deposit(&origin, deposit - old_deposit.amount)?;
 		} else if deposit < old_deposit.amount {
-			T::Currency::unreserve(&origin, old_deposit.amount - deposit);
+			Self::unreserve_deposit(&origin, old_deposit.amount - deposit);
 		}
 
 		// NOTE: we don't track the depositor in the CollectionOwner namespace as it's always a
@@ -114,6 +87,82 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	pub(crate) fn do_set_attributes(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		attributes: AttributesOf<T, I>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_config = Self::get_collection_config(&collection)?;
+		Self::ensure_can_set_attributes(
+			&origin,
+			&collection,
+			&collection_details,
+			&collection_config,
+			&maybe_item,
+			&namespace,
+		)?;
+
+		// Deposits of the `origin` are only settled once all the attributes are set.
+		let mut to_reserve: DepositBalanceOf<T, I> = Zero::zero();
+		let mut to_unreserve: DepositBalanceOf<T, I> = Zero::zero();
+		let deposit_owner = match namespace {
+			AttributeNamespace::CollectionOwner => None,
+			_ => Some(origin.clone()),
+		};
+		let count = attributes.len() as u32;
+
+		for (key, value) in attributes {
+			let attribute = Attribute::<T, I>::get((collection, maybe_item, &namespace, &key));
+			if attribute.is_none() {
+				collection_details.attributes.saturating_inc();
+			}
+
+			let old_deposit =
+				attribute.map_or(AttributeDeposit { account: None, amount: Zero::zero() }, |m| m.1);
+			let deposit =
+				Self::attribute_deposit(&collection_config, &namespace, key.len() + value.len());
+
+			// NOTE: the deposit of a previous depositor is returned right away, see
+			// `do_set_attribute`.
+			match old_deposit.account {
+				Some(account) if account != origin => {
+					Self::unreserve_deposit(&account, old_deposit.amount);
+				},
+				_ => to_unreserve.saturating_accrue(old_deposit.amount),
+			}
+			to_reserve.saturating_accrue(deposit);
+
+			if namespace == AttributeNamespace::CollectionOwner {
+				collection_details.owner_deposit.saturating_accrue(deposit);
+				collection_details.owner_deposit.saturating_reduce(old_deposit.amount);
+			}
+
+			Attribute::<T, I>::insert(
+				(&collection, maybe_item, &namespace, &key),
+				(&value, AttributeDeposit { account: deposit_owner.clone(), amount: deposit }),
+			);
+		}
+
+		if to_reserve > to_unreserve {
+			Self::reserve_deposit(&origin, to_reserve - to_unreserve)?;
+		} else if to_reserve < to_unreserve {
+			Self::unreserve_deposit(&origin, to_unreserve - to_reserve);
+		}
+
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::AttributesSet { collection, maybe_item, namespace, count });
+		Ok(())
+	}
+
 	pub(crate) fn do_force_set_attribute(
 		set_as: Option<T::AccountId>,
 		collection: T::CollectionId,
@@ -129,7 +178,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if let Some((_, deposit)) = attribute {
 			if deposit.account != set_as && deposit.amount != Zero::zero() {
 				if let Some(deposit_account) = deposit.account {
-					T::Currency::unreserve(&deposit_account, deposit.amount);
+					Self::unreserve_deposit(&deposit_account, deposit.amount);
 				}
 			}
 		} else {
@@ -201,12 +250,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			match namespace {
 				AttributeNamespace::CollectionOwner => {
 					collection_details.owner_deposit.saturating_reduce(deposit.amount);
-					T::Currency::unreserve(&collection_details.owner, deposit.amount);
+					Self::unreserve_deposit(&collection_details.owner, deposit.amount);
 				},
 				_ => (),
 			};
 			if let Some(deposit_account) = deposit.account {
-				T::Currency::unreserve(&deposit_account, deposit.amount);
+				Self::unreserve_deposit(&deposit_account, deposit.amount);
 			}
 			Collection::<T, I>::insert(collection, &collection_details);
 			Self::deposit_event(Event::AttributeCleared { collection, maybe_item, key, namespace });
@@ -269,7 +318,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(attributes <= witness.account_attributes, Error::<T, I>::BadWitness);
 
 			if !deposited.is_zero() {
-				T::Currency::unreserve(&delegate, deposited);
+				Self::unreserve_deposit(&delegate, deposited);
 			}
 
 			Self::deposit_event(Event::ItemAttributesApprovalRemoved {
@@ -281,6 +330,64 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Ensures that `origin` is allowed to set attributes in the `namespace` of the collection or
+	/// item, and that those attributes aren't locked.
+	fn ensure_can_set_attributes(
+		origin: &T::AccountId,
+		collection: &T::CollectionId,
+		collection_details: &CollectionDetailsFor<T, I>,
+		collection_config: &CollectionConfigFor<T, I>,
+		maybe_item: &Option<T::ItemId>,
+		namespace: &AttributeNamespace<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_valid_namespace(
+				origin,
+				namespace,
+				collection,
+				&collection_details.owner,
+				maybe_item,
+			)?,
+			Error::<T, I>::NoPermission
+		);
+
+		// for the `CollectionOwner` namespace we need to check if the collection/item is not locked
+		match namespace {
+			AttributeNamespace::CollectionOwner => match maybe_item {
+				None => {
+					ensure!(
+						collection_config.is_setting_enabled(CollectionSetting::UnlockedAttributes),
+						Error::<T, I>::LockedCollectionAttributes
+					)
+				},
+				Some(item) => {
+					let maybe_is_locked = Self::get_item_config(collection, item)
+						.map(|c| c.has_disabled_setting(ItemSetting::UnlockedAttributes))?;
+					ensure!(!maybe_is_locked, Error::<T, I>::LockedItemAttributes);
+				},
+			},
+			_ => (),
+		}
+		Ok(())
+	}
+
+	/// The deposit required to store an attribute of `len` bytes (key and value combined).
+	fn attribute_deposit(
+		collection_config: &CollectionConfigFor<T, I>,
+		namespace: &AttributeNamespace<T::AccountId>,
+		len: usize,
+	) -> DepositBalanceOf<T, I> {
+		if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) ||
+			namespace != &AttributeNamespace::CollectionOwner
+		{
+			T::DepositPerByte::get()
+				.saturating_mul((len as u32).into())
+				.saturating_add(T::AttributeDepositBase::get())
+		} else {
+			Zero::zero()
+		}
+	}
+
 	fn is_valid_namespace(
 		origin: &T::AccountId,
 		namespace: &AttributeNamespace<T::AccountId>,
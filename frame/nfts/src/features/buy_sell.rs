@@ -18,7 +18,7 @@
 use crate::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive},
+	traits::{Currency, ExistenceRequirement::KeepAlive},
 };
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -46,6 +46,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		sender: T::AccountId,
 		price: Option<ItemPrice<T, I>>,
 		whitelisted_buyer: Option<T::AccountId>,
+		expires_at: Option<T::BlockNumber>,
 	) -> DispatchResult {
 		ensure!(
 			Self::is_pallet_feature_enabled(PalletFeature::Trading),
@@ -62,18 +63,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		);
 
 		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(!item_config.is_soulbound(), Error::<T, I>::ItemSoulbound);
 		ensure!(
 			item_config.is_setting_enabled(ItemSetting::Transferable),
 			Error::<T, I>::ItemLocked
 		);
+		ensure!(!Self::is_collection_paused(&collection), Error::<T, I>::CollectionPaused);
 
 		if let Some(ref price) = price {
-			ItemPriceOf::<T, I>::insert(&collection, &item, (price, whitelisted_buyer.clone()));
+			if let Some(deadline) = expires_at {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(deadline >= now, Error::<T, I>::DeadlineExpired);
+				ensure!(
+					deadline.saturating_sub(now) <= T::MaxDeadlineDuration::get(),
+					Error::<T, I>::WrongDuration
+				);
+			}
+			ItemPriceOf::<T, I>::insert(
+				&collection,
+				&item,
+				(price, whitelisted_buyer.clone(), expires_at),
+			);
 			Self::deposit_event(Event::ItemPriceSet {
 				collection,
 				item,
 				price: *price,
 				whitelisted_buyer,
+				expires_at,
 			});
 		} else {
 			ItemPriceOf::<T, I>::remove(&collection, &item);
@@ -96,6 +112,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
 		ensure!(details.owner != buyer, Error::<T, I>::NoPermission);
+		ensure!(!Self::is_collection_paused(&collection), Error::<T, I>::CollectionPaused);
 
 		let price_info =
 			ItemPriceOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NotForSale)?;
@@ -106,16 +123,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
-			&buyer,
-			&details.owner,
-			price_info.0,
-			ExistenceRequirement::KeepAlive,
-		)?;
+		if let Some(deadline) = price_info.2 {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= deadline, Error::<T, I>::ListingExpired);
+		}
+
+		Self::pay_with_royalty(collection, item, &buyer, &details.owner, price_info.0)?;
 
 		let old_owner = details.owner.clone();
 
-		Self::do_transfer(collection, item, buyer.clone(), |_, _| Ok(()))?;
+		Self::do_transfer_at_price(collection, item, buyer.clone(), Some(price_info.0), |_, _| {
+			Ok(())
+		})?;
 
 		Self::deposit_event(Event::ItemBought {
 			collection,
@@ -127,4 +146,40 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// Remove the expired listings among the next `max` listings, carrying on from where the
+	/// previous call stopped. Returns the number of listings removed.
+	pub(crate) fn do_clear_expired_listings(max: u32) -> u32 {
+		if max == 0 {
+			return 0
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut listings = match ListingsCleanupCursor::<T, I>::take() {
+			Some(cursor) => ItemPriceOf::<T, I>::iter_from(cursor.into_inner()),
+			None => ItemPriceOf::<T, I>::iter(),
+		};
+
+		let mut expired = Vec::new();
+		let mut looked_at = 0;
+		while looked_at < max {
+			match listings.next() {
+				Some((collection, item, (_, _, Some(deadline)))) if deadline < now =>
+					expired.push((collection, item)),
+				Some(_) => {},
+				None => break,
+			}
+			looked_at += 1;
+		}
+		if looked_at == max {
+			let cursor = BoundedVec::truncate_from(listings.last_raw_key().to_vec());
+			ListingsCleanupCursor::<T, I>::put(cursor);
+		}
+
+		let removed = expired.len() as u32;
+		for (collection, item) in expired {
+			ItemPriceOf::<T, I>::remove(&collection, &item);
+			Self::deposit_event(Event::ItemPriceRemoved { collection, item });
+		}
+		removed
+	}
 }
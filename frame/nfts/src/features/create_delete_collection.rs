@@ -29,7 +29,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		ensure!(!Collection::<T, I>::contains_key(collection), Error::<T, I>::CollectionIdInUse);
 
-		T::Currency::reserve(&owner, deposit)?;
+		Self::reserve_deposit(&owner, deposit)?;
 
 		Collection::<T, I>::insert(
 			collection,
@@ -60,59 +60,186 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Destroy a `collection` at once, whatever the number of its entries.
 	pub fn do_destroy_collection(
 		collection: T::CollectionId,
 		witness: DestroyWitness,
 		maybe_check_owner: Option<T::AccountId>,
 	) -> Result<DestroyWitness, DispatchError> {
-		Collection::<T, I>::try_mutate_exists(collection, |maybe_details| {
-			let collection_details =
-				maybe_details.take().ok_or(Error::<T, I>::UnknownCollection)?;
-			if let Some(check_owner) = maybe_check_owner {
-				ensure!(collection_details.owner == check_owner, Error::<T, I>::NoPermission);
-			}
-			ensure!(collection_details.items == witness.items, Error::<T, I>::BadWitness);
-			ensure!(
-				collection_details.item_metadatas == witness.item_metadatas,
-				Error::<T, I>::BadWitness
-			);
-			ensure!(collection_details.attributes == witness.attributes, Error::<T, I>::BadWitness);
-
-			for (item, details) in Item::<T, I>::drain_prefix(&collection) {
-				Account::<T, I>::remove((&details.owner, &collection, &item));
-				T::Currency::unreserve(&details.deposit.account, details.deposit.amount);
+		Self::do_start_destroy(collection, witness, maybe_check_owner)?;
+		Self::do_continue_destroy(collection, u32::MAX)?;
+		Ok(witness)
+	}
+
+	/// Start the destruction of a `collection`, once `witness` has been checked against it.
+	///
+	/// The config of the collection is removed, so that it can no longer be used while its
+	/// entries are being removed. A collection with items locked by their custodian cannot be
+	/// destroyed until they are unlocked.
+	pub fn do_start_destroy(
+		collection: T::CollectionId,
+		witness: DestroyWitness,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(
+			!DestroyCursor::<T, I>::contains_key(&collection),
+			Error::<T, I>::CollectionDestroying
+		);
+		ensure!(details.destroy_witness() == witness, Error::<T, I>::BadWitness);
+		ensure!(
+			ItemLocks::<T, I>::iter_prefix(&collection).next().is_none(),
+			Error::<T, I>::ItemLocked
+		);
+
+		CollectionConfigOf::<T, I>::remove(&collection);
+		DestroyCursor::<T, I>::insert(&collection, DestroyStage::Items);
+		Self::deposit_event(Event::DestructionStarted { collection });
+		Ok(())
+	}
+
+	/// Remove at most `limit` of the remaining entries of a `collection` which is being
+	/// destroyed, moving on to the next stage of its destruction whenever one is completed.
+	///
+	/// Returns the weight consumed.
+	pub fn do_continue_destroy(
+		collection: T::CollectionId,
+		limit: u32,
+	) -> Result<Weight, DispatchError> {
+		let mut stage = DestroyCursor::<T, I>::get(&collection)
+			.ok_or(Error::<T, I>::CollectionNotDestroying)?;
+		let mut details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let mut budget = limit;
+		let mut weight = Weight::zero();
+		loop {
+			let removed = Self::destroy_stage(&collection, &mut details, stage, budget);
+			weight.saturating_accrue(Self::destroy_stage_weight(stage, removed));
+			// A stage is only known to be complete once fewer entries than allowed were left.
+			if removed == budget {
+				DestroyCursor::<T, I>::insert(&collection, stage);
+				Collection::<T, I>::insert(&collection, details);
+				return Ok(weight)
 			}
-			#[allow(deprecated)]
-			ItemMetadataOf::<T, I>::remove_prefix(&collection, None);
-			#[allow(deprecated)]
-			ItemPriceOf::<T, I>::remove_prefix(&collection, None);
-			#[allow(deprecated)]
-			PendingSwapOf::<T, I>::remove_prefix(&collection, None);
-			CollectionMetadataOf::<T, I>::remove(&collection);
-			Self::clear_roles(&collection)?;
-
-			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,)) {
-				if !deposit.amount.is_zero() {
-					if let Some(account) = deposit.account {
-						T::Currency::unreserve(&account, deposit.amount);
+			budget.saturating_reduce(removed);
+			stage = match stage {
+				DestroyStage::Items => DestroyStage::ItemMetadata,
+				DestroyStage::ItemMetadata => DestroyStage::Attributes,
+				DestroyStage::Attributes => DestroyStage::Configs,
+				DestroyStage::Configs => {
+					Self::finish_destroy(collection, details)?;
+					return Ok(weight)
+				},
+			};
+		}
+	}
+
+	/// An upper bound on the weight of `do_continue_destroy` with the given `limit`.
+	pub fn max_destroy_progress_weight(limit: u32) -> Weight {
+		T::WeightInfo::destroy_items(limit)
+			.saturating_add(T::WeightInfo::destroy_item_metadata(limit))
+			.saturating_add(T::WeightInfo::destroy_attributes(limit))
+			.saturating_add(T::WeightInfo::destroy_configs(limit))
+	}
+
+	fn destroy_stage_weight(stage: DestroyStage, removed: u32) -> Weight {
+		match stage {
+			DestroyStage::Items => T::WeightInfo::destroy_items(removed),
+			DestroyStage::ItemMetadata => T::WeightInfo::destroy_item_metadata(removed),
+			DestroyStage::Attributes => T::WeightInfo::destroy_attributes(removed),
+			DestroyStage::Configs => T::WeightInfo::destroy_configs(removed),
+		}
+	}
+
+	/// Remove at most `budget` entries of the `stage` of the destruction of `collection`,
+	/// returning the deposits which they held. Returns the number of entries removed.
+	fn destroy_stage(
+		collection: &T::CollectionId,
+		details: &mut CollectionDetailsFor<T, I>,
+		stage: DestroyStage,
+		budget: u32,
+	) -> u32 {
+		let limit = budget as usize;
+		match stage {
+			DestroyStage::Items => {
+				let mut removed = 0;
+				for (item, item_details) in Item::<T, I>::drain_prefix(collection).take(limit) {
+					Account::<T, I>::remove((&item_details.owner, collection, &item));
+					ItemPriceOf::<T, I>::remove(collection, &item);
+					PendingSwapOf::<T, I>::remove(collection, &item);
+					ItemAttributesApprovalsOf::<T, I>::remove(collection, &item);
+					ItemProvenance::<T, I>::remove(collection, &item);
+					ItemConfigOf::<T, I>::remove(collection, &item);
+					Self::unreserve_deposit(
+						&item_details.deposit.account,
+						item_details.deposit.amount,
+					);
+					removed += 1;
+				}
+				details.items.saturating_reduce(removed);
+				removed
+			},
+			DestroyStage::ItemMetadata => {
+				let removed =
+					ItemMetadataOf::<T, I>::drain_prefix(collection).take(limit).count() as u32;
+				details.item_metadatas.saturating_reduce(removed);
+				removed
+			},
+			DestroyStage::Attributes => {
+				let mut removed = 0;
+				for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((collection,)).take(limit)
+				{
+					if !deposit.amount.is_zero() {
+						if let Some(account) = deposit.account {
+							Self::unreserve_deposit(&account, deposit.amount);
+						}
 					}
+					removed += 1;
 				}
-			}
+				details.attributes.saturating_reduce(removed);
+				removed
+			},
+			DestroyStage::Configs => {
+				let mut removed =
+					ItemConfigOf::<T, I>::drain_prefix(collection).take(limit).count();
+				removed += ItemAttributesApprovalsOf::<T, I>::drain_prefix(collection)
+					.take(limit - removed)
+					.count();
+				removed += CollectionApprovalsOf::<T, I>::drain_prefix(collection)
+					.take(limit - removed)
+					.count();
+				removed +=
+					RandomMintSwaps::<T, I>::drain_prefix(collection).take(limit - removed).count();
+				removed += RandomMintCommitments::<T, I>::drain_prefix(collection)
+					.take(limit - removed)
+					.count();
+				removed as u32
+			},
+		}
+	}
 
-			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
-			T::Currency::unreserve(&collection_details.owner, collection_details.owner_deposit);
-			CollectionConfigOf::<T, I>::remove(&collection);
-			let _ = ItemConfigOf::<T, I>::clear_prefix(&collection, witness.items, None);
-			let _ =
-				ItemAttributesApprovalsOf::<T, I>::clear_prefix(&collection, witness.items, None);
-
-			Self::deposit_event(Event::Destroyed { collection });
-
-			Ok(DestroyWitness {
-				items: collection_details.items,
-				item_metadatas: collection_details.item_metadatas,
-				attributes: collection_details.attributes,
-			})
-		})
+	/// Remove what remains of a `collection` once all of its entries have been removed, and
+	/// return the deposit of its owner.
+	fn finish_destroy(
+		collection: T::CollectionId,
+		details: CollectionDetailsFor<T, I>,
+	) -> DispatchResult {
+		CollectionMetadataOf::<T, I>::remove(&collection);
+		Self::clear_roles(&collection)?;
+		CollectionAccount::<T, I>::remove(&details.owner, &collection);
+		Self::unreserve_deposit(&details.owner, details.owner_deposit);
+		PausedCollections::<T, I>::remove(&collection);
+		RandomMintPool::<T, I>::remove(&collection);
+		RevealCommitments::<T, I>::remove(&collection);
+		CollectionRoyaltyOf::<T, I>::remove(&collection);
+		DestroyCursor::<T, I>::remove(&collection);
+		Collection::<T, I>::remove(&collection);
+
+		Self::deposit_event(Event::Destroyed { collection });
+		Ok(())
 	}
 }
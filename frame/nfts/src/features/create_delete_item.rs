@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn do_mint(
@@ -69,7 +69,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					ItemConfigOf::<T, I>::insert(&collection, &item, item_config);
 				}
 
-				T::Currency::reserve(&deposit_account, deposit_amount)?;
+				Self::reserve_deposit(&deposit_account, deposit_amount)?;
 
 				let deposit = ItemDeposit { account: deposit_account, amount: deposit_amount };
 				let details = ItemDetails {
@@ -86,12 +86,81 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Check that `caller` may mint an item of `collection` under its mint settings, claiming the
+	/// witness item and paying the mint price where they apply.
+	pub(crate) fn ensure_can_mint(
+		collection: &T::CollectionId,
+		caller: &T::AccountId,
+		witness_data: Option<MintWitness<T::ItemId>>,
+		collection_details: &CollectionDetailsFor<T, I>,
+		collection_config: &CollectionConfigFor<T, I>,
+	) -> DispatchResult {
+		// Issuer can mint regardless of mint settings
+		if Self::has_role(collection, caller, CollectionRole::Issuer) {
+			return Ok(())
+		}
+
+		let mint_settings = collection_config.mint_settings;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		if let Some(start_block) = mint_settings.start_block {
+			ensure!(start_block <= now, Error::<T, I>::MintNotStarted);
+		}
+		if let Some(end_block) = mint_settings.end_block {
+			ensure!(end_block >= now, Error::<T, I>::MintEnded);
+		}
+
+		match mint_settings.mint_type {
+			MintType::Issuer => return Err(Error::<T, I>::NoPermission.into()),
+			MintType::HolderOf(collection_id) => {
+				let MintWitness { owner_of_item } =
+					witness_data.ok_or(Error::<T, I>::BadWitness)?;
+
+				let has_item =
+					Account::<T, I>::contains_key((caller, &collection_id, &owner_of_item));
+				ensure!(has_item, Error::<T, I>::BadWitness);
+
+				let attribute_key = Self::construct_attribute_key(
+					PalletAttributes::<T::CollectionId>::UsedToClaim(*collection).encode(),
+				)?;
+
+				let key = (
+					&collection_id,
+					Some(owner_of_item),
+					AttributeNamespace::Pallet,
+					&attribute_key,
+				);
+				let already_claimed = Attribute::<T, I>::contains_key(key.clone());
+				ensure!(!already_claimed, Error::<T, I>::AlreadyClaimed);
+
+				let value = Self::construct_attribute_value(vec![0])?;
+				Attribute::<T, I>::insert(
+					key,
+					(value, AttributeDeposit { account: None, amount: Zero::zero() }),
+				);
+			},
+			_ => {},
+		}
+
+		if let Some(price) = mint_settings.price {
+			T::Currency::transfer(
+				caller,
+				&collection_details.owner,
+				price,
+				ExistenceRequirement::KeepAlive,
+			)?;
+		}
+
+		Ok(())
+	}
+
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
 		with_details: impl FnOnce(&ItemDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		ensure!(!Self::is_item_custody_locked(&collection, &item), Error::<T, I>::ItemLocked);
 		let owner = Collection::<T, I>::try_mutate(
 			&collection,
 			|maybe_collection_details| -> Result<T::AccountId, DispatchError> {
@@ -102,7 +171,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				with_details(&details)?;
 
 				// Return the deposit.
-				T::Currency::unreserve(&details.deposit.account, details.deposit.amount);
+				Self::unreserve_deposit(&details.deposit.account, details.deposit.amount);
 				collection_details.items.saturating_dec();
 				Ok(details.owner)
 			},
@@ -113,6 +182,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
 		ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
+		ItemProvenance::<T, I>::remove(collection, item);
 
 		// NOTE: if item's settings are not empty (e.g. item's metadata is locked)
 		// then we keep the record and don't remove it
@@ -0,0 +1,171 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::fungibles::Transfer};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, UniqueSaturatedInto},
+	Rounding, SaturatedConversion,
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_set_deposit_asset(
+		who: T::AccountId,
+		asset: Option<DepositAssetIdOf<T, I>>,
+	) -> DispatchResult {
+		if let Some(asset) = asset.as_ref() {
+			ensure!(T::DepositAssetFilter::contains(asset), Error::<T, I>::DepositAssetNotAllowed);
+			if let Some(held) = AssetDepositOf::<T, I>::get(&who) {
+				ensure!(&held.asset == asset, Error::<T, I>::DepositAssetMismatch);
+			}
+			DepositAssetOf::<T, I>::insert(&who, asset);
+		} else {
+			DepositAssetOf::<T, I>::remove(&who);
+		}
+
+		Self::deposit_event(Event::DepositAssetSet { who, asset });
+		Ok(())
+	}
+
+	/// The account which holds the deposits paid in assets.
+	pub fn deposit_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Reserve a deposit of `amount` from `who`, in the asset they have chosen if any.
+	pub(crate) fn reserve_deposit(
+		who: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		let asset = match DepositAssetOf::<T, I>::get(who) {
+			Some(asset) if !amount.is_zero() => asset,
+			_ => return T::Currency::reserve(who, amount),
+		};
+		let held = T::DepositConversion::to_asset_balance(amount, asset)
+			.map_err(|_| Error::<T, I>::DepositConversionFailed)?;
+
+		AssetDepositOf::<T, I>::try_mutate(who, |maybe_deposit| -> DispatchResult {
+			let deposit = maybe_deposit.get_or_insert_with(|| AssetDeposit {
+				asset,
+				native: Zero::zero(),
+				held: Zero::zero(),
+			});
+			ensure!(deposit.asset == asset, Error::<T, I>::DepositAssetMismatch);
+			if !held.is_zero() {
+				T::DepositAssets::transfer(asset, who, &Self::deposit_account(), held, false)?;
+			}
+			deposit.native.saturating_accrue(amount);
+			deposit.held.saturating_accrue(held);
+			Ok(())
+		})
+	}
+
+	/// Release a deposit of `amount` back to `who`.
+	///
+	/// Deposits held in an asset are released first, in proportion to the part of their total
+	/// being released; whatever remains is unreserved from `Currency`.
+	pub(crate) fn unreserve_deposit(who: &T::AccountId, amount: DepositBalanceOf<T, I>) {
+		let mut remaining = amount;
+		if let Some(mut deposit) = AssetDepositOf::<T, I>::get(who) {
+			let (native, held) = Self::share_of(&deposit, amount);
+			// Should the custody account fail to pay out, keep the ledger intact so that the
+			// deposit can still be released later.
+			if held.is_zero() ||
+				T::DepositAssets::transfer(
+					deposit.asset,
+					&Self::deposit_account(),
+					who,
+					held,
+					false,
+				)
+				.is_ok()
+			{
+				deposit.native.saturating_reduce(native);
+				deposit.held.saturating_reduce(held);
+				if deposit.native.is_zero() {
+					AssetDepositOf::<T, I>::remove(who);
+				} else {
+					AssetDepositOf::<T, I>::insert(who, deposit);
+				}
+				remaining.saturating_reduce(native);
+			}
+		}
+		if !remaining.is_zero() {
+			T::Currency::unreserve(who, remaining);
+		}
+	}
+
+	/// Move a deposit of `amount` from `from` to `to`, keeping it reserved.
+	///
+	/// Deposits held in an asset stay with the custody account and are moved between the
+	/// ledgers of the two accounts.
+	pub(crate) fn repatriate_deposit(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		if from == to {
+			return Ok(())
+		}
+		let mut remaining = amount;
+		if let Some(mut deposit) = AssetDepositOf::<T, I>::get(from) {
+			let (native, held) = Self::share_of(&deposit, amount);
+			AssetDepositOf::<T, I>::try_mutate(to, |maybe_deposit| -> DispatchResult {
+				let target = maybe_deposit.get_or_insert_with(|| AssetDeposit {
+					asset: deposit.asset,
+					native: Zero::zero(),
+					held: Zero::zero(),
+				});
+				ensure!(target.asset == deposit.asset, Error::<T, I>::DepositAssetMismatch);
+				target.native.saturating_accrue(native);
+				target.held.saturating_accrue(held);
+				Ok(())
+			})?;
+			deposit.native.saturating_reduce(native);
+			deposit.held.saturating_reduce(held);
+			if deposit.native.is_zero() {
+				AssetDepositOf::<T, I>::remove(from);
+			} else {
+				AssetDepositOf::<T, I>::insert(from, deposit);
+			}
+			remaining.saturating_reduce(native);
+		}
+		if !remaining.is_zero() {
+			T::Currency::repatriate_reserved(from, to, remaining, Reserved)?;
+		}
+		Ok(())
+	}
+
+	/// The part of `deposit` covering `amount`: the native amount and the asset held for it.
+	fn share_of(
+		deposit: &AssetDepositFor<T, I>,
+		amount: DepositBalanceOf<T, I>,
+	) -> (DepositBalanceOf<T, I>, DepositAssetBalanceOf<T, I>) {
+		if amount >= deposit.native {
+			return (deposit.native, deposit.held)
+		}
+		let held = multiply_by_rational_with_rounding(
+			deposit.held.unique_saturated_into(),
+			amount.unique_saturated_into(),
+			deposit.native.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.unwrap_or_default();
+		(amount, held.saturated_into())
+	}
+}
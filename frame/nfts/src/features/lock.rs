@@ -44,6 +44,39 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	pub(crate) fn do_pause_collection(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(collection), Error::<T, I>::UnknownCollection);
+		ensure!(
+			Self::has_role(&collection, &origin, CollectionRole::Admin),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(!Self::is_collection_paused(&collection), Error::<T, I>::CollectionPaused);
+
+		PausedCollections::<T, I>::insert(collection, ());
+
+		Self::deposit_event(Event::<T, I>::CollectionPaused { collection });
+		Ok(())
+	}
+
+	pub(crate) fn do_unpause_collection(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		ensure!(
+			Self::has_role(&collection, &origin, CollectionRole::Admin),
+			Error::<T, I>::NoPermission
+		);
+		ensure!(Self::is_collection_paused(&collection), Error::<T, I>::CollectionNotPaused);
+
+		PausedCollections::<T, I>::remove(collection);
+
+		Self::deposit_event(Event::<T, I>::CollectionUnpaused { collection });
+		Ok(())
+	}
+
 	pub(crate) fn do_lock_item_transfer(
 		origin: T::AccountId,
 		collection: T::CollectionId,
@@ -84,6 +117,36 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	pub(crate) fn do_lock_item_custody(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		reason: LockIdentifier,
+	) -> DispatchResult {
+		ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+		ensure!(!Self::is_item_custody_locked(&collection, &item), Error::<T, I>::ItemLocked);
+
+		ItemLocks::<T, I>::insert(collection, item, reason);
+
+		Self::deposit_event(Event::<T, I>::ItemCustodyLocked { collection, item, reason });
+		Ok(())
+	}
+
+	pub(crate) fn do_unlock_item_custody(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		reason: LockIdentifier,
+	) -> DispatchResult {
+		ensure!(
+			ItemLocks::<T, I>::get(collection, item) == Some(reason),
+			Error::<T, I>::WrongLockReason
+		);
+
+		ItemLocks::<T, I>::remove(collection, item);
+
+		Self::deposit_event(Event::<T, I>::ItemCustodyUnlocked { collection, item, reason });
+		Ok(())
+	}
+
 	pub(crate) fn do_lock_item_properties(
 		maybe_check_owner: Option<T::AccountId>,
 		collection: T::CollectionId,
@@ -56,9 +56,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					.saturating_add(T::MetadataDepositBase::get());
 			}
 			if deposit > old_deposit {
-				T::Currency::reserve(&collection_details.owner, deposit - old_deposit)?;
+				Self::reserve_deposit(&collection_details.owner, deposit - old_deposit)?;
 			} else if deposit < old_deposit {
-				T::Currency::unreserve(&collection_details.owner, old_deposit - deposit);
+				Self::unreserve_deposit(&collection_details.owner, old_deposit - deposit);
 			}
 			collection_details.owner_deposit.saturating_accrue(deposit);
 
@@ -92,7 +92,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				collection_details.item_metadatas.saturating_dec();
 			}
 			let deposit = metadata.take().ok_or(Error::<T, I>::UnknownItem)?.deposit;
-			T::Currency::unreserve(&collection_details.owner, deposit);
+			Self::unreserve_deposit(&collection_details.owner, deposit);
 			collection_details.owner_deposit.saturating_reduce(deposit);
 
 			Collection::<T, I>::insert(&collection, &collection_details);
@@ -131,9 +131,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					.saturating_add(T::MetadataDepositBase::get());
 			}
 			if deposit > old_deposit {
-				T::Currency::reserve(&details.owner, deposit - old_deposit)?;
+				Self::reserve_deposit(&details.owner, deposit - old_deposit)?;
 			} else if deposit < old_deposit {
-				T::Currency::unreserve(&details.owner, old_deposit - deposit);
+				Self::unreserve_deposit(&details.owner, old_deposit - deposit);
 			}
 			details.owner_deposit.saturating_accrue(deposit);
 
@@ -165,7 +165,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		CollectionMetadataOf::<T, I>::try_mutate_exists(collection, |metadata| {
 			let deposit = metadata.take().ok_or(Error::<T, I>::UnknownCollection)?.deposit;
-			T::Currency::unreserve(&details.owner, deposit);
+			Self::unreserve_deposit(&details.owner, deposit);
 			Self::deposit_event(Event::CollectionMetadataCleared { collection });
 			Ok(())
 		})
@@ -21,8 +21,12 @@ pub mod attributes;
 pub mod buy_sell;
 pub mod create_delete_collection;
 pub mod create_delete_item;
+pub mod deposit_asset;
 pub mod lock;
 pub mod metadata;
+pub mod random_mint;
+pub mod reveal;
 pub mod roles;
+pub mod royalties;
 pub mod settings;
 pub mod transfer;
@@ -0,0 +1,162 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Randomness, ReservableCurrency},
+};
+use sp_runtime::traits::{Hash, One};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_enable_random_mint(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(!RandomMintPool::<T, I>::contains_key(&collection), Error::<T, I>::RandomMintOnly);
+		ensure!(details.items.is_zero(), Error::<T, I>::RandomMintUnavailable);
+
+		let collection_config = Self::get_collection_config(&collection)?;
+		let pool = collection_config.max_supply.ok_or(Error::<T, I>::RandomMintUnavailable)?;
+
+		RandomMintPool::<T, I>::insert(&collection, pool);
+		Self::deposit_event(Event::RandomMintEnabled { collection, pool });
+		Ok(())
+	}
+
+	pub(crate) fn do_commit_random_mint(
+		who: T::AccountId,
+		collection: T::CollectionId,
+		commitment: T::Hash,
+	) -> DispatchResult {
+		ensure!(
+			RandomMintPool::<T, I>::contains_key(&collection),
+			Error::<T, I>::RandomMintUnavailable
+		);
+		if let Some((_, _, deposit)) = RandomMintCommitments::<T, I>::take(&collection, &who) {
+			Self::forfeit_random_mint_deposit(collection, who.clone(), deposit);
+		}
+		let deposit = T::RandomMintDeposit::get();
+		T::Currency::reserve(&who, deposit)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		RandomMintSeedRequests::<T, I>::insert(now.saturating_add(T::RandomMintDelay::get()), ());
+		RandomMintCommitments::<T, I>::insert(&collection, &who, (commitment, now, deposit));
+		Self::deposit_event(Event::RandomMintCommitted { collection, who });
+		Ok(())
+	}
+
+	pub(crate) fn do_clear_random_mint_commitment(
+		collection: T::CollectionId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let (_, committed_at, deposit) = RandomMintCommitments::<T, I>::get(&collection, &who)
+			.ok_or(Error::<T, I>::NoCommitment)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			now > Self::random_mint_seed_block(committed_at)
+				.saturating_add(T::RandomMintRevealPeriod::get()),
+			Error::<T, I>::CommitmentNotExpired
+		);
+		RandomMintCommitments::<T, I>::remove(&collection, &who);
+		Self::forfeit_random_mint_deposit(collection, who, deposit);
+		Ok(())
+	}
+
+	/// Slash the `deposit` of a commitment of `who` to mint an item of `collection` at random,
+	/// which was replaced or not revealed in time.
+	fn forfeit_random_mint_deposit(
+		collection: T::CollectionId,
+		who: T::AccountId,
+		deposit: DepositBalanceOf<T, I>,
+	) {
+		let _ = T::Currency::slash_reserved(&who, deposit);
+		Self::deposit_event(Event::RandomMintCommitmentForfeited { collection, who, deposit });
+	}
+
+	/// The block in which the randomness is drawn for a commitment made in block `committed_at`.
+	fn random_mint_seed_block(
+		committed_at: <T as SystemConfig>::BlockNumber,
+	) -> <T as SystemConfig>::BlockNumber {
+		committed_at.saturating_add(T::RandomMintDelay::get())
+	}
+
+	/// Draw the randomness for the commitments to mint at random made `RandomMintDelay` blocks
+	/// before `now`, if there are any, and forget the randomness with which commitments may no
+	/// longer be revealed.
+	pub(crate) fn note_random_mint_seed(now: <T as SystemConfig>::BlockNumber) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 2);
+		if RandomMintSeedRequests::<T, I>::take(now).is_some() {
+			RandomMintSeeds::<T, I>::insert(now, T::Randomness::random(b"random_mint"));
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+		}
+		let period = T::RandomMintRevealPeriod::get().saturating_add(One::one());
+		RandomMintSeeds::<T, I>::remove(now.saturating_sub(period));
+		weight
+	}
+
+	/// Take the commitment of `who` to `secret` and draw an unassigned item id of `collection`
+	/// with it, releasing the deposit of the commitment.
+	///
+	/// The secret is mixed with the randomness drawn `RandomMintDelay` blocks after it was
+	/// committed to, so that neither the committer nor anyone else could predict the drawn id at
+	/// that time, and the committer can't choose the randomness by choosing when to reveal.
+	///
+	/// The ids are drawn without replacement from `0..pool`, by swapping the drawn position with
+	/// the last one of the pool, so that drawing takes a constant number of storage operations.
+	pub(crate) fn draw_item_id(
+		who: &T::AccountId,
+		collection: &T::CollectionId,
+		secret: &[u8; 32],
+	) -> Result<T::ItemId, DispatchError> {
+		let (commitment, committed_at, deposit) =
+			RandomMintCommitments::<T, I>::take(collection, who)
+				.ok_or(Error::<T, I>::NoCommitment)?;
+		ensure!(T::Hashing::hash_of(secret) == commitment, Error::<T, I>::WrongSecret);
+		let now = frame_system::Pallet::<T>::block_number();
+		let seed_block = Self::random_mint_seed_block(committed_at);
+		ensure!(
+			now <= seed_block.saturating_add(T::RandomMintRevealPeriod::get()),
+			Error::<T, I>::CommitmentExpired
+		);
+
+		let pool =
+			RandomMintPool::<T, I>::get(collection).ok_or(Error::<T, I>::RandomMintUnavailable)?;
+		let last = pool.checked_sub(1).ok_or(Error::<T, I>::MaxSupplyReached)?;
+
+		let (random, known_since) =
+			RandomMintSeeds::<T, I>::get(seed_block).ok_or(Error::<T, I>::CommitmentTooRecent)?;
+		ensure!(known_since > committed_at, Error::<T, I>::CommitmentTooRecent);
+		let entropy = T::Hashing::hash_of(&(&random, collection, who, secret));
+		let position = <u32>::decode(&mut entropy.as_ref())
+			.expect("secure hashes should always be bigger than u32; qed") %
+			pool;
+
+		let id = RandomMintSwaps::<T, I>::take(collection, position).unwrap_or(position);
+		if position != last {
+			let moved = RandomMintSwaps::<T, I>::take(collection, last).unwrap_or(last);
+			RandomMintSwaps::<T, I>::insert(collection, position, moved);
+		}
+		RandomMintPool::<T, I>::insert(collection, last);
+		T::Currency::unreserve(who, deposit);
+		Ok(id.into())
+	}
+}
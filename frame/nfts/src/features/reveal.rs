@@ -0,0 +1,98 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_runtime::traits::Hash;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_commit_reveal(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		hash: T::Hash,
+		duration: <T as SystemConfig>::BlockNumber,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+		ensure!(duration <= T::MaxDeadlineDuration::get(), Error::<T, I>::WrongDuration);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let deadline = now.saturating_add(duration);
+		RevealCommitments::<T, I>::try_mutate(&collection, |commitments| {
+			commitments.retain(|(_, deadline)| *deadline >= now);
+			commitments
+				.try_push((hash, deadline))
+				.map_err(|_| Error::<T, I>::TooManyRevealCommitments)
+		})?;
+
+		Self::deposit_event(Event::RevealCommitted { collection, hash, deadline });
+		Ok(())
+	}
+
+	/// Take the commitment of the owner of `collection` to `batch` salted with `salt` and apply
+	/// its entries as the owner would.
+	pub(crate) fn do_reveal_batch(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		batch: RevealBatchOf<T, I>,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		let hash = T::Hashing::hash_of(&(salt, &batch));
+		RevealCommitments::<T, I>::try_mutate_exists(&collection, |maybe_commitments| {
+			let commitments =
+				maybe_commitments.as_mut().ok_or(Error::<T, I>::UnknownRevealCommitment)?;
+			let index = commitments
+				.iter()
+				.position(|(committed, _)| committed == &hash)
+				.ok_or(Error::<T, I>::UnknownRevealCommitment)?;
+			let (_, deadline) = commitments.remove(index);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(deadline >= now, Error::<T, I>::DeadlineExpired);
+			if commitments.is_empty() {
+				*maybe_commitments = None;
+			}
+			Ok::<(), DispatchError>(())
+		})?;
+
+		for entry in batch {
+			match entry {
+				RevealEntry::ItemMetadata { item, data } =>
+					Self::do_set_item_metadata(Some(details.owner.clone()), collection, item, data)?,
+				RevealEntry::Attribute { maybe_item, key, value } => Self::do_set_attribute(
+					details.owner.clone(),
+					collection,
+					maybe_item,
+					AttributeNamespace::CollectionOwner,
+					key,
+					value,
+				)?,
+			}
+		}
+
+		Self::deposit_event(Event::BatchRevealed { collection, hash });
+		Ok(())
+	}
+}
@@ -0,0 +1,98 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub(crate) fn do_set_collection_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		royalty: Option<CollectionRoyaltyFor<T, I>>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Trading),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		match royalty {
+			Some(royalty) => {
+				ensure!(royalty.is_whole(), Error::<T, I>::WrongRoyaltyShares);
+				ensure!(royalty.percentage <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+				CollectionRoyaltyOf::<T, I>::insert(&collection, &royalty);
+				Self::deposit_event(Event::CollectionRoyaltySet { collection, royalty });
+			},
+			None => {
+				CollectionRoyaltyOf::<T, I>::remove(&collection);
+				Self::deposit_event(Event::CollectionRoyaltyRemoved { collection });
+			},
+		}
+
+		Ok(())
+	}
+
+	/// The amount owed to each beneficiary of the royalty of `collection` on a sale of one of its
+	/// items for `price`. Beneficiaries owed nothing are left out.
+	pub(crate) fn royalty_payments(
+		collection: &T::CollectionId,
+		price: ItemPrice<T, I>,
+	) -> Vec<(T::AccountId, ItemPrice<T, I>)> {
+		let royalty = match CollectionRoyaltyOf::<T, I>::get(collection) {
+			Some(royalty) => royalty,
+			None => return Vec::new(),
+		};
+		let owed = royalty.percentage * price;
+		royalty
+			.beneficiaries
+			.into_iter()
+			.map(|(beneficiary, share)| (beneficiary, share * owed))
+			.filter(|(_, amount)| !amount.is_zero())
+			.collect()
+	}
+
+	/// Pay `price` from `buyer` to `seller` for `item` of `collection`, of which the royalty of
+	/// the collection goes to its beneficiaries instead. A share too small to create the account
+	/// of its beneficiary is left to the seller, so that it can't fail the sale.
+	pub(crate) fn pay_with_royalty(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let mut proceeds = price;
+		let minimum_balance = T::Currency::minimum_balance();
+		for (beneficiary, amount) in Self::royalty_payments(&collection, price) {
+			if amount < minimum_balance && T::Currency::total_balance(&beneficiary).is_zero() {
+				continue
+			}
+			T::Currency::transfer(buyer, &beneficiary, amount, KeepAlive)?;
+			proceeds.saturating_reduce(amount);
+			Self::deposit_event(Event::RoyaltyPaid { collection, item, beneficiary, amount });
+		}
+		T::Currency::transfer(buyer, seller, proceeds, KeepAlive)
+	}
+}
@@ -24,6 +24,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		config: CollectionConfigFor<T, I>,
 	) -> DispatchResult {
 		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		ensure!(
+			!DestroyCursor::<T, I>::contains_key(&collection),
+			Error::<T, I>::CollectionDestroying
+		);
 		CollectionConfigOf::<T, I>::insert(&collection, config);
 		Self::deposit_event(Event::CollectionConfigChanged { collection });
 		Ok(())
@@ -47,6 +51,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 
 		ensure!(details.items <= max_supply, Error::<T, I>::MaxSupplyTooSmall);
+		ensure!(!RandomMintPool::<T, I>::contains_key(&collection), Error::<T, I>::RandomMintOnly);
 
 		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
 			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
@@ -82,8 +87,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub(crate) fn get_collection_config(
 		collection_id: &T::CollectionId,
 	) -> Result<CollectionConfigFor<T, I>, DispatchError> {
-		let config =
-			CollectionConfigOf::<T, I>::get(&collection_id).ok_or(Error::<T, I>::NoConfig)?;
+		let config = CollectionConfigOf::<T, I>::get(&collection_id).ok_or_else(|| {
+			// The config of a collection is removed as soon as its destruction is started.
+			if DestroyCursor::<T, I>::contains_key(&collection_id) {
+				Error::<T, I>::CollectionDestroying
+			} else {
+				Error::<T, I>::NoConfig
+			}
+		})?;
 		Ok(config)
 	}
 
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::PalletInfoAccess};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn do_transfer(
@@ -27,10 +27,60 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			&CollectionDetailsFor<T, I>,
 			&mut ItemDetailsFor<T, I>,
 		) -> DispatchResult,
+	) -> DispatchResult {
+		Self::do_transfer_at_price(collection, item, dest, None, with_details)
+	}
+
+	/// Make a `transfer` authorized by the owner of its item with `signature`, consuming the
+	/// owner's next nonce.
+	pub(crate) fn do_transfer_pre_signed(
+		transfer: PreSignedTransferOf<T, I>,
+		signature: T::OffchainSignature,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= transfer.deadline, Error::<T, I>::DeadlineExpired);
+		let message = Self::pre_signed_transfer_message(&transfer);
+		let PreSignedTransfer { collection, item, dest, nonce, .. } = transfer;
+
+		Self::do_transfer(collection, item, dest, |_, details| {
+			ensure!(signature.verify(&message[..], &details.owner), Error::<T, I>::WrongSignature);
+			PreSignedTransferNonce::<T, I>::try_mutate(&details.owner, |next| {
+				ensure!(*next == nonce, Error::<T, I>::WrongNonce);
+				next.saturating_inc();
+				Ok(())
+			})
+		})
+	}
+
+	/// The message signed by the owner of an item to authorize `transfer`: the SCALE-encoded
+	/// genesis hash, name of the pallet instance and `transfer`, wrapped in `<Bytes>` as wallets
+	/// wrap the raw messages they sign. It can't be replayed on another chain or instance, nor
+	/// be mistaken for a transaction.
+	pub fn pre_signed_transfer_message(transfer: &PreSignedTransferOf<T, I>) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		let mut message = b"<Bytes>".to_vec();
+		(genesis_hash, <Self as PalletInfoAccess>::name().as_bytes(), transfer)
+			.encode_to(&mut message);
+		message.extend_from_slice(b"</Bytes>");
+		message
+	}
+
+	/// Transfer `item` to `dest`, noting `price` in the item's provenance if it was sold.
+	pub(crate) fn do_transfer_at_price(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		price: Option<ItemPrice<T, I>>,
+		with_details: impl FnOnce(
+			&CollectionDetailsFor<T, I>,
+			&mut ItemDetailsFor<T, I>,
+		) -> DispatchResult,
 	) -> DispatchResult {
 		let collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		ensure!(!Self::is_item_custody_locked(&collection, &item), Error::<T, I>::ItemLocked);
+		ensure!(!Self::is_collection_paused(&collection), Error::<T, I>::CollectionPaused);
 
 		let collection_config = Self::get_collection_config(&collection)?;
 		ensure!(
@@ -39,6 +89,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		);
 
 		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(!item_config.is_soulbound(), Error::<T, I>::ItemSoulbound);
 		ensure!(
 			item_config.is_setting_enabled(ItemSetting::Transferable),
 			Error::<T, I>::ItemLocked
@@ -50,12 +101,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if details.deposit.account == details.owner {
 			// Move the deposit to the new owner.
-			T::Currency::repatriate_reserved(
-				&details.owner,
-				&dest,
-				details.deposit.amount,
-				Reserved,
-			)?;
+			Self::repatriate_deposit(&details.owner, &dest, details.deposit.amount)?;
 		}
 
 		Account::<T, I>::remove((&details.owner, &collection, &item));
@@ -72,6 +118,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
 
+		if collection_config.has_disabled_setting(CollectionSetting::UntrackedProvenance) {
+			let record = ProvenanceRecord {
+				at: frame_system::Pallet::<T>::block_number(),
+				from: origin.clone(),
+				to: details.owner.clone(),
+				price,
+			};
+			// Drops the oldest record once the provenance is full.
+			ItemProvenance::<T, I>::mutate(collection, item, |provenance| {
+				let _ = provenance.force_insert_keep_right(provenance.len(), record);
+			});
+		}
+
 		Self::deposit_event(Event::Transferred {
 			collection,
 			item,
@@ -97,12 +156,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			// Move the deposit to the new owner.
-			T::Currency::repatriate_reserved(
-				&details.owner,
-				&owner,
-				details.owner_deposit,
-				Reserved,
-			)?;
+			Self::repatriate_deposit(&details.owner, &owner, details.owner_deposit)?;
 			CollectionAccount::<T, I>::remove(&details.owner, &collection);
 			CollectionAccount::<T, I>::insert(&owner, &collection, ());
 
@@ -148,12 +202,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			// Move the deposit to the new owner.
-			T::Currency::repatriate_reserved(
-				&details.owner,
-				&owner,
-				details.owner_deposit,
-				Reserved,
-			)?;
+			Self::repatriate_deposit(&details.owner, &owner, details.owner_deposit)?;
 
 			CollectionAccount::<T, I>::remove(&details.owner, &collection);
 			CollectionAccount::<T, I>::insert(&owner, &collection, ());
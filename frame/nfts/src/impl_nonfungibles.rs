@@ -93,7 +93,9 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 		) {
 			(Some(cc), Some(ic))
 				if cc.is_setting_enabled(CollectionSetting::TransferableItems) &&
-					ic.is_setting_enabled(ItemSetting::Transferable) =>
+					ic.is_setting_enabled(ItemSetting::Transferable) &&
+					!ic.is_soulbound() &&
+					!Self::is_item_custody_locked(collection, item) =>
 				true,
 			_ => false,
 		}
@@ -154,6 +156,7 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId, ItemConfig
 		item_config: &ItemConfig,
 		deposit_collection_owner: bool,
 	) -> DispatchResult {
+		ensure!(!RandomMintPool::<T, I>::contains_key(collection), Error::<T, I>::RandomMintOnly);
 		Self::do_mint(
 			*collection,
 			*item,
@@ -292,6 +295,38 @@ impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
 	}
 }
 
+impl<T: Config<I>, I: 'static> LockableNonFungible<T::AccountId> for Pallet<T, I> {
+	fn lock(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		reason: LockIdentifier,
+	) -> DispatchResult {
+		Self::do_lock_item_custody(*collection, *item, reason)
+	}
+
+	fn unlock(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		reason: LockIdentifier,
+	) -> DispatchResult {
+		Self::do_unlock_item_custody(*collection, *item, reason)
+	}
+
+	fn locked_by(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<LockIdentifier> {
+		ItemLocks::<T, I>::get(collection, item)
+	}
+}
+
+impl<T: Config<I>, I: 'static> InspectRoyalties<T::AccountId, ItemPrice<T, I>> for Pallet<T, I> {
+	fn royalties(
+		collection: &Self::CollectionId,
+		_item: &Self::ItemId,
+		price: ItemPrice<T, I>,
+	) -> Vec<(T::AccountId, ItemPrice<T, I>)> {
+		Self::royalty_payments(collection, price)
+	}
+}
+
 impl<T: Config<I>, I: 'static> InspectEnumerable<T::AccountId> for Pallet<T, I> {
 	type CollectionsIterator = KeyPrefixIterator<<T as Config<I>>::CollectionId>;
 	type ItemsIterator = KeyPrefixIterator<<T as Config<I>>::ItemId>;
@@ -24,7 +24,7 @@
 //! * [`System`](../frame_system/index.html)
 //! * [`Support`](../frame_support/index.html)
 
-#![recursion_limit = "256"]
+#![recursion_limit = "512"]
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -41,18 +41,23 @@ mod impl_nonfungibles;
 mod types;
 
 pub mod macros;
+pub mod migration;
 pub mod weights;
 
 use codec::{Decode, Encode};
-use frame_support::traits::{
-	tokens::{AttributeNamespace, Locker},
-	BalanceStatus::Reserved,
-	Currency, EnsureOriginWithArg, ReservableCurrency,
+use frame_support::{
+	traits::{
+		fungibles,
+		tokens::{AttributeNamespace, BalanceConversion, Locker},
+		BalanceStatus::Reserved,
+		Contains, Currency, EnsureOriginWithArg, LockIdentifier, Randomness, ReservableCurrency,
+	},
+	PalletId,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
-	RuntimeDebug,
+	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	PerThing, Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -65,26 +70,52 @@ type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Sourc
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+	use frame_support::{pallet_prelude::*, storage::KeyLenOf};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
-	pub trait BenchmarkHelper<CollectionId, ItemId> {
+	pub trait BenchmarkHelper<CollectionId, ItemId, Public, AccountId, Signature> {
 		fn collection(i: u16) -> CollectionId;
 		fn item(i: u16) -> ItemId;
+		fn signer() -> (Public, AccountId);
+		fn sign(signer: &Public, message: &[u8]) -> Signature;
 	}
 	#[cfg(feature = "runtime-benchmarks")]
-	impl<CollectionId: From<u16>, ItemId: From<u16>> BenchmarkHelper<CollectionId, ItemId> for () {
+	impl<CollectionId, ItemId, Public, AccountId, Signature>
+		BenchmarkHelper<CollectionId, ItemId, Public, AccountId, Signature> for ()
+	where
+		CollectionId: From<u16>,
+		ItemId: From<u16>,
+		Public: IdentifyAccount<AccountId = AccountId>
+			+ From<sp_core::ed25519::Public>
+			+ TryInto<sp_core::ed25519::Public>
+			+ Clone,
+		Signature: From<sp_core::ed25519::Signature>,
+	{
 		fn collection(i: u16) -> CollectionId {
 			i.into()
 		}
 		fn item(i: u16) -> ItemId {
 			i.into()
 		}
+		fn signer() -> (Public, AccountId) {
+			let public: Public = sp_io::crypto::ed25519_generate(0.into(), None).into();
+			(public.clone(), public.into_account())
+		}
+		fn sign(signer: &Public, message: &[u8]) -> Signature {
+			let public = signer.clone().try_into().ok().expect("the signer is an ed25519 key");
+			sp_io::crypto::ed25519_sign(0.into(), &public, message)
+				.expect("the key of the signer is in the keystore")
+				.into()
+		}
 	}
 
 	#[pallet::config]
@@ -98,7 +129,9 @@ pub mod pallet {
 		type CollectionId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
 
 		/// The type used to identify a unique item within a collection.
-		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
+		///
+		/// It converts from `u32` so that the ids of items minted at random can be drawn.
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy + From<u32>;
 
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
@@ -107,11 +140,15 @@ pub mod pallet {
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-		/// Standard collection creation is only allowed if the origin attempting it and the
-		/// collection are in this set.
+		/// Standard collection creation is only allowed if the origin attempting it, the
+		/// collection and the config it is requested with are in this set. The success value is
+		/// the creator of the collection.
+		///
+		/// Origins which only check the collection, such as `AsEnsureOriginWithArg`, work as
+		/// they did when the collection was their only argument.
 		type CreateOrigin: EnsureOriginWithArg<
 			Self::RuntimeOrigin,
-			Self::CollectionId,
+			(Self::CollectionId, CollectionConfigFor<Self, I>),
 			Success = Self::AccountId,
 		>;
 
@@ -163,17 +200,100 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxTips: Get<u32>;
 
+		/// The max number of beneficiaries the royalty of a collection could be split among.
+		#[pallet::constant]
+		type MaxRoyaltyBeneficiaries: Get<u32>;
+
+		/// The max part of the price of a sale the royalty of a collection could take.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// The max number of attributes that could be set in a single `set_attributes` call.
+		#[pallet::constant]
+		type MaxAttributesPerCall: Get<u32>;
+
+		/// The maximum number of storage entries removed by a single call destroying a
+		/// collection.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
 		/// The max duration in blocks for deadlines.
 		#[pallet::constant]
 		type MaxDeadlineDuration: Get<<Self as SystemConfig>::BlockNumber>;
 
+		/// The max number of batches of metadata and attributes a collection owner could be
+		/// committed to reveal at once.
+		#[pallet::constant]
+		type MaxRevealCommitments: Get<u32>;
+
+		/// The maximum number of ownership changes kept for an item of a collection which tracks
+		/// provenance.
+		#[pallet::constant]
+		type ProvenanceLength: Get<u32>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
 
+		/// The fungible assets in which deposits may be paid instead of `Currency`.
+		type DepositAssets: fungibles::Transfer<Self::AccountId>;
+
+		/// The assets which accounts may choose to pay their deposits in. These should be
+		/// sufficient assets, so that the pallet's account can hold them.
+		type DepositAssetFilter: Contains<DepositAssetIdOf<Self, I>>;
+
+		/// Converts a deposit denominated in `Currency` into the amount of a deposit asset to be
+		/// held instead.
+		type DepositConversion: BalanceConversion<
+			DepositBalanceOf<Self, I>,
+			DepositAssetIdOf<Self, I>,
+			DepositAssetBalanceOf<Self, I>,
+		>;
+
+		/// The pallet's id, used for deriving the account which holds deposits paid in assets.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Something that provides randomness in the runtime, used to assign the ids of items
+		/// minted with `mint_random`.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// The funds that must be reserved for a commitment to mint an item at random, until it is
+		/// revealed. They are always reserved in `Currency`, and forfeited if the commitment is
+		/// replaced or not revealed in time.
+		#[pallet::constant]
+		type RandomMintDeposit: Get<DepositBalanceOf<Self, I>>;
+
+		/// The number of blocks after a commitment to mint an item at random in which the
+		/// randomness it is revealed with is drawn.
+		///
+		/// It must be greater than the number of blocks for which the output of `Randomness` may
+		/// be known in advance, e.g. 81 for `pallet-randomness-collective-flip`, or no commitment
+		/// can be revealed.
+		#[pallet::constant]
+		type RandomMintDelay: Get<<Self as SystemConfig>::BlockNumber>;
+
+		/// The number of blocks after the randomness for a commitment to mint an item at random
+		/// is drawn within which the commitment must be revealed.
+		#[pallet::constant]
+		type RandomMintRevealPeriod: Get<<Self as SystemConfig>::BlockNumber>;
+
+		/// The signature with which owners authorize the transfers of their items off-chain, see
+		/// `transfer_pre_signed`.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key of an off-chain signer, identifying their on-chain account.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
-		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
+		type Helper: BenchmarkHelper<
+			Self::CollectionId,
+			Self::ItemId,
+			Self::OffchainPublic,
+			Self::AccountId,
+			Self::OffchainSignature,
+		>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -281,7 +401,8 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	/// A price of an item.
+	/// A price of an item, the only account which may buy it (if any) and the last block at which
+	/// it may be bought (if any).
 	#[pallet::storage]
 	pub(super) type ItemPriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
@@ -289,10 +410,16 @@ pub mod pallet {
 		T::CollectionId,
 		Blake2_128Concat,
 		T::ItemId,
-		(ItemPrice<T, I>, Option<T::AccountId>),
+		(ItemPrice<T, I>, Option<T::AccountId>, Option<<T as SystemConfig>::BlockNumber>),
 		OptionQuery,
 	>;
 
+	/// The raw key of `ItemPriceOf` after which the next call to `clear_expired_listings` carries
+	/// on looking for expired listings. Not set if it should start from the first listing.
+	#[pallet::storage]
+	pub(super) type ListingsCleanupCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<u8, KeyLenOf<ItemPriceOf<T, I>>>, OptionQuery>;
+
 	/// Item attribute approvals.
 	#[pallet::storage]
 	pub(super) type ItemAttributesApprovalsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -305,6 +432,30 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The last ownership changes of an item, oldest first, if its collection tracks provenance.
+	#[pallet::storage]
+	pub(super) type ItemProvenance<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		ProvenanceOf<T, I>,
+		ValueQuery,
+	>;
+
+	/// Accounts approved by an owner to transfer any of their items in a collection.
+	#[pallet::storage]
+	pub(super) type CollectionApprovalsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId, // owner
+		ApprovalsOf<T, I>,
+		ValueQuery,
+	>;
+
 	/// Stores the `CollectionId` that is going to be used for the next collection.
 	/// This gets incremented whenever a new collection is created.
 	#[pallet::storage]
@@ -333,6 +484,11 @@ pub mod pallet {
 	pub(super) type CollectionConfigOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionConfigFor<T, I>, OptionQuery>;
 
+	/// The royalty owed on the sales of the items of a collection, if any.
+	#[pallet::storage]
+	pub(super) type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionRoyaltyFor<T, I>, OptionQuery>;
+
 	/// Config of an item.
 	#[pallet::storage]
 	pub(super) type ItemConfigOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -345,6 +501,100 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Collections that are temporarily paused by their Admin.
+	#[pallet::storage]
+	pub(super) type PausedCollections<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (), OptionQuery>;
+
+	/// The reason for which an item is locked in place by its custodian, if it is.
+	#[pallet::storage]
+	pub(super) type ItemLocks<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		LockIdentifier,
+		OptionQuery,
+	>;
+
+	/// The asset an account has chosen to pay its deposits in, if not `Currency`.
+	#[pallet::storage]
+	pub(super) type DepositAssetOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DepositAssetIdOf<T, I>, OptionQuery>;
+
+	/// The deposits of an account which are held in an asset rather than reserved in `Currency`.
+	#[pallet::storage]
+	pub(super) type AssetDepositOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AssetDepositFor<T, I>, OptionQuery>;
+
+	/// The number of item ids of a collection which remain to be assigned by `mint_random`. Only
+	/// set for collections whose items are minted at random.
+	#[pallet::storage]
+	pub(super) type RandomMintPool<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, OptionQuery>;
+
+	/// The item ids which were moved within the pool of a collection as ids were assigned, by
+	/// their position in the pool. A position without an entry holds the id equal to it.
+	#[pallet::storage]
+	pub(super) type RandomMintSwaps<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		u32,
+		u32,
+		OptionQuery,
+	>;
+
+	/// The blocks in which the randomness is to be drawn for commitments to mint at random.
+	#[pallet::storage]
+	pub(super) type RandomMintSeedRequests<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, <T as SystemConfig>::BlockNumber, (), OptionQuery>;
+
+	/// The randomness drawn in a block for the commitments to mint at random made
+	/// `RandomMintDelay` blocks before, along with the block since which it was known. Kept for
+	/// the `RandomMintRevealPeriod`.
+	#[pallet::storage]
+	pub(super) type RandomMintSeeds<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		<T as SystemConfig>::BlockNumber,
+		(T::Hash, <T as SystemConfig>::BlockNumber),
+		OptionQuery,
+	>;
+
+	/// The hashes of the secrets which accounts committed to for `mint_random`, along with the
+	/// block in which they were committed and the deposit reserved for them.
+	#[pallet::storage]
+	pub(super) type RandomMintCommitments<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::Hash, <T as SystemConfig>::BlockNumber, DepositBalanceOf<T, I>),
+		OptionQuery,
+	>;
+
+	/// The hashes of the batches of metadata and attributes which the owner of a collection
+	/// committed to reveal, along with the last block in which each may be revealed.
+	#[pallet::storage]
+	pub(super) type RevealCommitments<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RevealCommitmentsOf<T, I>, ValueQuery>;
+
+	/// The stage reached by the destruction of a collection. Only set for collections which are
+	/// being destroyed.
+	#[pallet::storage]
+	pub(super) type DestroyCursor<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, DestroyStage, OptionQuery>;
+
+	/// The nonce which the next pre-signed transfer authorized by an account must carry. Every
+	/// pre-signed transfer made increments it, so that none can be replayed.
+	#[pallet::storage]
+	pub(super) type PreSignedTransferNonce<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -352,6 +602,8 @@ pub mod pallet {
 		Created { collection: T::CollectionId, creator: T::AccountId, owner: T::AccountId },
 		/// A `collection` was force-created.
 		ForceCreated { collection: T::CollectionId, owner: T::AccountId },
+		/// The destruction of a `collection` was started.
+		DestructionStarted { collection: T::CollectionId },
 		/// A `collection` was destroyed.
 		Destroyed { collection: T::CollectionId },
 		/// An `item` was issued.
@@ -378,6 +630,12 @@ pub mod pallet {
 		},
 		/// Some `collection` was locked.
 		CollectionLocked { collection: T::CollectionId },
+		/// All transfers, sales and approvals within a `collection` were paused.
+		CollectionPaused { collection: T::CollectionId },
+		/// A paused `collection` was resumed.
+		CollectionUnpaused { collection: T::CollectionId },
+		/// The asset in which `who` pays their deposits has been changed.
+		DepositAssetSet { who: T::AccountId, asset: Option<DepositAssetIdOf<T, I>> },
 		/// The owner changed.
 		OwnerChanged { collection: T::CollectionId, new_owner: T::AccountId },
 		/// The management team changed.
@@ -406,6 +664,22 @@ pub mod pallet {
 		},
 		/// All approvals of an item got cancelled.
 		AllApprovalsCancelled { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// An `owner` approved a `delegate` to transfer any of their items in a `collection`.
+		CollectionTransferApproved {
+			collection: T::CollectionId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			deadline: Option<<T as SystemConfig>::BlockNumber>,
+		},
+		/// An approval for a `delegate` account to transfer any of the items of an `owner` in a
+		/// `collection` was cancelled.
+		CollectionApprovalCancelled {
+			collection: T::CollectionId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+		},
+		/// All approvals of an `owner` in a `collection` got cancelled.
+		AllCollectionApprovalsCancelled { collection: T::CollectionId, owner: T::AccountId },
 		/// A `collection` has had its config changed by the `Force` origin.
 		CollectionConfigChanged { collection: T::CollectionId },
 		/// New metadata has been set for a `collection`.
@@ -430,6 +704,13 @@ pub mod pallet {
 			value: BoundedVec<u8, T::ValueLimit>,
 			namespace: AttributeNamespace<T::AccountId>,
 		},
+		/// A number of attributes were set for a `collection` / `maybe_item` at once.
+		AttributesSet {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			count: u32,
+		},
 		/// Attribute metadata has been cleared for a `collection` or `item`.
 		AttributeCleared {
 			collection: T::CollectionId,
@@ -463,6 +744,7 @@ pub mod pallet {
 			item: T::ItemId,
 			price: ItemPrice<T, I>,
 			whitelisted_buyer: Option<T::AccountId>,
+			expires_at: Option<<T as SystemConfig>::BlockNumber>,
 		},
 		/// The price for the item was removed.
 		ItemPriceRemoved { collection: T::CollectionId, item: T::ItemId },
@@ -474,6 +756,18 @@ pub mod pallet {
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
+		/// The royalty of a collection was set.
+		CollectionRoyaltySet { collection: T::CollectionId, royalty: CollectionRoyaltyFor<T, I> },
+		/// The royalty of a collection was removed.
+		CollectionRoyaltyRemoved { collection: T::CollectionId },
+		/// A beneficiary of the royalty of a collection was paid their share of it on the sale
+		/// of an item.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			beneficiary: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
 		/// A tip was sent.
 		TipSent {
 			collection: T::CollectionId,
@@ -511,6 +805,32 @@ pub mod pallet {
 			price: Option<PriceWithDirection<ItemPrice<T, I>>>,
 			deadline: <T as SystemConfig>::BlockNumber,
 		},
+		/// The items of a `collection` are now minted at random, out of a `pool` of item ids.
+		RandomMintEnabled { collection: T::CollectionId, pool: u32 },
+		/// The account `who` committed to a secret for minting an item of a `collection` at
+		/// random.
+		RandomMintCommitted { collection: T::CollectionId, who: T::AccountId },
+		/// The `deposit` of a commitment of `who` to mint an item of a `collection` at random was
+		/// forfeited, as the commitment was replaced or not revealed in time.
+		RandomMintCommitmentForfeited {
+			collection: T::CollectionId,
+			who: T::AccountId,
+			deposit: DepositBalanceOf<T, I>,
+		},
+		/// The owner of a `collection` committed to reveal the batch of metadata and attributes
+		/// with the given `hash` by the `deadline`.
+		RevealCommitted {
+			collection: T::CollectionId,
+			hash: T::Hash,
+			deadline: <T as SystemConfig>::BlockNumber,
+		},
+		/// The batch of metadata and attributes with the given `hash` was revealed and applied to
+		/// a `collection`.
+		BatchRevealed { collection: T::CollectionId, hash: T::Hash },
+		/// An `item` was locked in place for the given `reason`.
+		ItemCustodyLocked { collection: T::CollectionId, item: T::ItemId, reason: LockIdentifier },
+		/// An `item` locked for the given `reason` was unlocked.
+		ItemCustodyUnlocked { collection: T::CollectionId, item: T::ItemId, reason: LockIdentifier },
 	}
 
 	#[pallet::error]
@@ -563,10 +883,16 @@ pub mod pallet {
 		NotForSale,
 		/// The provided bid is too low.
 		BidTooLow,
+		/// The listing of the item has expired.
+		ListingExpired,
 		/// The item has reached its approval limit.
 		ReachedApprovalLimit,
 		/// The deadline has already expired.
 		DeadlineExpired,
+		/// The signature does not match the owner of the item and the signed payload.
+		WrongSignature,
+		/// The nonce of the pre-signed transfer is not the next one of its signer.
+		WrongNonce,
 		/// The duration provided should be less than or equal to `MaxDeadlineDuration`.
 		WrongDuration,
 		/// The method is disabled by system settings.
@@ -587,6 +913,61 @@ pub mod pallet {
 		AlreadyClaimed,
 		/// The provided data is incorrect.
 		IncorrectData,
+		/// The collection is paused.
+		CollectionPaused,
+		/// The collection is not paused.
+		CollectionNotPaused,
+		/// The asset may not be used to pay deposits.
+		DepositAssetNotAllowed,
+		/// Deposits are still held in a different asset.
+		DepositAssetMismatch,
+		/// The deposit could not be converted into the deposit asset.
+		DepositConversionFailed,
+		/// The items of the collection are minted at random, so their ids can't be chosen.
+		RandomMintOnly,
+		/// The items of the collection are not minted at random, or can't be as the collection
+		/// has no max supply or already has items.
+		RandomMintUnavailable,
+		/// The account has not committed to a secret for minting at random.
+		NoCommitment,
+		/// The secret does not match the commitment.
+		WrongSecret,
+		/// The randomness to mint with is not drawn yet, or is not newer than the commitment.
+		CommitmentTooRecent,
+		/// The commitment was not revealed within the `RandomMintRevealPeriod`.
+		CommitmentExpired,
+		/// The commitment may still be revealed.
+		CommitmentNotExpired,
+		/// The item is soulbound, so it may not be transferred, traded or approved for transfer.
+		ItemSoulbound,
+		/// The collection is being destroyed.
+		CollectionDestroying,
+		/// The collection is not being destroyed.
+		CollectionNotDestroying,
+		/// The item is not locked for the given reason.
+		WrongLockReason,
+		/// The collection owner is committed to reveal too many batches already.
+		TooManyRevealCommitments,
+		/// The batch does not match any commitment of the collection owner.
+		UnknownRevealCommitment,
+		/// The shares of the beneficiaries of a royalty don't add up to 100%.
+		WrongRoyaltyShares,
+		/// The royalty takes a greater part of the price than is allowed.
+		RoyaltyTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			Self::note_random_mint_seed(now)
+		}
+
+		fn integrity_test() {
+			assert!(
+				!T::RandomMintDelay::get().is_zero(),
+				"the randomness for a commitment must be drawn after it is made"
+			);
+		}
 	}
 
 	#[pallet::call]
@@ -595,7 +976,8 @@ pub mod pallet {
 		///
 		/// This new collection has no items initially and its owner is the origin.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must pass `CreateOrigin` for the collection and the `config` requested, and
+		/// the sender must have sufficient funds free.
 		///
 		/// `ItemDeposit` funds of sender are reserved.
 		///
@@ -616,7 +998,7 @@ pub mod pallet {
 			let collection =
 				NextCollectionId::<T, I>::get().unwrap_or(T::CollectionId::initial_value());
 
-			let owner = T::CreateOrigin::ensure_origin(origin, &collection)?;
+			let owner = T::CreateOrigin::ensure_origin(origin, &(collection, config))?;
 			let admin = T::Lookup::lookup(admin)?;
 
 			// DepositRequired can be disabled by calling the force_create() only
@@ -682,18 +1064,20 @@ pub mod pallet {
 		/// - `witness`: Information on the items minted in the collection. This must be
 		/// correct.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// The collection can no longer be used once its destruction is started. Its items, then
+		/// the metadata of its items, then its attributes and finally its configs are removed, at
+		/// most `RemoveItemsLimit` entries at a time. This call removes the first of them, and
+		/// anyone may remove the rest with `continue_destroy`. The deposits are returned as their
+		/// entries are removed.
+		///
+		/// Emits `DestructionStarted` event when successful, and `Destroyed` once the collection
+		/// is fully destroyed.
 		///
-		/// Weight: `O(n + m)` where:
-		/// - `n = witness.items`
-		/// - `m = witness.item_metadatas`
-		/// - `a = witness.attributes`
+		/// Weight: `O(RemoveItemsLimit)`
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::destroy(
-			witness.items,
- 			witness.item_metadatas,
-			witness.attributes,
- 		))]
+		#[pallet::weight(T::WeightInfo::destroy().saturating_add(
+			Pallet::<T, I>::max_destroy_progress_weight(T::RemoveItemsLimit::get())
+		))]
 		pub fn destroy(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -702,14 +1086,10 @@ pub mod pallet {
 			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
-			let details = Self::do_destroy_collection(collection, witness, maybe_check_owner)?;
-
-			Ok(Some(T::WeightInfo::destroy(
-				details.items,
-				details.item_metadatas,
-				details.attributes,
-			))
-			.into())
+			Self::do_start_destroy(collection, witness, maybe_check_owner)?;
+			let weight = Self::do_continue_destroy(collection, T::RemoveItemsLimit::get())?;
+
+			Ok(Some(T::WeightInfo::destroy().saturating_add(weight)).into())
 		}
 
 		/// Mint an item of a particular collection.
@@ -738,10 +1118,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 			let mint_to = T::Lookup::lookup(mint_to)?;
+			ensure!(
+				!RandomMintPool::<T, I>::contains_key(&collection),
+				Error::<T, I>::RandomMintOnly
+			);
 
 			let collection_config = Self::get_collection_config(&collection)?;
 			let item_settings = collection_config.mint_settings.default_item_settings;
-			let item_config = ItemConfig { settings: item_settings };
+			let item_config = ItemConfig { settings: item_settings, soulbound: None };
 
 			Self::do_mint(
 				collection,
@@ -751,67 +1135,13 @@ pub mod pallet {
 				item_config,
 				false,
 				|collection_details, collection_config| {
-					// Issuer can mint regardless of mint settings
-					if Self::has_role(&collection, &caller, CollectionRole::Issuer) {
-						return Ok(())
-					}
-
-					let mint_settings = collection_config.mint_settings;
-					let now = frame_system::Pallet::<T>::block_number();
-
-					if let Some(start_block) = mint_settings.start_block {
-						ensure!(start_block <= now, Error::<T, I>::MintNotStarted);
-					}
-					if let Some(end_block) = mint_settings.end_block {
-						ensure!(end_block >= now, Error::<T, I>::MintEnded);
-					}
-
-					match mint_settings.mint_type {
-						MintType::Issuer => return Err(Error::<T, I>::NoPermission.into()),
-						MintType::HolderOf(collection_id) => {
-							let MintWitness { owner_of_item } =
-								witness_data.ok_or(Error::<T, I>::BadWitness)?;
-
-							let has_item = Account::<T, I>::contains_key((
-								&caller,
-								&collection_id,
-								&owner_of_item,
-							));
-							ensure!(has_item, Error::<T, I>::BadWitness);
-
-							let attribute_key = Self::construct_attribute_key(
-								PalletAttributes::<T::CollectionId>::UsedToClaim(collection)
-									.encode(),
-							)?;
-
-							let key = (
-								&collection_id,
-								Some(owner_of_item),
-								AttributeNamespace::Pallet,
-								&attribute_key,
-							);
-							let already_claimed = Attribute::<T, I>::contains_key(key.clone());
-							ensure!(!already_claimed, Error::<T, I>::AlreadyClaimed);
-
-							let value = Self::construct_attribute_value(vec![0])?;
-							Attribute::<T, I>::insert(
-								key,
-								(value, AttributeDeposit { account: None, amount: Zero::zero() }),
-							);
-						},
-						_ => {},
-					}
-
-					if let Some(price) = mint_settings.price {
-						T::Currency::transfer(
-							&caller,
-							&collection_details.owner,
-							price,
-							ExistenceRequirement::KeepAlive,
-						)?;
-					}
-
-					Ok(())
+					Self::ensure_can_mint(
+						&collection,
+						&caller,
+						witness_data,
+						collection_details,
+						collection_config,
+					)
 				},
 			)
 		}
@@ -824,7 +1154,7 @@ pub mod pallet {
 		/// - `collection`: The collection of the item to be minted.
 		/// - `item`: An identifier of the new item.
 		/// - `mint_to`: Account into which the item will be minted.
-		/// - `item_config`: A config of the new item.
+		/// - `item_config`: A config of the new item, which may make it soulbound.
 		///
 		/// Emits `Issued` event when successful.
 		///
@@ -849,6 +1179,10 @@ pub mod pallet {
 					Error::<T, I>::NoPermission
 				);
 			}
+			ensure!(
+				!RandomMintPool::<T, I>::contains_key(&collection),
+				Error::<T, I>::RandomMintOnly
+			);
 			Self::do_mint(collection, item, mint_to.clone(), mint_to, item_config, true, |_, _| {
 				Ok(())
 			})
@@ -860,6 +1194,10 @@ pub mod pallet {
 		/// - the Admin of the `collection`;
 		/// - the Owner of the `item`;
 		///
+		/// If the item is soulbound, the signing account must instead be either the Owner of the
+		/// `item` if its rules allow the holder to burn it, or the Issuer of the `collection` if
+		/// they allow the item to be revoked.
+		///
 		/// - `collection`: The collection of the item to be burned.
 		/// - `item`: The item to be burned.
 		/// - `check_owner`: If `Some` then the operation will fail with `WrongOwner` unless the
@@ -881,8 +1219,15 @@ pub mod pallet {
 			let check_owner = check_owner.map(T::Lookup::lookup).transpose()?;
 
 			Self::do_burn(collection, item, |details| {
-				let is_admin = Self::has_role(&collection, &origin, CollectionRole::Admin);
-				let is_permitted = is_admin || details.owner == origin;
+				let is_permitted = match Self::get_item_config(&collection, &item)?.soulbound {
+					Some(rules) =>
+						(rules.holder_may_burn && details.owner == origin) ||
+							(rules.issuer_may_revoke &&
+								Self::has_role(&collection, &origin, CollectionRole::Issuer)),
+					None =>
+						details.owner == origin ||
+							Self::has_role(&collection, &origin, CollectionRole::Admin),
+				};
 				ensure!(is_permitted, Error::<T, I>::NoPermission);
 				ensure!(
 					check_owner.map_or(true, |o| o == details.owner),
@@ -897,7 +1242,9 @@ pub mod pallet {
 		/// Origin must be Signed and the signing account must be either:
 		/// - the Admin of the `collection`;
 		/// - the Owner of the `item`;
-		/// - the approved delegate for the `item` (in this case, the approval is reset).
+		/// - the approved delegate for the `item` (in this case, the approval is reset);
+		/// - an approved delegate of the Owner for the whole `collection`, even if their approval
+		///   for the `item` has expired.
 		///
 		/// Arguments:
 		/// - `collection`: The collection of the item to be transferred.
@@ -921,11 +1268,22 @@ pub mod pallet {
 			Self::do_transfer(collection, item, dest, |_, details| {
 				let is_admin = Self::has_role(&collection, &origin, CollectionRole::Admin);
 				if details.owner != origin && !is_admin {
-					let deadline =
-						details.approvals.get(&origin).ok_or(Error::<T, I>::NoPermission)?;
-					if let Some(d) = deadline {
-						let block_number = frame_system::Pallet::<T>::block_number();
-						ensure!(block_number <= *d, Error::<T, I>::ApprovalExpired);
+					let block_number = frame_system::Pallet::<T>::block_number();
+					let unexpired = |deadline: &Option<T::BlockNumber>| {
+						deadline.map_or(true, |d| block_number <= d)
+					};
+					let item_approval = details.approvals.get(&origin);
+					// An expired approval for the item leaves the approval for the collection.
+					if !item_approval.map_or(false, unexpired) {
+						match CollectionApprovalsOf::<T, I>::get(&collection, &details.owner)
+							.get(&origin)
+						{
+							Some(deadline) =>
+								ensure!(unexpired(deadline), Error::<T, I>::ApprovalExpired),
+							None if item_approval.is_some() =>
+								return Err(Error::<T, I>::ApprovalExpired.into()),
+							None => return Err(Error::<T, I>::NoPermission.into()),
+						}
 					}
 				}
 				Ok(())
@@ -976,9 +1334,9 @@ pub mod pallet {
 				};
 				let old = details.deposit.amount;
 				if old > deposit {
-					T::Currency::unreserve(&details.deposit.account, old - deposit);
+					Self::unreserve_deposit(&details.deposit.account, old - deposit);
 				} else if deposit > old {
-					if T::Currency::reserve(&details.deposit.account, deposit - old).is_err() {
+					if Self::reserve_deposit(&details.deposit.account, deposit - old).is_err() {
 						// NOTE: No alterations made to collection_details in this iteration so far,
 						// so this is OK to do.
 						continue
@@ -1621,6 +1979,8 @@ pub mod pallet {
 		/// - `item`: The item to set the price for.
 		/// - `price`: The price for the item. Pass `None`, to reset the price.
 		/// - `buyer`: Restricts the buy operation to a specific account.
+		/// - `expires_at`: The last block at which the item may be bought at this price, at most
+		///   `MaxDeadlineDuration` blocks from now. Pass `None` for the listing to never expire.
 		///
 		/// Emits `ItemPriceSet` on success if the price is not `None`.
 		/// Emits `ItemPriceRemoved` on success if the price is `None`.
@@ -1632,10 +1992,11 @@ pub mod pallet {
 			item: T::ItemId,
 			price: Option<ItemPrice<T, I>>,
 			whitelisted_buyer: Option<AccountIdLookupOf<T>>,
+			expires_at: Option<<T as SystemConfig>::BlockNumber>,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
-			Self::do_set_price(collection, item, origin, price, whitelisted_buyer)
+			Self::do_set_price(collection, item, origin, price, whitelisted_buyer, expires_at)
 		}
 
 		/// Allows to buy an item if it's up for sale.
@@ -1648,7 +2009,7 @@ pub mod pallet {
 		///
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(T::WeightInfo::buy_item(T::MaxRoyaltyBeneficiaries::get()))]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1748,7 +2109,7 @@ pub mod pallet {
 		///
 		/// Emits `SwapClaimed` on success.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(T::WeightInfo::claim_swap(T::MaxRoyaltyBeneficiaries::get()))]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1767,6 +2128,462 @@ pub mod pallet {
 				witness_price,
 			)
 		}
+
+		/// Temporarily disallow transfers, sales and approvals of all items within a collection.
+		///
+		/// Items can still be burned by their owners while the collection is paused.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the `collection`.
+		///
+		/// - `collection`: The collection to be paused.
+		///
+		/// Emits `CollectionPaused`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::pause_collection())]
+		pub fn pause_collection(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_pause_collection(origin, collection)
+		}
+
+		/// Re-allow transfers, sales and approvals within a previously paused collection.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the `collection`.
+		///
+		/// - `collection`: The collection to be unpaused.
+		///
+		/// Emits `CollectionUnpaused`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::unpause_collection())]
+		pub fn unpause_collection(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_unpause_collection(origin, collection)
+		}
+
+		/// Set multiple attributes for a collection or item at once.
+		///
+		/// Origin must be Signed and must conform to the namespace ruleset:
+		/// - `CollectionOwner` namespace could be modified by the `collection` owner only;
+		/// - `ItemOwner` namespace could be modified by the `maybe_item` owner only. `maybe_item`
+		///   should be set in that case;
+		/// - `Account(AccountId)` namespace could be modified only when the `origin` was given a
+		///   permission to do so;
+		///
+		/// The funds of `origin` are reserved or unreserved once, according to the total deposit
+		/// required by all the attributes, taking into account any already reserved funds.
+		///
+		/// - `collection`: The identifier of the collection whose item's metadata to set.
+		/// - `maybe_item`: The identifier of the item whose metadata to set.
+		/// - `namespace`: Attributes' namespace.
+		/// - `attributes`: The keys of the attributes and the values to set them to.
+		///
+		/// Emits `AttributesSet`.
+		///
+		/// Weight: `O(A)` where `A` is the number of attributes.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_attributes(attributes.len() as u32))]
+		pub fn set_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			attributes: AttributesOf<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_attributes(origin, collection, maybe_item, namespace, attributes)
+		}
+
+		/// Choose the asset in which the deposits of the sender are paid from now on.
+		///
+		/// Origin must be Signed.
+		///
+		/// Deposits taken in an asset are held by the pallet's account and are returned in the
+		/// same asset, in proportion to the part of the deposit being released. Deposits are
+		/// released from those held in an asset first. While some deposits are held in an asset,
+		/// a different asset cannot be chosen.
+		///
+		/// - `asset`: The asset to pay deposits in, or `None` to pay them in `Currency`.
+		///
+		/// Emits `DepositAssetSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_deposit_asset())]
+		pub fn set_deposit_asset(
+			origin: OriginFor<T>,
+			asset: Option<DepositAssetIdOf<T, I>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_deposit_asset(origin, asset)
+		}
+
+		/// Approve a delegated third-party account to transfer any item of the sender in a
+		/// collection, including items received later.
+		///
+		/// Origin must be Signed.
+		///
+		/// Unlike approvals of a single item, these approvals are kept when an item is transferred
+		/// and apply to whoever the sender is in the collection.
+		///
+		/// - `collection`: The collection whose items of the sender are approved for delegated
+		///   transfer.
+		/// - `delegate`: The account to delegate permission to transfer the items.
+		/// - `maybe_deadline`: Optional deadline for the approval. Specified by providing the
+		/// 	number of blocks after which the approval will expire
+		///
+		/// Emits `CollectionTransferApproved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::approve_collection_transfer())]
+		pub fn approve_collection_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			delegate: AccountIdLookupOf<T>,
+			maybe_deadline: Option<<T as SystemConfig>::BlockNumber>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_approve_collection_transfer(origin, collection, delegate, maybe_deadline)
+		}
+
+		/// Cancel one of the collection-wide transfer approvals of the sender.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `collection`: The collection of the approval to be cancelled.
+		/// - `delegate`: The account that is going to loose their approval.
+		///
+		/// Emits `CollectionApprovalCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::cancel_collection_approval())]
+		pub fn cancel_collection_approval(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			delegate: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::do_cancel_collection_approval(origin, collection, delegate)
+		}
+
+		/// Cancel all the collection-wide transfer approvals of the sender in a collection.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `collection`: The collection whose approvals will be cleared.
+		///
+		/// Emits `AllCollectionApprovalsCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::clear_all_collection_approvals())]
+		pub fn clear_all_collection_approvals(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_clear_all_collection_approvals(origin, collection)
+		}
+
+		/// Mint the items of a collection at random from now on.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The collection to mint at random. It must have a max supply and no
+		///   items.
+		///
+		/// The ids `0..max_supply` are then assigned by `mint_random`, in an order which can't be
+		/// predicted, and items can no longer be minted by id.
+		///
+		/// Emits `RandomMintEnabled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::enable_random_mint())]
+		pub fn enable_random_mint(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_enable_random_mint(maybe_check_owner, collection)
+		}
+
+		/// Commit to a secret with which to mint an item of a collection at random.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `collection`: The collection of the item to be minted.
+		/// - `commitment`: The hash of the secret to be revealed to `mint_random`.
+		///
+		/// The `RandomMintDeposit` is reserved from the sender until the commitment is revealed.
+		/// Any earlier commitment of the sender for the `collection` is replaced, and its deposit
+		/// forfeited. The commitment may be revealed once the randomness is drawn for it,
+		/// `RandomMintDelay` blocks later.
+		///
+		/// Emits `RandomMintCommitted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::commit_random_mint())]
+		pub fn commit_random_mint(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_commit_random_mint(who, collection, commitment)
+		}
+
+		/// Mint an item of a collection, whose id is drawn at random from the ids which are not
+		/// assigned yet.
+		///
+		/// The origin must be Signed and have committed to `secret` with `commit_random_mint`
+		/// `RandomMintDelay` blocks before the randomness which is mixed with it was drawn, at most
+		/// `RandomMintRevealPeriod` blocks ago. The mint settings of the `collection` apply as for
+		/// `mint`. The deposit of the commitment is released.
+		///
+		/// - `collection`: The collection of the item to be minted.
+		/// - `secret`: The secret committed to, which is mixed with the on-chain randomness.
+		/// - `mint_to`: Account into which the item will be minted.
+		/// - `witness_data`: When the mint type is `HolderOf(collection_id)`, then the owned
+		///   item_id from that collection needs to be provided within the witness data object.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::mint_random())]
+		pub fn mint_random(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			secret: [u8; 32],
+			mint_to: AccountIdLookupOf<T>,
+			witness_data: Option<MintWitness<T::ItemId>>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			let mint_to = T::Lookup::lookup(mint_to)?;
+			let item = Self::draw_item_id(&caller, &collection, &secret)?;
+
+			let collection_config = Self::get_collection_config(&collection)?;
+			let item_settings = collection_config.mint_settings.default_item_settings;
+			let item_config = ItemConfig { settings: item_settings, soulbound: None };
+
+			Self::do_mint(
+				collection,
+				item,
+				caller.clone(),
+				mint_to,
+				item_config,
+				false,
+				|collection_details, collection_config| {
+					Self::ensure_can_mint(
+						&collection,
+						&caller,
+						witness_data,
+						collection_details,
+						collection_config,
+					)
+				},
+			)
+		}
+
+		/// Continue the destruction of a collection, removing at most `RemoveItemsLimit` of its
+		/// remaining entries.
+		///
+		/// Origin must be Signed. Anyone may continue the destruction of any collection.
+		///
+		/// - `collection`: The collection being destroyed.
+		///
+		/// Emits `Destroyed` event once the collection is fully destroyed.
+		///
+		/// Weight: `O(RemoveItemsLimit)`
+		#[pallet::call_index(47)]
+		#[pallet::weight(Pallet::<T, I>::max_destroy_progress_weight(T::RemoveItemsLimit::get()))]
+		pub fn continue_destroy(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let weight = Self::do_continue_destroy(collection, T::RemoveItemsLimit::get())?;
+			Ok(Some(weight).into())
+		}
+
+		/// Remove the listings which have expired, looking at no more than `max` listings.
+		///
+		/// Origin must be Signed. Anyone may clear expired listings.
+		///
+		/// Each call carries on from the listing at which the previous one stopped, so repeated
+		/// calls eventually look at all listings.
+		///
+		/// - `max`: The maximum number of listings to look at.
+		///
+		/// Emits `ItemPriceRemoved` for every expired listing removed.
+		///
+		/// Weight: `O(max)`
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::clear_expired_listings(*max))]
+		pub fn clear_expired_listings(origin: OriginFor<T>, max: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_clear_expired_listings(max);
+			Ok(())
+		}
+
+		/// Transfer an item on behalf of its owner, who authorized the transfer by signing it
+		/// off-chain.
+		///
+		/// Origin must be Signed. Anyone may submit the transfer, paying its fees, so that the
+		/// owner does not have to.
+		///
+		/// - `transfer`: The transfer signed by the owner: the item, the account to receive it, the
+		///   last block in which it may be made and the next nonce of the owner, see
+		///   `PreSignedTransferNonce`.
+		/// - `signature`: The signature by the owner of the item of the message returned by
+		///   `pre_signed_transfer_message` for `transfer`.
+		///
+		/// Emits `Transferred`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::transfer_pre_signed())]
+		pub fn transfer_pre_signed(
+			origin: OriginFor<T>,
+			transfer: Box<PreSignedTransferOf<T, I>>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_transfer_pre_signed(*transfer, signature)
+		}
+
+		/// Commit to reveal a batch of metadata and attributes of a collection, such as the traits
+		/// of its items, by a deadline.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection the batch is for.
+		/// - `hash`: The hash of the SCALE-encoded pair of a secret salt and the batch, to be
+		///   revealed with `reveal_batch`. The salt keeps a batch of few possible values from being
+		///   guessed from its hash before it is revealed.
+		/// - `duration`: The number of blocks, from the current one, in which the batch may be
+		///   revealed. Should be less than or equal to `MaxDeadlineDuration`.
+		///
+		/// Commitments whose deadline has passed are dropped.
+		///
+		/// Emits `RevealCommitted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::commit_reveal())]
+		pub fn commit_reveal(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			hash: T::Hash,
+			duration: <T as SystemConfig>::BlockNumber,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_commit_reveal(maybe_check_owner, collection, hash, duration)
+		}
+
+		/// Reveal a batch of metadata and attributes of a collection committed to with
+		/// `commit_reveal`, and apply it as the collection owner.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection the batch is for.
+		/// - `batch`: The batch, whose hash with `salt` must match a commitment whose deadline has
+		///   not passed.
+		/// - `salt`: The salt committed to along with the batch.
+		///
+		/// The entries are applied in order, as `set_metadata` and `set_attribute` in the
+		/// `CollectionOwner` namespace would, and the commitment is removed.
+		///
+		/// Emits `BatchRevealed` event when successful.
+		///
+		/// Weight: `O(batch.len())`
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::reveal_batch(batch.len() as u32))]
+		pub fn reveal_batch(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			batch: RevealBatchOf<T, I>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_reveal_batch(maybe_check_owner, collection, batch, salt)
+		}
+
+		/// Set or remove the royalty owed on every sale of an item of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection to set the royalty for.
+		/// - `royalty`: The part of the price of a sale owed as royalty, at most `MaxRoyalty`, and
+		///   the beneficiaries it is split among, whose shares must add up to 100%, or `None` to
+		///   remove the royalty.
+		///
+		/// The royalty is paid by the buyer out of the price, so that the seller receives the
+		/// rest, on `buy_item` and on `claim_swap` with a price. A share too small to create the
+		/// account of its beneficiary is left to the seller.
+		///
+		/// Emits `CollectionRoyaltySet` or `CollectionRoyaltyRemoved` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty(T::MaxRoyaltyBeneficiaries::get()))]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalty: Option<CollectionRoyaltyFor<T, I>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_royalty(maybe_check_owner, collection, royalty)
+		}
+
+		/// Remove a commitment to mint an item of a collection at random which was not revealed
+		/// in time, forfeiting its deposit.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `collection`: The collection of the commitment.
+		/// - `who`: The account which made the commitment.
+		///
+		/// Emits `RandomMintCommitmentForfeited` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::clear_random_mint_commitment())]
+		pub fn clear_random_mint_commitment(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_clear_random_mint_commitment(collection, who)
+		}
 	}
 }
 
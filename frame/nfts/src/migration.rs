@@ -0,0 +1,101 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the NFTs pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+pub mod v1 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::nfts::migration::v1";
+
+	/// Config of an item, before items could be soulbound.
+	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ItemConfig {
+		pub settings: ItemSettings,
+	}
+
+	/// Adds an expiry, none, to the prices of all listed items, and marks all existing items as
+	/// not soulbound.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 0, "migration from version 0 to 1.");
+			let prices = ItemPriceOf::<T, I>::iter_keys().count() as u32;
+			let configs = ItemConfigOf::<T, I>::iter_keys().count() as u32;
+			Ok((prices, configs).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 0 {
+				log::warn!(target: TARGET, "skipping migration from v0 to v1.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut prices = 0u64;
+			ItemPriceOf::<T, I>::translate::<(ItemPrice<T, I>, Option<T::AccountId>), _>(
+				|_, _, (price, buyer)| {
+					prices.saturating_inc();
+					Some((price, buyer, None))
+				},
+			);
+			log::info!(target: TARGET, "migrated the prices of {} items.", prices);
+
+			let mut configs = 0u64;
+			ItemConfigOf::<T, I>::translate_values::<ItemConfig, _>(|old| {
+				configs.saturating_inc();
+				Some(crate::ItemConfig { settings: old.settings, soulbound: None })
+			});
+			log::info!(target: TARGET, "migrated the configs of {} items.", configs);
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			let entries = prices.saturating_add(configs).saturating_add(1);
+			T::DbWeight::get().reads_writes(entries, entries)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 1, "must upgrade from version 0 to 1.");
+			let (prices, configs): (u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			ensure!(
+				ItemPriceOf::<T, I>::iter_values().count() as u32 == prices,
+				"no price should be lost."
+			);
+			ensure!(
+				ItemPriceOf::<T, I>::iter_values().all(|(_, _, expires_at)| expires_at.is_none()),
+				"no price should expire."
+			);
+			ensure!(
+				ItemConfigOf::<T, I>::iter_values().count() as u32 == configs,
+				"no item config should be lost."
+			);
+			ensure!(
+				ItemConfigOf::<T, I>::iter_values().all(|config| !config.is_soulbound()),
+				"no item should be soulbound."
+			);
+			Ok(())
+		}
+	}
+}
@@ -22,12 +22,14 @@ use crate as pallet_nfts;
 
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Everything},
+	PalletId,
 };
+use frame_support_test::TestRandomness;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -41,6 +43,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -84,8 +87,34 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	pallet_assets::runtime_benchmarks_enabled! {
+		type BenchmarkHelper = ();
+	}
+}
+
 parameter_types! {
+	pub const NftsPalletId: PalletId = PalletId(*b"py/nfts_");
 	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub const MaxRoyalty: Permill = Permill::from_percent(50);
 }
 
 impl Config for Test {
@@ -107,11 +136,45 @@ impl Config for Test {
 	type ApprovalsLimit = ConstU32<10>;
 	type ItemAttributesApprovalsLimit = ConstU32<2>;
 	type MaxTips = ConstU32<10>;
+	type MaxRoyaltyBeneficiaries = ConstU32<10>;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type RemoveItemsLimit = ConstU32<10>;
 	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxRevealCommitments = ConstU32<2>;
+	type ProvenanceLength = ConstU32<3>;
 	type Features = Features;
+	type DepositAssets = Assets;
+	type DepositAssetFilter = Everything;
+	type DepositConversion = pallet_assets::BalanceToAssetBalance<Balances, Test, ConvertInto>;
+	type PalletId = NftsPalletId;
+	type Randomness = TestRandomness<Self>;
+	type RandomMintDeposit = ConstU64<1>;
+	type RandomMintDelay = ConstU64<2>;
+	type RandomMintRevealPeriod = ConstU64<10>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
-	type Helper = ();
+	type Helper = NftsBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u32, u32, UintAuthorityId, u64, TestSignature> for NftsBenchmarkHelper {
+	fn collection(i: u16) -> u32 {
+		i.into()
+	}
+	fn item(i: u16) -> u32 {
+		i.into()
+	}
+	fn signer() -> (UintAuthorityId, u64) {
+		(UintAuthorityId(42), 42)
+	}
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
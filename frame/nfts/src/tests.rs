@@ -23,12 +23,15 @@ use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::Dispatchable,
 	traits::{
-		tokens::nonfungibles_v2::{Destroy, Mutate},
-		Currency, Get,
+		tokens::nonfungibles_v2::{
+			Destroy, Inspect, InspectRoyalties, LockableNonFungible, Mutate,
+		},
+		Currency, Get, Hooks,
 	},
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::bounded::BoundedVec;
+use sp_runtime::traits::Hash;
 use sp_std::prelude::*;
 
 fn items() -> Vec<(u64, u32, u32)> {
@@ -92,6 +95,13 @@ fn item_attributes_approvals(collection_id: u32, item_id: u32) -> Vec<u64> {
 	s
 }
 
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Nfts::on_initialize(System::block_number());
+	}
+}
+
 fn events() -> Vec<Event<Test>> {
 	let result = System::events()
 		.into_iter()
@@ -127,11 +137,11 @@ fn default_collection_config() -> CollectionConfigFor<Test> {
 }
 
 fn default_item_config() -> ItemConfig {
-	ItemConfig { settings: ItemSettings::all_enabled() }
+	ItemConfig { settings: ItemSettings::all_enabled(), soulbound: None }
 }
 
 fn item_config_from_disabled_settings(settings: BitFlags<ItemSetting>) -> ItemConfig {
-	ItemConfig { settings: ItemSettings::from_disabled(settings) }
+	ItemConfig { settings: ItemSettings::from_disabled(settings), soulbound: None }
 }
 
 #[test]
@@ -227,6 +237,68 @@ fn destroy_with_bad_witness_should_not_work() {
 	});
 }
 
+#[test]
+fn destroy_should_work_in_stages() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(1),
+			1,
+			collection_config_with_all_settings_enabled()
+		));
+		for item in 0..12 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 0, item, 1, None));
+		}
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(1), 0, 0, bvec![0]));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(1), 0, 1, bvec![0]));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert!(Balances::reserved_balance(&1) > 0);
+
+		// The first `RemoveItemsLimit` items are removed right away.
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(1), 0, w));
+		assert!(events().contains(&Event::<Test>::DestructionStarted { collection: 0 }));
+		assert_eq!(DestroyCursor::<Test>::get(0), Some(DestroyStage::Items));
+		assert_eq!(Collection::<Test>::get(0).unwrap().items, 2);
+		assert_eq!(items().len(), 2);
+
+		// The collection can't be used or destroyed twice meanwhile.
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(1), 0, 12, 1, None),
+			Error::<Test>::CollectionDestroying
+		);
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_noop!(
+			Nfts::destroy(RuntimeOrigin::signed(1), 0, w),
+			Error::<Test>::CollectionDestroying
+		);
+
+		// Anyone may complete the destruction.
+		assert_ok!(Nfts::continue_destroy(RuntimeOrigin::signed(5), 0));
+		assert!(events().contains(&Event::<Test>::Destroyed { collection: 0 }));
+		assert!(!Collection::<Test>::contains_key(0));
+		assert!(!DestroyCursor::<Test>::contains_key(0));
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 0));
+		assert!(attributes(0).is_empty());
+		assert_eq!(ItemConfigOf::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(collections(), vec![]);
+		assert_eq!(items(), vec![]);
+
+		assert_noop!(
+			Nfts::continue_destroy(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::CollectionNotDestroying
+		);
+	});
+}
+
 #[test]
 fn mint_should_work() {
 	new_test_ext().execute_with(|| {
@@ -339,6 +411,124 @@ fn transfer_should_work() {
 	});
 }
 
+#[test]
+fn pre_signed_transfers_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 2, default_item_config()));
+
+		let transfer = |dest, deadline, nonce| PreSignedTransfer {
+			collection: 0,
+			item: 42,
+			dest,
+			deadline,
+			nonce,
+		};
+		let sign = |signer, transfer: &PreSignedTransferOf<Test>| {
+			sp_runtime::testing::TestSignature(signer, Nfts::pre_signed_transfer_message(transfer))
+		};
+
+		// Only the owner may sign the transfer, and only the transfer signed may be made.
+		let t = transfer(3, 10, 0);
+		assert_noop!(
+			Nfts::transfer_pre_signed(RuntimeOrigin::signed(5), Box::new(t.clone()), sign(3, &t)),
+			Error::<Test>::WrongSignature
+		);
+		assert_noop!(
+			Nfts::transfer_pre_signed(
+				RuntimeOrigin::signed(5),
+				Box::new(transfer(4, 10, 0)),
+				sign(2, &t)
+			),
+			Error::<Test>::WrongSignature
+		);
+
+		// Any account may submit it before the deadline.
+		assert_ok!(Nfts::transfer_pre_signed(
+			RuntimeOrigin::signed(5),
+			Box::new(t.clone()),
+			sign(2, &t)
+		));
+		assert_eq!(items(), vec![(3, 0, 42)]);
+		System::assert_last_event(
+			Event::<Test>::Transferred { collection: 0, item: 42, from: 2, to: 3 }.into(),
+		);
+
+		// The transfer may not be replayed once the item is back with its signer.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 2));
+		assert_noop!(
+			Nfts::transfer_pre_signed(RuntimeOrigin::signed(5), Box::new(t.clone()), sign(2, &t)),
+			Error::<Test>::WrongNonce
+		);
+		let t = transfer(3, 10, 1);
+		System::set_block_number(11);
+		assert_noop!(
+			Nfts::transfer_pre_signed(RuntimeOrigin::signed(5), Box::new(t.clone()), sign(2, &t)),
+			Error::<Test>::DeadlineExpired
+		);
+		System::set_block_number(10);
+		assert_ok!(Nfts::transfer_pre_signed(
+			RuntimeOrigin::signed(5),
+			Box::new(t.clone()),
+			sign(2, &t)
+		));
+		assert_eq!(items(), vec![(3, 0, 42)]);
+
+		// A signature of the bare transfer, as for another chain or instance, is refused.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 2));
+		let t = transfer(3, 10, 2);
+		assert_noop!(
+			Nfts::transfer_pre_signed(
+				RuntimeOrigin::signed(5),
+				Box::new(t.clone()),
+				sp_runtime::testing::TestSignature(2, t.encode())
+			),
+			Error::<Test>::WrongSignature
+		);
+	});
+}
+
+#[test]
+fn provenance_tracking_works() {
+	new_test_ext().execute_with(|| {
+		let record = |at, from, to, price| ProvenanceRecord { at, from, to, price };
+		Balances::make_free_balance_be(&3, 100);
+
+		// ownership changes are not recorded unless the collection asks for it
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 1, default_item_config()));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert!(Nfts::provenance(0, 42).is_empty());
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			1,
+			collection_config_from_disabled_settings(
+				CollectionSetting::DepositRequired | CollectionSetting::UntrackedProvenance
+			)
+		));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 1, 42, 1, default_item_config()));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(1), 1, 42, 2));
+		System::set_block_number(2);
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(2), 1, 42, Some(20), None, None));
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(3), 1, 42, 20));
+		assert_eq!(Nfts::provenance(1, 42), vec![record(1, 1, 2, None), record(2, 2, 3, Some(20))]);
+
+		// the oldest records are dropped once `ProvenanceLength` is reached
+		System::set_block_number(3);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 1, 42, 4));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(4), 1, 42, 5));
+		assert_eq!(
+			Nfts::provenance(1, 42),
+			vec![record(2, 2, 3, Some(20)), record(3, 3, 4, None), record(3, 4, 5, None)]
+		);
+
+		// and the whole history goes away with the item
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(5), 1, 42, None));
+		assert!(Nfts::provenance(1, 42).is_empty());
+	});
+}
+
 #[test]
 fn locking_transfer_should_work() {
 	new_test_ext().execute_with(|| {
@@ -367,6 +557,65 @@ fn locking_transfer_should_work() {
 	});
 }
 
+#[test]
+fn pausing_collection_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::set_team(RuntimeOrigin::signed(1), 0, 1, 4, 1));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 0, 42, 2, None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 0, 43, 2, None));
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(2), 0, 42, Some(10), None, None));
+
+		assert_noop!(
+			Nfts::pause_collection(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::unpause_collection(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::CollectionNotPaused
+		);
+		assert_ok!(Nfts::pause_collection(RuntimeOrigin::signed(4), 0));
+		assert!(events().contains(&Event::<Test>::CollectionPaused { collection: 0 }));
+		assert_noop!(
+			Nfts::pause_collection(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::CollectionPaused
+		);
+
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(2), 0, 42, 3),
+			Error::<Test>::CollectionPaused
+		);
+		assert_noop!(
+			Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None),
+			Error::<Test>::CollectionPaused
+		);
+		assert_noop!(
+			Nfts::set_price(RuntimeOrigin::signed(2), 0, 43, Some(10), None, None),
+			Error::<Test>::CollectionPaused
+		);
+		assert_noop!(
+			Nfts::buy_item(RuntimeOrigin::signed(3), 0, 42, 10),
+			Error::<Test>::CollectionPaused
+		);
+
+		// owners can still burn their items
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(2), 0, 43, Some(2)));
+
+		assert_noop!(
+			Nfts::unpause_collection(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::unpause_collection(RuntimeOrigin::signed(4), 0));
+		assert!(events().contains(&Event::<Test>::CollectionUnpaused { collection: 0 }));
+
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(3), 0, 42, 10));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 2));
+		assert_eq!(items(), vec![(2, 0, 42)]);
+	});
+}
+
 #[test]
 fn origin_guards_should_work() {
 	new_test_ext().execute_with(|| {
@@ -883,6 +1132,114 @@ fn set_item_owner_attributes_should_work() {
 	});
 }
 
+#[test]
+fn set_multiple_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			1,
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 0, 2, default_item_config()));
+
+		// can't set for the non-owned item
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(1),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![(bvec![0], bvec![0])],
+			),
+			Error::<Test>::NoPermission,
+		);
+
+		events();
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![(bvec![0], bvec![0]), (bvec![1], bvec![0]), (bvec![2], bvec![0])],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(2), 9);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 3);
+		assert_eq!(
+			events(),
+			vec![Event::AttributesSet {
+				collection: 0,
+				maybe_item: Some(0),
+				namespace: AttributeNamespace::ItemOwner,
+				count: 3,
+			}]
+		);
+
+		// deposits of the previous owner are returned, the new owner pays for the rest at once
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(2), 0, 0, 3));
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(3),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![(bvec![0], bvec![0; 10]), (bvec![3], bvec![0])],
+		));
+		assert_eq!(Balances::reserved_balance(2), 6);
+		assert_eq!(Balances::reserved_balance(3), 15);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 4);
+
+		// all or nothing
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(3),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![(bvec![4], bvec![0; 50]), (bvec![5], bvec![0; 50])],
+			),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// shrinking attributes releases the difference
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(3),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![(bvec![0], bvec![0]), (bvec![3], bvec![0])],
+		));
+		assert_eq!(Balances::reserved_balance(3), 6);
+
+		// the collection owner namespace respects locks
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(1),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedAttributes.into())
+		));
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![(bvec![0], bvec![0])],
+			),
+			Error::<Test>::LockedCollectionAttributes,
+		);
+	});
+}
+
 #[test]
 fn set_external_account_attributes_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1290,72 +1647,177 @@ fn burn_works() {
 }
 
 #[test]
-fn approval_lifecycle_works() {
+fn soulbound_items_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
-		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 2, default_item_config()));
-		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_ok!(Nfts::set_team(RuntimeOrigin::signed(1), 0, 2, 3, 4));
+		let soulbound = |holder_may_burn, issuer_may_revoke| ItemConfig {
+			settings: ItemSettings::all_enabled(),
+			soulbound: Some(SoulboundRules { holder_may_burn, issuer_may_revoke }),
+		};
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(2), 0, 42, 5, soulbound(true, false)));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(2), 0, 43, 5, soulbound(false, true)));
+		assert!(!<Nfts as Inspect<_>>::can_transfer(&0, &42));
+
+		// The item may not change hands in any way.
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 3),
-			Error::<Test>::NoPermission
+			Nfts::transfer(RuntimeOrigin::signed(5), 0, 42, 6),
+			Error::<Test>::ItemSoulbound
 		);
-		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
-
-		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(4), 0, 42, 2, None));
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(2), 0, 42, 2));
-
-		// ensure we can't buy an item when the collection has a NonTransferableItems flag
-		let collection_id = 1;
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
-			1,
-			collection_config_from_disabled_settings(
-				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
-			)
-		));
-
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 1, collection_id, 1, None));
-
 		assert_noop!(
-			Nfts::approve_transfer(RuntimeOrigin::signed(1), collection_id, 1, 2, None),
-			Error::<Test>::ItemsNonTransferable
+			Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 6),
+			Error::<Test>::ItemSoulbound
 		);
-	});
-}
-
-#[test]
-fn cancel_approval_works() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
-		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 2, default_item_config()));
-
-		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(2), 1, 42, 3),
-			Error::<Test>::UnknownItem
+			Nfts::approve_transfer(RuntimeOrigin::signed(5), 0, 42, 6, None),
+			Error::<Test>::ItemSoulbound
 		);
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 43, 3),
-			Error::<Test>::UnknownItem
+			Nfts::set_price(RuntimeOrigin::signed(5), 0, 42, Some(1), None, None),
+			Error::<Test>::ItemSoulbound
 		);
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(3), 0, 42, 3),
-			Error::<Test>::NoPermission
+			Nfts::create_swap(RuntimeOrigin::signed(5), 0, 42, 0, Some(43), None, 1),
+			Error::<Test>::ItemSoulbound
 		);
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(4), 0, 42));
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 42, 4),
-			Error::<Test>::NotDelegate
+			Nfts::transfer(RuntimeOrigin::signed(5), 0, 42, 6),
+			Error::<Test>::ItemSoulbound
 		);
 
-		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 42, 3));
+		// Only the holder may burn the first item, and only the Issuer may revoke the second.
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 42, 3),
-			Error::<Test>::NotDelegate
+			Nfts::burn(RuntimeOrigin::signed(2), 0, 42, None),
+			Error::<Test>::NoPermission
 		);
-
-		let current_block = 1;
-		System::set_block_number(current_block);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(3), 0, 42, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(5), 0, 42, None));
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(5), 0, 43, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(3), 0, 43, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(2), 0, 43, Some(5)));
+		assert_eq!(items(), vec![]);
+	});
+}
+
+#[test]
+fn custody_locks_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 0, 42, 2, None));
+		let reason = *b"fraction";
+		assert_noop!(
+			<Nfts as LockableNonFungible<_>>::lock(&0, &43, reason),
+			Error::<Test>::UnknownItem
+		);
+		assert_ok!(<Nfts as LockableNonFungible<_>>::lock(&0, &42, reason));
+		assert!(events().contains(&Event::<Test>::ItemCustodyLocked {
+			collection: 0,
+			item: 42,
+			reason
+		}));
+		assert_eq!(<Nfts as LockableNonFungible<_>>::locked_by(&0, &42), Some(reason));
+		assert_noop!(
+			<Nfts as LockableNonFungible<_>>::lock(&0, &42, *b"another "),
+			Error::<Test>::ItemLocked
+		);
+
+		// The item stays with its owner, who may neither transfer nor burn it.
+		assert_eq!(<Nfts as Inspect<_>>::owner(&0, &42), Some(2));
+		assert!(!<Nfts as Inspect<_>>::can_transfer(&0, &42));
+		assert_noop!(Nfts::transfer(RuntimeOrigin::signed(2), 0, 42, 3), Error::<Test>::ItemLocked);
+		assert_noop!(Nfts::burn(RuntimeOrigin::signed(2), 0, 42, None), Error::<Test>::ItemLocked);
+		// Nor may the collection be destroyed from under the custodian.
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_noop!(Nfts::destroy(RuntimeOrigin::signed(1), 0, w), Error::<Test>::ItemLocked);
+
+		// Only the lock's own reason unlocks it.
+		assert_noop!(
+			<Nfts as LockableNonFungible<_>>::unlock(&0, &42, *b"another "),
+			Error::<Test>::WrongLockReason
+		);
+		assert_ok!(<Nfts as LockableNonFungible<_>>::unlock(&0, &42, reason));
+		assert_eq!(<Nfts as LockableNonFungible<_>>::locked_by(&0, &42), None);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 2, default_item_config()));
+		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 3),
+			Error::<Test>::NoPermission
+		);
+		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
+
+		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(4), 0, 42, 2, None));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(2), 0, 42, 2));
+
+		// ensure we can't buy an item when the collection has a NonTransferableItems flag
+		let collection_id = 1;
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			1,
+			collection_config_from_disabled_settings(
+				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
+			)
+		));
+
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 1, collection_id, 1, None));
+
+		assert_noop!(
+			Nfts::approve_transfer(RuntimeOrigin::signed(1), collection_id, 1, 2, None),
+			Error::<Test>::ItemsNonTransferable
+		);
+	});
+}
+
+#[test]
+fn cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 2, default_item_config()));
+
+		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(2), 1, 42, 3),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 43, 3),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(3), 0, 42, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 42, 4),
+			Error::<Test>::NotDelegate
+		);
+
+		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(2), 0, 42, 3),
+			Error::<Test>::NotDelegate
+		);
+
+		let current_block = 1;
+		System::set_block_number(current_block);
 		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 69, 2, default_item_config()));
 		// approval expires after 2 blocks.
 		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, Some(2)));
@@ -1537,6 +1999,76 @@ fn clear_all_transfer_approvals_works() {
 	});
 }
 
+#[test]
+fn collection_approvals_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 42, 2, default_item_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::signed(1), 0, 43, 2, default_item_config()));
+
+		assert_noop!(
+			Nfts::approve_collection_transfer(RuntimeOrigin::signed(2), 1, 3, None),
+			Error::<Test>::UnknownCollection
+		);
+		// Only holders of the collection may approve delegates for it.
+		assert_noop!(
+			Nfts::approve_collection_transfer(RuntimeOrigin::signed(5), 0, 3, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::approve_collection_transfer(RuntimeOrigin::signed(2), 0, 3, Some(5)));
+		assert!(events().contains(&Event::<Test>::CollectionTransferApproved {
+			collection: 0,
+			owner: 2,
+			delegate: 3,
+			deadline: Some(6),
+		}));
+
+		// The approval covers every item of the owner and outlives a transfer.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 43, 4));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(4), 0, 43, 2));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 43, 5));
+		// It is not given on behalf of other owners.
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 5),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(4), 0, 42, 2));
+		System::set_block_number(7);
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 5),
+			Error::<Test>::ApprovalExpired
+		);
+
+		assert_noop!(
+			Nfts::cancel_collection_approval(RuntimeOrigin::signed(2), 0, 4),
+			Error::<Test>::NotDelegate
+		);
+		assert_ok!(Nfts::cancel_collection_approval(RuntimeOrigin::signed(2), 0, 3));
+		assert!(!CollectionApprovalsOf::<Test>::contains_key(0, 2));
+
+		assert_ok!(Nfts::approve_collection_transfer(RuntimeOrigin::signed(2), 0, 3, None));
+		assert_ok!(Nfts::approve_collection_transfer(RuntimeOrigin::signed(2), 0, 4, None));
+		// An expired approval for the item does not shadow the one for the collection.
+		assert_ok!(Nfts::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, Some(1)));
+		System::set_block_number(9);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(4), 0, 42, 2));
+		assert_ok!(Nfts::clear_all_collection_approvals(RuntimeOrigin::signed(2), 0));
+		assert!(events()
+			.contains(&Event::<Test>::AllCollectionApprovalsCancelled { collection: 0, owner: 2 }));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(3), 0, 42, 5),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(4), 0, 42, 5),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
 #[test]
 fn max_supply_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1592,6 +2124,138 @@ fn max_supply_should_work() {
 	});
 }
 
+#[test]
+fn random_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		let max_supply = 5;
+		let commitment = |secret: u8| <Test as SystemConfig>::Hashing::hash_of(&[secret; 32]);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_noop!(
+			Nfts::enable_random_mint(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::RandomMintUnavailable
+		);
+		assert_ok!(Nfts::set_collection_max_supply(RuntimeOrigin::signed(1), 0, max_supply));
+		assert_noop!(
+			Nfts::enable_random_mint(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::commit_random_mint(RuntimeOrigin::signed(2), 0, commitment(0)),
+			Error::<Test>::RandomMintUnavailable
+		);
+		assert_ok!(Nfts::enable_random_mint(RuntimeOrigin::signed(1), 0));
+		assert!(events().contains(&Event::<Test>::RandomMintEnabled { collection: 0, pool: 5 }));
+
+		// Items can no longer be minted by id, nor can the supply change.
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(1), 0, 0, 1, None),
+			Error::<Test>::RandomMintOnly
+		);
+		assert_noop!(
+			Nfts::force_mint(RuntimeOrigin::root(), 0, 0, 1, default_item_config()),
+			Error::<Test>::RandomMintOnly
+		);
+		assert_noop!(
+			Nfts::set_collection_max_supply(RuntimeOrigin::signed(1), 0, max_supply + 1),
+			Error::<Test>::RandomMintOnly
+		);
+
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(1),
+			0,
+			MintSettings { mint_type: MintType::Public, ..Default::default() }
+		));
+		assert_noop!(
+			Nfts::mint_random(RuntimeOrigin::signed(2), 0, [0; 32], 2, None),
+			Error::<Test>::NoCommitment
+		);
+		assert_ok!(Nfts::commit_random_mint(RuntimeOrigin::signed(2), 0, commitment(0)));
+		assert!(events().contains(&Event::<Test>::RandomMintCommitted { collection: 0, who: 2 }));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		// Replacing the commitment forfeits the deposit of the earlier one.
+		assert_ok!(Nfts::commit_random_mint(RuntimeOrigin::signed(2), 0, commitment(0)));
+		assert!(events().contains(&Event::<Test>::RandomMintCommitmentForfeited {
+			collection: 0,
+			who: 2,
+			deposit: 1,
+		}));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		assert_eq!(Balances::total_balance(&2), 99);
+		// The randomness for the commitment is not drawn yet.
+		assert_noop!(
+			Nfts::mint_random(RuntimeOrigin::signed(2), 0, [0; 32], 2, None),
+			Error::<Test>::CommitmentTooRecent
+		);
+		run_to_block(3);
+		assert!(RandomMintSeeds::<Test>::contains_key(3));
+		assert_noop!(
+			Nfts::mint_random(RuntimeOrigin::signed(2), 0, [1; 32], 2, None),
+			Error::<Test>::WrongSecret
+		);
+
+		// Every id of the pool is assigned exactly once.
+		for secret in 0..max_supply as u8 {
+			if secret > 0 {
+				assert_ok!(Nfts::commit_random_mint(
+					RuntimeOrigin::signed(2),
+					0,
+					commitment(secret)
+				));
+				run_to_block(System::block_number() + 2);
+			}
+			assert_ok!(Nfts::mint_random(RuntimeOrigin::signed(2), 0, [secret; 32], 2, None));
+		}
+		let mut minted: Vec<u32> = items().into_iter().map(|(_, _, item)| item).collect();
+		minted.sort();
+		assert_eq!(minted, vec![0, 1, 2, 3, 4]);
+		assert_eq!(RandomMintPool::<Test>::get(0), Some(0));
+		assert_eq!(RandomMintSwaps::<Test>::iter_prefix(0).count(), 0);
+		// The deposits of the commitments were released as they were revealed.
+		assert_eq!(RandomMintCommitments::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+
+		// A commitment can't be revealed once its period has passed, and its deposit may then be
+		// forfeited by anyone.
+		assert_ok!(Nfts::commit_random_mint(RuntimeOrigin::signed(2), 0, commitment(8)));
+		let seed_block = System::block_number() + 2;
+		assert_noop!(
+			Nfts::clear_random_mint_commitment(RuntimeOrigin::signed(3), 0, 2),
+			Error::<Test>::CommitmentNotExpired
+		);
+		run_to_block(seed_block + 10);
+		assert_noop!(
+			Nfts::clear_random_mint_commitment(RuntimeOrigin::signed(3), 0, 2),
+			Error::<Test>::CommitmentNotExpired
+		);
+		run_to_block(seed_block + 11);
+		assert!(!RandomMintSeeds::<Test>::contains_key(seed_block));
+		assert_noop!(
+			Nfts::mint_random(RuntimeOrigin::signed(2), 0, [8; 32], 2, None),
+			Error::<Test>::CommitmentExpired
+		);
+		assert_ok!(Nfts::clear_random_mint_commitment(RuntimeOrigin::signed(3), 0, 2));
+		System::assert_last_event(
+			Event::<Test>::RandomMintCommitmentForfeited { collection: 0, who: 2, deposit: 1 }
+				.into(),
+		);
+		assert!(!RandomMintCommitments::<Test>::contains_key(0, 2));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::total_balance(&2), 98);
+		assert_noop!(
+			Nfts::clear_random_mint_commitment(RuntimeOrigin::signed(3), 0, 2),
+			Error::<Test>::NoCommitment
+		);
+
+		assert_ok!(Nfts::commit_random_mint(RuntimeOrigin::signed(2), 0, commitment(9)));
+		run_to_block(System::block_number() + 2);
+		assert_noop!(
+			Nfts::mint_random(RuntimeOrigin::signed(2), 0, [9; 32], 2, None),
+			Error::<Test>::MaxSupplyReached
+		);
+	});
+}
+
 #[test]
 fn mint_settings_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1678,6 +2342,7 @@ fn set_price_should_work() {
 			item_1,
 			Some(1),
 			None,
+			None,
 		));
 
 		assert_ok!(Nfts::set_price(
@@ -1685,7 +2350,8 @@ fn set_price_should_work() {
 			collection_id,
 			item_2,
 			Some(2),
-			Some(3)
+			Some(3),
+			None
 		));
 
 		let item = ItemPriceOf::<Test>::get(collection_id, item_1).unwrap();
@@ -1701,6 +2367,7 @@ fn set_price_should_work() {
 			item: item_1,
 			price: 1,
 			whitelisted_buyer: None,
+			expires_at: None,
 		}));
 
 		// validate we can unset the price
@@ -1709,6 +2376,7 @@ fn set_price_should_work() {
 			collection_id,
 			item_2,
 			None,
+			None,
 			None
 		));
 		assert!(events().contains(&Event::<Test>::ItemPriceRemoved {
@@ -1736,7 +2404,14 @@ fn set_price_should_work() {
 		));
 
 		assert_noop!(
-			Nfts::set_price(RuntimeOrigin::signed(user_id), collection_id, item_1, Some(2), None),
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_id),
+				collection_id,
+				item_1,
+				Some(2),
+				None,
+				None
+			),
 			Error::<Test>::ItemsNonTransferable
 		);
 	});
@@ -1772,6 +2447,7 @@ fn buy_item_should_work() {
 			item_1,
 			Some(price_1),
 			None,
+			None,
 		));
 
 		assert_ok!(Nfts::set_price(
@@ -1780,6 +2456,7 @@ fn buy_item_should_work() {
 			item_2,
 			Some(price_2),
 			Some(user_3),
+			None,
 		));
 
 		// can't buy for less
@@ -1842,6 +2519,7 @@ fn buy_item_should_work() {
 				item_3,
 				Some(price_1),
 				None,
+				None,
 			));
 
 			// lock the collection
@@ -1888,6 +2566,107 @@ fn buy_item_should_work() {
 	});
 }
 
+#[test]
+fn listings_should_expire() {
+	new_test_ext().execute_with(|| {
+		let user_1 = 1;
+		let user_2 = 2;
+		let collection_id = 0;
+		let price = 20;
+		let max_duration: u64 = <Test as Config>::MaxDeadlineDuration::get();
+
+		Balances::make_free_balance_be(&user_2, 100);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), user_1, default_collection_config()));
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				item,
+				user_1,
+				None
+			));
+		}
+
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				1,
+				Some(price),
+				None,
+				Some(0)
+			),
+			Error::<Test>::DeadlineExpired
+		);
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				1,
+				Some(price),
+				None,
+				Some(1 + max_duration + 1)
+			),
+			Error::<Test>::WrongDuration
+		);
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			1,
+			Some(price),
+			None,
+			Some(5)
+		));
+		assert!(events().contains(&Event::<Test>::ItemPriceSet {
+			collection: collection_id,
+			item: 1,
+			price,
+			whitelisted_buyer: None,
+			expires_at: Some(5),
+		}));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			2,
+			Some(price),
+			None,
+			Some(10)
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			3,
+			Some(price),
+			None,
+			None
+		));
+
+		// the listing may no longer be bought once its deadline has passed
+		System::set_block_number(6);
+		assert_noop!(
+			Nfts::buy_item(RuntimeOrigin::signed(user_2), collection_id, 1, price),
+			Error::<Test>::ListingExpired
+		);
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(user_2), collection_id, 2, price));
+
+		// anyone may clear expired listings, a few at a time
+		assert_ok!(Nfts::clear_expired_listings(RuntimeOrigin::signed(user_2), 0));
+		assert!(ItemPriceOf::<Test>::contains_key(collection_id, 1));
+		assert_ok!(Nfts::clear_expired_listings(RuntimeOrigin::signed(user_2), 1));
+		assert!(ListingsCleanupCursor::<Test>::exists());
+		assert_ok!(Nfts::clear_expired_listings(RuntimeOrigin::signed(user_2), 1));
+		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, 1));
+		assert!(ItemPriceOf::<Test>::contains_key(collection_id, 3));
+		assert!(events()
+			.contains(&Event::<Test>::ItemPriceRemoved { collection: collection_id, item: 1 }));
+
+		// the cursor is cleared once all listings have been looked at
+		assert_ok!(Nfts::clear_expired_listings(RuntimeOrigin::signed(user_2), 1));
+		assert!(!ListingsCleanupCursor::<Test>::exists());
+	});
+}
+
 #[test]
 fn pay_tips_should_work() {
 	new_test_ext().execute_with(|| {
@@ -2283,6 +3062,169 @@ fn claim_swap_should_work() {
 	});
 }
 
+#[test]
+fn collection_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = 1;
+		let user_2 = 2;
+		let beneficiary_1 = 3;
+		let beneficiary_2 = 4;
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 200;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&beneficiary_1, 1);
+		Balances::make_free_balance_be(&beneficiary_2, 1);
+
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), user_1, default_collection_config()));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(user_1), collection_id, item_1, user_1, None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(user_1), collection_id, item_2, user_1, None));
+
+		let royalty = CollectionRoyalty {
+			percentage: Permill::from_percent(10),
+			beneficiaries: bvec![
+				(beneficiary_1, Permill::from_percent(75)),
+				(beneficiary_2, Permill::from_percent(25)),
+			],
+		};
+
+		// only the owner of the collection can set its royalty
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_2),
+				collection_id,
+				Some(royalty.clone()),
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// the shares of the beneficiaries need to add up to 100%
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				Some(CollectionRoyalty {
+					percentage: Permill::from_percent(10),
+					beneficiaries: bvec![
+						(beneficiary_1, Permill::from_percent(60)),
+						(beneficiary_2, Permill::from_percent(30)),
+					],
+				}),
+			),
+			Error::<Test>::WrongRoyaltyShares
+		);
+
+		// the royalty can't take more of the price than `MaxRoyalty`
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				Some(CollectionRoyalty {
+					percentage: Permill::from_percent(51),
+					..royalty.clone()
+				}),
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			Some(royalty.clone()),
+		));
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltySet {
+			collection: collection_id,
+			royalty: royalty.clone(),
+		}));
+		assert_eq!(
+			<Nfts as InspectRoyalties<_, _>>::royalties(&collection_id, &item_1, price),
+			vec![(beneficiary_1, 15), (beneficiary_2, 5)]
+		);
+
+		// the royalty is paid out of the price of a sale
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			item_1,
+			Some(price),
+			None,
+			None,
+		));
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(user_2), collection_id, item_1, price));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + price - 20);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert_eq!(Balances::total_balance(&beneficiary_1), 1 + 15);
+		assert_eq!(Balances::total_balance(&beneficiary_2), 1 + 5);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			beneficiary: beneficiary_1,
+			amount: 15,
+		}));
+		assert!(events.contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			beneficiary: beneficiary_2,
+			amount: 5,
+		}));
+
+		// and out of the price of a swap, by whoever receives the item the price is paid for
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_2),
+			collection_id,
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(PriceWithDirection { amount: price, direction: PriceDirection::Send }),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			item_2,
+			collection_id,
+			item_1,
+			Some(PriceWithDirection { amount: price, direction: PriceDirection::Send }),
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 2 * (price - 20));
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 2 * price);
+		assert_eq!(Balances::total_balance(&beneficiary_1), 1 + 2 * 15);
+		assert_eq!(Balances::total_balance(&beneficiary_2), 1 + 2 * 5);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_2,
+			beneficiary: beneficiary_1,
+			amount: 15,
+		}));
+
+		// the force origin can remove the royalty
+		assert_ok!(Nfts::set_collection_royalty(RuntimeOrigin::root(), collection_id, None));
+		assert!(!CollectionRoyaltyOf::<Test>::contains_key(collection_id));
+		assert!(events()
+			.contains(&Event::<Test>::CollectionRoyaltyRemoved { collection: collection_id }));
+		assert_eq!(
+			<Nfts as InspectRoyalties<_, _>>::royalties(&collection_id, &item_1, price),
+			vec![]
+		);
+
+		// destroying the collection removes its royalty
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			Some(royalty),
+		));
+		let w = Nfts::get_destroy_witness(&collection_id).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(user_1), collection_id, w));
+		assert!(!CollectionRoyaltyOf::<Test>::contains_key(collection_id));
+	});
+}
+
 #[test]
 fn various_collection_settings() {
 	new_test_ext().execute_with(|| {
@@ -2385,7 +3327,14 @@ fn pallet_level_feature_flags_should_work() {
 
 		// PalletFeature::Trading
 		assert_noop!(
-			Nfts::set_price(RuntimeOrigin::signed(user_id), collection_id, item_id, Some(1), None),
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_id),
+				collection_id,
+				item_id,
+				Some(1),
+				None,
+				None
+			),
 			Error::<Test>::MethodDisabled
 		);
 		assert_noop!(
@@ -2498,3 +3447,173 @@ fn add_remove_item_attributes_approval_should_work() {
 		assert_eq!(item_attributes_approvals(collection_id, item_id), vec![user_3]);
 	})
 }
+
+#[test]
+fn deposits_paid_in_asset_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = 1;
+		let user_2 = 2;
+		let deposit_account = Nfts::deposit_account();
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+		// One unit of `Currency` is worth two units of the asset.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, user_1, true, 2));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, user_1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user_1), 0, user_1, 100));
+
+		assert_ok!(Nfts::set_deposit_asset(RuntimeOrigin::signed(user_1), Some(0)));
+		assert!(events().contains(&Event::<Test>::DepositAssetSet { who: user_1, asset: Some(0) }));
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(user_1),
+			user_1,
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(Balances::reserved_balance(&user_1), 0);
+		assert_eq!(Assets::balance(0, user_1), 96);
+		assert_eq!(Assets::balance(0, deposit_account), 4);
+
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::signed(user_1), 0, bvec![0, 0]));
+		assert_eq!(Assets::balance(0, user_1), 90);
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::signed(user_1), 0));
+		assert_eq!(Assets::balance(0, user_1), 96);
+
+		// A different asset can't be chosen while deposits are held in another one.
+		assert_noop!(
+			Nfts::set_deposit_asset(RuntimeOrigin::signed(user_1), Some(1)),
+			Error::<Test>::DepositAssetMismatch
+		);
+
+		// Deposits held in an asset move with the collection and are returned in that asset.
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, user_2));
+		assert!(AssetDepositOf::<Test>::get(user_1).is_none());
+		assert_eq!(Balances::reserved_balance(&user_2), 0);
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(user_2), 0, w));
+		assert_eq!(Assets::balance(0, user_2), 4);
+		assert_eq!(Assets::balance(0, deposit_account), 0);
+		assert!(AssetDepositOf::<Test>::get(user_2).is_none());
+
+		// Once the deposits are returned, `Currency` can be used again.
+		assert_ok!(Nfts::set_deposit_asset(RuntimeOrigin::signed(user_1), None));
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(user_1),
+			user_1,
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(Balances::reserved_balance(&user_1), 2);
+		assert_eq!(Assets::balance(0, user_1), 96);
+	});
+}
+
+#[test]
+fn reveal_batch_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), 1, default_collection_config()));
+		assert_ok!(Nfts::force_mint(RuntimeOrigin::root(), 0, 42, 2, default_item_config()));
+
+		let batch: RevealBatchOf<Test> = bvec![
+			RevealEntry::ItemMetadata { item: 42, data: bvec![1, 2] },
+			RevealEntry::Attribute { maybe_item: Some(42), key: bvec![0], value: bvec![3] },
+			RevealEntry::Attribute { maybe_item: None, key: bvec![1], value: bvec![4] },
+		];
+		let salt = [7u8; 32];
+		let hash = <Test as SystemConfig>::Hashing::hash_of(&(salt, &batch));
+		assert_noop!(
+			Nfts::commit_reveal(RuntimeOrigin::signed(2), 0, hash, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::commit_reveal(RuntimeOrigin::signed(1), 0, hash, 10001),
+			Error::<Test>::WrongDuration
+		);
+		assert_noop!(
+			Nfts::reveal_batch(RuntimeOrigin::signed(1), 0, batch.clone(), salt),
+			Error::<Test>::UnknownRevealCommitment
+		);
+		assert_ok!(Nfts::commit_reveal(RuntimeOrigin::signed(1), 0, hash, 10));
+		assert!(events().contains(&Event::<Test>::RevealCommitted {
+			collection: 0,
+			hash,
+			deadline: 11
+		}));
+
+		// The commitments are bounded, but those whose deadline passed are dropped.
+		let other = |i: u8| <Test as SystemConfig>::Hashing::hash_of(&i);
+		assert_ok!(Nfts::commit_reveal(RuntimeOrigin::signed(1), 0, other(0), 0));
+		assert_noop!(
+			Nfts::commit_reveal(RuntimeOrigin::signed(1), 0, other(1), 10),
+			Error::<Test>::TooManyRevealCommitments
+		);
+		System::set_block_number(2);
+		assert_ok!(Nfts::commit_reveal(RuntimeOrigin::signed(1), 0, other(1), 0));
+		assert_eq!(RevealCommitments::<Test>::get(0).len(), 2);
+
+		// Only the batch committed to may be revealed, and only by the deadline.
+		let mut altered = batch.clone();
+		altered[0] = RevealEntry::ItemMetadata { item: 42, data: bvec![9] };
+		assert_noop!(
+			Nfts::reveal_batch(RuntimeOrigin::signed(1), 0, altered, salt),
+			Error::<Test>::UnknownRevealCommitment
+		);
+		// Nor without the salt it was committed with.
+		assert_noop!(
+			Nfts::reveal_batch(RuntimeOrigin::signed(1), 0, batch.clone(), [0; 32]),
+			Error::<Test>::UnknownRevealCommitment
+		);
+		assert_noop!(
+			Nfts::reveal_batch(RuntimeOrigin::signed(2), 0, batch.clone(), salt),
+			Error::<Test>::NoPermission
+		);
+		System::set_block_number(12);
+		assert_noop!(
+			Nfts::reveal_batch(RuntimeOrigin::signed(1), 0, batch.clone(), salt),
+			Error::<Test>::DeadlineExpired
+		);
+		System::set_block_number(11);
+		assert_ok!(Nfts::reveal_batch(RuntimeOrigin::signed(1), 0, batch.clone(), salt));
+		assert!(events().contains(&Event::<Test>::BatchRevealed { collection: 0, hash }));
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 42).unwrap().data.into_inner(), vec![1, 2]);
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(42), AttributeNamespace::CollectionOwner, bvec![0], bvec![3]),
+				(None, AttributeNamespace::CollectionOwner, bvec![1], bvec![4]),
+			]
+		);
+		assert_noop!(
+			Nfts::reveal_batch(RuntimeOrigin::signed(1), 0, batch, salt),
+			Error::<Test>::UnknownRevealCommitment
+		);
+		assert_eq!(RevealCommitments::<Test>::get(0).len(), 1);
+	});
+}
+
+#[test]
+fn migration_v1_works() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Nfts>();
+		unhashed::put(&ItemPriceOf::<Test>::hashed_key_for(0, 42), &(20u64, Some(3u64)));
+		unhashed::put(&ItemPriceOf::<Test>::hashed_key_for(0, 43), &(30u64, None::<u64>));
+		let settings = ItemSettings::all_enabled();
+		unhashed::put(
+			&ItemConfigOf::<Test>::hashed_key_for(0, 42),
+			&migration::v1::ItemConfig { settings },
+		);
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Nfts::on_chain_storage_version(), 1);
+		assert_eq!(ItemPriceOf::<Test>::get(0, 42), Some((20, Some(3), None)));
+		assert_eq!(ItemPriceOf::<Test>::get(0, 43), Some((30, None, None)));
+		assert_eq!(
+			ItemConfigOf::<Test>::get(0, 42),
+			Some(ItemConfig { settings, soulbound: None })
+		);
+	});
+}
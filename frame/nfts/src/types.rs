@@ -54,11 +54,53 @@ pub(super) type ItemTipOf<T, I = ()> = ItemTip<
 	<T as SystemConfig>::AccountId,
 	BalanceOf<T, I>,
 >;
+pub(super) type DepositAssetIdOf<T, I = ()> = <<T as Config<I>>::DepositAssets as fungibles::Inspect<
+	<T as SystemConfig>::AccountId,
+>>::AssetId;
+pub(super) type DepositAssetBalanceOf<T, I = ()> = <<T as Config<I>>::DepositAssets as fungibles::Inspect<
+	<T as SystemConfig>::AccountId,
+>>::Balance;
+pub(super) type AssetDepositFor<T, I> =
+	AssetDeposit<DepositAssetIdOf<T, I>, DepositBalanceOf<T, I>, DepositAssetBalanceOf<T, I>>;
+pub(super) type AttributesOf<T, I = ()> = BoundedVec<
+	(BoundedVec<u8, <T as Config<I>>::KeyLimit>, BoundedVec<u8, <T as Config<I>>::ValueLimit>),
+	<T as Config<I>>::MaxAttributesPerCall,
+>;
 pub(super) type CollectionConfigFor<T, I = ()> = CollectionConfig<
 	BalanceOf<T, I>,
 	<T as SystemConfig>::BlockNumber,
 	<T as Config<I>>::CollectionId,
 >;
+pub(super) type ProvenanceRecordOf<T, I = ()> = ProvenanceRecord<
+	<T as SystemConfig>::AccountId,
+	ItemPrice<T, I>,
+	<T as SystemConfig>::BlockNumber,
+>;
+pub(super) type ProvenanceOf<T, I = ()> =
+	BoundedVec<ProvenanceRecordOf<T, I>, <T as Config<I>>::ProvenanceLength>;
+pub(super) type PreSignedTransferOf<T, I = ()> = PreSignedTransfer<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	<T as SystemConfig>::AccountId,
+	<T as SystemConfig>::BlockNumber,
+>;
+pub(super) type RevealEntryOf<T, I = ()> = RevealEntry<
+	<T as Config<I>>::ItemId,
+	BoundedVec<u8, <T as Config<I>>::StringLimit>,
+	BoundedVec<u8, <T as Config<I>>::KeyLimit>,
+	BoundedVec<u8, <T as Config<I>>::ValueLimit>,
+>;
+pub(super) type RevealBatchOf<T, I = ()> =
+	BoundedVec<RevealEntryOf<T, I>, <T as Config<I>>::MaxAttributesPerCall>;
+pub(super) type RevealCommitmentsOf<T, I = ()> = BoundedVec<
+	(<T as SystemConfig>::Hash, <T as SystemConfig>::BlockNumber),
+	<T as Config<I>>::MaxRevealCommitments,
+>;
+pub(super) type RoyaltyBeneficiariesOf<T, I = ()> = BoundedVec<
+	(<T as SystemConfig>::AccountId, Permill),
+	<T as Config<I>>::MaxRoyaltyBeneficiaries,
+>;
+pub(super) type CollectionRoyaltyFor<T, I = ()> = CollectionRoyalty<RoyaltyBeneficiariesOf<T, I>>;
 
 pub trait Incrementable {
 	fn increment(&self) -> Self;
@@ -106,6 +148,19 @@ impl<AccountId, DepositBalance> CollectionDetails<AccountId, DepositBalance> {
 	}
 }
 
+/// The stages through which a collection is destroyed, in order.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DestroyStage {
+	/// The items are removed, along with their prices, swaps and approvals.
+	Items,
+	/// The metadata of the items is removed.
+	ItemMetadata,
+	/// The attributes of the collection and of its items are removed.
+	Attributes,
+	/// The remaining item configs and per-account data are removed, then the collection itself.
+	Configs,
+}
+
 /// Witness data for items mint transactions.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct MintWitness<ItemId> {
@@ -134,6 +189,17 @@ pub struct ItemDeposit<DepositBalance, AccountId> {
 	pub(super) amount: DepositBalance,
 }
 
+/// Information about the deposits of an account which are held in an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetDeposit<AssetId, DepositBalance, AssetBalance> {
+	/// The asset the deposits are held in.
+	pub(super) asset: AssetId,
+	/// The total of the deposits, denominated in `Currency`.
+	pub(super) native: DepositBalance,
+	/// The amount of `asset` held for them.
+	pub(super) held: AssetBalance,
+}
+
 /// Information about the collection's metadata.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(StringLimit))]
@@ -177,6 +243,31 @@ pub struct ItemTip<CollectionId, ItemId, AccountId, Amount> {
 	pub(super) amount: Amount,
 }
 
+/// A transfer of an item, authorized by its owner signing it off-chain.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PreSignedTransfer<CollectionId, ItemId, AccountId, Deadline> {
+	/// The collection of the item.
+	pub(super) collection: CollectionId,
+	/// The item to be transferred.
+	pub(super) item: ItemId,
+	/// The account to receive ownership of the item.
+	pub(super) dest: AccountId,
+	/// The last block in which the transfer may be made.
+	pub(super) deadline: Deadline,
+	/// The nonce of the owner's pre-signed transfers, which must be the next one.
+	pub(super) nonce: u32,
+}
+
+/// A change to the metadata or attributes of a collection, revealed in a batch committed to by
+/// the collection owner in advance.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RevealEntry<ItemId, Data, Key, Value> {
+	/// Set the metadata of an item.
+	ItemMetadata { item: ItemId, data: Data },
+	/// Set an attribute of the collection, or of an item, in the `CollectionOwner` namespace.
+	Attribute { maybe_item: Option<ItemId>, key: Key, value: Value },
+}
+
 /// Information about the pending swap.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
@@ -199,6 +290,19 @@ pub struct AttributeDeposit<DepositBalance, AccountId> {
 	pub(super) amount: DepositBalance,
 }
 
+/// A single change of the ownership of an item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProvenanceRecord<AccountId, Price, BlockNumber> {
+	/// The block in which the item changed hands.
+	pub at: BlockNumber,
+	/// The previous owner.
+	pub from: AccountId,
+	/// The new owner.
+	pub to: AccountId,
+	/// The price paid, if the item was sold through `buy_item`.
+	pub price: Option<Price>,
+}
+
 /// Specifies whether the tokens will be sent or received.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum PriceDirection {
@@ -232,6 +336,9 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// When this isn't set then the last ownership changes of each item are recorded in
+	/// `ItemProvenance`.
+	UntrackedProvenance,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
@@ -371,6 +478,18 @@ impl ItemSettings {
 
 impl_codec_bitflags!(ItemSettings, u64, ItemSetting);
 
+/// The rules of a soulbound item, which can never be transferred from the account it was minted
+/// into.
+#[derive(
+	Encode, Decode, Default, PartialEq, Eq, RuntimeDebug, Clone, Copy, MaxEncodedLen, TypeInfo,
+)]
+pub struct SoulboundRules {
+	/// The holder of the item may burn it.
+	pub holder_may_burn: bool,
+	/// The Issuer of the collection may revoke the item by burning it.
+	pub issuer_may_revoke: bool,
+}
+
 /// Item's configuration.
 #[derive(
 	Encode, Decode, Default, PartialEq, RuntimeDebug, Clone, Copy, MaxEncodedLen, TypeInfo,
@@ -378,6 +497,8 @@ impl_codec_bitflags!(ItemSettings, u64, ItemSetting);
 pub struct ItemConfig {
 	/// Item's settings.
 	pub(super) settings: ItemSettings,
+	/// The rules of the item if it is soulbound. Set at mint and never changed.
+	pub(super) soulbound: Option<SoulboundRules>,
 }
 
 impl ItemConfig {
@@ -396,6 +517,9 @@ impl ItemConfig {
 	pub fn disable_setting(&mut self, setting: ItemSetting) {
 		self.settings.0.insert(setting);
 	}
+	pub fn is_soulbound(&self) -> bool {
+		self.soulbound.is_some()
+	}
 }
 
 /// Support for up to 64 system-enabled features on a collection.
@@ -463,3 +587,21 @@ impl CollectionRoles {
 	}
 }
 impl_codec_bitflags!(CollectionRoles, u8, CollectionRole);
+
+/// The royalty owed to the beneficiaries of a collection on every sale of one of its items.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionRoyalty<Beneficiaries> {
+	/// The part of the price of a sale which is owed as royalty, out of the proceeds of the
+	/// seller.
+	pub percentage: Permill,
+	/// The beneficiaries of the royalty, each with their share of it.
+	pub beneficiaries: Beneficiaries,
+}
+
+impl<AccountId, S: Get<u32>> CollectionRoyalty<BoundedVec<(AccountId, Permill), S>> {
+	/// Whether the shares of the beneficiaries add up to exactly 100%.
+	pub fn is_whole(&self) -> bool {
+		let parts = self.beneficiaries.iter().map(|(_, share)| share.deconstruct() as u64);
+		parts.sum::<u64>() == Permill::one().deconstruct() as u64
+	}
+}
@@ -15,14 +15,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_nfts
+//! Weights for pallet_nfts
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! NOT FULLY GENERATED: the functions marked as placeholders were written by hand after the
+//! last run of the benchmark CLI and have not been benchmarked. Regenerate this file with the
+//! command below before these weights are relied upon.
+//!
+//! Last run of the Substrate benchmark CLI version 4.0.0-dev:
 //! DATE: 2022-12-22, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `bm3`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
 
-// Executed Command:
+// Command to regenerate:
 // /home/benchbot/cargo_target_dir/production/substrate
 // benchmark
 // pallet
@@ -50,7 +54,11 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create() -> Weight;
 	fn force_create() -> Weight;
-	fn destroy(n: u32, m: u32, a: u32, ) -> Weight;
+	fn destroy() -> Weight;
+	fn destroy_items(n: u32, ) -> Weight;
+	fn destroy_item_metadata(m: u32, ) -> Weight;
+	fn destroy_attributes(a: u32, ) -> Weight;
+	fn destroy_configs(c: u32, ) -> Weight;
 	fn mint() -> Weight;
 	fn force_mint() -> Weight;
 	fn burn() -> Weight;
@@ -59,12 +67,15 @@ pub trait WeightInfo {
 	fn lock_item_transfer() -> Weight;
 	fn unlock_item_transfer() -> Weight;
 	fn lock_collection() -> Weight;
+	fn pause_collection() -> Weight;
+	fn unpause_collection() -> Weight;
 	fn transfer_ownership() -> Weight;
 	fn set_team() -> Weight;
 	fn force_collection_owner() -> Weight;
 	fn force_collection_config() -> Weight;
 	fn lock_item_properties() -> Weight;
 	fn set_attribute() -> Weight;
+	fn set_attributes(n: u32, ) -> Weight;
 	fn force_set_attribute() -> Weight;
 	fn clear_attribute() -> Weight;
 	fn approve_item_attributes() -> Weight;
@@ -80,11 +91,24 @@ pub trait WeightInfo {
 	fn set_collection_max_supply() -> Weight;
 	fn update_mint_settings() -> Weight;
 	fn set_price() -> Weight;
-	fn buy_item() -> Weight;
+	fn buy_item(b: u32, ) -> Weight;
+	fn clear_expired_listings(n: u32, ) -> Weight;
+	fn transfer_pre_signed() -> Weight;
 	fn pay_tips(n: u32, ) -> Weight;
 	fn create_swap() -> Weight;
 	fn cancel_swap() -> Weight;
-	fn claim_swap() -> Weight;
+	fn claim_swap(b: u32, ) -> Weight;
+	fn set_deposit_asset() -> Weight;
+	fn approve_collection_transfer() -> Weight;
+	fn cancel_collection_approval() -> Weight;
+	fn clear_all_collection_approvals() -> Weight;
+	fn enable_random_mint() -> Weight;
+	fn commit_random_mint() -> Weight;
+	fn mint_random() -> Weight;
+	fn commit_reveal() -> Weight;
+	fn reveal_batch(n: u32, ) -> Weight;
+	fn set_collection_royalty(b: u32, ) -> Weight;
+	fn clear_random_mint_commitment() -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -112,34 +136,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts ItemLocks (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	fn destroy() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(32_307_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts Item (r:1001 w:1000)
-	// Storage: Nfts Attribute (r:1001 w:1000)
-	// Storage: Nfts ItemMetadataOf (r:0 w:1000)
-	// Storage: Nfts CollectionRoleOf (r:0 w:1)
-	// Storage: Nfts CollectionMetadataOf (r:0 w:1)
-	// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	// Storage: Nfts ItemConfigOf (r:0 w:1000)
 	// Storage: Nfts Account (r:0 w:1000)
-	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: Nfts ItemPriceOf (r:0 w:1000)
+	// Storage: Nfts PendingSwapOf (r:0 w:1000)
+	// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1000)
+	// Storage: Nfts ItemConfigOf (r:0 w:1000)
+	// Storage: Nfts ItemProvenance (r:0 w:1000)
 	/// The range of component `n` is `[0, 1000]`.
+	fn destroy_items(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(40_683_211)
+			.saturating_add(Weight::from_ref_time(14_259_407).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts ItemMetadataOf (r:1001 w:1000)
 	/// The range of component `m` is `[0, 1000]`.
+	fn destroy_item_metadata(m: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(37_348_876)
+			.saturating_add(Weight::from_ref_time(2_774_315).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts Attribute (r:1001 w:1000)
 	/// The range of component `a` is `[0, 1000]`.
-	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
-		// Minimum execution time: 19_183_393 nanoseconds.
-		Weight::from_ref_time(17_061_526_855)
-			// Standard Error: 16_689
-			.saturating_add(Weight::from_ref_time(353_523).saturating_mul(n.into()))
-			// Standard Error: 16_689
-			.saturating_add(Weight::from_ref_time(1_861_080).saturating_mul(m.into()))
-			// Standard Error: 16_689
-			.saturating_add(Weight::from_ref_time(8_858_987).saturating_mul(a.into()))
-			.saturating_add(T::DbWeight::get().reads(1003))
+	fn destroy_attributes(a: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(38_190_532)
+			.saturating_add(Weight::from_ref_time(9_102_643).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(T::DbWeight::get().writes(3005))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
 	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts ItemConfigOf (r:1001 w:1000)
+	// Storage: Nfts ItemAttributesApprovalsOf (r:1 w:0)
+	// Storage: Nfts CollectionApprovalsOf (r:1 w:0)
+	// Storage: Nfts RandomMintSwaps (r:1 w:0)
+	// Storage: Nfts RandomMintCommitments (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	// Storage: Nfts CollectionMetadataOf (r:0 w:1)
+	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: Nfts PausedCollections (r:0 w:1)
+	// Storage: Nfts RandomMintPool (r:0 w:1)
+	/// The range of component `c` is `[0, 1000]`.
+	fn destroy_configs(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(64_015_783)
+			.saturating_add(Weight::from_ref_time(3_401_928).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	// Storage: Nfts RandomMintPool (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts Item (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
@@ -147,21 +221,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Nfts ItemConfigOf (r:1 w:1)
 	// Storage: Nfts Account (r:0 w:1)
 	fn mint() -> Weight {
-		// Minimum execution time: 57_753 nanoseconds.
-		Weight::from_ref_time(58_313_000)
-			.saturating_add(T::DbWeight::get().reads(5))
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(60_214_000)
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts RandomMintPool (r:1 w:0)
 	// Storage: Nfts Item (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts ItemConfigOf (r:1 w:1)
 	// Storage: Nfts Account (r:0 w:1)
 	fn force_mint() -> Weight {
-		// Minimum execution time: 56_429 nanoseconds.
-		Weight::from_ref_time(57_202_000)
-			.saturating_add(T::DbWeight::get().reads(5))
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(59_087_000)
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Nfts Collection (r:1 w:1)
@@ -172,11 +247,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Nfts ItemPriceOf (r:0 w:1)
 	// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	// Storage: Nfts ItemProvenance (r:0 w:1)
 	fn burn() -> Weight {
-		// Minimum execution time: 59_681 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(60_058_000)
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -187,11 +263,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Nfts Account (r:0 w:2)
 	// Storage: Nfts ItemPriceOf (r:0 w:1)
 	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	// Storage: Nfts ItemProvenance (r:1 w:1)
 	fn transfer() -> Weight {
-		// Minimum execution time: 66_085 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(67_065_000)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -230,6 +307,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts PausedCollections (r:1 w:1)
+	fn pause_collection() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(26_902_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts PausedCollections (r:1 w:1)
+	fn unpause_collection() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(25_413_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	// Storage: Nfts OwnershipAcceptance (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts CollectionAccount (r:0 w:2)
@@ -282,6 +376,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Attribute (r:10 w:10)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_attributes(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(47_215_391)
+			.saturating_add(Weight::from_ref_time(12_870_412).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts Attribute (r:1 w:1)
 	fn force_set_attribute() -> Weight {
 		// Minimum execution time: 36_402 nanoseconds.
@@ -392,10 +500,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Nfts CollectionConfigOf (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts RandomMintPool (r:1 w:0)
 	fn set_collection_max_supply() -> Weight {
-		// Minimum execution time: 28_235 nanoseconds.
-		Weight::from_ref_time(28_967_000)
-			.saturating_add(T::DbWeight::get().reads(2))
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(30_112_000)
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: Nfts Collection (r:1 w:0)
@@ -421,14 +530,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts ItemConfigOf (r:1 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts CollectionRoyaltyOf (r:1 w:0)
+	// Storage: System Account (r:11 w:11)
 	// Storage: Nfts Account (r:0 w:2)
 	// Storage: Nfts PendingSwapOf (r:0 w:1)
-	fn buy_item() -> Weight {
-		// Minimum execution time: 70_971 nanoseconds.
+	// Storage: Nfts ItemProvenance (r:1 w:1)
+	/// The range of component `b` is `[0, 10]`.
+	fn buy_item(b: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(72_036_000)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_ref_time(17_512_000).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	// Storage: Nfts ListingsCleanupCursor (r:1 w:1)
+	// Storage: Nfts ItemPriceOf (r:1000 w:1000)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_expired_listings(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(10_318_000)
+			.saturating_add(Weight::from_ref_time(9_847_112).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
@@ -458,14 +585,145 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts ItemConfigOf (r:2 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts CollectionRoyaltyOf (r:1 w:0)
+	// Storage: System Account (r:11 w:11)
 	// Storage: Nfts Account (r:0 w:4)
 	// Storage: Nfts ItemPriceOf (r:0 w:2)
-	fn claim_swap() -> Weight {
-		// Minimum execution time: 101_076 nanoseconds.
+	// Storage: Nfts ItemProvenance (r:2 w:2)
+	/// The range of component `b` is `[0, 10]`.
+	fn claim_swap(b: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(101_863_000)
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(Weight::from_ref_time(17_512_000).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	// Storage: Nfts AssetDepositOf (r:1 w:0)
+	// Storage: Nfts DepositAssetOf (r:0 w:1)
+	fn set_deposit_asset() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(20_351_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts PausedCollections (r:1 w:0)
+	// Storage: Nfts Account (r:1 w:0)
+	// Storage: Nfts CollectionApprovalsOf (r:1 w:1)
+	fn approve_collection_transfer() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(34_102_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts CollectionApprovalsOf (r:1 w:1)
+	fn cancel_collection_approval() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(25_331_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts CollectionApprovalsOf (r:0 w:1)
+	fn clear_all_collection_approvals() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(18_466_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts RandomMintPool (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	fn enable_random_mint() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(28_341_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts RandomMintPool (r:1 w:0)
+	// Storage: Nfts RandomMintCommitments (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts RandomMintSeedRequests (r:0 w:1)
+	fn commit_random_mint() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(31_207_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Nfts RandomMintCommitments (r:1 w:1)
+	// Storage: Nfts RandomMintPool (r:1 w:1)
+	// Storage: Nfts RandomMintSeeds (r:1 w:0)
+	// Storage: Nfts RandomMintSwaps (r:2 w:2)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	// Storage: Nfts AssetDepositOf (r:1 w:0)
+	fn mint_random() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(82_437_000)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts PreSignedTransferNonce (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:2)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	// Storage: Nfts ItemProvenance (r:1 w:1)
+	fn transfer_pre_signed() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(114_203_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts RevealCommitments (r:1 w:1)
+	fn commit_reveal() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(24_873_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts Collection (r:11 w:10)
+	// Storage: Nfts RevealCommitments (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:10 w:0)
+	// Storage: Nfts ItemConfigOf (r:10 w:0)
+	// Storage: Nfts Attribute (r:10 w:10)
+	/// The range of component `n` is `[1, 10]`.
+	fn reveal_batch(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(50_327_914)
+			.saturating_add(Weight::from_ref_time(17_402_358).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionRoyaltyOf (r:0 w:1)
+	/// The range of component `b` is `[0, 10]`.
+	fn set_collection_royalty(b: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(24_208_000)
+			.saturating_add(Weight::from_ref_time(96_000).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Nfts RandomMintCommitments (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_random_mint_commitment() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(27_618_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
 
@@ -493,34 +751,84 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(5))
 	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts ItemLocks (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	fn destroy() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(32_307_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts Item (r:1001 w:1000)
-	// Storage: Nfts Attribute (r:1001 w:1000)
-	// Storage: Nfts ItemMetadataOf (r:0 w:1000)
-	// Storage: Nfts CollectionRoleOf (r:0 w:1)
-	// Storage: Nfts CollectionMetadataOf (r:0 w:1)
-	// Storage: Nfts CollectionConfigOf (r:0 w:1)
-	// Storage: Nfts ItemConfigOf (r:0 w:1000)
 	// Storage: Nfts Account (r:0 w:1000)
-	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: Nfts ItemPriceOf (r:0 w:1000)
+	// Storage: Nfts PendingSwapOf (r:0 w:1000)
+	// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1000)
+	// Storage: Nfts ItemConfigOf (r:0 w:1000)
+	// Storage: Nfts ItemProvenance (r:0 w:1000)
 	/// The range of component `n` is `[0, 1000]`.
+	fn destroy_items(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(40_683_211)
+			.saturating_add(Weight::from_ref_time(14_259_407).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts ItemMetadataOf (r:1001 w:1000)
 	/// The range of component `m` is `[0, 1000]`.
+	fn destroy_item_metadata(m: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(37_348_876)
+			.saturating_add(Weight::from_ref_time(2_774_315).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts Attribute (r:1001 w:1000)
 	/// The range of component `a` is `[0, 1000]`.
-	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
-		// Minimum execution time: 19_183_393 nanoseconds.
-		Weight::from_ref_time(17_061_526_855)
-			// Standard Error: 16_689
-			.saturating_add(Weight::from_ref_time(353_523).saturating_mul(n.into()))
-			// Standard Error: 16_689
-			.saturating_add(Weight::from_ref_time(1_861_080).saturating_mul(m.into()))
-			// Standard Error: 16_689
-			.saturating_add(Weight::from_ref_time(8_858_987).saturating_mul(a.into()))
-			.saturating_add(RocksDbWeight::get().reads(1003))
+	fn destroy_attributes(a: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(38_190_532)
+			.saturating_add(Weight::from_ref_time(9_102_643).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(RocksDbWeight::get().writes(3005))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
 	}
+	// Storage: Nfts DestroyCursor (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts ItemConfigOf (r:1001 w:1000)
+	// Storage: Nfts ItemAttributesApprovalsOf (r:1 w:0)
+	// Storage: Nfts CollectionApprovalsOf (r:1 w:0)
+	// Storage: Nfts RandomMintSwaps (r:1 w:0)
+	// Storage: Nfts RandomMintCommitments (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	// Storage: Nfts CollectionMetadataOf (r:0 w:1)
+	// Storage: Nfts CollectionAccount (r:0 w:1)
+	// Storage: Nfts PausedCollections (r:0 w:1)
+	// Storage: Nfts RandomMintPool (r:0 w:1)
+	/// The range of component `c` is `[0, 1000]`.
+	fn destroy_configs(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(64_015_783)
+			.saturating_add(Weight::from_ref_time(3_401_928).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(8))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	// Storage: Nfts RandomMintPool (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts Item (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
@@ -528,21 +836,22 @@ impl WeightInfo for () {
 	// Storage: Nfts ItemConfigOf (r:1 w:1)
 	// Storage: Nfts Account (r:0 w:1)
 	fn mint() -> Weight {
-		// Minimum execution time: 57_753 nanoseconds.
-		Weight::from_ref_time(58_313_000)
-			.saturating_add(RocksDbWeight::get().reads(5))
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(60_214_000)
+			.saturating_add(RocksDbWeight::get().reads(6))
 			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts RandomMintPool (r:1 w:0)
 	// Storage: Nfts Item (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts ItemConfigOf (r:1 w:1)
 	// Storage: Nfts Account (r:0 w:1)
 	fn force_mint() -> Weight {
-		// Minimum execution time: 56_429 nanoseconds.
-		Weight::from_ref_time(57_202_000)
-			.saturating_add(RocksDbWeight::get().reads(5))
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(59_087_000)
+			.saturating_add(RocksDbWeight::get().reads(6))
 			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 	// Storage: Nfts Collection (r:1 w:1)
@@ -553,11 +862,12 @@ impl WeightInfo for () {
 	// Storage: Nfts ItemPriceOf (r:0 w:1)
 	// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
 	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	// Storage: Nfts ItemProvenance (r:0 w:1)
 	fn burn() -> Weight {
-		// Minimum execution time: 59_681 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(60_058_000)
 			.saturating_add(RocksDbWeight::get().reads(4))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
 	}
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -568,11 +878,12 @@ impl WeightInfo for () {
 	// Storage: Nfts Account (r:0 w:2)
 	// Storage: Nfts ItemPriceOf (r:0 w:1)
 	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	// Storage: Nfts ItemProvenance (r:1 w:1)
 	fn transfer() -> Weight {
-		// Minimum execution time: 66_085 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(67_065_000)
-			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(7))
 	}
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -611,6 +922,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts PausedCollections (r:1 w:1)
+	fn pause_collection() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(26_902_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts PausedCollections (r:1 w:1)
+	fn unpause_collection() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(25_413_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 	// Storage: Nfts OwnershipAcceptance (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts CollectionAccount (r:0 w:2)
@@ -663,6 +991,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Attribute (r:10 w:10)
+	/// The range of component `n` is `[1, 10]`.
+	fn set_attributes(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(47_215_391)
+			.saturating_add(Weight::from_ref_time(12_870_412).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: Nfts Collection (r:1 w:1)
 	// Storage: Nfts Attribute (r:1 w:1)
 	fn force_set_attribute() -> Weight {
 		// Minimum execution time: 36_402 nanoseconds.
@@ -773,10 +1115,11 @@ impl WeightInfo for () {
 	}
 	// Storage: Nfts CollectionConfigOf (r:1 w:1)
 	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts RandomMintPool (r:1 w:0)
 	fn set_collection_max_supply() -> Weight {
-		// Minimum execution time: 28_235 nanoseconds.
-		Weight::from_ref_time(28_967_000)
-			.saturating_add(RocksDbWeight::get().reads(2))
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(30_112_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
 	// Storage: Nfts Collection (r:1 w:0)
@@ -802,14 +1145,32 @@ impl WeightInfo for () {
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts ItemConfigOf (r:1 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts CollectionRoyaltyOf (r:1 w:0)
+	// Storage: System Account (r:11 w:11)
 	// Storage: Nfts Account (r:0 w:2)
 	// Storage: Nfts PendingSwapOf (r:0 w:1)
-	fn buy_item() -> Weight {
-		// Minimum execution time: 70_971 nanoseconds.
+	// Storage: Nfts ItemProvenance (r:1 w:1)
+	/// The range of component `b` is `[0, 10]`.
+	fn buy_item(b: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(72_036_000)
-			.saturating_add(RocksDbWeight::get().reads(6))
-			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(Weight::from_ref_time(17_512_000).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	// Storage: Nfts ListingsCleanupCursor (r:1 w:1)
+	// Storage: Nfts ItemPriceOf (r:1000 w:1000)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_expired_listings(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(10_318_000)
+			.saturating_add(Weight::from_ref_time(9_847_112).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
@@ -839,13 +1200,144 @@ impl WeightInfo for () {
 	// Storage: Nfts Collection (r:1 w:0)
 	// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	// Storage: Nfts ItemConfigOf (r:2 w:0)
-	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts CollectionRoyaltyOf (r:1 w:0)
+	// Storage: System Account (r:11 w:11)
 	// Storage: Nfts Account (r:0 w:4)
 	// Storage: Nfts ItemPriceOf (r:0 w:2)
-	fn claim_swap() -> Weight {
-		// Minimum execution time: 101_076 nanoseconds.
+	// Storage: Nfts ItemProvenance (r:2 w:2)
+	/// The range of component `b` is `[0, 10]`.
+	fn claim_swap(b: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(101_863_000)
-			.saturating_add(RocksDbWeight::get().reads(8))
-			.saturating_add(RocksDbWeight::get().writes(11))
+			.saturating_add(Weight::from_ref_time(17_512_000).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(13))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	// Storage: Nfts AssetDepositOf (r:1 w:0)
+	// Storage: Nfts DepositAssetOf (r:0 w:1)
+	fn set_deposit_asset() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(20_351_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts PausedCollections (r:1 w:0)
+	// Storage: Nfts Account (r:1 w:0)
+	// Storage: Nfts CollectionApprovalsOf (r:1 w:1)
+	fn approve_collection_transfer() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(34_102_000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts CollectionApprovalsOf (r:1 w:1)
+	fn cancel_collection_approval() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(25_331_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts CollectionApprovalsOf (r:0 w:1)
+	fn clear_all_collection_approvals() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(18_466_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts RandomMintPool (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	fn enable_random_mint() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(28_341_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts RandomMintPool (r:1 w:0)
+	// Storage: Nfts RandomMintCommitments (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts RandomMintSeedRequests (r:0 w:1)
+	fn commit_random_mint() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(31_207_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: Nfts RandomMintCommitments (r:1 w:1)
+	// Storage: Nfts RandomMintPool (r:1 w:1)
+	// Storage: Nfts RandomMintSeeds (r:1 w:0)
+	// Storage: Nfts RandomMintSwaps (r:2 w:2)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts Collection (r:1 w:1)
+	// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:1)
+	// Storage: Nfts AssetDepositOf (r:1 w:0)
+	fn mint_random() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(82_437_000)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	// Storage: Nfts ItemConfigOf (r:1 w:0)
+	// Storage: Nfts Item (r:1 w:1)
+	// Storage: Nfts PreSignedTransferNonce (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Nfts Account (r:0 w:2)
+	// Storage: Nfts ItemPriceOf (r:0 w:1)
+	// Storage: Nfts PendingSwapOf (r:0 w:1)
+	// Storage: Nfts ItemProvenance (r:1 w:1)
+	fn transfer_pre_signed() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(114_203_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts RevealCommitments (r:1 w:1)
+	fn commit_reveal() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(24_873_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts Collection (r:11 w:10)
+	// Storage: Nfts RevealCommitments (r:1 w:1)
+	// Storage: Nfts CollectionConfigOf (r:10 w:0)
+	// Storage: Nfts ItemConfigOf (r:10 w:0)
+	// Storage: Nfts Attribute (r:10 w:10)
+	/// The range of component `n` is `[1, 10]`.
+	fn reveal_batch(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(50_327_914)
+			.saturating_add(Weight::from_ref_time(17_402_358).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: Nfts Collection (r:1 w:0)
+	// Storage: Nfts CollectionRoyaltyOf (r:0 w:1)
+	/// The range of component `b` is `[0, 10]`.
+	fn set_collection_royalty(b: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(24_208_000)
+			.saturating_add(Weight::from_ref_time(96_000).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Nfts RandomMintCommitments (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_random_mint_commitment() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(27_618_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 }
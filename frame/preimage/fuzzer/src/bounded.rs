@@ -0,0 +1,111 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Running
+//! Running this fuzzer can be done with `cargo hfuzz run bounded`. `honggfuzz` CLI options can
+//! be used by setting `HFUZZ_RUN_ARGS`, such as `-n 4` to use 4 threads.
+//!
+//! # Debugging a panic
+//! Once a panic is found, it can be debugged with
+//! `cargo hfuzz run-debug bounded hfuzz_workspace/bounded/*.fuzz`.
+//!
+//! # More information
+//! More information about `honggfuzz` can be found
+//! [here](https://docs.rs/honggfuzz/).
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{Bounded, QueryPreimage, StorePreimage};
+use honggfuzz::fuzz;
+use pallet_preimage::mock::{new_test_ext, Preimage};
+use sp_io::hashing::blake2_256;
+
+/// The `MaxEncodedLen` of any `Bounded` which other pallets rely upon.
+const BOUNDED_MEL: usize = 131;
+
+/// The maximum length that a `Legacy` lookup is assumed to have.
+const MAX_LEGACY_LEN: u32 = 1_000_000;
+
+/// Checks the invariants which must hold for every `Bounded` value, regardless of its variant.
+fn check_invariants(bounded: &Bounded<Vec<u8>>) {
+	assert_eq!(<Bounded<Vec<u8>> as MaxEncodedLen>::max_encoded_len(), BOUNDED_MEL);
+
+	let encoded = bounded.encode();
+	assert!(encoded.len() <= BOUNDED_MEL, "encoded length exceeds the MEL");
+	let decoded = Bounded::<Vec<u8>>::decode(&mut &encoded[..]).expect("must decode");
+	assert_eq!(&decoded, bounded, "decoding must round-trip");
+
+	assert_eq!(bounded.lookup_needed(), bounded.lookup_len().is_some());
+	if let Some(len) = bounded.len() {
+		assert!(bounded.lookup_len().map_or(true, |l| l == len));
+	}
+}
+
+fn main() {
+	new_test_ext().execute_with(|| loop {
+		fuzz!(|data: (Vec<u8>, [u8; 32])| {
+			let (value, legacy_hash) = data;
+			let encoded = value.encode();
+
+			let bounded =
+				<Preimage as StorePreimage>::bound(value.clone()).expect("value is within limits");
+			check_invariants(&bounded);
+
+			// Hash and length always describe the encoded value.
+			assert_eq!(bounded.hash(), blake2_256(&encoded).into());
+			assert_eq!(bounded.len(), Some(encoded.len() as u32));
+			match bounded {
+				Bounded::Inline(ref inline) => {
+					assert!(encoded.len() <= 128);
+					assert_eq!(&inline[..], &encoded[..]);
+				},
+				Bounded::Lookup { len, .. } => {
+					assert!(encoded.len() > 128);
+					assert_eq!(bounded.lookup_len(), Some(len));
+				},
+				Bounded::Legacy { .. } => panic!("`bound` must never create a legacy value"),
+			}
+
+			// The data needed for realization is there and decodes to the original value.
+			assert!(<Preimage as QueryPreimage>::have(&bounded));
+			let (peeked, maybe_len) =
+				<Preimage as QueryPreimage>::peek(&bounded).expect("must be peekable");
+			assert_eq!(peeked, value);
+			assert_eq!(maybe_len, bounded.lookup_len());
+
+			// Transmuting into something that encodes alike changes nothing observable.
+			let transmuted: Bounded<&[u8]> = bounded.clone().transmute();
+			assert_eq!(transmuted.hash(), bounded.hash());
+			assert_eq!(transmuted.len(), bounded.len());
+			assert_eq!(transmuted.encode(), bounded.encode());
+			let back: Bounded<Vec<u8>> = transmuted.transmute();
+			assert_eq!(back, bounded);
+
+			// Legacy values carry no length and assume the maximal lookup length.
+			#[allow(deprecated)]
+			let legacy = Bounded::<Vec<u8>>::from_legacy_hash(legacy_hash);
+			check_invariants(&legacy);
+			assert_eq!(legacy.hash(), legacy_hash.into());
+			assert_eq!(legacy.len(), None);
+			assert_eq!(legacy.lookup_len(), Some(MAX_LEGACY_LEN));
+			assert_eq!(legacy.clone().transmute::<&[u8]>().encode(), legacy.encode());
+
+			// Clean up, so that the next iteration starts with an empty store.
+			<Preimage as QueryPreimage>::drop(&bounded);
+			assert_eq!(<Preimage as QueryPreimage>::have(&bounded), !bounded.lookup_needed());
+		})
+	});
+}
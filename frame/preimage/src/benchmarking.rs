@@ -163,5 +163,27 @@ benchmarks! {
 		assert_eq!(StatusFor::<T>::get(&hash), Some(s));
 	}
 
+	// Expire the unnoted requests due in a block.
+	expire_requests {
+		let n in 0 .. T::MaxExpiriesPerBlock::get();
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut hashes = vec![];
+		for i in 0..n {
+			let (_, hash) = sized_preimage_and_hash::<T>(i);
+			let s = RequestStatus::Requested { deposit: None, count: 1, len: None };
+			StatusFor::<T>::insert(&hash, s);
+			RequestExpiresAt::<T>::insert(&hash, now);
+			assert_ok!(ExpiryAgenda::<T>::try_append(now, hash));
+			hashes.push(hash);
+		}
+	}: {
+		Preimage::<T>::on_initialize(now);
+	}
+	verify {
+		for hash in hashes {
+			assert_eq!(StatusFor::<T>::get(&hash), None);
+		}
+	}
+
 	impl_benchmark_test_suite!(Preimage, crate::mock::new_test_ext(), crate::mock::Test);
 }
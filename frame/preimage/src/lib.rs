@@ -31,14 +31,14 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod migration;
-#[cfg(test)]
-mod mock;
+#[cfg(any(test, feature = "fuzz"))]
+pub mod mock;
 #[cfg(test)]
 mod tests;
 pub mod weights;
 
-use sp_runtime::traits::{BadOrigin, Hash, Saturating};
-use sp_std::{borrow::Cow, prelude::*};
+use sp_runtime::traits::{BadOrigin, Hash, One, Saturating};
+use sp_std::{borrow::Cow, prelude::*, vec};
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
@@ -46,8 +46,9 @@ use frame_support::{
 	ensure,
 	pallet_prelude::Get,
 	traits::{
-		Currency, Defensive, FetchResult, Hash as PreimageHash, PreimageProvider,
-		PreimageRecipient, QueryPreimage, ReservableCurrency, StorePreimage,
+		Currency, Defensive, FetchResult, Hash as PreimageHash, PreimageCapabilities,
+		PreimageFeatures, PreimageProvider, PreimageRecipient, QueryPreimage, ReservableCurrency,
+		StorePreimage,
 	},
 	BoundedSlice, BoundedVec,
 };
@@ -71,12 +72,70 @@ pub enum RequestStatus<AccountId, Balance> {
 	Requested { deposit: Option<(AccountId, Balance)>, count: u32, len: Option<u32> },
 }
 
+/// Decides whether, and when, the requests for a preimage which is not noted expire, so that the
+/// requests of consumers which never see their preimage noted do not linger forever.
+///
+/// Expired requests are dropped outright: clearing one afterwards fails with `NotRequested`, as
+/// for any preimage which is not requested.
+pub trait RequestExpiry<Hash, BlockNumber> {
+	/// The block at which the requests for `hash`, first made in block `now`, expire if it has
+	/// not been noted by then. `None` keeps them until they are cleared.
+	fn expires_at(hash: &Hash, now: BlockNumber) -> Option<BlockNumber>;
+}
+
+impl<Hash, BlockNumber> RequestExpiry<Hash, BlockNumber> for () {
+	fn expires_at(_: &Hash, _: BlockNumber) -> Option<BlockNumber> {
+		None
+	}
+}
+
+/// Expires the requests for any preimage which is not noted within `Period` blocks.
+pub struct ExpireAfter<Period>(PhantomData<Period>);
+impl<Hash, BlockNumber: Saturating, Period: Get<BlockNumber>> RequestExpiry<Hash, BlockNumber>
+	for ExpireAfter<Period>
+{
+	fn expires_at(_: &Hash, now: BlockNumber) -> Option<BlockNumber> {
+		Some(now.saturating_add(Period::get()))
+	}
+}
+
+/// The availability of the preimages of a batch of calls, as reported by
+/// [`Pallet::availability`]. Bit `i` of each bitmap, counting from the lowest bit of its first
+/// byte, is about the `i`th call.
+#[derive(Clone, Eq, PartialEq, Default, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct PreimageAvailability {
+	/// Whether the preimage of each call is noted with the call's length.
+	pub available: Vec<u8>,
+	/// Whether the preimage of each call is requested, and so kept for as long as it is.
+	pub requested: Vec<u8>,
+	/// The estimated weight of fetching and decoding the available preimages.
+	pub decode_weight: Weight,
+}
+
+impl PreimageAvailability {
+	/// Whether the preimage of the `i`th call is available.
+	pub fn is_available(&self, i: usize) -> bool {
+		Self::bit(&self.available, i)
+	}
+
+	/// Whether the preimage of the `i`th call is requested.
+	pub fn is_requested(&self, i: usize) -> bool {
+		Self::bit(&self.requested, i)
+	}
+
+	fn bit(bitmap: &[u8], i: usize) -> bool {
+		bitmap.get(i / 8).map_or(false, |byte| byte & (1 << (i % 8)) != 0)
+	}
+}
+
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 /// Maximum size of preimage we can store is 4mb.
 const MAX_SIZE: u32 = 4 * 1024 * 1024;
 
+const LOG_TARGET: &str = "runtime::preimage";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -104,6 +163,27 @@ pub mod pallet {
 
 		/// The per-byte deposit for placing a preimage on chain.
 		type ByteDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of outstanding requests for a single hash. Further requests through
+		/// `request_preimage` or `QueryPreimage::try_request` are refused until some of the
+		/// existing ones are cleared. Requests which cannot fail, such as those made through
+		/// `QueryPreimage::request`, are still counted.
+		#[pallet::constant]
+		type MaxRequests: Get<u32>;
+
+		/// Whether values passed to `StorePreimage::bound_checked` or `note_checked` are checked to
+		/// be canonically encoded before they are noted. This costs an extra decoding of each
+		/// value.
+		type CheckCanonicalEncoding: Get<bool>;
+
+		/// Decides when the requests for a preimage which is not noted expire. Use `()` to keep
+		/// them until they are cleared.
+		type RequestExpiry: RequestExpiry<Self::Hash, Self::BlockNumber>;
+
+		/// The maximum number of preimages whose requests may expire in the same block. The
+		/// requests which would expire in a block which is already full are kept until cleared.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -120,6 +200,9 @@ pub mod pallet {
 		Requested { hash: T::Hash },
 		/// A preimage has ben cleared.
 		Cleared { hash: T::Hash },
+		/// The `count` outstanding requests for a preimage which was not noted in time have
+		/// expired.
+		RequestsExpired { hash: T::Hash, count: u32 },
 	}
 
 	#[pallet::error]
@@ -136,6 +219,8 @@ pub mod pallet {
 		Requested,
 		/// The preimage request cannot be removed since no outstanding requests exist.
 		NotRequested,
+		/// The maximum number of outstanding requests for this preimage has been reached.
+		TooManyRequests,
 	}
 
 	/// The request status of a given hash.
@@ -147,6 +232,33 @@ pub mod pallet {
 	pub(super) type PreimageFor<T: Config> =
 		StorageMap<_, Identity, (T::Hash, u32), BoundedVec<u8, ConstU32<MAX_SIZE>>>;
 
+	/// The block at which the requests for a preimage expire, if it is not noted by then.
+	#[pallet::storage]
+	pub(super) type RequestExpiresAt<T: Config> = StorageMap<_, Identity, T::Hash, T::BlockNumber>;
+
+	/// The preimages whose requests expire in a given block. Entries for preimages which have
+	/// been noted or cleared since are skipped.
+	#[pallet::storage]
+	pub(super) type ExpiryAgenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::Hash, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let agenda = ExpiryAgenda::<T>::take(now);
+			let weight = T::WeightInfo::expire_requests(agenda.len() as u32);
+			for hash in agenda {
+				Self::expire_requests(&hash, now);
+			}
+			weight
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register a preimage on-chain.
@@ -184,12 +296,13 @@ pub mod pallet {
 		///
 		/// If the preimage requests has already been provided on-chain, we unreserve any deposit
 		/// a user may have paid, and take the control of the preimage out of their hands.
+		///
+		/// Fails if there are already `MaxRequests` outstanding requests for `hash`.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::request_preimage())]
 		pub fn request_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
 			T::ManagerOrigin::ensure_origin(origin)?;
-			Self::do_request_preimage(&hash);
-			Ok(())
+			Self::do_try_request_preimage(&hash).map(|_| ())
 		}
 
 		/// Clear a previously made request for a preimage.
@@ -251,6 +364,9 @@ impl<T: Config> Pallet<T> {
 		};
 		let was_requested = matches!(status, RequestStatus::Requested { .. });
 		StatusFor::<T>::insert(hash, status);
+		if was_requested {
+			RequestExpiresAt::<T>::remove(hash);
+		}
 
 		let _ = Self::insert(&hash, preimage)
 			.defensive_proof("Unable to insert. Logic error in `note_bytes`?");
@@ -260,23 +376,74 @@ impl<T: Config> Pallet<T> {
 		Ok((was_requested, hash))
 	}
 
-	// This function will add a hash to the list of requested preimages.
+	// This function will add a hash to the list of requested preimages, returning the number of
+	// outstanding requests for it.
 	//
 	// If the preimage already exists before the request is made, the deposit for the preimage is
 	// returned to the user, and removed from their management.
-	fn do_request_preimage(hash: &T::Hash) {
-		let (count, len, deposit) =
-			StatusFor::<T>::get(hash).map_or((1, None, None), |x| match x {
-				RequestStatus::Requested { mut count, len, deposit } => {
-					count.saturating_inc();
-					(count, len, deposit)
-				},
-				RequestStatus::Unrequested { deposit, len } => (1, Some(len), Some(deposit)),
-			});
+	//
+	// Fails if there are already `MaxRequests` outstanding requests for the hash.
+	fn do_try_request_preimage(hash: &T::Hash) -> Result<u32, DispatchError> {
+		ensure!(Self::request_count(hash) < T::MaxRequests::get(), Error::<T>::TooManyRequests);
+		Ok(Self::do_request_preimage(hash))
+	}
+
+	// As `do_try_request_preimage`, but always counts the request, so that it stays balanced
+	// with the eventual `do_unrequest_preimage` of a caller which cannot handle a failure.
+	fn do_request_preimage(hash: &T::Hash) -> u32 {
+		let (count, len, deposit) = match StatusFor::<T>::get(hash) {
+			Some(RequestStatus::Requested { count, len, deposit }) =>
+				(count.saturating_add(1), len, deposit),
+			Some(RequestStatus::Unrequested { deposit, len }) => (1, Some(len), Some(deposit)),
+			None => (1, None, None),
+		};
 		StatusFor::<T>::insert(hash, RequestStatus::Requested { count, len, deposit });
 		if count == 1 {
+			if len.is_none() {
+				Self::schedule_expiry(hash);
+			}
 			Self::deposit_event(Event::Requested { hash: *hash });
 		}
+		count
+	}
+
+	// Count a request made by another pallet, reporting if it goes beyond `MaxRequests`.
+	fn request_unchecked(hash: &T::Hash) {
+		let count = Self::do_request_preimage(hash);
+		if count > T::MaxRequests::get() {
+			log::warn!(
+				target: LOG_TARGET,
+				"{} outstanding requests for preimage {:?} exceed `MaxRequests` - request leak?",
+				count,
+				hash,
+			);
+		}
+	}
+
+	// Schedule the expiry of the requests for the unnoted preimage of `hash`, if the
+	// `RequestExpiry` policy and the room in the agenda of the block allow it.
+	fn schedule_expiry(hash: &T::Hash) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let at = match T::RequestExpiry::expires_at(hash, now) {
+			Some(at) => at.max(now.saturating_add(One::one())),
+			None => return,
+		};
+		if ExpiryAgenda::<T>::try_append(at, hash).is_ok() {
+			RequestExpiresAt::<T>::insert(hash, at);
+		}
+	}
+
+	// Drop all outstanding requests for the preimage of `hash` if they are due to expire `now`
+	// and it has still not been noted.
+	fn expire_requests(hash: &T::Hash, now: T::BlockNumber) {
+		if RequestExpiresAt::<T>::get(hash) != Some(now) {
+			return
+		}
+		RequestExpiresAt::<T>::remove(hash);
+		if let Some(RequestStatus::Requested { count, len: None, .. }) = StatusFor::<T>::get(hash) {
+			StatusFor::<T>::remove(hash);
+			Self::deposit_event(Event::RequestsExpired { hash: *hash, count });
+		}
 	}
 
 	// Clear a preimage from the storage of the chain, returning any deposit that may be reserved.
@@ -326,7 +493,10 @@ impl<T: Config> Pallet<T> {
 				debug_assert!(count == 1, "preimage request counter at zero?");
 				match (len, deposit) {
 					// Preimage was never noted.
-					(None, _) => StatusFor::<T>::remove(hash),
+					(None, _) => {
+						StatusFor::<T>::remove(hash);
+						RequestExpiresAt::<T>::remove(hash);
+					},
 					// Preimage was noted without owner - just remove it.
 					(Some(len), None) => {
 						Self::remove(hash, len);
@@ -344,6 +514,17 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Clear a request made by another pallet, which may have expired meanwhile.
+	fn unrequest_defensive(hash: &T::Hash) {
+		let res = Self::do_unrequest_preimage(hash);
+		debug_assert!(
+			res.is_ok() ||
+				T::RequestExpiry::expires_at(hash, frame_system::Pallet::<T>::block_number())
+					.is_some(),
+			"do_unrequest_preimage failed - counter underflow?"
+		);
+	}
+
 	fn insert(hash: &T::Hash, preimage: Cow<[u8]>) -> Result<(), ()> {
 		BoundedSlice::<u8, ConstU32<MAX_SIZE>>::try_from(preimage.as_ref())
 			.map_err(|_| ())
@@ -354,6 +535,47 @@ impl<T: Config> Pallet<T> {
 		PreimageFor::<T>::remove((hash, len))
 	}
 
+	/// The availability of the preimages of `calls`, given by their hash and length, with the
+	/// weight of decoding those available as estimated by `decode_weight` from their length.
+	///
+	/// Reads the status of every call, so it is meant for runtime APIs rather than dispatchables.
+	pub fn availability(
+		calls: &[(T::Hash, u32)],
+		decode_weight: impl Fn(u32) -> Weight,
+	) -> PreimageAvailability {
+		let bytes = (calls.len() + 7) / 8;
+		let mut result = PreimageAvailability {
+			available: vec![0; bytes],
+			requested: vec![0; bytes],
+			decode_weight: Weight::zero(),
+		};
+		for (i, (hash, len)) in calls.iter().enumerate() {
+			use RequestStatus::*;
+			let bit = 1 << (i % 8);
+			let noted = match StatusFor::<T>::get(hash) {
+				Some(Requested { len: noted, .. }) => {
+					result.requested[i / 8] |= bit;
+					noted
+				},
+				Some(Unrequested { len: noted, .. }) => Some(noted),
+				None => None,
+			};
+			if noted == Some(*len) {
+				result.available[i / 8] |= bit;
+				result.decode_weight.saturating_accrue(decode_weight(*len));
+			}
+		}
+		result
+	}
+
+	/// The number of outstanding requests for the given hash.
+	pub fn request_count(hash: &T::Hash) -> u32 {
+		match StatusFor::<T>::get(hash) {
+			Some(RequestStatus::Requested { count, .. }) => count,
+			_ => 0,
+		}
+	}
+
 	fn have(hash: &T::Hash) -> bool {
 		Self::len(hash).is_some()
 	}
@@ -389,12 +611,11 @@ impl<T: Config> PreimageProvider<T::Hash> for Pallet<T> {
 	}
 
 	fn request_preimage(hash: &T::Hash) {
-		Self::do_request_preimage(hash)
+		Self::request_unchecked(hash)
 	}
 
 	fn unrequest_preimage(hash: &T::Hash) {
-		let res = Self::do_unrequest_preimage(hash);
-		debug_assert!(res.is_ok(), "do_unrequest_preimage failed - counter underflow?");
+		Self::unrequest_defensive(hash);
 	}
 }
 
@@ -427,13 +648,20 @@ impl<T: Config<Hash = PreimageHash>> QueryPreimage for Pallet<T> {
 		matches!(StatusFor::<T>::get(hash), Some(RequestStatus::Requested { .. }))
 	}
 
+	fn request_count(hash: &T::Hash) -> u32 {
+		Pallet::<T>::request_count(hash)
+	}
+
 	fn request(hash: &T::Hash) {
-		Self::do_request_preimage(hash)
+		Self::request_unchecked(hash)
+	}
+
+	fn try_request(hash: &T::Hash) -> Result<u32, DispatchError> {
+		Self::do_try_request_preimage(hash)
 	}
 
 	fn unrequest(hash: &T::Hash) {
-		let res = Self::do_unrequest_preimage(hash);
-		debug_assert!(res.is_ok(), "do_unrequest_preimage failed - counter underflow?");
+		Self::unrequest_defensive(hash);
 	}
 }
 
@@ -452,6 +680,14 @@ impl<T: Config<Hash = PreimageHash>> StorePreimage for Pallet<T> {
 		}
 	}
 
+	fn capabilities() -> PreimageCapabilities {
+		let features = match T::CheckCanonicalEncoding::get() {
+			true => PreimageFeatures::CANONICAL,
+			false => PreimageFeatures::empty(),
+		};
+		PreimageCapabilities { features, max_len: MAX_SIZE }
+	}
+
 	fn unnote(hash: &T::Hash) {
 		// Should never fail if authorization check is skipped.
 		let res = Self::do_unnote_preimage(hash, None);
@@ -94,6 +94,7 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 10;
+	pub static ExpiryPeriod: Option<u64> = None;
 }
 
 ord_parameter_types! {
@@ -107,6 +108,17 @@ impl Config for Test {
 	type ManagerOrigin = EnsureSignedBy<One, u64>;
 	type BaseDeposit = ConstU64<2>;
 	type ByteDeposit = ConstU64<1>;
+	type MaxRequests = ConstU32<3>;
+	type CheckCanonicalEncoding = frame_support::traits::ConstBool<true>;
+	type RequestExpiry = RequestExpiry;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+}
+
+pub struct RequestExpiry;
+impl crate::RequestExpiry<H256, u64> for RequestExpiry {
+	fn expires_at(_: &H256, now: u64) -> Option<u64> {
+		ExpiryPeriod::get().map(|period| now + period)
+	}
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -244,6 +244,98 @@ fn unrequest_preimage_works() {
 	});
 }
 
+#[test]
+fn request_count_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let hash = hashed([1]);
+		assert_eq!(Preimage::request_count(&hash), 0);
+		assert_eq!(<Preimage as QueryPreimage>::try_request(&hash), Ok(1));
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hash));
+		assert_eq!(<Preimage as QueryPreimage>::try_request(&hash), Ok(3));
+		assert_eq!(<Preimage as QueryPreimage>::request_count(&hash), 3);
+
+		// `MaxRequests` is reached; further requests are refused.
+		assert_noop!(
+			Preimage::request_preimage(RuntimeOrigin::signed(1), hash),
+			Error::<Test>::TooManyRequests
+		);
+		assert_noop!(
+			<Preimage as QueryPreimage>::try_request(&hash),
+			Error::<Test>::TooManyRequests
+		);
+
+		// Clearing a request makes room for another.
+		assert_ok!(Preimage::unrequest_preimage(RuntimeOrigin::signed(1), hash));
+		assert_eq!(Preimage::request_count(&hash), 2);
+		assert_eq!(<Preimage as QueryPreimage>::try_request(&hash), Ok(3));
+
+		// Requests which cannot fail are still counted, so every one can be cleared.
+		<Preimage as QueryPreimage>::request(&hash);
+		assert_eq!(Preimage::request_count(&hash), 4);
+		for _ in 0..4 {
+			<Preimage as QueryPreimage>::unrequest(&hash);
+		}
+		assert!(!<Preimage as QueryPreimage>::is_requested(&hash));
+	});
+}
+
+#[test]
+fn unnoted_requests_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ExpiryPeriod::set(Some(10));
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([2])));
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([3])));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![2]));
+		// The agenda of block 11 is full, so the third request is kept.
+		assert_eq!(RequestExpiresAt::<Test>::get(hashed([3])), None);
+
+		Preimage::on_initialize(10);
+		assert_eq!(Preimage::request_count(&hashed([1])), 2);
+		Preimage::on_initialize(11);
+		System::assert_last_event(RuntimeEvent::Preimage(Event::RequestsExpired {
+			hash: hashed([1]),
+			count: 2,
+		}));
+		assert!(!Preimage::preimage_requested(&hashed([1])));
+		assert!(!ExpiryAgenda::<Test>::contains_key(11));
+
+		// The noted preimage and the unscheduled request are untouched.
+		assert!(Preimage::have_preimage(&hashed([2])));
+		assert_eq!(Preimage::request_count(&hashed([2])), 1);
+		assert_eq!(Preimage::request_count(&hashed([3])), 1);
+
+		// Unrequesting after the expiry fails as for any other unrequested preimage.
+		assert_noop!(
+			Preimage::unrequest_preimage(RuntimeOrigin::signed(1), hashed([1])),
+			Error::<Test>::NotRequested
+		);
+		<Preimage as QueryPreimage>::unrequest(&hashed([1]));
+		ExpiryPeriod::set(None);
+	});
+}
+
+#[test]
+fn cleared_and_renewed_requests_expire_on_time() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ExpiryPeriod::set(Some(10));
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
+		assert_ok!(Preimage::unrequest_preimage(RuntimeOrigin::signed(1), hashed([1])));
+		System::set_block_number(5);
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
+
+		// The request made in block 5 does not expire with the one cleared before it.
+		Preimage::on_initialize(11);
+		assert_eq!(Preimage::request_count(&hashed([1])), 1);
+		Preimage::on_initialize(15);
+		assert_eq!(Preimage::request_count(&hashed([1])), 0);
+		ExpiryPeriod::set(None);
+	});
+}
+
 #[test]
 fn user_noted_then_requested_preimage_is_refunded_once_only() {
 	new_test_ext().execute_with(|| {
@@ -357,6 +449,32 @@ fn query_and_store_preimage_workflow() {
 	});
 }
 
+#[test]
+fn availability_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
+		<Preimage as QueryPreimage>::request(&hashed([2, 2]));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![3; 3]));
+		<Preimage as QueryPreimage>::request(&hashed([3, 3, 3]));
+
+		let mut calls = vec![
+			(hashed([1]), 1),
+			(hashed([2, 2]), 2),
+			(hashed([3, 3, 3]), 3),
+			(hashed([1]), 2),
+			(hashed([4]), 1),
+		];
+		calls.extend(vec![(hashed([3, 3, 3]), 3); 4]);
+		let result = Preimage::availability(&calls, |len| Weight::from_ref_time(len.into()));
+
+		assert_eq!(result.available, vec![0b1110_0101, 0b1]);
+		assert_eq!(result.requested, vec![0b1110_0110, 0b1]);
+		assert_eq!(result.decode_weight, Weight::from_ref_time(16));
+		assert!(result.is_available(8) && result.is_requested(8));
+		assert!(!result.is_available(9) && !result.is_requested(9));
+	});
+}
+
 /// The request function behaves as expected.
 #[test]
 fn query_preimage_request_works() {
@@ -488,3 +606,42 @@ fn store_preimage_bound_too_large_errors() {
 		assert_ok!(<Preimage as StorePreimage>::bound(data.clone()));
 	});
 }
+
+/// A value whose encoding has a padding byte, which its decoding leaves unread.
+#[derive(Debug, PartialEq)]
+struct Padded(u8);
+
+impl Encode for Padded {
+	fn encode_to<O: codec::Output + ?Sized>(&self, dest: &mut O) {
+		dest.push_byte(self.0);
+		dest.push_byte(0);
+	}
+}
+
+impl Decode for Padded {
+	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		Ok(Padded(input.read_byte()?))
+	}
+}
+
+#[test]
+fn store_preimage_bound_checks_canonical_encoding() {
+	new_test_ext().execute_with(|| {
+		assert!(<Preimage as StorePreimage>::capabilities().checks_canonical());
+		assert_ok!(<Preimage as StorePreimage>::bound_checked(vec![1u8, 2, 3]));
+		assert_err!(
+			<Preimage as StorePreimage>::bound_checked(Padded(1)),
+			DispatchError::Other("value is not canonically encoded")
+		);
+		assert_err!(
+			<Preimage as StorePreimage>::note_checked::<u16>(Cow::Borrowed(&[1, 0, 0])),
+			DispatchError::Other("value is not canonically encoded")
+		);
+		assert_ok!(<Preimage as StorePreimage>::note_checked::<u16>(Cow::Borrowed(&[1, 0])));
+
+		// Unchecked values, and those of providers which do not check the encoding, are bound as
+		// they are.
+		assert_ok!(<Preimage as StorePreimage>::bound(Padded(1)));
+		assert_ok!(<() as StorePreimage>::bound_checked(Padded(1)));
+	});
+}
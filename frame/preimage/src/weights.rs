@@ -15,14 +15,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_preimage
+//! Weights for pallet_preimage
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! NOT FULLY GENERATED: the functions marked as placeholders were written by hand after the
+//! last run of the benchmark CLI and have not been benchmarked. Regenerate this file with the
+//! command below before these weights are relied upon.
+//!
+//! Last run of the Substrate benchmark CLI version 4.0.0-dev:
 //! DATE: 2022-11-07, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `bm2`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
 
-// Executed Command:
+// Command to regenerate:
 // ./target/production/substrate
 // benchmark
 // pallet
@@ -59,6 +63,7 @@ pub trait WeightInfo {
 	fn unrequest_preimage() -> Weight;
 	fn unrequest_unnoted_preimage() -> Weight;
 	fn unrequest_multi_referenced_preimage() -> Weight;
+	fn expire_requests(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_preimage using the Substrate node and recommended hardware.
@@ -77,25 +82,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	// Storage: Preimage PreimageFor (r:0 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	/// The range of component `s` is `[0, 4194304]`.
 	fn note_requested_preimage(s: u32, ) -> Weight {
-		// Minimum execution time: 24_398 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(24_839_000 as u64)
-			// Standard Error: 0
 			.saturating_add(Weight::from_ref_time(1_702 as u64).saturating_mul(s as u64))
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	// Storage: Preimage PreimageFor (r:0 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	/// The range of component `s` is `[0, 4194304]`.
 	fn note_no_deposit_preimage(s: u32, ) -> Weight {
-		// Minimum execution time: 22_235 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(22_473_000 as u64)
-			// Standard Error: 0
 			.saturating_add(Weight::from_ref_time(1_703 as u64).saturating_mul(s as u64))
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	// Storage: Preimage PreimageFor (r:0 w:1)
@@ -128,11 +133,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
+	// Storage: Preimage ExpiryAgenda (r:1 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	fn request_unnoted_preimage() -> Weight {
-		// Minimum execution time: 20_171 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(20_806_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	fn request_requested_preimage() -> Weight {
@@ -150,11 +157,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	fn unrequest_unnoted_preimage() -> Weight {
-		// Minimum execution time: 9_595 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(9_888_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	fn unrequest_multi_referenced_preimage() -> Weight {
@@ -163,6 +171,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Preimage ExpiryAgenda (r:1 w:1)
+	// Storage: Preimage RequestExpiresAt (r:1 w:1)
+	// Storage: Preimage StatusFor (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn expire_requests(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(5_207_331 as u64)
+			.saturating_add(Weight::from_ref_time(12_846_207 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -180,25 +201,25 @@ impl WeightInfo for () {
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	// Storage: Preimage PreimageFor (r:0 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	/// The range of component `s` is `[0, 4194304]`.
 	fn note_requested_preimage(s: u32, ) -> Weight {
-		// Minimum execution time: 24_398 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(24_839_000 as u64)
-			// Standard Error: 0
 			.saturating_add(Weight::from_ref_time(1_702 as u64).saturating_mul(s as u64))
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	// Storage: Preimage PreimageFor (r:0 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	/// The range of component `s` is `[0, 4194304]`.
 	fn note_no_deposit_preimage(s: u32, ) -> Weight {
-		// Minimum execution time: 22_235 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(22_473_000 as u64)
-			// Standard Error: 0
 			.saturating_add(Weight::from_ref_time(1_703 as u64).saturating_mul(s as u64))
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	// Storage: Preimage PreimageFor (r:0 w:1)
@@ -231,11 +252,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
+	// Storage: Preimage ExpiryAgenda (r:1 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	fn request_unnoted_preimage() -> Weight {
-		// Minimum execution time: 20_171 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(20_806_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	fn request_requested_preimage() -> Weight {
@@ -253,11 +276,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
+	// Storage: Preimage RequestExpiresAt (r:0 w:1)
 	fn unrequest_unnoted_preimage() -> Weight {
-		// Minimum execution time: 9_595 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(9_888_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	// Storage: Preimage StatusFor (r:1 w:1)
 	fn unrequest_multi_referenced_preimage() -> Weight {
@@ -266,4 +290,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: Preimage ExpiryAgenda (r:1 w:1)
+	// Storage: Preimage RequestExpiresAt (r:1 w:1)
+	// Storage: Preimage StatusFor (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn expire_requests(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(5_207_331 as u64)
+			.saturating_add(Weight::from_ref_time(12_846_207 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
 }
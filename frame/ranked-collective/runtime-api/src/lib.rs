@@ -0,0 +1,61 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for ranked-collective pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_ranked_collective::{Rank, TallyCertificate, VoteRecord, VoteRejection, Votes};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for checking votes on the polls of a ranked collective.
+	pub trait RankedCollectiveApi<AccountId, PollIndex, Tally, Hash, BlockNumber>
+		where
+			AccountId: Codec,
+			PollIndex: Codec,
+			Tally: Codec,
+			Hash: Codec,
+			BlockNumber: Codec,
+	{
+		/// Returns why a vote of `who` with `aye` on `poll` would be rejected, if it would be.
+		fn dry_run_vote(who: AccountId, poll: PollIndex, aye: bool) -> Result<(), VoteRejection>;
+
+		/// Returns the [`TallyCertificate`] of `poll`, if it has been decided.
+		fn tally_certificate(
+			poll: PollIndex,
+		) -> Option<TallyCertificate<PollIndex, Rank, Tally, Hash, BlockNumber>>;
+
+		/// Returns the tally of `poll`, if it is ongoing.
+		fn tally(poll: PollIndex) -> Option<Tally>;
+
+		/// Returns the members who have at least the rank `at_least_rank`.
+		fn members(at_least_rank: Rank) -> Vec<AccountId>;
+
+		/// Returns the vote of `who` on `poll`, if they have voted on it and it is ongoing.
+		fn vote_of(poll: PollIndex, who: AccountId) -> Option<VoteRecord>;
+
+		/// Returns the votes `who` would cast on the polls of `class`, including those delegated
+		/// to them, if they may vote on these.
+		fn voting_power(who: AccountId, class: Rank) -> Option<Votes>;
+
+		/// Returns the ongoing polls on which the rank of `who` lets them vote, with their class
+		/// and tally.
+		fn votable_polls(who: AccountId) -> Vec<(PollIndex, Rank, Tally)>;
+	}
+}
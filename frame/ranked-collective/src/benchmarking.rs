@@ -22,7 +22,7 @@ use super::*;
 use crate::Pallet as RankedCollective;
 
 use frame_benchmarking::{account, benchmarks_instance_pallet, whitelisted_caller};
-use frame_support::{assert_ok, dispatch::UnfilteredDispatchable};
+use frame_support::{assert_ok, dispatch::UnfilteredDispatchable, traits::ranked, BoundedVec};
 use frame_system::RawOrigin as SystemOrigin;
 
 const SEED: u32 = 0;
@@ -47,6 +47,15 @@ fn make_member<T: Config<I>, I: 'static>(rank: Rank) -> T::AccountId {
 	who
 }
 
+/// Mark `who` as the subject of a disciplinary poll which has already ended, so that their review
+/// is cleared by the next demotion or removal.
+fn mark_under_ended_review<T: Config<I>, I: 'static>(who: &T::AccountId) {
+	let class = T::Polls::classes().into_iter().next().unwrap();
+	let poll = T::Polls::create_ongoing(class).expect("Must always be able to create a poll");
+	assert_ok!(T::Polls::end_ongoing(poll, false));
+	UnderReview::<T, I>::insert(who, poll);
+}
+
 benchmarks_instance_pallet! {
 	add_member {
 		let who = account::<T::AccountId>("member", 0, SEED);
@@ -61,12 +70,13 @@ benchmarks_instance_pallet! {
 
 	remove_member {
 		let r in 0 .. 10;
-		let rank = r as u16;
+		let rank = ranked::rank_from_u32(r);
 		let first = make_member::<T, I>(rank);
 		let who = make_member::<T, I>(rank);
 		let who_lookup = T::Lookup::unlookup(who.clone());
 		let last = make_member::<T, I>(rank);
 		let last_index = (0..=rank).map(|r| IdToIndex::<T, I>::get(r, &last).unwrap()).collect::<Vec<_>>();
+		mark_under_ended_review::<T, I>(&who);
 		let origin = T::DemoteOrigin::successful_origin();
 		let call = Call::<T, I>::remove_member { who: who_lookup, min_rank: rank };
 	}: { call.dispatch_bypass_filter(origin)? }
@@ -80,7 +90,7 @@ benchmarks_instance_pallet! {
 
 	promote_member {
 		let r in 0 .. 10;
-		let rank = r as u16;
+		let rank = ranked::rank_from_u32(r);
 		let who = make_member::<T, I>(rank);
 		let who_lookup = T::Lookup::unlookup(who.clone());
 		let origin = T::PromoteOrigin::successful_origin();
@@ -93,12 +103,13 @@ benchmarks_instance_pallet! {
 
 	demote_member {
 		let r in 0 .. 10;
-		let rank = r as u16;
+		let rank = ranked::rank_from_u32(r);
 		let first = make_member::<T, I>(rank);
 		let who = make_member::<T, I>(rank);
 		let who_lookup = T::Lookup::unlookup(who.clone());
 		let last = make_member::<T, I>(rank);
 		let last_index = IdToIndex::<T, I>::get(rank, &last).unwrap();
+		mark_under_ended_review::<T, I>(&who);
 		let origin = T::DemoteOrigin::successful_origin();
 		let call = Call::<T, I>::demote_member { who: who_lookup };
 	}: { call.dispatch_bypass_filter(origin)? }
@@ -112,28 +123,68 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
-	vote {
-		let caller: T::AccountId = whitelisted_caller();
-		let caller_lookup = T::Lookup::unlookup(caller.clone());
-		assert_ok!(Pallet::<T, I>::add_member(T::PromoteOrigin::successful_origin(), caller_lookup.clone()));
+	vote_new {
+		let d in 0 .. T::MaxDelegators::get();
+
 		// Create a poll
 		let class = T::Polls::classes().into_iter().next().unwrap();
 		let rank = T::MinRankOfClass::convert(class.clone());
-		for _ in 0..rank {
-			assert_ok!(Pallet::<T, I>::promote_member(
-				T::PromoteOrigin::successful_origin(),
-				caller_lookup.clone()
+		let poll = T::Polls::create_ongoing(class.clone()).expect("Must always be able to create a poll for rank 0");
+		let caller = make_member::<T, I>(rank);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+
+		// Every delegator's votes are counted with the caller's.
+		for _ in 0..d {
+			let delegator = make_member::<T, I>(rank);
+			assert_ok!(Pallet::<T, I>::delegate(
+				SystemOrigin::Signed(delegator).into(),
+				class.clone(),
+				caller_lookup.clone(),
 			));
 		}
+	}: vote(SystemOrigin::Signed(caller.clone()), poll, true)
+	verify {
+		let votes = Votes::from(1 + d);
+		let tally = Tally::from_parts(1, 0, votes, 0);
+		let ev = Event::Voted { who: caller, poll, vote: VoteRecord::Aye(votes), rank_votes: 1, tally };
+		assert_last_event::<T, I>(ev.into());
+	}
 
-		let poll = T::Polls::create_ongoing(class).expect("Must always be able to create a poll for rank 0");
+	vote_existing {
+		let d in 0 .. T::MaxDelegators::get();
 
-		// Vote once.
-		assert_ok!(Pallet::<T, I>::vote(SystemOrigin::Signed(caller.clone()).into(), poll, true));
-	}: _(SystemOrigin::Signed(caller.clone()), poll, false)
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		// Create a poll
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let rank = T::MinRankOfClass::convert(class.clone());
+		let poll = T::Polls::create_ongoing(class.clone()).expect("Must always be able to create a poll for rank 0");
+
+		// Vote once, then exchange the voter's account for the caller's, which the vote moves to.
+		let old = make_member::<T, I>(rank);
+		assert_ok!(Pallet::<T, I>::vote(SystemOrigin::Signed(old.clone()).into(), poll, true));
+		assert_ok!(Pallet::<T, I>::exchange_member(
+			T::ExchangeOrigin::successful_origin(),
+			T::Lookup::unlookup(old),
+			caller_lookup.clone(),
+			rank,
+			ActivePolls::<T, I>::count(),
+		));
+
+		// Every delegator's votes are counted with the caller's.
+		for _ in 0..d {
+			let delegator = make_member::<T, I>(rank);
+			assert_ok!(Pallet::<T, I>::delegate(
+				SystemOrigin::Signed(delegator).into(),
+				class.clone(),
+				caller_lookup.clone(),
+			));
+		}
+	}: vote(SystemOrigin::Signed(caller.clone()), poll, false)
 	verify {
-		let tally = Tally::from_parts(0, 0, 1);
-		let ev = Event::Voted { who: caller, poll, vote: VoteRecord::Nay(1), tally };
+		let votes = Votes::from(1 + d);
+		let tally = Tally::from_parts(0, 1, 0, votes);
+		let ev = Event::Voted { who: caller, poll, vote: VoteRecord::Nay(votes), rank_votes: 1, tally };
 		assert_last_event::<T, I>(ev.into());
 	}
 
@@ -142,22 +193,345 @@ benchmarks_instance_pallet! {
 
 		// Create a poll
 		let class = T::Polls::classes().into_iter().next().unwrap();
-		let rank = T::MinRankOfClass::convert(class.clone());
 		let poll = T::Polls::create_ongoing(class).expect("Must always be able to create a poll");
 
-		// Vote in the poll by each of `n` members
+		// Fill each of `n` pages of votes on the poll.
+		let page = VotePage {
+			voted: u64::MAX,
+			votes: BoundedVec::truncate_from(vec![VoteRecord::Aye(1); VOTE_PAGE_SIZE as usize]),
+		};
 		for i in 0..n {
-			let who = make_member::<T, I>(rank);
-			assert_ok!(Pallet::<T, I>::vote(SystemOrigin::Signed(who).into(), poll, true));
+			VotePages::<T, I>::insert(poll, i, &page);
 		}
 
 		// End the poll.
 		T::Polls::end_ongoing(poll, false).expect("Must always be able to end a poll");
 
-		assert_eq!(Voting::<T, I>::iter_prefix(poll).count(), n as usize);
+		assert_eq!(VotePages::<T, I>::iter_prefix(poll).count(), n as usize);
 	}: _(SystemOrigin::Signed(whitelisted_caller()), poll, n)
 	verify {
-		assert_eq!(Voting::<T, I>::iter().count(), 0);
+		assert_eq!(VotePages::<T, I>::iter().count(), 0);
+	}
+
+	select_panel {
+		let n in 1 .. T::MaxPanelSize::get();
+
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let rank = T::MinRankOfClass::convert(class.clone());
+		for _ in 0..n {
+			make_member::<T, I>(rank);
+		}
+		let poll = T::Polls::create_ongoing(class).expect("Must always be able to create a poll");
+
+		let origin = T::PanelOrigin::successful_origin();
+		let call = Call::<T, I>::select_panel { poll, size: n, seed: 0 };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Panels::<T, I>::get(poll).map(|p| p.len()), Some(n as usize));
+	}
+
+	resign {
+		let r in 0 .. 10;
+		let rank = ranked::rank_from_u32(r);
+		let first = make_member::<T, I>(rank);
+		let who = make_member::<T, I>(rank);
+		let last = make_member::<T, I>(rank);
+		let last_index = (0..=rank).map(|r| IdToIndex::<T, I>::get(r, &last).unwrap()).collect::<Vec<_>>();
+	}: _(SystemOrigin::Signed(who.clone()), rank, true)
+	verify {
+		for r in 0..=rank {
+			assert_eq!(MemberCount::<T, I>::get(r), 2);
+			assert_ne!(last_index[r as usize], IdToIndex::<T, I>::get(r, &last).unwrap());
+		}
+		assert!(ResignedUntil::<T, I>::contains_key(&who));
+	}
+
+	force_set_ranks {
+		let n in 1 .. T::MaxRankChanges::get();
+		let r in 0 .. 10;
+		let rank = ranked::rank_from_u32(r);
+		let changes = (0..n)
+			.map(|i| (account::<T::AccountId>("member", i, SEED), rank))
+			.collect::<Vec<_>>();
+		let witness = RankedCollective::<T, I>::rank_changes_witness(&changes);
+		let changes: BoundedVec<_, _> = changes.try_into().unwrap();
+	}: _(SystemOrigin::Root, changes, rank, witness)
+	verify {
+		assert_eq!(MemberCount::<T, I>::get(0), n);
+		assert_eq!(MemberCount::<T, I>::get(rank), n);
+	}
+
+	freeze_class {
+		let class = T::Polls::classes().into_iter().next().unwrap();
+	}: _(SystemOrigin::Root, class.clone())
+	verify {
+		assert!(FrozenClasses::<T, I>::contains_key(&class));
+	}
+
+	unfreeze_class {
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		FrozenClasses::<T, I>::insert(&class, ());
+	}: _(SystemOrigin::Root, class.clone())
+	verify {
+		assert!(!FrozenClasses::<T, I>::contains_key(&class));
+	}
+
+	veto {
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let poll = T::Polls::create_ongoing(class).expect("Must always be able to create a poll");
+		let rank = T::VetoRank::get();
+		// All but the last veto are registered already, so that the poll is vetoed.
+		for _ in 1..T::VetoThreshold::get() {
+			let who = make_member::<T, I>(rank);
+			assert_ok!(Pallet::<T, I>::veto(SystemOrigin::Signed(who).into(), poll));
+		}
+		let caller = make_member::<T, I>(rank);
+	}: _(SystemOrigin::Signed(caller), poll)
+	verify {
+		assert!(!Vetoes::<T, I>::contains_key(poll));
+	}
+
+	mark_under_review {
+		let who = make_member::<T, I>(0);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		// The member is still marked for an earlier poll, which has ended.
+		mark_under_ended_review::<T, I>(&who);
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let poll = T::Polls::create_ongoing(class).expect("Must always be able to create a poll");
+		let origin = T::ReviewOrigin::successful_origin(&(who.clone(), poll));
+		let call = Call::<T, I>::mark_under_review { who: who_lookup, poll };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(UnderReview::<T, I>::get(&who), Some(poll));
+	}
+
+	exchange_member {
+		let r in 0 .. 10;
+		let p in 0 .. 10;
+		let rank = ranked::rank_from_u32(r);
+		let who = make_member::<T, I>(rank);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let new_who = account::<T::AccountId>("new", 0, SEED);
+		let new_who_lookup = T::Lookup::unlookup(new_who.clone());
+		mark_under_ended_review::<T, I>(&who);
+		// The member voted, sits on the panel and vetoed on each of `p` active polls.
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut polls = Vec::new();
+		for _ in 0..p {
+			let poll = T::Polls::create_ongoing(class.clone())
+				.expect("Must always be able to create a poll");
+			Pallet::<T, I>::set_vote(poll, &who, VoteRecord::Aye(1));
+			Panels::<T, I>::insert(poll, BoundedVec::truncate_from(vec![who.clone()]));
+			Vetoes::<T, I>::insert(poll, (now, BoundedVec::truncate_from(vec![who.clone()])));
+			ActivePolls::<T, I>::insert(poll, ());
+			polls.push(poll);
+		}
+		let origin = T::ExchangeOrigin::successful_origin();
+		let call = Call::<T, I>::exchange_member {
+			who: who_lookup,
+			new_who: new_who_lookup,
+			min_rank: rank,
+			polls: p,
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Members::<T, I>::contains_key(&who));
+		assert_eq!(Members::<T, I>::get(&new_who).map(|x| x.rank), Some(rank));
+		for poll in polls {
+			assert_eq!(Pallet::<T, I>::vote_of(poll, &new_who), Some(VoteRecord::Aye(1)));
+		}
+		assert_last_event::<T, I>(Event::MemberExchanged { who, new_who }.into());
+	}
+
+	remap_class {
+		let v in 0 .. 10;
+
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let new_rank = T::MinRankOfClass::convert(class.clone());
+		let old_rank = new_rank + 1;
+		// Vote on each of `v` polls with a weight as if the class had `old_rank`, so that every
+		// vote visited is on a poll of its own.
+		let who = make_member::<T, I>(old_rank);
+		let old_votes = T::VoteWeight::convert(0);
+		let mut polls = Vec::new();
+		for _ in 0..v {
+			let poll = T::Polls::create_ongoing(class.clone())
+				.expect("Must always be able to create a poll");
+			assert_ok!(Pallet::<T, I>::vote(SystemOrigin::Signed(who.clone()).into(), poll, true));
+			Pallet::<T, I>::set_vote(poll, &who, VoteRecord::Aye(old_votes));
+			polls.push(poll);
+		}
+	}: _(SystemOrigin::Root, old_rank, new_rank, v)
+	verify {
+		let new_votes = T::VoteWeight::convert(1);
+		for poll in polls {
+			assert_eq!(Pallet::<T, I>::vote_of(poll, &who), Some(VoteRecord::Aye(new_votes)));
+		}
+	}
+
+	induct_member {
+		let who = account::<T::AccountId>("member", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let origin = T::PromoteOrigin::successful_origin();
+		let call = Call::<T, I>::induct_member { who: who_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(ProbationaryCount::<T, I>::get(), 1);
+		assert_last_event::<T, I>(Event::MemberInducted { who }.into());
+	}
+
+	confirm_member {
+		let who = account::<T::AccountId>("member", 0, SEED);
+		assert_ok!(Pallet::<T, I>::do_induct_member(who.clone()));
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let origin = T::PromoteOrigin::successful_origin();
+		let call = Call::<T, I>::confirm_member { who: who_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(ProbationaryCount::<T, I>::get(), 0);
+		assert_last_event::<T, I>(Event::MemberConfirmed { who }.into());
+	}
+
+	set_vote_curve {
+		let points = (0..MAX_VOTE_CURVE_POINTS)
+			.map(|i| (ranked::rank_from_u32(i), Votes::from(i * 2 + 1)))
+			.collect::<Vec<_>>();
+		let points = VoteCurvePoints::truncate_from(points);
+		let curve = VoteCurve::new(points.clone()).unwrap();
+	}: _(SystemOrigin::Root, Some(points))
+	verify {
+		assert_eq!(VoteWeightCurve::<T, I>::get(), Some(curve));
+	}
+
+	split_members {
+		let n in 0 .. 100;
+		let r in 0 .. 10;
+		let rank = ranked::rank_from_u32(r);
+		for _ in 0..n {
+			make_member::<T, I>(rank);
+		}
+	}: _(SystemOrigin::Root, rank, rank, true, n)
+	verify {
+		assert!(SplitCursor::<T, I>::exists());
+	}
+
+	delegate {
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let to = make_member::<T, I>(0);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		// All but one delegator slots of the delegate are taken.
+		for _ in 1..T::MaxDelegators::get() {
+			let delegator = make_member::<T, I>(0);
+			assert_ok!(Pallet::<T, I>::delegate(
+				SystemOrigin::Signed(delegator).into(),
+				class.clone(),
+				to_lookup.clone(),
+			));
+		}
+		let caller = make_member::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller.clone()), class.clone(), to_lookup)
+	verify {
+		assert_eq!(Delegations::<T, I>::get(&class, &caller), Some(to));
+	}
+
+	undelegate {
+		let p in 0 .. 10;
+
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let rank = T::MinRankOfClass::convert(class.clone());
+		let to = make_member::<T, I>(rank);
+		let to_lookup = T::Lookup::unlookup(to.clone());
+		let caller = make_member::<T, I>(rank);
+		// The caller is the first of `MaxDelegators` delegators.
+		for delegator in [caller.clone()]
+			.into_iter()
+			.chain((1..T::MaxDelegators::get()).map(|_| make_member::<T, I>(0)))
+		{
+			assert_ok!(Pallet::<T, I>::delegate(
+				SystemOrigin::Signed(delegator).into(),
+				class.clone(),
+				to_lookup.clone(),
+			));
+		}
+		// The delegate voted with the votes of the caller on each of `p` active polls.
+		let mut polls = Vec::new();
+		for _ in 0..p {
+			let poll = T::Polls::create_ongoing(class.clone())
+				.expect("Must always be able to create a poll");
+			assert_ok!(Pallet::<T, I>::vote(SystemOrigin::Signed(to.clone()).into(), poll, true));
+			polls.push(poll);
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), class.clone(), p)
+	verify {
+		assert!(!Delegations::<T, I>::contains_key(&class, &caller));
+		assert_eq!(Delegators::<T, I>::decode_len(&class, &to), Some(T::MaxDelegators::get() as usize - 1));
+		for poll in polls {
+			assert_eq!(Pallet::<T, I>::vote_of(poll, &caller), None);
+		}
+	}
+
+	retract_vote {
+		let d in 0 .. T::MaxDelegators::get();
+
+		let class = T::Polls::classes().into_iter().next().unwrap();
+		let rank = T::MinRankOfClass::convert(class.clone());
+		let poll = T::Polls::create_ongoing(class.clone()).expect("Must always be able to create a poll");
+		let caller = make_member::<T, I>(rank);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+
+		// Every delegator's votes are counted with the caller's.
+		for _ in 0..d {
+			let delegator = make_member::<T, I>(rank);
+			assert_ok!(Pallet::<T, I>::delegate(
+				SystemOrigin::Signed(delegator).into(),
+				class.clone(),
+				caller_lookup.clone(),
+			));
+		}
+		assert_ok!(Pallet::<T, I>::vote(SystemOrigin::Signed(caller.clone()).into(), poll, true));
+	}: _(SystemOrigin::Signed(caller.clone()), poll)
+	verify {
+		assert_eq!(VotePages::<T, I>::iter_prefix(poll).count(), 0);
+		let ev = Event::VoteRetracted { who: caller, poll, vote: VoteRecord::Aye((1 + d).into()) };
+		assert_last_event::<T, I>(ev.into());
+	}
+
+	set_class_limit {
+		let rank = ranked::rank_from_u32(1);
+	}: _(SystemOrigin::Root, rank, Some(0))
+	verify {
+		assert_eq!(ClassLimits::<T, I>::get(rank), Some(0));
+	}
+
+	set_rank {
+		let r in 0 .. 10;
+		let rank = ranked::rank_from_u32(r);
+		// The member is moved down from `rank` to zero, replacing the index of the first member
+		// of each rank by that of the last.
+		let first = make_member::<T, I>(rank);
+		let who = make_member::<T, I>(rank);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let last = make_member::<T, I>(rank);
+		mark_under_ended_review::<T, I>(&who);
+		let origin = T::AdminOrigin::successful_origin();
+		let call = Call::<T, I>::set_rank { who: who_lookup, rank: 0, max_rank: rank };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Members::<T, I>::get(&who).map(|x| x.rank), Some(0));
+		assert_eq!(MemberCount::<T, I>::get(rank), if rank == 0 { 3 } else { 2 });
+	}
+
+	scan_members {
+		let n in 0 .. 100;
+		for _ in 0..n {
+			make_member::<T, I>(0);
+		}
+	}: {
+		Pallet::<T, I>::scan_members(None, n);
+	}
+	verify {
+		assert_eq!(Pallet::<T, I>::scan_members(None, n).0.len() as u32, n);
 	}
 
 	impl_benchmark_test_suite!(RankedCollective, crate::tests::new_test_ext(), crate::tests::Test);
@@ -0,0 +1,436 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of the ranked collective acting as the tally provider of `pallet-referenda`.
+//! Defines its own runtime in which referenda are submitted, voted on by members of different
+//! ranks, confirmed and enacted through the scheduler.
+
+#![cfg(test)]
+
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{
+		schedule::DispatchTime, AsEnsureOriginWithArg, ConstBool, ConstU16, ConstU32, ConstU64,
+		EqualPrivilegeOnly, Everything, NeverEnsureOrigin, OnInitialize, OriginTrait,
+		StorePreimage,
+	},
+	weights::Weight,
+};
+use frame_support_test::TestRandomness;
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
+use pallet_referenda::{
+	impl_tracksinfo_get, BoundedCallOf, Curve, DecidingStatus, ReferendumIndex, ReferendumInfo,
+	ReferendumInfoFor, ReferendumStatus, TrackInfo, TracksInfo,
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Identity, IdentityLookup},
+	Perbill,
+};
+
+use super::*;
+use crate as pallet_ranked_collective;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
+		Scheduler: pallet_scheduler,
+		Club: pallet_ranked_collective,
+		Referenda: pallet_referenda,
+	}
+);
+
+parameter_types! {
+	pub MaxWeight: Weight = Weight::from_ref_time(2_000_000_000_000);
+	pub const RankSyncPolicy: SyncPolicy = SyncPolicy::Manual;
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+impl pallet_balances::Config for Test {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type BaseDeposit = ();
+	type ByteDeposit = ();
+	type MaxRequests = ConstU32<100>;
+	type CheckCanonicalEncoding = ConstBool<false>;
+	type RequestExpiry = ();
+	type MaxExpiriesPerBlock = ConstU32<0>;
+}
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaxWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = ConstU32<100>;
+	type WeightInfo = ();
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = Preimage;
+}
+
+/// Two tracks: `Root` referenda are decided by every member (class 0) whereas referenda with the
+/// `None` origin may only be voted on by members of rank 1 and above (class 1).
+pub struct TestTracksInfo;
+impl TracksInfo<u64, u64> for TestTracksInfo {
+	type Id = u16;
+	type RuntimeOrigin = <RuntimeOrigin as OriginTrait>::PalletsOrigin;
+	fn tracks() -> &'static [(Self::Id, TrackInfo<u64, u64>)] {
+		static DATA: [(u16, TrackInfo<u64, u64>); 2] = [
+			(
+				0u16,
+				TrackInfo {
+					name: "root",
+					max_deciding: 1,
+					decision_deposit: 10,
+					prepare_period: 2,
+					decision_period: 10,
+					confirm_period: 2,
+					min_enactment_period: 2,
+					min_approval: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(50),
+						ceil: Perbill::from_percent(100),
+					},
+					min_support: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(0),
+						ceil: Perbill::from_percent(50),
+					},
+				},
+			),
+			(
+				1u16,
+				TrackInfo {
+					name: "none",
+					max_deciding: 1,
+					decision_deposit: 10,
+					prepare_period: 2,
+					decision_period: 10,
+					confirm_period: 2,
+					min_enactment_period: 2,
+					min_approval: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(50),
+						ceil: Perbill::from_percent(100),
+					},
+					min_support: Curve::LinearDecreasing {
+						length: Perbill::from_percent(100),
+						floor: Perbill::from_percent(0),
+						ceil: Perbill::from_percent(100),
+					},
+				},
+			),
+		];
+		&DATA[..]
+	}
+	fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+		match frame_system::RawOrigin::try_from(id.clone()) {
+			Ok(frame_system::RawOrigin::Root) => Ok(0),
+			Ok(frame_system::RawOrigin::None) => Ok(1),
+			_ => Err(()),
+		}
+	}
+}
+impl_tracksinfo_get!(TestTracksInfo, u64, u64);
+
+impl pallet_referenda::Config for Test {
+	type WeightInfo = ();
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type Scheduler = Scheduler;
+	type Currency = Balances;
+	type SubmitOrigin = EnsureSigned<u64>;
+	type CancelOrigin = EnsureRoot<u64>;
+	type KillOrigin = EnsureRoot<u64>;
+	type Slash = ();
+	type Votes = Votes;
+	type Tally = TallyOf<Test>;
+	type SubmissionDeposit = ConstU64<2>;
+	type MaxQueued = ConstU32<3>;
+	type UndecidingTimeout = ConstU64<20>;
+	type AlarmInterval = ConstU64<1>;
+	type Tracks = TestTracksInfo;
+	type Preimages = Preimage;
+	type OnPollCompleted = Club;
+}
+
+impl Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type PromoteOrigin = EnsureRootWithSuccess<u64, ConstU16<65535>>;
+	type DemoteOrigin = EnsureRootWithSuccess<u64, ConstU16<65535>>;
+	type MembershipOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<Rank>>;
+	type Polls = Referenda;
+	type MinRankOfClass = Identity;
+	type VoteWeight = Geometric;
+	type SupportIncludesNays = ConstBool<false>;
+	type SnapshotVoters = ConstBool<false>;
+	type CompactVoteEvents = ConstBool<false>;
+	type Randomness = TestRandomness<Self>;
+	type PanelOrigin = EnsureRoot<u64>;
+	type MaxPanelSize = ConstU32<10>;
+	type IdleCleanupWeight = ();
+	type ResignationCooldown = ConstU64<10>;
+	type MaxRankChanges = ConstU32<16>;
+	type MaxFreeVotes = ConstU32<3>;
+	type FreeVotePeriod = ConstU64<10>;
+	type VetoRank = ConstU16<2>;
+	type VetoThreshold = ConstU32<2>;
+	type VetoPeriod = ConstU64<5>;
+	type MembershipNft = ();
+	type ReviewOrigin = frame_system::EnsureRoot<u64>;
+	type MaxDelegators = ConstU32<16>;
+	type ExchangeOrigin = frame_system::EnsureRoot<u64>;
+	type SplitTarget = frame_support::traits::ranked::NoMembers<u64>;
+	type DemotionPeriod = ();
+	type MaxInactivityChecks = ConstU32<0>;
+	type MaxMembersPerRank = ConstU32<{ u32::MAX }>;
+	type OnMemberAdded = ();
+	type OnRankChanged = ();
+	type OnMemberRemoved = ();
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type RankSource = frame_support::traits::ranked::NoMembers<u64>;
+	type RankSyncPolicy = RankSyncPolicy;
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let balances = vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)];
+	pallet_balances::GenesisConfig::<Test> { balances }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		// Members 1 to 5 are of ranks 3, 2, 1, 0 and 0 respectively.
+		for (who, rank) in [(1, 3), (2, 2), (3, 1), (4, 0), (5, 0)] {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), who));
+			for _ in 0..rank {
+				assert_ok!(Club::promote_member(RuntimeOrigin::root(), who));
+			}
+		}
+	});
+	ext
+}
+
+fn next_block() {
+	System::set_block_number(System::block_number() + 1);
+	Scheduler::on_initialize(System::block_number());
+}
+
+fn run_to(n: u64) {
+	while System::block_number() < n {
+		next_block();
+	}
+}
+
+fn set_balance_proposal_bounded(value: u64) -> BoundedCallOf<Test, ()> {
+	let c = RuntimeCall::Balances(pallet_balances::Call::set_balance {
+		who: 42,
+		new_free: value,
+		new_reserved: 0,
+	});
+	<Preimage as StorePreimage>::bound(c).unwrap()
+}
+
+/// Submit a referendum on the track of `origin`, place its decision deposit and wait until it
+/// enters the deciding phase.
+fn begin_referendum(origin: frame_system::RawOrigin<u64>, value: u64) -> ReferendumIndex {
+	let index = pallet_referenda::ReferendumCount::<Test>::get();
+	assert_ok!(Referenda::submit(
+		RuntimeOrigin::signed(5),
+		Box::new(origin.into()),
+		set_balance_proposal_bounded(value),
+		DispatchTime::After(0),
+	));
+	assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(5), index));
+	while !matches!(
+		ReferendumInfoFor::<Test>::get(index),
+		Some(ReferendumInfo::Ongoing(ReferendumStatus { deciding: Some(_), .. }))
+	) {
+		next_block();
+	}
+	index
+}
+
+fn tally(index: ReferendumIndex) -> TallyOf<Test> {
+	Referenda::ensure_ongoing(index).unwrap().tally
+}
+
+fn confirming_until(index: ReferendumIndex) -> Option<u64> {
+	match ReferendumInfoFor::<Test>::get(index) {
+		Some(ReferendumInfo::Ongoing(ReferendumStatus {
+			deciding: Some(DecidingStatus { confirming, .. }),
+			..
+		})) => confirming,
+		_ => panic!("Not deciding"),
+	}
+}
+
+fn support(index: ReferendumIndex, class: Rank) -> Perbill {
+	<TallyOf<Test> as VoteTally<Votes, Rank>>::support(&tally(index), class)
+}
+
+#[test]
+fn referendum_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		let index = begin_referendum(frame_system::RawOrigin::Root, 100);
+		assert_eq!(index, 0);
+		assert_eq!(System::block_number(), 3);
+
+		// Every member may vote on the root track, weighted by their excess rank.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), index, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), index, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(4), index, false));
+		assert_eq!(tally(index), Tally::from_parts(2, 1, 16, 1));
+		assert_eq!(support(index, 0), Perbill::from_percent(40));
+
+		// Not unanimous and not enough support, so not confirming.
+		next_block();
+		assert_eq!(confirming_until(index), None);
+
+		// Member 4 changes their mind and member 3 joins in: approval is unanimous and the
+		// support of 80% is above the threshold.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(4), index, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), index, true));
+		assert_eq!(tally(index), Tally::from_parts(4, 0, 20, 0));
+		assert_eq!(support(index, 0), Perbill::from_percent(80));
+		next_block();
+		assert_eq!(confirming_until(index), Some(System::block_number() + 2));
+
+		// Once confirmed, the referendum is approved and enacted after the enactment period.
+		run_to(System::block_number() + 2);
+		assert!(matches!(
+			ReferendumInfoFor::<Test>::get(index),
+			Some(ReferendumInfo::Approved(..))
+		));
+		assert_noop!(Club::vote(RuntimeOrigin::signed(5), index, true), Error::<Test>::NotPolling);
+		let certificate = Club::tally_certificate(index).unwrap();
+		assert!(certificate.approved);
+		assert_eq!(certificate.tally, Tally::from_parts(4, 0, 20, 0));
+		assert_eq!(certificate.members, MembershipCommitment::<Test>::get());
+		assert_eq!(Balances::free_balance(42), 0);
+		run_to(System::block_number() + 2);
+		assert_eq!(Balances::free_balance(42), 100);
+
+		// The votes may now be cleaned up and the deposits refunded.
+		let votes = VotePages::<Test>::iter_prefix_values(index).map(|page| page.len());
+		assert_eq!(votes.sum::<u32>(), 4);
+		assert_ok!(Club::cleanup_poll(RuntimeOrigin::signed(5), index, 10));
+		assert!(VotePages::<Test>::iter_prefix(index).next().is_none());
+		assert_ok!(Referenda::refund_decision_deposit(RuntimeOrigin::signed(5), index));
+		assert_ok!(Referenda::refund_submission_deposit(RuntimeOrigin::signed(5), index));
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
+
+#[test]
+fn track_class_sets_minimum_rank() {
+	new_test_ext().execute_with(|| {
+		let index = begin_referendum(frame_system::RawOrigin::None, 100);
+
+		// Only members of rank 1 and above may vote on the `None` track.
+		assert_noop!(Club::vote(RuntimeOrigin::signed(4), index, true), Error::<Test>::RankTooLow);
+		assert_noop!(Club::vote(RuntimeOrigin::signed(6), index, true), Error::<Test>::NotMember);
+
+		// Rank 1 is the baseline, so member 3 has a single vote and member 1 has six.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), index, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), index, false));
+		assert_eq!(tally(index), Tally::from_parts(1, 1, 1, 6));
+		assert_eq!(support(index, 1), Perbill::from_rational(1u32, 3u32));
+	});
+}
+
+#[test]
+fn demotion_during_referendum_works() {
+	new_test_ext().execute_with(|| {
+		let index = begin_referendum(frame_system::RawOrigin::None, 100);
+
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), index, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), index, false));
+		assert_eq!(tally(index), Tally::from_parts(1, 1, 3, 1));
+
+		// A demotion leaves the existing vote untouched...
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
+		assert_eq!(tally(index), Tally::from_parts(1, 1, 3, 1));
+		// ...but voting again counts with the weight of the new rank.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), index, true));
+		assert_eq!(tally(index), Tally::from_parts(1, 1, 1, 1));
+
+		// Dropping below the minimum rank of the track removes the right to vote.
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 3));
+		assert_eq!(tally(index), Tally::from_parts(1, 1, 1, 1));
+		assert_noop!(Club::vote(RuntimeOrigin::signed(3), index, true), Error::<Test>::RankTooLow);
+
+		// The support is measured against the members currently eligible to vote.
+		assert_eq!(MemberCount::<Test>::get(1), 2);
+		assert_eq!(support(index, 1), Perbill::from_percent(50));
+
+		// Removed members can no longer vote at all.
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 2, 1));
+		assert_noop!(Club::vote(RuntimeOrigin::signed(2), index, true), Error::<Test>::NotMember);
+		assert_eq!(support(index, 1), Perbill::from_percent(100));
+	});
+}
@@ -23,8 +23,18 @@
 //! ranks in the system thus allowing potentially public membership. A member of at least a given
 //! rank can be selected at random in O(1) time, allowing for various games to be constructed using
 //! this as a primitive. Members may only be promoted and demoted by one rank at a time, however
-//! all operations (save one) are O(1) in complexity. The only operation which is not O(1) is the
-//! `remove_member` since they must be removed from all ranks from the present down to zero.
+//! all operations (save two) are O(1) in complexity. The only operations which are not O(1) are
+//! `remove_member` and `resign` since the member must be removed from all ranks from the present
+//! down to zero. Members may `resign` of their own accord, optionally barring themselves from
+//! being added back for the `ResignationCooldown` period. Root may also re-rank several accounts
+//! at once with `force_set_ranks`, which is `O(changes * rank)`.
+//!
+//! Several accounts may be added, promoted or removed at once with `add_members`,
+//! `promote_members` and `remove_members`, e.g. to bootstrap a collective.
+//!
+//! New members may also be added on probation with `induct_member`. They hold rank 0 but may not
+//! vote, veto, delegate or be promoted, and are not counted among the voters of rank 0, until
+//! `confirm_member` makes them full members.
 //!
 //! Different ranks have different voting power, and are able to vote in different polls. In general
 //! rank privileges are cumulative. Higher ranks are able to vote in any polls open to lower ranks.
@@ -34,48 +44,135 @@
 //! Two `Config` trait items control these "rank privileges": `MinRankOfClass` and `VoteWeight`.
 //! The first controls which ranks are allowed to vote on a particular class of poll. The second
 //! controls the weight of a vote given the voter's rank compared to the minimum rank of the poll.
+//! Root may replace `VoteWeight` on-chain with `set_vote_curve`, a piecewise-linear curve from
+//! the excess rank of a voter to their votes.
+//!
+//! Members may also `abstain` on the polls they may vote on. Abstentions are counted separately
+//! in the `Tally`, towards the turnout of a poll but neither towards its approval nor its support.
+//!
+//! Each vote is reported by a `Voted` event carrying the updated tally of the poll. Chains with
+//! very active voting may set `CompactVoteEvents` to emit `VotedCompact` instead, which leaves the
+//! tally out; it remains available through `tally_of` and the runtime API.
+//!
+//! A poll may also be decided by a panel of members of the required rank, selected at random with
+//! `select_panel`. Once a panel is selected, only the votes of its members are accepted, and the
+//! support of the poll is measured against the size of the panel.
+//!
+//! Members may `delegate` their votes on the polls of a class to another member, until they
+//! `undelegate`. The vote weight of the delegators is then added to each aye or nay vote of the
+//! delegate on those polls, though it does not count towards their support. Undelegating takes the
+//! vote weight back from the votes the delegate cast on ongoing polls. Delegations to and from a
+//! member end as they leave the collective.
+//!
+//! A member may `exchange_member` their account for a new one, e.g. to rotate their keys, as may
+//! the `ExchangeOrigin` for any member. The new account keeps their rank and takes over their
+//! votes, panel seats and vetoes on ongoing polls.
+//!
+//! When the `Polls` provider reports a decided poll through `OnPollCompleted`, a
+//! `TallyCertificate` with its final tally and a commitment to the membership at that time is
+//! stored. Other chains may check the certificate, e.g. through the runtime API, without
+//! replaying the votes.
+//!
+//! The votes of such completed polls are removed in `on_idle`, within `IdleCleanupWeight` in each
+//! block. Anyone may also remove them with `cleanup_poll`. The votes of a poll are kept in
+//! `VotePages` of up to `VOTE_PAGE_SIZE` voters each, by the index which an account gets when it
+//! first votes, so that they are read and removed a page at a time.
+//!
+//! Root may `freeze_class` to stop accepting votes on the polls of a class, e.g. while the
+//! membership is being reorganised, until it calls `unfreeze_class`.
+//!
+//! Members of at least `VetoRank` may `veto` an ongoing poll. Once `VetoThreshold` members have
+//! vetoed it within `VetoPeriod` blocks of the first veto, regardless of their voting power, the
+//! `Polls` provider is notified so that it may cancel or suspend the poll.
 //!
 //! An origin control, `EnsureRank`, ensures that the origin is a member of the collective of at
 //! least a particular rank.
+//!
+//! The `ChangeMembersAdapter` implements `InitializeMembers` and `ChangeMembers`, so that the
+//! membership of the collective may be driven by pallets written for `pallet-collective`, such as
+//! `pallet-elections-phragmen`.
+//!
+//! The pallet also implements the `RankedMembers` trait of `frame_support::traits::ranked`, so
+//! that other pallets, such as a salary pallet, may read and change ranks without dispatching.
+//! Such pallets may also react to members joining, changing rank and leaving through the
+//! `OnMemberAdded`, `OnRankChanged` and `OnMemberRemoved` hooks.
+//!
+//! Where another pallet holds the authoritative ranks, it is the `RankSource`. Anyone may
+//! `sync_rank` an account to its rank there, and the source may push its changes by naming this
+//! pallet as its `OnMemberAdded` and `OnRankChanged` handler. Differences are resolved by the
+//! `RankSyncPolicy`: the source wins, the higher rank wins, or they are left to the `AdminOrigin`.
+//!
+//! When a runtime upgrade changes the minimum rank of a class, Root may `remap_class` to
+//! re-weigh the votes already cast on its ongoing polls under the new rank, cancelling those of
+//! members below it.
+//!
+//! Root may `split_members` to export the members of a range of ranks, with their ranks, into
+//! the `SplitTarget`, such as another instance of the pallet, and optionally remove them from this
+//! one. The walk over the members spans as many calls as needed, and each exported member is
+//! reported by an event.
+//!
+//! The initial members and their ranks may be given in the genesis config. The
+//! `MembershipNft` hooks are not called for them.
+//!
+//! The membership may be mirrored through the `MembershipNft` hooks. The `NftMembership` adapter
+//! mints a non-transferable NFT to each added member, keeps their rank in its `rank` attribute and
+//! burns it when they leave, so that wallets and NFT-gated tooling can recognise the members.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
 
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::traits::{CheckedDiv, Saturating, Zero};
 use sp_runtime::{
-	traits::{Convert, StaticLookup},
+	traits::{Convert, Hash, StaticLookup},
 	ArithmeticError::Overflow,
 	Perbill, RuntimeDebug,
 };
-use sp_std::{marker::PhantomData, prelude::*};
+use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, prelude::*};
 
 use frame_support::{
 	codec::{Decode, Encode, MaxEncodedLen},
-	dispatch::{DispatchError, DispatchResultWithPostInfo, PostDispatchInfo},
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
 	ensure,
-	traits::{EnsureOrigin, PollStatus, Polling, VoteTally},
-	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	storage::{
+		indexed_set::{IndexedSet, IndexedSetError},
+		with_storage_layer, with_transaction, TransactionOutcome,
+	},
+	traits::{
+		meets_approval, meets_support,
+		ranked::{self, OnMemberAdded, OnMemberRemoved, OnRankChanged, RankedMembers},
+		tokens::nonfungibles_v2,
+		ChangeMembers, ConstU32, Defensive, EnsureOrigin, EnsureOriginWithArg, Get,
+		InitializeMembers, OnPollCompleted, PollStatus, Polling, Randomness, StorageOps, VoteTally,
+	},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 
+mod integration_test;
 #[cfg(test)]
 mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 pub use pallet::*;
 pub use weights::WeightInfo;
 
-/// A number of members.
-pub type MemberIndex = u32;
+pub use frame_support::traits::ranked::{MemberIndex, Rank, Votes};
 
-/// Member rank.
-pub type Rank = u16;
+/// The log target of this pallet.
+const LOG_TARGET: &str = "runtime::ranked-collective";
 
-/// Votes.
-pub type Votes = u32;
+/// The members of each rank, indexed by `IdToIndex` and `IndexToId`.
+pub type MemberIndices<T, I> = IndexedSet<
+	Rank,
+	<T as frame_system::Config>::AccountId,
+	MemberCount<T, I>,
+	IdToIndex<T, I>,
+	IndexToId<T, I>,
+>;
 
 /// Aggregated votes for an ongoing poll by members of the ranked collective.
 #[derive(
@@ -92,60 +189,136 @@ pub type Votes = u32;
 #[codec(mel_bound())]
 pub struct Tally<T, I, M: GetMaxVoters> {
 	bare_ayes: MemberIndex,
+	bare_nays: MemberIndex,
+	bare_abstains: MemberIndex,
 	ayes: Votes,
 	nays: Votes,
+	/// The number of members eligible to vote on the poll: the size of its panel if it has one,
+	/// or else the number when it received its first vote, if snapshotted. See
+	/// `Config::SnapshotVoters`.
+	max_voters: Option<MemberIndex>,
 	dummy: PhantomData<(T, I, M)>,
 }
 
 impl<T: Config<I>, I: 'static, M: GetMaxVoters> Tally<T, I, M> {
-	pub fn from_parts(bare_ayes: MemberIndex, ayes: Votes, nays: Votes) -> Self {
-		Tally { bare_ayes, ayes, nays, dummy: PhantomData }
+	pub fn from_parts(
+		bare_ayes: MemberIndex,
+		bare_nays: MemberIndex,
+		ayes: Votes,
+		nays: Votes,
+	) -> Self {
+		Tally {
+			bare_ayes,
+			bare_nays,
+			bare_abstains: 0,
+			ayes,
+			nays,
+			max_voters: None,
+			dummy: PhantomData,
+		}
+	}
+
+	/// The number of (non-rank-weighted) nays.
+	pub fn bare_nays(&self) -> MemberIndex {
+		self.bare_nays
+	}
+
+	/// The number of members who explicitly abstained.
+	pub fn bare_abstains(&self) -> MemberIndex {
+		self.bare_abstains
+	}
+
+	/// The number of members who took part in the poll, whether by voting or by abstaining.
+	pub fn turnout(&self) -> MemberIndex {
+		self.bare_ayes.saturating_add(self.bare_nays).saturating_add(self.bare_abstains)
+	}
+
+	/// The number of members eligible to vote on the poll as snapshotted on its first vote or the
+	/// selection of its panel, if any.
+	pub fn max_voters(&self) -> Option<MemberIndex> {
+		self.max_voters
+	}
+
+	/// The members counted towards the support of the poll, not weighted by rank, and the number
+	/// of members eligible to vote on it. The ayes are counted, and the nays as well if
+	/// `SupportIncludesNays`.
+	fn supporters(&self, class: Rank) -> (MemberIndex, MemberIndex) {
+		let supporters = if T::SupportIncludesNays::get() {
+			self.bare_ayes.saturating_add(self.bare_nays)
+		} else {
+			self.bare_ayes
+		};
+		(supporters, self.max_voters.unwrap_or_else(|| M::get_max_voters(class)))
 	}
 }
 
-// Use (non-rank-weighted) ayes for calculating support.
 // Allow only promotion/demotion by one rank only.
 // Allow removal of member with rank zero only.
 // This keeps everything O(1) while still allowing arbitrary number of ranks.
 
-// All functions of VoteTally now include the class as a param.
-
 pub type TallyOf<T, I = ()> = Tally<T, I, Pallet<T, I>>;
 pub type PollIndexOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Index;
+pub type ClassOf<T, I = ()> = <<T as Config<I>>::Polls as Polling<TallyOf<T, I>>>::Class;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 impl<T: Config<I>, I: 'static, M: GetMaxVoters> VoteTally<Votes, Rank> for Tally<T, I, M> {
 	fn new(_: Rank) -> Self {
-		Self { bare_ayes: 0, ayes: 0, nays: 0, dummy: PhantomData }
+		Self::from_parts(0, 0, 0, 0)
 	}
 	fn ayes(&self, _: Rank) -> Votes {
-		self.bare_ayes
+		self.bare_ayes.into()
 	}
 	fn support(&self, class: Rank) -> Perbill {
-		Perbill::from_rational(self.bare_ayes, M::get_max_voters(class))
+		let (supporters, max_voters) = self.supporters(class);
+		Perbill::from_rational(supporters, max_voters)
 	}
 	fn approval(&self, _: Rank) -> Perbill {
-		Perbill::from_rational(self.ayes, 1.max(self.ayes + self.nays))
+		Perbill::from_rational(self.ayes, 1.max(self.ayes.saturating_add(self.nays)))
+	}
+	fn meets_support(&self, class: Rank, threshold: Perbill) -> bool {
+		let (supporters, max_voters) = self.supporters(class);
+		meets_support(supporters.into(), max_voters.into(), threshold)
+	}
+	fn meets_approval(&self, _: Rank, threshold: Perbill) -> bool {
+		meets_approval(self.ayes, self.nays, threshold)
 	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn unanimity(class: Rank) -> Self {
 		Self {
 			bare_ayes: M::get_max_voters(class),
-			ayes: M::get_max_voters(class),
+			bare_nays: 0,
+			bare_abstains: 0,
+			ayes: M::get_max_voters(class).into(),
 			nays: 0,
+			max_voters: None,
 			dummy: PhantomData,
 		}
 	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn rejection(class: Rank) -> Self {
-		Self { bare_ayes: 0, ayes: 0, nays: M::get_max_voters(class), dummy: PhantomData }
+		Self {
+			bare_ayes: 0,
+			bare_nays: M::get_max_voters(class),
+			bare_abstains: 0,
+			ayes: 0,
+			nays: M::get_max_voters(class).into(),
+			max_voters: None,
+			dummy: PhantomData,
+		}
 	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn from_requirements(support: Perbill, approval: Perbill, class: Rank) -> Self {
 		let c = M::get_max_voters(class);
+		if T::SupportIncludesNays::get() {
+			// Support is measured over all participants, so split them according to `approval`.
+			let participants = support * c;
+			let ayes = approval * participants;
+			let nays = participants - ayes;
+			return Self::from_parts(ayes, nays, ayes.into(), nays.into())
+		}
 		let ayes = support * c;
 		let nays = ((ayes as u64) * 1_000_000_000u64 / approval.deconstruct() as u64) as u32 - ayes;
-		Self { bare_ayes: ayes, ayes, nays, dummy: PhantomData }
+		Self::from_parts(ayes, nays, ayes.into(), nays.into())
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -165,9 +338,87 @@ impl<T: Config<I>, I: 'static, M: GetMaxVoters> VoteTally<Votes, Rank> for Tally
 
 /// Record needed for every member.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct MemberRecord {
+pub struct MemberRecord<BlockNumber> {
 	/// The rank of the member.
 	rank: Rank,
+	/// Whether the member is on probation, see `induct_member`.
+	probationary: bool,
+	/// The block in which the member was last promoted, if they have been since joining.
+	last_promotion: Option<BlockNumber>,
+	/// The block in which the member was last demoted, if they have been since joining.
+	last_demotion: Option<BlockNumber>,
+	/// The block in which the member last voted, if they have since joining.
+	last_vote: Option<BlockNumber>,
+	/// The block from which the activity of the member is tracked: when they joined or ended
+	/// their probation, or when their inactivity was first checked without any activity on
+	/// record.
+	tracked_since: Option<BlockNumber>,
+}
+
+/// The record of a member of the collective of `T`.
+pub type MemberRecordOf<T> = MemberRecord<<T as frame_system::Config>::BlockNumber>;
+
+impl<BlockNumber: Ord + Copy> MemberRecord<BlockNumber> {
+	/// The record of a full member of the given `rank`, without any activity.
+	pub fn new(rank: Rank) -> Self {
+		Self {
+			rank,
+			probationary: false,
+			last_promotion: None,
+			last_demotion: None,
+			last_vote: None,
+			tracked_since: None,
+		}
+	}
+
+	/// The rank of the member.
+	pub fn rank(&self) -> Rank {
+		self.rank
+	}
+
+	/// Whether the member is on probation.
+	pub fn is_probationary(&self) -> bool {
+		self.probationary
+	}
+
+	/// The block in which the member was last promoted, if they have been since joining.
+	pub fn last_promotion(&self) -> Option<BlockNumber> {
+		self.last_promotion
+	}
+
+	/// The block in which the member was last demoted, if they have been since joining.
+	pub fn last_demotion(&self) -> Option<BlockNumber> {
+		self.last_demotion
+	}
+
+	/// The block in which the member last voted, if they have since joining.
+	pub fn last_vote(&self) -> Option<BlockNumber> {
+		self.last_vote
+	}
+
+	/// The block from which the activity of the member is tracked, if known.
+	pub fn tracked_since(&self) -> Option<BlockNumber> {
+		self.tracked_since
+	}
+
+	/// The latest block in which the member was promoted, demoted or voted, or from which their
+	/// activity is tracked, if any.
+	pub fn last_activity(&self) -> Option<BlockNumber> {
+		self.last_promotion
+			.max(self.last_demotion)
+			.max(self.last_vote)
+			.max(self.tracked_since)
+	}
+}
+
+/// The storage operations which `vote` does on the votes of a poll in the worst case, as its
+/// benchmarks measure them, when `delegators` members delegate their votes to the voter.
+///
+/// The first vote also marks the poll as active.
+fn vote_poll_ops(delegators: u32, first: bool) -> StorageOps {
+	let delegators = delegators as u64;
+	let first = first as u64;
+	StorageOps { reads: 6 + first + 3 * delegators, writes: 1 + 2 * first + delegators }
 }
 
 /// Record needed for every vote.
@@ -177,6 +428,143 @@ pub enum VoteRecord {
 	Aye(Votes),
 	/// Vote was a nay with given vote weight.
 	Nay(Votes),
+	/// The member took part in the poll without voting either way. Carries no vote weight.
+	Abstain,
+	/// The given vote weight of the member was added to the vote of their delegate.
+	Delegated(Votes),
+}
+
+/// The index of an account among those which ever voted, which places its votes on every poll
+/// into the `VotePages` of the poll.
+pub type VoterIndex = u32;
+
+/// The number of voters whose votes on a poll share a `VotePage`.
+pub const VOTE_PAGE_SIZE: VoterIndex = 64;
+
+/// The votes on a poll of the `VOTE_PAGE_SIZE` voters whose indices fall into one page, of which
+/// only those who voted take up space.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VotePage {
+	/// Bit `i` is set if the voter of slot `i` of the page voted.
+	voted: u64,
+	/// The votes of the voters whose bit is set, in the order of their slots.
+	votes: BoundedVec<VoteRecord, ConstU32<VOTE_PAGE_SIZE>>,
+}
+
+impl VotePage {
+	/// The position in `votes` of the vote of `slot`, if it was cast.
+	fn position(&self, slot: VoterIndex) -> usize {
+		(self.voted & ((1u64 << slot) - 1)).count_ones() as usize
+	}
+
+	fn is_set(&self, slot: VoterIndex) -> bool {
+		self.voted & (1u64 << slot) != 0
+	}
+
+	/// The vote of the voter of `slot`, if any.
+	pub fn get(&self, slot: VoterIndex) -> Option<VoteRecord> {
+		self.is_set(slot).then(|| self.votes[self.position(slot)])
+	}
+
+	/// Record `vote` for the voter of `slot`, returning the vote it replaces.
+	fn insert(&mut self, slot: VoterIndex, vote: VoteRecord) -> Option<VoteRecord> {
+		let position = self.position(slot);
+		if self.is_set(slot) {
+			return Some(sp_std::mem::replace(&mut self.votes[position], vote))
+		}
+		// Cannot fail, as there is room for the vote of every slot.
+		if self.votes.try_insert(position, vote).is_ok() {
+			self.voted |= 1u64 << slot;
+		}
+		None
+	}
+
+	/// Remove the vote of the voter of `slot`, returning it.
+	fn remove(&mut self, slot: VoterIndex) -> Option<VoteRecord> {
+		if !self.is_set(slot) {
+			return None
+		}
+		self.voted &= !(1u64 << slot);
+		Some(self.votes.remove(self.position(slot)))
+	}
+
+	/// The number of votes in the page.
+	pub fn len(&self) -> u32 {
+		self.voted.count_ones()
+	}
+
+	/// Whether the page holds no vote.
+	pub fn is_empty(&self) -> bool {
+		self.voted == 0
+	}
+
+	/// The slots of the voters who voted and their votes, in the order of the slots.
+	pub fn iter(&self) -> impl Iterator<Item = (VoterIndex, VoteRecord)> + '_ {
+		(0..VOTE_PAGE_SIZE)
+			.filter(|slot| self.is_set(*slot))
+			.zip(self.votes.iter().copied())
+	}
+}
+
+/// How a rank differing from that of `Config::RankSource` is resolved when it is synced.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SyncPolicy {
+	/// The member is moved to the rank of the source.
+	SourceWins,
+	/// The member is moved to the rank of the source if it is higher than theirs.
+	Max,
+	/// The rank of the member is left as it is, and a `RankConflict` is reported for the
+	/// `AdminOrigin` to resolve.
+	Manual,
+}
+
+/// The reason for which a vote would be rejected, as reported by `dry_run_vote`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum VoteRejection {
+	/// The voter is not a member of the collective.
+	NotMember,
+	/// The rank of the voter is below the `required` rank of the poll's class.
+	RankTooLow { required: Rank },
+	/// The poll is unknown or no longer ongoing.
+	NotPolling,
+	/// Votes on the polls of the poll's class are paused with `freeze_class`.
+	ClassFrozen,
+	/// A panel has been selected for the poll, and the voter is not part of it.
+	NotInPanel,
+	/// The members of the voter's rank may not vote on the polls of the poll's class, see
+	/// `set_class_limit`.
+	ClassNotAllowed,
+	/// The vote would fail for another reason.
+	Other(DispatchError),
+}
+
+/// A kind of operation on the membership of the collective.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum MemberOperation {
+	/// A non-member is added at rank zero.
+	Add,
+	/// A member's rank is increased by one.
+	Promote,
+	/// A member's rank is decreased by one.
+	Demote,
+	/// A member is removed from all ranks.
+	Remove,
+}
+
+impl VoteRecord {
+	/// Whether the vote was an aye.
+	pub fn is_aye(&self) -> bool {
+		matches!(self, VoteRecord::Aye(_))
+	}
+
+	/// The vote weight with which the vote was cast.
+	pub fn votes(&self) -> Votes {
+		match self {
+			VoteRecord::Aye(votes) | VoteRecord::Nay(votes) | VoteRecord::Delegated(votes) =>
+				*votes,
+			VoteRecord::Abstain => 0,
+		}
+	}
 }
 
 impl From<(bool, Votes)> for VoteRecord {
@@ -188,6 +576,34 @@ impl From<(bool, Votes)> for VoteRecord {
 	}
 }
 
+/// A compact record of how the collective decided a poll, from which e.g. bridges and light
+/// clients of other chains may verify the outcome without replaying its votes.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TallyCertificate<PollIndex, Class, Tally, Hash, BlockNumber> {
+	/// The poll which was decided.
+	pub poll: PollIndex,
+	/// The class of the poll.
+	pub class: Class,
+	/// The final tally of the poll.
+	pub tally: Tally,
+	/// Whether the poll was approved.
+	pub approved: bool,
+	/// The number of members who could vote on the poll, against which its support is measured.
+	pub eligible: MemberIndex,
+	/// The commitment to the membership of the collective when the poll was decided.
+	pub members: Hash,
+	/// The block at which the poll was decided.
+	pub at: BlockNumber,
+}
+
+pub type TallyCertificateOf<T, I = ()> = TallyCertificate<
+	PollIndexOf<T, I>,
+	ClassOf<T, I>,
+	TallyOf<T, I>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
 /// Vote-weight scheme where all voters get one vote regardless of rank.
 pub struct Unit;
 impl Convert<Rank, Votes> for Unit {
@@ -207,7 +623,7 @@ impl Convert<Rank, Votes> for Unit {
 pub struct Linear;
 impl Convert<Rank, Votes> for Linear {
 	fn convert(r: Rank) -> Votes {
-		(r + 1) as Votes
+		Votes::from(r) + 1
 	}
 }
 
@@ -222,11 +638,54 @@ impl Convert<Rank, Votes> for Linear {
 pub struct Geometric;
 impl Convert<Rank, Votes> for Geometric {
 	fn convert(r: Rank) -> Votes {
-		let v = (r + 1) as Votes;
+		let v = Votes::from(r) + 1;
 		v * (v + 1) / 2
 	}
 }
 
+/// The maximum number of points of a `VoteCurve`.
+pub const MAX_VOTE_CURVE_POINTS: u32 = 16;
+
+/// The points of a `VoteCurve`.
+pub type VoteCurvePoints = BoundedVec<(Rank, Votes), ConstU32<MAX_VOTE_CURVE_POINTS>>;
+
+/// Vote-weight scheme set on-chain, which takes the place of `Config::VoteWeight` while it is set.
+///
+/// It is a piecewise-linear curve through points of an excess rank and the votes it gets, sorted
+/// by strictly increasing excess rank and non-decreasing votes. The votes are interpolated, rounded
+/// down, between the points, and flat before the first and after the last one.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VoteCurve(VoteCurvePoints);
+
+impl VoteCurve {
+	/// The curve through `points`, or `None` if there are none or they are not sorted.
+	pub fn new(points: VoteCurvePoints) -> Option<Self> {
+		let sorted = points.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 <= w[1].1);
+		(!points.is_empty() && sorted).then_some(Self(points))
+	}
+
+	/// The points of the curve.
+	pub fn points(&self) -> &[(Rank, Votes)] {
+		&self.0
+	}
+
+	/// The votes of a member with the given `excess` rank.
+	pub fn votes(&self, excess: Rank) -> Votes {
+		let next = self.0.iter().position(|&(rank, _)| rank > excess);
+		match next {
+			Some(0) => self.0[0].1,
+			Some(i) => {
+				let (r0, v0) = self.0[i - 1];
+				let (r1, v1) = self.0[i];
+				// Widened so that the product cannot overflow, the quotient fits in `Votes`.
+				let rise = (v1 - v0) as u128 * (excess - r0) as u128 / (r1 - r0) as u128;
+				v0.saturating_add(rise as Votes)
+			},
+			None => self.0.last().map_or(0, |&(_, votes)| votes),
+		}
+	}
+}
+
 /// Trait for getting the maximum number of voters for a given rank.
 pub trait GetMaxVoters {
 	/// Return the maximum number of voters for the rank `r`.
@@ -234,12 +693,46 @@ pub trait GetMaxVoters {
 }
 impl<T: Config<I>, I: 'static> GetMaxVoters for Pallet<T, I> {
 	fn get_max_voters(r: Rank) -> MemberIndex {
-		MemberCount::<T, I>::get(r)
+		let count = MemberCount::<T, I>::get(r);
+		match r {
+			// Members on probation hold rank 0, but may not vote.
+			0 => count.saturating_sub(ProbationaryCount::<T, I>::get()),
+			_ => count,
+		}
+	}
+}
+
+/// Stores the certificate of each poll decided by the `Polls` provider.
+impl<T: Config<I>, I: 'static> OnPollCompleted<PollIndexOf<T, I>, ClassOf<T, I>, TallyOf<T, I>>
+	for Pallet<T, I>
+{
+	fn on_poll_completed(
+		poll: PollIndexOf<T, I>,
+		class: ClassOf<T, I>,
+		tally: &TallyOf<T, I>,
+		approved: bool,
+	) {
+		let eligible = tally
+			.max_voters
+			.unwrap_or_else(|| Self::get_max_voters(T::MinRankOfClass::convert(class.clone())));
+		let certificate = TallyCertificate {
+			poll,
+			class,
+			tally: tally.clone(),
+			approved,
+			eligible,
+			members: MembershipCommitment::<T, I>::get(),
+			at: frame_system::Pallet::<T>::block_number(),
+		};
+		TallyCertificates::<T, I>::insert(poll, certificate);
+		ActivePolls::<T, I>::remove(poll);
+		CompletedPolls::<T, I>::insert(poll, ());
+		Self::deposit_event(Event::TallyCertified { poll, approved });
 	}
 }
 
-/// Guard to ensure that the given origin is a member of the collective. The rank of the member is
-/// the `Success` value.
+/// Guard to ensure that the given origin is a full member of the collective, not on probation. The
+/// rank of the member is the `Success` value.
 pub struct EnsureRanked<T, I, const MIN_RANK: u16>(PhantomData<(T, I)>);
 impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigin>
 	for EnsureRanked<T, I, MIN_RANK>
@@ -249,14 +742,16 @@ impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigi
 	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
 		let who = frame_system::EnsureSigned::try_origin(o)?;
 		match Members::<T, I>::get(&who) {
-			Some(MemberRecord { rank, .. }) if rank >= MIN_RANK => Ok(rank),
+			Some(MemberRecord { rank, probationary: false, .. }) if rank >= MIN_RANK => Ok(rank),
 			_ => Err(frame_system::RawOrigin::Signed(who).into()),
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
-		let who = IndexToId::<T, I>::get(MIN_RANK, 0).ok_or(())?;
+		let who = IndexToId::<T, I>::get(MIN_RANK, 0)
+			.filter(|who| !Members::<T, I>::get(who).map_or(true, |r| r.probationary))
+			.ok_or(())?;
 		Ok(frame_system::RawOrigin::Signed(who).into())
 	}
 
@@ -274,8 +769,8 @@ impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigi
 	}
 }
 
-/// Guard to ensure that the given origin is a member of the collective. The account ID of the
-/// member is the `Success` value.
+/// Guard to ensure that the given origin is a full member of the collective, not on probation. The
+/// account ID of the member is the `Success` value.
 pub struct EnsureMember<T, I, const MIN_RANK: u16>(PhantomData<(T, I)>);
 impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigin>
 	for EnsureMember<T, I, MIN_RANK>
@@ -285,14 +780,16 @@ impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigi
 	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
 		let who = frame_system::EnsureSigned::try_origin(o)?;
 		match Members::<T, I>::get(&who) {
-			Some(MemberRecord { rank, .. }) if rank >= MIN_RANK => Ok(who),
+			Some(MemberRecord { rank, probationary: false, .. }) if rank >= MIN_RANK => Ok(who),
 			_ => Err(frame_system::RawOrigin::Signed(who).into()),
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
-		let who = IndexToId::<T, I>::get(MIN_RANK, 0).ok_or(())?;
+		let who = IndexToId::<T, I>::get(MIN_RANK, 0)
+			.filter(|who| !Members::<T, I>::get(who).map_or(true, |r| r.probationary))
+			.ok_or(())?;
 		Ok(frame_system::RawOrigin::Signed(who).into())
 	}
 
@@ -310,8 +807,8 @@ impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigi
 	}
 }
 
-/// Guard to ensure that the given origin is a member of the collective. The pair of both the
-/// account ID and the rank of the member is the `Success` value.
+/// Guard to ensure that the given origin is a full member of the collective, not on probation. The
+/// pair of both the account ID and the rank of the member is the `Success` value.
 pub struct EnsureRankedMember<T, I, const MIN_RANK: u16>(PhantomData<(T, I)>);
 impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigin>
 	for EnsureRankedMember<T, I, MIN_RANK>
@@ -321,14 +818,17 @@ impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigi
 	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
 		let who = frame_system::EnsureSigned::try_origin(o)?;
 		match Members::<T, I>::get(&who) {
-			Some(MemberRecord { rank, .. }) if rank >= MIN_RANK => Ok((who, rank)),
+			Some(MemberRecord { rank, probationary: false, .. }) if rank >= MIN_RANK =>
+				Ok((who, rank)),
 			_ => Err(frame_system::RawOrigin::Signed(who).into()),
 		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
-		let who = IndexToId::<T, I>::get(MIN_RANK, 0).ok_or(())?;
+		let who = IndexToId::<T, I>::get(MIN_RANK, 0)
+			.filter(|who| !Members::<T, I>::get(who).map_or(true, |r| r.probationary))
+			.ok_or(())?;
 		Ok(frame_system::RawOrigin::Signed(who).into())
 	}
 
@@ -346,14 +846,215 @@ impl<T: Config<I>, I: 'static, const MIN_RANK: u16> EnsureOrigin<T::RuntimeOrigi
 	}
 }
 
+/// Guard to ensure that the given origin is a full member of the collective, not on probation, who
+/// may vote on polls of the given class, i.e. whose rank is at least `MinRankOfClass` of it. The
+/// account ID of the member is the `Success` value.
+pub struct EnsureMemberOfClass<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> EnsureOriginWithArg<T::RuntimeOrigin, ClassOf<T, I>>
+	for EnsureMemberOfClass<T, I>
+{
+	type Success = T::AccountId;
+
+	fn try_origin(
+		o: T::RuntimeOrigin,
+		class: &ClassOf<T, I>,
+	) -> Result<Self::Success, T::RuntimeOrigin> {
+		let who = frame_system::EnsureSigned::try_origin(o)?;
+		let min_rank = T::MinRankOfClass::convert(class.clone());
+		match Members::<T, I>::get(&who) {
+			Some(MemberRecord { rank, probationary: false, .. }) if rank >= min_rank => Ok(who),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(class: &ClassOf<T, I>) -> Result<T::RuntimeOrigin, ()> {
+		let min_rank = T::MinRankOfClass::convert(class.clone());
+		let who = match IndexToId::<T, I>::get(min_rank, 0)
+			.filter(|who| !Members::<T, I>::get(who).map_or(true, |r| r.probationary))
+		{
+			Some(who) => who,
+			None => {
+				let who: T::AccountId = frame_benchmarking::whitelisted_caller();
+				crate::Pallet::<T, I>::do_add_member_to_rank(who.clone(), min_rank)
+					.map_err(|_| ())?;
+				who
+			},
+		};
+		Ok(frame_system::RawOrigin::Signed(who).into())
+	}
+}
+
+/// Guard to ensure that the given origin is the member targeted by a demotion or removal, allowing
+/// members to step down on their own. The rank of the member is the `Success` value.
+pub struct EnsureSelfDemotion<T, I>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static>
+	EnsureOriginWithArg<T::RuntimeOrigin, (T::AccountId, MemberOperation)>
+	for EnsureSelfDemotion<T, I>
+{
+	type Success = Rank;
+
+	fn try_origin(
+		o: T::RuntimeOrigin,
+		(target, operation): &(T::AccountId, MemberOperation),
+	) -> Result<Self::Success, T::RuntimeOrigin> {
+		let who = frame_system::EnsureSigned::try_origin(o)?;
+		match (operation, Members::<T, I>::get(&who)) {
+			(
+				MemberOperation::Demote | MemberOperation::Remove,
+				Some(MemberRecord { rank, .. }),
+			) if &who == target => Ok(rank),
+			_ => Err(frame_system::RawOrigin::Signed(who).into()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(
+		(target, operation): &(T::AccountId, MemberOperation),
+	) -> Result<T::RuntimeOrigin, ()> {
+		match operation {
+			MemberOperation::Demote | MemberOperation::Remove =>
+				Ok(frame_system::RawOrigin::Signed(target.clone()).into()),
+			MemberOperation::Add | MemberOperation::Promote => Err(()),
+		}
+	}
+}
+
+/// Adapter driving the membership of the collective through the `InitializeMembers` and
+/// `ChangeMembers` traits, as used to feed the members of `pallet-collective`.
+///
+/// Incoming accounts which are not yet members are added at rank `R`; outgoing members are removed
+/// entirely, whatever their rank. Accounts which are already members keep their rank.
+pub struct ChangeMembersAdapter<T, I, R>(PhantomData<(T, I, R)>);
+
+impl<T: Config<I>, I: 'static, R: Get<Rank>> InitializeMembers<T::AccountId>
+	for ChangeMembersAdapter<T, I, R>
+{
+	fn initialize_members(members: &[T::AccountId]) {
+		for who in members {
+			if !Members::<T, I>::contains_key(who) {
+				let _ = Pallet::<T, I>::do_add_member_to_rank(who.clone(), R::get()).defensive();
+			}
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static, R: Get<Rank>> ChangeMembers<T::AccountId>
+	for ChangeMembersAdapter<T, I, R>
+{
+	fn change_members_sorted(
+		incoming: &[T::AccountId],
+		outgoing: &[T::AccountId],
+		_sorted_new: &[T::AccountId],
+	) {
+		for who in outgoing {
+			if Members::<T, I>::contains_key(who) {
+				let _ = Pallet::<T, I>::do_remove_member(who.clone()).defensive();
+			}
+		}
+		Self::initialize_members(incoming);
+	}
+}
+
+impl<T: Config<I>, I: 'static> ranked::RankedMembers for Pallet<T, I> {
+	type AccountId = T::AccountId;
+
+	fn rank_of(who: &T::AccountId) -> Option<Rank> {
+		Members::<T, I>::get(who).map(|record| record.rank)
+	}
+
+	fn induct(who: &T::AccountId) -> DispatchResult {
+		Self::do_add_member(who.clone())
+	}
+
+	fn promote(who: &T::AccountId) -> DispatchResult {
+		Self::do_promote_member(who.clone(), None)
+	}
+
+	fn demote(who: &T::AccountId) -> DispatchResult {
+		Self::do_demote_member(who.clone())
+	}
+}
+
+/// Something which mirrors the membership of the collective, for example as NFT badges.
+///
+/// Each hook is called once the membership change which it reports has been written to storage;
+/// an error reverts that change.
+pub trait MembershipNft<AccountId> {
+	/// `who` was added to the collective at rank `rank`.
+	fn on_added(who: &AccountId, rank: Rank) -> DispatchResult;
+
+	/// The rank of `who`, who remains a member, changed to `rank`.
+	fn on_rank_changed(who: &AccountId, rank: Rank) -> DispatchResult;
+
+	/// `who` was removed from the collective.
+	fn on_removed(who: &AccountId) -> DispatchResult;
+}
+
+/// No mirroring of the membership.
+impl<AccountId> MembershipNft<AccountId> for () {
+	fn on_added(_: &AccountId, _: Rank) -> DispatchResult {
+		Ok(())
+	}
+	fn on_rank_changed(_: &AccountId, _: Rank) -> DispatchResult {
+		Ok(())
+	}
+	fn on_removed(_: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// The key of the attribute holding the rank of a member on their membership NFT.
+pub const RANK_ATTRIBUTE_KEY: &[u8] = b"rank";
+
+/// Adapter mirroring the membership of the collective as NFTs of `Nfts`, through the
+/// `nonfungibles_v2` traits.
+///
+/// Each member holds the item `ItemOf::convert(who)` of the collection `Collection`, minted with
+/// the item config `Badge` when they are added and burned when they are removed. Their rank is
+/// kept, SCALE-encoded, in the attribute [`RANK_ATTRIBUTE_KEY`] of the item. `Badge` should make
+/// the item non-transferable, so that it stays with the member.
+pub struct NftMembership<Nfts, Collection, ItemOf, ItemConfig, Badge>(
+	PhantomData<(Nfts, Collection, ItemOf, ItemConfig, Badge)>,
+);
+
+impl<AccountId, Nfts, Collection, ItemOf, ItemConfig, Badge> MembershipNft<AccountId>
+	for NftMembership<Nfts, Collection, ItemOf, ItemConfig, Badge>
+where
+	Nfts: nonfungibles_v2::Mutate<AccountId, ItemConfig>,
+	Collection: Get<Nfts::CollectionId>,
+	ItemOf: Convert<AccountId, Nfts::ItemId>,
+	Badge: Get<ItemConfig>,
+	AccountId: Clone,
+{
+	fn on_added(who: &AccountId, rank: Rank) -> DispatchResult {
+		let item = ItemOf::convert(who.clone());
+		Nfts::mint_into(&Collection::get(), &item, who, &Badge::get(), false)?;
+		Nfts::set_attribute(&Collection::get(), &item, RANK_ATTRIBUTE_KEY, &rank.encode())
+	}
+
+	fn on_rank_changed(who: &AccountId, rank: Rank) -> DispatchResult {
+		let item = ItemOf::convert(who.clone());
+		Nfts::set_attribute(&Collection::get(), &item, RANK_ATTRIBUTE_KEY, &rank.encode())
+	}
+
+	fn on_removed(who: &AccountId) -> DispatchResult {
+		Nfts::burn(&Collection::get(), &ItemOf::convert(who.clone()), Some(who))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{pallet_prelude::*, storage::KeyLenOf};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
@@ -373,6 +1074,16 @@ pub mod pallet {
 		/// maximum rank *from which* the demotion/removal may be.
 		type DemoteOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Rank>;
 
+		/// An origin which may perform a membership operation depending on the targeted account
+		/// and the kind of operation. It is consulted when `PromoteOrigin` (for additions and
+		/// promotions) or `DemoteOrigin` (for demotions and removals) fails, and its success value
+		/// has the same meaning as theirs.
+		type MembershipOrigin: EnsureOriginWithArg<
+			Self::RuntimeOrigin,
+			(Self::AccountId, MemberOperation),
+			Success = Rank,
+		>;
+
 		/// The polling system used for our voting.
 		type Polls: Polling<TallyOf<Self, I>, Votes = Votes, Moment = Self::BlockNumber>;
 
@@ -386,6 +1097,142 @@ pub mod pallet {
 		/// Rank_delta is defined as the number of ranks above the minimum required to take part
 		/// in the poll.
 		type VoteWeight: Convert<Rank, Votes>;
+
+		/// Whether the nays are counted towards the support of a poll.
+		///
+		/// If `false`, support is the proportion of eligible members voting aye. If `true`, it is
+		/// the proportion of eligible members voting at all, as some decision curves require.
+		type SupportIncludesNays: Get<bool>;
+
+		/// Whether the number of members eligible to vote on a poll is snapshotted when it
+		/// receives its first vote.
+		///
+		/// If `true`, the support of a poll is measured against that snapshot for the rest of its
+		/// lifetime, rather than against the members eligible at the time it is read, so members
+		/// joining or leaving do not move it.
+		type SnapshotVoters: Get<bool>;
+
+		/// Whether votes are reported by `VotedCompact` rather than `Voted`.
+		///
+		/// `VotedCompact` leaves out the updated tally of the poll, which may be read through
+		/// `tally_of` instead, keeping the events small on chains with very active voting.
+		type CompactVoteEvents: Get<bool>;
+
+		/// Something that provides randomness in the runtime, used to select voting panels.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// The origin required to select a voting panel for a poll.
+		type PanelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of members which may be selected into a voting panel.
+		#[pallet::constant]
+		type MaxPanelSize: Get<u32>;
+
+		/// The maximum weight which `on_idle` may spend in each block removing the votes of
+		/// completed polls. Zero leaves them to `cleanup_poll`.
+		#[pallet::constant]
+		type IdleCleanupWeight: Get<Weight>;
+
+		/// The period for which a member who resigns with a cooldown may not be added back to
+		/// the collective.
+		#[pallet::constant]
+		type ResignationCooldown: Get<Self::BlockNumber>;
+
+		/// The maximum number of accounts which may be re-ranked by a single `force_set_ranks`, or
+		/// added, promoted or removed by a single batch membership call.
+		#[pallet::constant]
+		type MaxRankChanges: Get<u32>;
+
+		/// The number of first votes on a poll which a member may cast without paying a fee in
+		/// each `FreeVotePeriod`. Further votes pay the normal fee.
+		#[pallet::constant]
+		type MaxFreeVotes: Get<u32>;
+
+		/// The length of the periods over which `MaxFreeVotes` is counted.
+		#[pallet::constant]
+		type FreeVotePeriod: Get<Self::BlockNumber>;
+
+		/// The minimum rank of the members who may veto a poll.
+		#[pallet::constant]
+		type VetoRank: Get<Rank>;
+
+		/// The number of vetoes, regardless of the rank of their members, by which a poll is
+		/// vetoed. Zero disables vetoes.
+		#[pallet::constant]
+		type VetoThreshold: Get<u32>;
+
+		/// The period, counted from the first veto of a poll, within which the remaining vetoes
+		/// must be registered. Vetoes which do not reach the threshold in time lapse.
+		#[pallet::constant]
+		type VetoPeriod: Get<Self::BlockNumber>;
+
+		/// Mirrors the membership of the collective, for example as non-transferable NFTs with
+		/// the rank of their holder as an attribute through [`NftMembership`]. Use `()` to not
+		/// mirror it.
+		type MembershipNft: MembershipNft<Self::AccountId>;
+
+		/// The origin which may mark a member as the subject of an ongoing disciplinary poll,
+		/// which bars them from being demoted or removed until the poll ends. It is given the
+		/// member and the poll, and should only pass if the poll is about the member.
+		type ReviewOrigin: EnsureOriginWithArg<
+			Self::RuntimeOrigin,
+			(Self::AccountId, PollIndexOf<Self, I>),
+		>;
+
+		/// The maximum number of members who may delegate their votes on the polls of a class to
+		/// the same member.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+
+		/// The origin which may exchange the account of any member. Members may always exchange
+		/// their own account.
+		type ExchangeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The collective into which `split_members` exports members, such as another instance
+		/// of this pallet. Use [`ranked::NoMembers`] if the membership is never split.
+		type SplitTarget: ranked::RankedMembers<AccountId = Self::AccountId>;
+
+		/// The period after which a member of the given rank who has not been promoted, demoted
+		/// or voted is demoted by one rank, or removed if they are of rank zero. Zero exempts the
+		/// rank, so `()` disables inactivity demotions.
+		///
+		/// Members without any recorded activity count as active in the block in which their
+		/// activity is first checked.
+		type DemotionPeriod: Convert<Rank, Self::BlockNumber>;
+
+		/// The maximum number of members whose activity `on_initialize` checks in each block,
+		/// walking over all members in turn. Zero disables inactivity demotions.
+		#[pallet::constant]
+		type MaxInactivityChecks: Get<u32>;
+
+		/// The maximum number of members of each rank, counting those of higher ranks. It bounds
+		/// the size of the collective, and so the number of voters of each poll.
+		#[pallet::constant]
+		type MaxMembersPerRank: Get<MemberIndex>;
+
+		/// Handler for when a member joins the collective, such as a salary pallet registering
+		/// them for payment. Not called for the members of the genesis.
+		type OnMemberAdded: OnMemberAdded<Self::AccountId>;
+
+		/// Handler for when the rank of a member changes without them leaving the collective.
+		type OnRankChanged: OnRankChanged<Self::AccountId>;
+
+		/// Handler for when a member leaves the collective, for whatever reason.
+		type OnMemberRemoved: OnMemberRemoved<Self::AccountId>;
+
+		/// The origin which may move any member directly to any rank with `set_rank`.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The authoritative record of ranks, such as another membership pallet, from which
+		/// `sync_rank` takes the rank of a member. Use [`ranked::NoMembers`] if no ranks are
+		/// synced.
+		///
+		/// The source may also push its changes by naming this pallet as its `OnMemberAdded` and
+		/// `OnRankChanged` handler.
+		type RankSource: ranked::RankedMembers<AccountId = Self::AccountId>;
+
+		/// How a rank differing from that of the `RankSource` is resolved.
+		type RankSyncPolicy: Get<SyncPolicy>;
 	}
 
 	/// The number of members in the collective who have at least the rank according to the index
@@ -397,7 +1244,13 @@ pub mod pallet {
 	/// The current members of the collective.
 	#[pallet::storage]
 	pub type Members<T: Config<I>, I: 'static = ()> =
-		StorageMap<_, Twox64Concat, T::AccountId, MemberRecord>;
+		StorageMap<_, Twox64Concat, T::AccountId, MemberRecordOf<T>>;
+
+	/// The number of members on probation. They hold rank 0, but are not counted among its
+	/// voters.
+	#[pallet::storage]
+	pub type ProbationaryCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, MemberIndex, ValueQuery>;
 
 	/// The index of each ranks's member into the group of members who have at least that rank.
 	#[pallet::storage]
@@ -410,147 +1263,435 @@ pub mod pallet {
 	pub type IndexToId<T: Config<I>, I: 'static = ()> =
 		StorageDoubleMap<_, Twox64Concat, Rank, Twox64Concat, MemberIndex, T::AccountId>;
 
-	/// Votes on a given proposal, if it is ongoing.
+	/// Votes on a given proposal, if it is ongoing, by page of the `VoterIds` of the voters.
 	#[pallet::storage]
-	pub type Voting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+	pub type VotePages<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		PollIndexOf<T, I>,
 		Twox64Concat,
-		T::AccountId,
-		VoteRecord,
+		VoterIndex,
+		VotePage,
 	>;
 
+	/// The index of each account which ever voted into the `VotePages`. It is kept when the
+	/// account leaves the collective, so that its votes are found again if it rejoins.
 	#[pallet::storage]
-	pub type VotingCleanup<T: Config<I>, I: 'static = ()> =
-		StorageMap<_, Blake2_128Concat, PollIndexOf<T, I>, BoundedVec<u8, KeyLenOf<Voting<T, I>>>>;
+	pub type VoterIds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, VoterIndex>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config<I>, I: 'static = ()> {
-		/// A member `who` has been added.
-		MemberAdded { who: T::AccountId },
-		/// The member `who`se rank has been changed to the given `rank`.
-		RankChanged { who: T::AccountId, rank: Rank },
-		/// The member `who` of given `rank` has been removed from the collective.
-		MemberRemoved { who: T::AccountId, rank: Rank },
-		/// The member `who` has voted for the `poll` with the given `vote` leading to an updated
-		/// `tally`.
-		Voted { who: T::AccountId, poll: PollIndexOf<T, I>, vote: VoteRecord, tally: TallyOf<T, I> },
-	}
+	/// The account of each index of `VoterIds`.
+	#[pallet::storage]
+	pub type Voters<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, VoterIndex, T::AccountId>;
 
-	#[pallet::error]
-	pub enum Error<T, I = ()> {
-		/// Account is already a member.
-		AlreadyMember,
-		/// Account is not a member.
-		NotMember,
-		/// The given poll index is unknown or has closed.
-		NotPolling,
-		/// The given poll is still ongoing.
-		Ongoing,
-		/// There are no further records to be removed.
-		NoneRemaining,
-		/// Unexpected error in state.
-		Corruption,
-		/// The member's rank is too low to vote.
-		RankTooLow,
-		/// The information provided is incorrect.
-		InvalidWitness,
-		/// The origin is not sufficiently privileged to do the operation.
-		NoPermission,
-	}
+	/// The index which the next account to vote for the first time gets.
+	#[pallet::storage]
+	pub type NextVoterId<T: Config<I>, I: 'static = ()> = StorageValue<_, VoterIndex, ValueQuery>;
 
-	#[pallet::call]
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		/// Introduce a new member.
-		///
-		/// - `origin`: Must be the `AdminOrigin`.
-		/// - `who`: Account of non-member which will become a member.
-		/// - `rank`: The rank to give the new member.
-		///
-		/// Weight: `O(1)`
-		#[pallet::call_index(0)]
+	/// The number of votes removed so far from a poll whose cleanup is unfinished.
+	#[pallet::storage]
+	pub type VotesRemoved<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PollIndexOf<T, I>, u32, ValueQuery>;
+
+	/// The completed polls whose votes are yet to be removed by `on_idle` or `cleanup_poll`.
+	#[pallet::storage]
+	pub type CompletedPolls<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PollIndexOf<T, I>, ()>;
+
+	/// The panel of members selected to decide on a given poll, if any. Only votes of panel
+	/// members are accepted for such a poll.
+	#[pallet::storage]
+	pub type Panels<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		PollIndexOf<T, I>,
+		BoundedVec<T::AccountId, T::MaxPanelSize>,
+	>;
+
+	/// The block number until which a former member who resigned with a cooldown may not be
+	/// added back to the collective.
+	#[pallet::storage]
+	pub type ResignedUntil<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber>;
+
+	/// The period in which a member last cast a fee-less vote, and the number of fee-less votes
+	/// they have cast in it.
+	#[pallet::storage]
+	pub type FreeVotesUsed<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::BlockNumber, u32)>;
+
+	/// The vote-weight scheme used instead of `VoteWeight`, if any.
+	#[pallet::storage]
+	pub type VoteWeightCurve<T: Config<I>, I: 'static = ()> = StorageValue<_, VoteCurve>;
+
+	/// The highest minimum rank of the poll classes on which the members of a rank may vote, for
+	/// the ranks which are limited.
+	#[pallet::storage]
+	pub type ClassLimits<T: Config<I>, I: 'static = ()> = StorageMap<_, Twox64Concat, Rank, Rank>;
+
+	/// The classes of polls on which votes are not accepted for the time being.
+	#[pallet::storage]
+	pub type FrozenClasses<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ClassOf<T, I>, ()>;
+
+	/// The block at which the veto window of a poll opened, and the members who have vetoed it
+	/// since.
+	#[pallet::storage]
+	pub type Vetoes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		PollIndexOf<T, I>,
+		(T::BlockNumber, BoundedVec<T::AccountId, T::VetoThreshold>),
+	>;
+
+	/// The disciplinary poll of which a member is the subject. The entry is stale, and cleared
+	/// lazily, once the poll is no longer ongoing.
+	#[pallet::storage]
+	pub type UnderReview<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, PollIndexOf<T, I>>;
+
+	/// The commitment to the membership of the collective: the hash of the previous commitment,
+	/// the account and the new rank (if any) of every change to the membership, in order.
+	#[pallet::storage]
+	pub type MembershipCommitment<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::Hash, ValueQuery>;
+
+	/// The certificates of the polls which have been decided.
+	#[pallet::storage]
+	pub type TallyCertificates<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PollIndexOf<T, I>, TallyCertificateOf<T, I>>;
+
+	/// The member to whom a member delegated their votes on the polls of a class.
+	#[pallet::storage]
+	pub type Delegations<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, ClassOf<T, I>, Twox64Concat, T::AccountId, T::AccountId>;
+
+	/// The members who delegated their votes on the polls of a class to a member.
+	#[pallet::storage]
+	pub type Delegators<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassOf<T, I>,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// The ongoing polls for which this pallet holds votes, a panel or vetoes, so that they can be
+	/// moved along with the account of a member.
+	#[pallet::storage]
+	pub type ActivePolls<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Blake2_128Concat, PollIndexOf<T, I>, ()>;
+
+	/// The old and new rank of an unfinished `remap_class`, and the poll and voter index of the
+	/// vote from which it continues.
+	#[pallet::storage]
+	pub type ClassRemapCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (Rank, Rank, PollIndexOf<T, I>, VoterIndex)>;
+
+	/// The last key of `Members` visited by an unfinished walk over the members, from which
+	/// `scan_members` continues it.
+	pub type MembersCursorOf<T, I> = BoundedVec<u8, KeyLenOf<Members<T, I>>>;
+
+	/// The rank range and removal flag of an unfinished `split_members`, and the last key of
+	/// `Members` which it visited.
+	#[pallet::storage]
+	pub type SplitCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (Rank, Rank, bool, MembersCursorOf<T, I>)>;
+
+	/// The last key of `Members` whose activity `on_initialize` checked, if its walk over the
+	/// members is unfinished.
+	#[pallet::storage]
+	pub type InactivityCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, MembersCursorOf<T, I>>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// The initial members of the collective, each with their rank.
+		pub members: Vec<(T::AccountId, Rank)>,
+		pub phantom: PhantomData<I>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self { members: Default::default(), phantom: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			let accounts: BTreeSet<_> = self.members.iter().map(|(who, _)| who).collect();
+			assert_eq!(
+				accounts.len(),
+				self.members.len(),
+				"Members cannot contain duplicate accounts."
+			);
+			// Every member holds rank 0, so no rank has more members than it.
+			assert!(
+				self.members.len() <= T::MaxMembersPerRank::get() as usize,
+				"Members cannot be more than `MaxMembersPerRank`."
+			);
+
+			for (who, rank) in &self.members {
+				for r in 0..=*rank {
+					Pallet::<T, I>::add_to_rank(who, r)
+						.expect("members are no more than `MaxMembersPerRank`; qed");
+				}
+				Members::<T, I>::insert(who, MemberRecord::new(*rank));
+				Pallet::<T, I>::note_membership_change(who, Some(*rank));
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A member `who` has been added.
+		MemberAdded { who: T::AccountId },
+		/// The member `who`se rank has been changed to the given `rank`.
+		RankChanged { who: T::AccountId, rank: Rank },
+		/// The member `who` of given `rank` has been removed from the collective.
+		MemberRemoved { who: T::AccountId, rank: Rank },
+		/// The member `who` has voted for the `poll` with the given `vote` leading to an updated
+		/// `tally`. The vote counts once towards the bare tally, and carries the `rank_votes` of
+		/// the rank of `who` along with the votes of their delegators. An abstention carries no
+		/// votes, `rank_votes` being those an aye or nay would have carried.
+		Voted {
+			who: T::AccountId,
+			poll: PollIndexOf<T, I>,
+			vote: VoteRecord,
+			rank_votes: Votes,
+			tally: TallyOf<T, I>,
+		},
+		/// The member `who` has voted for the `poll` with the given `vote`. Emitted instead of
+		/// `Voted` when `CompactVoteEvents` is set.
+		VotedCompact { who: T::AccountId, poll: PollIndexOf<T, I>, vote: VoteRecord },
+		/// All votes of the finished `poll` have been removed, `votes_removed` in total. Not
+		/// emitted for polls without votes.
+		PollFinishedCleanup { poll: PollIndexOf<T, I>, votes_removed: u32 },
+		/// A `panel` of members has been selected to decide on the `poll`.
+		PanelSelected { poll: PollIndexOf<T, I>, panel: BoundedVec<T::AccountId, T::MaxPanelSize> },
+		/// The member `who` of given `rank` has resigned from the collective. If `cooldown_until`
+		/// is given, they may not be added back before that block.
+		MemberResigned { who: T::AccountId, rank: Rank, cooldown_until: Option<T::BlockNumber> },
+		/// Votes on the polls of `class` are no longer accepted.
+		ClassFrozen { class: ClassOf<T, I> },
+		/// Votes on the polls of `class` are accepted again.
+		ClassUnfrozen { class: ClassOf<T, I> },
+		/// The member `who` has vetoed the `poll`, which now has `vetoes` vetoes in its veto
+		/// window.
+		VetoRegistered { who: T::AccountId, poll: PollIndexOf<T, I>, vetoes: u32 },
+		/// The veto window of the `poll` closed before enough vetoes were registered, and its
+		/// `vetoes` vetoes lapsed.
+		VetoesLapsed { poll: PollIndexOf<T, I>, vetoes: u32 },
+		/// The `poll` has been vetoed by the given members, and its provider notified.
+		PollVetoed { poll: PollIndexOf<T, I>, vetoers: Vec<T::AccountId> },
+		/// The member `who` was marked as the subject of the ongoing disciplinary `poll`.
+		MarkedUnderReview { who: T::AccountId, poll: PollIndexOf<T, I> },
+		/// The disciplinary `poll` of which the member `who` was the subject has ended.
+		ReviewEnded { who: T::AccountId, poll: PollIndexOf<T, I> },
+		/// The `poll` was decided, and its certificate stored.
+		TallyCertified { poll: PollIndexOf<T, I>, approved: bool },
+		/// The member `who` delegated their votes on the polls of `class` to the member `to`.
+		Delegated { who: T::AccountId, class: ClassOf<T, I>, to: T::AccountId },
+		/// The member `who` no longer delegates their votes on the polls of `class`.
+		Undelegated { who: T::AccountId, class: ClassOf<T, I> },
+		/// The account of the member `who` has been exchanged for `new_who`.
+		MemberExchanged { who: T::AccountId, new_who: T::AccountId },
+		/// `votes` votes have been re-weighed from `old_rank` to `new_rank`, and the walk over the
+		/// votes is `complete` or not.
+		ClassRemapped { old_rank: Rank, new_rank: Rank, votes: u32, complete: bool },
+		/// The account `who` has been added as a member on probation.
+		MemberInducted { who: T::AccountId },
+		/// The member `who` has ended their probation and become a full member.
+		MemberConfirmed { who: T::AccountId },
+		/// The vote-weight scheme has been set to `curve`, or back to `VoteWeight` if `None`.
+		VoteCurveSet { curve: Option<VoteCurve> },
+		/// The member `who` has been exported with their `rank` into the `SplitTarget`.
+		MemberExported { who: T::AccountId, rank: Rank },
+		/// The member `who` of `rank` could not be exported into the `SplitTarget`.
+		MemberNotExported { who: T::AccountId, rank: Rank, error: DispatchError },
+		/// A `split_members` call exported `exported` members of ranks `min_rank` to `max_rank`,
+		/// and the walk over the members is `complete` or not.
+		MembersSplit { min_rank: Rank, max_rank: Rank, exported: u32, complete: bool },
+		/// The member `who` of `rank` has been demoted for not being promoted, demoted or voting
+		/// since block `last_activity`.
+		DemotedForInactivity { who: T::AccountId, rank: Rank, last_activity: T::BlockNumber },
+		/// The member `who` has retracted their `vote` on the `poll`.
+		VoteRetracted { who: T::AccountId, poll: PollIndexOf<T, I>, vote: VoteRecord },
+		/// The members of `rank` may vote on the polls of classes with a minimum rank of at most
+		/// `limit`, or of any class they are ranked for if `None`.
+		ClassLimitSet { rank: Rank, limit: Option<Rank> },
+		/// The `rank` of `who`, if they are a member, differs from the `source_rank` of the
+		/// `RankSource` and is left for the `AdminOrigin` to resolve.
+		RankConflict { who: T::AccountId, rank: Option<Rank>, source_rank: Rank },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Account is already a member.
+		AlreadyMember,
+		/// Account is not a member.
+		NotMember,
+		/// The given poll index is unknown or has closed.
+		NotPolling,
+		/// The given poll is still ongoing.
+		Ongoing,
+		/// There are no further records to be removed.
+		NoneRemaining,
+		/// Unexpected error in state.
+		Corruption,
+		/// The member's rank is too low to vote.
+		RankTooLow,
+		/// The information provided is incorrect.
+		InvalidWitness,
+		/// The origin is not sufficiently privileged to do the operation.
+		NoPermission,
+		/// A panel has already been selected for the poll.
+		PanelAlreadySelected,
+		/// The poll already has votes, so a panel can no longer be selected for it.
+		PollHasVotes,
+		/// The panel size is zero, exceeds `MaxPanelSize` or the number of eligible members.
+		InvalidPanelSize,
+		/// The member is not part of the panel selected for the poll.
+		NotInPanel,
+		/// The account resigned recently and may not be added back until its cooldown ends.
+		InCooldown,
+		/// Votes on the polls of the class are not accepted for the time being.
+		ClassFrozen,
+		/// The class is not frozen.
+		NotFrozen,
+		/// Vetoes are disabled.
+		VetoDisabled,
+		/// The member has already vetoed the poll in its current veto window.
+		AlreadyVetoed,
+		/// The member is the subject of an ongoing disciplinary poll.
+		MemberUnderReview,
+		/// The member already delegates their votes on the polls of the class.
+		AlreadyDelegating,
+		/// The member does not delegate their votes on the polls of the class.
+		NotDelegating,
+		/// Votes may not be delegated to oneself, to a member who delegates their own, or by a
+		/// member to whom others delegate.
+		InvalidDelegate,
+		/// The delegate already has `MaxDelegators` delegators on the class.
+		TooManyDelegators,
+		/// The votes of the member on the poll are delegated, or were counted with the vote of
+		/// their delegate.
+		VoteDelegated,
+		/// The member is on probation.
+		Probationary,
+		/// The member is not on probation.
+		NotProbationary,
+		/// The points of the vote curve are missing or not sorted.
+		InvalidVoteCurve,
+		/// The rank already has `MaxMembersPerRank` members.
+		TooManyMembers,
+		/// The account has not voted on the poll.
+		NotVoted,
+		/// The members of the account's rank may not vote on the polls of this class.
+		ClassNotAllowed,
+		/// The account has no rank in the `RankSource`.
+		NotInSource,
+		/// The rank of the account already follows from that of the `RankSource`.
+		AlreadySynced,
+		/// The new account has votes on ongoing polls, which it kept from an earlier membership.
+		NewAccountVoted,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::demote_inactive_members(now, T::MaxInactivityChecks::get())
+		}
+
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::cleanup_completed_polls(remaining_weight.min(T::IdleCleanupWeight::get()))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Introduce a new member.
+		///
+		/// - `origin`: Must be the `PromoteOrigin` or pass `MembershipOrigin` for `Add`.
+		/// - `who`: Account of non-member which will become a member.
+		/// - `rank`: The rank to give the new member.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::add_member())]
 		pub fn add_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
-			let _ = T::PromoteOrigin::ensure_origin(origin)?;
-			let who = T::Lookup::lookup(who)?;
+			let (who, _) = Self::ensure_membership_origin(origin, who, MemberOperation::Add)?;
 			Self::do_add_member(who)
 		}
 
 		/// Increment the rank of an existing member by one.
 		///
-		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `origin`: Must be the `PromoteOrigin` or pass `MembershipOrigin` for `Promote`.
 		/// - `who`: Account of existing member.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::promote_member(0))]
 		pub fn promote_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
-			let max_rank = T::PromoteOrigin::ensure_origin(origin)?;
-			let who = T::Lookup::lookup(who)?;
+			let (who, max_rank) =
+				Self::ensure_membership_origin(origin, who, MemberOperation::Promote)?;
 			Self::do_promote_member(who, Some(max_rank))
 		}
 
 		/// Decrement the rank of an existing member by one. If the member is already at rank zero,
 		/// then they are removed entirely.
 		///
-		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `origin`: Must be the `DemoteOrigin` or pass `MembershipOrigin` for `Demote`.
 		/// - `who`: Account of existing member of rank greater than zero.
 		///
 		/// Weight: `O(1)`, less if the member's index is highest in its rank.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::demote_member(0))]
 		pub fn demote_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
-			let max_rank = T::DemoteOrigin::ensure_origin(origin)?;
-			let who = T::Lookup::lookup(who)?;
-			let mut record = Self::ensure_member(&who)?;
-			let rank = record.rank;
-			ensure!(max_rank >= rank, Error::<T, I>::NoPermission);
+			let (who, max_rank) =
+				Self::ensure_membership_origin(origin, who, MemberOperation::Demote)?;
+			let record = Self::ensure_member(&who)?;
+			ensure!(max_rank >= record.rank, Error::<T, I>::NoPermission);
+			Self::ensure_not_under_review(&who)?;
 
-			Self::remove_from_rank(&who, rank)?;
-			let maybe_rank = rank.checked_sub(1);
-			match maybe_rank {
-				None => {
-					Members::<T, I>::remove(&who);
-					Self::deposit_event(Event::MemberRemoved { who, rank: 0 });
-				},
-				Some(rank) => {
-					record.rank = rank;
-					Members::<T, I>::insert(&who, &record);
-					Self::deposit_event(Event::RankChanged { who, rank });
-				},
-			}
-			Ok(())
+			Self::do_demote_member(who)
 		}
 
 		/// Remove the member entirely.
 		///
-		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `origin`: Must be the `DemoteOrigin` or pass `MembershipOrigin` for `Remove`.
 		/// - `who`: Account of existing member of rank greater than zero.
 		/// - `min_rank`: The rank of the member or greater.
 		///
 		/// Weight: `O(min_rank)`.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::remove_member(*min_rank as u32))]
+		#[pallet::weight(T::WeightInfo::remove_member(ranked::rank_to_u32(*min_rank)))]
 		pub fn remove_member(
 			origin: OriginFor<T>,
 			who: AccountIdLookupOf<T>,
 			min_rank: Rank,
 		) -> DispatchResultWithPostInfo {
-			let max_rank = T::DemoteOrigin::ensure_origin(origin)?;
-			let who = T::Lookup::lookup(who)?;
+			let (who, max_rank) =
+				Self::ensure_membership_origin(origin, who, MemberOperation::Remove)?;
 			let MemberRecord { rank, .. } = Self::ensure_member(&who)?;
 			ensure!(min_rank >= rank, Error::<T, I>::InvalidWitness);
 			ensure!(max_rank >= rank, Error::<T, I>::NoPermission);
+			Self::ensure_not_under_review(&who)?;
 
-			for r in 0..=rank {
-				Self::remove_from_rank(&who, r)?;
-			}
-			Members::<T, I>::remove(&who);
-			Self::deposit_event(Event::MemberRemoved { who, rank });
+			Self::do_remove_member(who)?;
 			Ok(PostDispatchInfo {
-				actual_weight: Some(T::WeightInfo::remove_member(rank as u32)),
+				actual_weight: Some(T::WeightInfo::remove_member(ranked::rank_to_u32(rank))),
 				pays_fee: Pays::Yes,
 			})
 		}
@@ -562,55 +1703,23 @@ pub mod pallet {
 		/// - `aye`: `true` if the vote is to approve the proposal, `false` otherwise.
 		///
 		/// Transaction fees are be waived if the member is voting on any particular proposal
-		/// for the first time and the call is successful. Subsequent vote changes will charge a
-		/// fee.
+		/// for the first time and the call is successful, up to `MaxFreeVotes` times in each
+		/// `FreeVotePeriod`. Subsequent vote changes will charge a fee.
 		///
-		/// Weight: `O(1)`, less if there was no previous vote on the poll by the member.
+		/// Weight: `O(D)` where `D` is the number of members delegating to the sender on the
+		/// poll's class, less if the vote of the member on the poll is unchanged.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::vote())]
+		#[pallet::weight(
+			T::WeightInfo::vote_new(T::MaxDelegators::get())
+				.max(T::WeightInfo::vote_existing(T::MaxDelegators::get()))
+		)]
 		pub fn vote(
 			origin: OriginFor<T>,
 			poll: PollIndexOf<T, I>,
 			aye: bool,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let record = Self::ensure_member(&who)?;
-			use VoteRecord::*;
-			let mut pays = Pays::Yes;
-
-			let (tally, vote) = T::Polls::try_access_poll(
-				poll,
-				|mut status| -> Result<(TallyOf<T, I>, VoteRecord), DispatchError> {
-					match status {
-						PollStatus::None | PollStatus::Completed(..) =>
-							Err(Error::<T, I>::NotPolling)?,
-						PollStatus::Ongoing(ref mut tally, class) => {
-							match Voting::<T, I>::get(&poll, &who) {
-								Some(Aye(votes)) => {
-									tally.bare_ayes.saturating_dec();
-									tally.ayes.saturating_reduce(votes);
-								},
-								Some(Nay(votes)) => tally.nays.saturating_reduce(votes),
-								None => pays = Pays::No,
-							}
-							let min_rank = T::MinRankOfClass::convert(class);
-							let votes = Self::rank_to_votes(record.rank, min_rank)?;
-							let vote = VoteRecord::from((aye, votes));
-							match aye {
-								true => {
-									tally.bare_ayes.saturating_inc();
-									tally.ayes.saturating_accrue(votes);
-								},
-								false => tally.nays.saturating_accrue(votes),
-							}
-							Voting::<T, I>::insert(&poll, &who, &vote);
-							Ok((tally.clone(), vote))
-						},
-					}
-				},
-			)?;
-			Self::deposit_event(Event::Voted { who, poll, vote, tally });
-			Ok(pays.into())
+			Self::do_vote(who, poll, Some(aye))
 		}
 
 		/// Remove votes from the given poll. It must have ended.
@@ -618,7 +1727,7 @@ pub mod pallet {
 		/// - `origin`: Must be `Signed` by any account.
 		/// - `poll_index`: Index of a poll which is completed and for which votes continue to
 		///   exist.
-		/// - `max`: Maximum number of vote items from remove in this call.
+		/// - `max`: Maximum number of pages of votes to remove in this call.
 		///
 		/// Transaction fees are waived if the operation is successful.
 		///
@@ -633,94 +1742,1892 @@ pub mod pallet {
 			ensure_signed(origin)?;
 			ensure!(T::Polls::as_ongoing(poll_index).is_none(), Error::<T, I>::Ongoing);
 
-			let r = Voting::<T, I>::clear_prefix(
-				poll_index,
-				max,
-				VotingCleanup::<T, I>::take(poll_index).as_ref().map(|c| &c[..]),
-			);
-			if r.unique == 0 {
+			let (removed, _) = Self::clear_votes(poll_index, max);
+			if removed == 0 {
 				// return Err(Error::<T, I>::NoneRemaining)
 				return Ok(Pays::Yes.into())
 			}
-			if let Some(cursor) = r.maybe_cursor {
-				VotingCleanup::<T, I>::insert(poll_index, BoundedVec::truncate_from(cursor));
-			}
 			Ok(PostDispatchInfo {
-				actual_weight: Some(T::WeightInfo::cleanup_poll(r.unique)),
+				actual_weight: Some(T::WeightInfo::cleanup_poll(removed)),
 				pays_fee: Pays::No,
 			})
 		}
-	}
 
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		fn ensure_member(who: &T::AccountId) -> Result<MemberRecord, DispatchError> {
-			Members::<T, I>::get(who).ok_or(Error::<T, I>::NotMember.into())
+		/// Select a panel of members at random to decide on the given poll.
+		///
+		/// The panel is drawn from the members of at least the minimum rank of the poll's class.
+		/// Once it is selected, only the votes of panel members are accepted for the poll, and its
+		/// support is measured against the size of the panel.
+		///
+		/// - `origin`: Must be the `PanelOrigin`.
+		/// - `poll`: Index of a poll which is ongoing and has no votes yet.
+		/// - `size`: The number of members to select.
+		/// - `seed`: Arbitrary value which is mixed into the on-chain randomness.
+		///
+		/// Weight: `O(size)`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::select_panel(*size))]
+		pub fn select_panel(
+			origin: OriginFor<T>,
+			poll: PollIndexOf<T, I>,
+			size: MemberIndex,
+			seed: u64,
+		) -> DispatchResult {
+			T::PanelOrigin::ensure_origin(origin)?;
+			ensure!(!Panels::<T, I>::contains_key(poll), Error::<T, I>::PanelAlreadySelected);
+			let panel = T::Polls::try_access_poll(poll, |mut status| match status {
+				PollStatus::Ongoing(ref mut tally, class) => {
+					ensure!(tally.turnout() == 0, Error::<T, I>::PollHasVotes);
+					let panel = Self::draw_panel(class, size, seed)?;
+					tally.max_voters = Some(panel.len() as MemberIndex);
+					Ok(panel)
+				},
+				PollStatus::None | PollStatus::Completed(..) =>
+					Err(Error::<T, I>::NotPolling.into()),
+			})?;
+			Panels::<T, I>::insert(poll, &panel);
+			ActivePolls::<T, I>::insert(poll, ());
+			Self::deposit_event(Event::PanelSelected { poll, panel });
+			Ok(())
 		}
 
-		fn rank_to_votes(rank: Rank, min: Rank) -> Result<Votes, DispatchError> {
-			let excess = rank.checked_sub(min).ok_or(Error::<T, I>::RankTooLow)?;
-			Ok(T::VoteWeight::convert(excess))
+		/// Leave the collective, giving up all ranks.
+		///
+		/// - `origin`: Must be `Signed` by a member account.
+		/// - `min_rank`: The rank of the member or greater.
+		/// - `cooldown`: Whether the account should be barred from being added back to the
+		///   collective for `ResignationCooldown` blocks.
+		///
+		/// Weight: `O(min_rank)`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::resign(ranked::rank_to_u32(*min_rank)))]
+		pub fn resign(
+			origin: OriginFor<T>,
+			min_rank: Rank,
+			cooldown: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let record = Self::ensure_member(&who)?;
+			let rank = record.rank;
+			ensure!(min_rank >= rank, Error::<T, I>::InvalidWitness);
+
+			Self::forget_member(&who, &record)?;
+			let cooldown_until = cooldown.then(|| {
+				let until = frame_system::Pallet::<T>::block_number()
+					.saturating_add(T::ResignationCooldown::get());
+				ResignedUntil::<T, I>::insert(&who, until);
+				until
+			});
+			Self::deposit_event(Event::MemberResigned { who, rank, cooldown_until });
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::resign(ranked::rank_to_u32(rank))),
+				pays_fee: Pays::Yes,
+			})
 		}
 
-		fn remove_from_rank(who: &T::AccountId, rank: Rank) -> DispatchResult {
-			let last_index = MemberCount::<T, I>::get(rank).saturating_sub(1);
-			let index = IdToIndex::<T, I>::get(rank, &who).ok_or(Error::<T, I>::Corruption)?;
-			if index != last_index {
-				let last =
-					IndexToId::<T, I>::get(rank, last_index).ok_or(Error::<T, I>::Corruption)?;
-				IdToIndex::<T, I>::insert(rank, &last, index);
-				IndexToId::<T, I>::insert(rank, index, &last);
-			}
-			MemberCount::<T, I>::mutate(rank, |r| r.saturating_dec());
-			Ok(())
+		/// Set the rank of each of several accounts at once, adding those which are not members.
+		///
+		/// - `origin`: Must be Root.
+		/// - `changes`: The accounts together with the rank each of them should have.
+		/// - `max_rank`: The highest rank held by any of the accounts in `changes`, before or after
+		///   the change, or greater.
+		/// - `witness`: The `rank_changes_witness` of `changes`, taken against the membership the
+		///   changes were prepared for. The call fails if the membership has drifted since.
+		///
+		/// All changes are applied or none is.
+		///
+		/// Weight: `O(changes * max_rank)`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::force_set_ranks(changes.len() as u32, ranked::rank_to_u32(*max_rank))
+		)]
+		pub fn force_set_ranks(
+			origin: OriginFor<T>,
+			changes: BoundedVec<(T::AccountId, Rank), T::MaxRankChanges>,
+			max_rank: Rank,
+			witness: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(Self::rank_changes_witness(&changes) == witness, Error::<T, I>::InvalidWitness);
+
+			let mut highest = 0;
+			for (who, rank) in changes.iter() {
+				let current = Members::<T, I>::get(who).map(|r| r.rank);
+				highest = highest.max(*rank).max(current.unwrap_or_default());
+				ensure!(max_rank >= highest, Error::<T, I>::InvalidWitness);
+				Self::do_set_rank(who.clone(), current, *rank)?;
+			}
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::force_set_ranks(
+					changes.len() as u32,
+					ranked::rank_to_u32(highest),
+				)),
+				pays_fee: Pays::Yes,
+			})
 		}
 
-		/// Adds a member into the ranked collective at level 0.
+		/// Stop accepting votes on the polls of a class, e.g. while the membership is being
+		/// reorganised.
 		///
-		/// No origin checks are executed.
-		pub fn do_add_member(who: T::AccountId) -> DispatchResult {
-			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
-			let index = MemberCount::<T, I>::get(0);
-			let count = index.checked_add(1).ok_or(Overflow)?;
+		/// - `origin`: Must be Root.
+		/// - `class`: The class of polls to freeze.
+		///
+		/// The `Polls` provider is notified through `Polling::on_class_frozen`.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::freeze_class())]
+		pub fn freeze_class(origin: OriginFor<T>, class: ClassOf<T, I>) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!FrozenClasses::<T, I>::contains_key(&class), Error::<T, I>::ClassFrozen);
+			FrozenClasses::<T, I>::insert(&class, ());
+			T::Polls::on_class_frozen(&class, true);
+			Self::deposit_event(Event::ClassFrozen { class });
+			Ok(())
+		}
 
-			Members::<T, I>::insert(&who, MemberRecord { rank: 0 });
-			IdToIndex::<T, I>::insert(0, &who, index);
-			IndexToId::<T, I>::insert(0, index, &who);
-			MemberCount::<T, I>::insert(0, count);
-			Self::deposit_event(Event::MemberAdded { who });
+		/// Accept votes on the polls of a class again, after it was frozen with `freeze_class`.
+		///
+		/// - `origin`: Must be Root.
+		/// - `class`: The class of polls to unfreeze.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::unfreeze_class())]
+		pub fn unfreeze_class(origin: OriginFor<T>, class: ClassOf<T, I>) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(FrozenClasses::<T, I>::take(&class).is_some(), Error::<T, I>::NotFrozen);
+			T::Polls::on_class_frozen(&class, false);
+			Self::deposit_event(Event::ClassUnfrozen { class });
 			Ok(())
 		}
 
-		/// Promotes a member in the ranked collective into the next role.
+		/// Veto an ongoing poll.
 		///
-		/// A `maybe_max_rank` may be provided to check that the member does not get promoted beyond
-		/// a certain rank. Is `None` is provided, then the rank will be incremented without checks.
-		pub fn do_promote_member(
-			who: T::AccountId,
-			maybe_max_rank: Option<Rank>,
-		) -> DispatchResult {
+		/// The first veto of a poll opens its veto window of `VetoPeriod` blocks. Once
+		/// `VetoThreshold` members have vetoed the poll within the window, the `Polls` provider
+		/// is notified through `Polling::on_vetoed`, which may cancel or suspend the poll. Vetoes
+		/// which are still short of the threshold when the window closes lapse, and the next veto
+		/// opens a new window.
+		///
+		/// - `origin`: Must be `Signed` by a member account of at least `VetoRank`.
+		/// - `poll`: Index of a poll which is ongoing.
+		///
+		/// Weight: `O(VetoThreshold)`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::veto())]
+		pub fn veto(origin: OriginFor<T>, poll: PollIndexOf<T, I>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let threshold = T::VetoThreshold::get();
+			ensure!(threshold > 0, Error::<T, I>::VetoDisabled);
 			let record = Self::ensure_member(&who)?;
-			let rank = record.rank.checked_add(1).ok_or(Overflow)?;
-			if let Some(max_rank) = maybe_max_rank {
-				ensure!(max_rank >= rank, Error::<T, I>::NoPermission);
+			ensure!(!record.probationary, Error::<T, I>::Probationary);
+			ensure!(record.rank >= T::VetoRank::get(), Error::<T, I>::RankTooLow);
+			ensure!(T::Polls::as_ongoing(poll).is_some(), Error::<T, I>::NotPolling);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let (since, mut vetoers) = match Vetoes::<T, I>::get(poll) {
+				Some((since, vetoers)) if now < since.saturating_add(T::VetoPeriod::get()) =>
+					(since, vetoers),
+				maybe_lapsed => {
+					if let Some((_, lapsed)) = maybe_lapsed {
+						let vetoes = lapsed.len() as u32;
+						Self::deposit_event(Event::VetoesLapsed { poll, vetoes });
+					}
+					(now, BoundedVec::default())
+				},
+			};
+			ensure!(!vetoers.contains(&who), Error::<T, I>::AlreadyVetoed);
+			vetoers.try_push(who.clone()).map_err(|_| Error::<T, I>::Corruption)?;
+
+			let vetoes = vetoers.len() as u32;
+			Self::deposit_event(Event::VetoRegistered { who, poll, vetoes });
+			if vetoes >= threshold {
+				Vetoes::<T, I>::remove(poll);
+				T::Polls::on_vetoed(poll);
+				Self::deposit_event(Event::PollVetoed { poll, vetoers: vetoers.into_inner() });
+			} else {
+				Vetoes::<T, I>::insert(poll, (since, vetoers));
+				ActivePolls::<T, I>::insert(poll, ());
 			}
-			let index = MemberCount::<T, I>::get(rank);
-			MemberCount::<T, I>::insert(rank, index.checked_add(1).ok_or(Overflow)?);
-			IdToIndex::<T, I>::insert(rank, &who, index);
-			IndexToId::<T, I>::insert(rank, index, &who);
-			Members::<T, I>::insert(&who, MemberRecord { rank });
-			Self::deposit_event(Event::RankChanged { who, rank });
 			Ok(())
 		}
 
-		/// Add a member to the rank collective, and continue to promote them until a certain rank
-		/// is reached.
-		pub fn do_add_member_to_rank(who: T::AccountId, rank: Rank) -> DispatchResult {
-			Self::do_add_member(who.clone())?;
-			for _ in 0..rank {
-				Self::do_promote_member(who.clone(), None)?;
+		/// Mark a member as the subject of an ongoing disciplinary poll.
+		///
+		/// Until the poll ends, the member may not be demoted or removed, so that the outcome of
+		/// the poll is not preempted.
+		///
+		/// - `origin`: Must pass the `ReviewOrigin` for `who` and `poll`.
+		/// - `who`: Account of an existing member.
+		/// - `poll`: Index of a poll which is ongoing and about `who`.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::mark_under_review())]
+		pub fn mark_under_review(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			poll: PollIndexOf<T, I>,
+		) -> DispatchResult {
+			let who = T::Lookup::lookup(who)?;
+			T::ReviewOrigin::ensure_origin(origin, &(who.clone(), poll))?;
+			Self::ensure_member(&who)?;
+			ensure!(T::Polls::as_ongoing(poll).is_some(), Error::<T, I>::NotPolling);
+			if let Some(current) = UnderReview::<T, I>::get(&who) {
+				ensure!(T::Polls::as_ongoing(current).is_none(), Error::<T, I>::MemberUnderReview);
 			}
+
+			UnderReview::<T, I>::insert(&who, poll);
+			Self::deposit_event(Event::MarkedUnderReview { who, poll });
 			Ok(())
 		}
+
+		/// Record an explicit abstention of the sender on the given poll, replacing any aye or nay
+		/// vote they cast on it.
+		///
+		/// An abstention counts towards the turnout of the poll, but neither towards its approval
+		/// nor its support.
+		///
+		/// - `origin`: Must be `Signed` by a member account.
+		/// - `poll`: Index of a poll which is ongoing.
+		///
+		/// Transaction fees are waived as for `vote`.
+		///
+		/// Weight: `O(D)` where `D` is the number of members delegating to the sender on the
+		/// poll's class, less if the vote of the member on the poll is unchanged.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::WeightInfo::vote_new(T::MaxDelegators::get())
+				.max(T::WeightInfo::vote_existing(T::MaxDelegators::get()))
+		)]
+		pub fn abstain(
+			origin: OriginFor<T>,
+			poll: PollIndexOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_vote(who, poll, None)
+		}
+
+		/// Exchange the account of a member for a new account, which takes over their rank, their
+		/// indices and their votes on ongoing polls.
+		///
+		/// The votes of the member, their seats on panels and their vetoes on the `ActivePolls`
+		/// are moved to the new account, as are the delegations to and from the member.
+		///
+		/// - `origin`: Must be the `ExchangeOrigin`, or `Signed` by `who`.
+		/// - `who`: Account of an existing member.
+		/// - `new_who`: Account which is not a member.
+		/// - `min_rank`: The rank of the member or greater.
+		/// - `polls`: The number of `ActivePolls` or greater.
+		///
+		/// Weight: `O(min_rank + polls)`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::exchange_member(ranked::rank_to_u32(*min_rank), *polls))]
+		pub fn exchange_member(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			new_who: AccountIdLookupOf<T>,
+			min_rank: Rank,
+			polls: u32,
+		) -> DispatchResultWithPostInfo {
+			let maybe_signer = match T::ExchangeOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let who = T::Lookup::lookup(who)?;
+			let new_who = T::Lookup::lookup(new_who)?;
+			if let Some(signer) = maybe_signer {
+				ensure!(signer == who, DispatchError::BadOrigin);
+			}
+			let MemberRecord { rank, .. } = Self::ensure_member(&who)?;
+			ensure!(min_rank >= rank, Error::<T, I>::InvalidWitness);
+			let active = ActivePolls::<T, I>::count();
+			ensure!(polls >= active, Error::<T, I>::InvalidWitness);
+
+			Self::do_exchange_member(who, new_who)?;
+			Ok(Some(T::WeightInfo::exchange_member(ranked::rank_to_u32(rank), active)).into())
+		}
+
+		/// Introduce several new members at once.
+		///
+		/// - `origin`: Must be the `PromoteOrigin` or pass `MembershipOrigin` for `Add` on each
+		///   account.
+		/// - `who`: Accounts of non-members which will become members.
+		///
+		/// All accounts are added or none is.
+		///
+		/// Weight: `O(who)`
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::add_member().saturating_mul(who.len() as u64))]
+		pub fn add_members(
+			origin: OriginFor<T>,
+			who: BoundedVec<T::AccountId, T::MaxRankChanges>,
+		) -> DispatchResult {
+			Self::ensure_membership_origins(origin, &who, MemberOperation::Add)?;
+			for who in who {
+				Self::do_add_member(who)?;
+			}
+			Ok(())
+		}
+
+		/// Increment the rank of several existing members by one at once.
+		///
+		/// - `origin`: Must be the `PromoteOrigin` or pass `MembershipOrigin` for `Promote` on each
+		///   account.
+		/// - `who`: Accounts of existing members.
+		///
+		/// All members are promoted or none is.
+		///
+		/// Weight: `O(who)`
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::promote_member(0).saturating_mul(who.len() as u64))]
+		pub fn promote_members(
+			origin: OriginFor<T>,
+			who: BoundedVec<T::AccountId, T::MaxRankChanges>,
+		) -> DispatchResult {
+			let max_ranks =
+				Self::ensure_membership_origins(origin, &who, MemberOperation::Promote)?;
+			for (who, max_rank) in who.into_iter().zip(max_ranks) {
+				Self::do_promote_member(who, Some(max_rank))?;
+			}
+			Ok(())
+		}
+
+		/// Remove several members entirely at once.
+		///
+		/// - `origin`: Must be the `DemoteOrigin` or pass `MembershipOrigin` for `Remove` on each
+		///   account.
+		/// - `who`: Accounts of existing members.
+		/// - `min_rank`: The highest rank of the members or greater.
+		///
+		/// All members are removed or none is.
+		///
+		/// Weight: `O(who * min_rank)`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(
+			T::WeightInfo::remove_member(ranked::rank_to_u32(*min_rank))
+				.saturating_mul(who.len() as u64)
+		)]
+		pub fn remove_members(
+			origin: OriginFor<T>,
+			who: BoundedVec<T::AccountId, T::MaxRankChanges>,
+			min_rank: Rank,
+		) -> DispatchResultWithPostInfo {
+			let max_ranks = Self::ensure_membership_origins(origin, &who, MemberOperation::Remove)?;
+			let mut actual_weight = Weight::zero();
+			for (who, max_rank) in who.into_iter().zip(max_ranks) {
+				let MemberRecord { rank, .. } = Self::ensure_member(&who)?;
+				ensure!(min_rank >= rank, Error::<T, I>::InvalidWitness);
+				ensure!(max_rank >= rank, Error::<T, I>::NoPermission);
+				Self::ensure_not_under_review(&who)?;
+
+				Self::do_remove_member(who)?;
+				actual_weight
+					.saturating_accrue(T::WeightInfo::remove_member(ranked::rank_to_u32(rank)));
+			}
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+		}
+
+		/// Delegate the votes of the sender on the polls of `class` to another member.
+		///
+		/// Whenever the delegate votes aye or nay on a poll of the class, the vote weight of the
+		/// sender is added to their vote, unless the sender voted on the poll themselves. It
+		/// counts towards the approval of the poll, but not towards its support, and only if the
+		/// sender is on the panel of the poll, if it has one. While delegating, the sender may not
+		/// vote on the polls of the class.
+		///
+		/// - `origin`: Must be `Signed` by a member account.
+		/// - `class`: The class of polls on which to delegate.
+		/// - `to`: The member to delegate to. They may not delegate on `class` themselves.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			to: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			ensure!(
+				!Self::ensure_member(&who)?.probationary && !Self::ensure_member(&to)?.probationary,
+				Error::<T, I>::Probationary
+			);
+			ensure!(
+				!Delegations::<T, I>::contains_key(&class, &who),
+				Error::<T, I>::AlreadyDelegating
+			);
+			ensure!(
+				who != to &&
+					!Delegations::<T, I>::contains_key(&class, &to) &&
+					Delegators::<T, I>::decode_len(&class, &who).unwrap_or(0) == 0,
+				Error::<T, I>::InvalidDelegate
+			);
+			Delegators::<T, I>::try_mutate(&class, &to, |delegators| {
+				delegators.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyDelegators)
+			})?;
+			Delegations::<T, I>::insert(&class, &who, &to);
+			Self::deposit_event(Event::Delegated { who, class, to });
+			Ok(())
+		}
+
+		/// Stop delegating the votes of the sender on the polls of `class`.
+		///
+		/// The vote weight of the sender is taken back from the votes which the delegate has
+		/// already cast on the `ActivePolls` of the class, so that the sender may vote on those
+		/// polls themselves.
+		///
+		/// - `origin`: Must be `Signed` by an account which delegates on `class`.
+		/// - `class`: The class of polls on which to stop delegating.
+		/// - `polls`: The number of `ActivePolls` or greater.
+		///
+		/// Weight: `O(polls)`
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::undelegate(*polls))]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			polls: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let active = ActivePolls::<T, I>::count();
+			ensure!(polls >= active, Error::<T, I>::InvalidWitness);
+			let to = Delegations::<T, I>::take(&class, &who).ok_or(Error::<T, I>::NotDelegating)?;
+			Delegators::<T, I>::mutate(&class, &to, |delegators| {
+				delegators.retain(|delegator| delegator != &who)
+			});
+			Self::withdraw_delegated_votes(&who, &to, &class);
+			Self::deposit_event(Event::Undelegated { who, class });
+			Ok(Some(T::WeightInfo::undelegate(active)).into())
+		}
+
+		/// Re-weigh the votes on ongoing polls after a runtime upgrade changed the minimum rank of
+		/// their class from `old_rank` to `new_rank`.
+		///
+		/// - `origin`: Must be Root.
+		/// - `old_rank`: The minimum rank of the class when the votes were cast.
+		/// - `new_rank`: The minimum rank of the class now. Polls of other classes are skipped.
+		/// - `max_votes`: Maximum number of votes to visit in this call.
+		///
+		/// Each aye or nay whose weight is that of its voter's rank under `old_rank` is given the
+		/// weight of the rank under `new_rank`, and the tally of its poll is adjusted through the
+		/// `Polls` provider. The votes of members below `new_rank` are cancelled. Other votes, such
+		/// as those cast since the upgrade or carrying delegated votes, are left unchanged. The
+		/// polls keep their class.
+		///
+		/// A call continues where the last call with the same ranks stopped, until
+		/// `ClassRemapped` reports the walk to be complete.
+		///
+		/// Weight: `O(max_votes)`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::remap_class(*max_votes))]
+		pub fn remap_class(
+			origin: OriginFor<T>,
+			old_rank: Rank,
+			new_rank: Rank,
+			max_votes: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let resume = match ClassRemapCursor::<T, I>::take() {
+				Some((old, new, poll, id)) if (old, new) == (old_rank, new_rank) =>
+					Some((poll, id)),
+				_ => None,
+			};
+			let walk = match resume {
+				Some((poll, id)) => VotePages::<T, I>::iter_from(
+					VotePages::<T, I>::hashed_key_for(poll, id / VOTE_PAGE_SIZE),
+				),
+				None => VotePages::<T, I>::iter(),
+			};
+			// The page on which the last call stopped comes first, from the vote it stopped at.
+			let first = resume.and_then(|(poll, id)| {
+				let page = id / VOTE_PAGE_SIZE;
+				VotePages::<T, I>::get(poll, page)
+					.map(|votes| (poll, page, votes, id % VOTE_PAGE_SIZE))
+			});
+			let pages =
+				first.into_iter().chain(walk.map(|(poll, page, votes)| (poll, page, votes, 0)));
+			let mut votes = 0;
+			let mut stopped = None;
+			for (poll, page, page_votes, from) in pages {
+				let mut batch = Vec::new();
+				for (slot, vote) in page_votes.iter().filter(|(slot, _)| *slot >= from) {
+					let id = page * VOTE_PAGE_SIZE + slot;
+					if votes == max_votes {
+						stopped = Some((poll, id));
+						break
+					}
+					votes += 1;
+					if let Some(who) = Voters::<T, I>::get(id) {
+						batch.push((who, vote));
+					}
+				}
+				if !batch.is_empty() {
+					Self::remap_votes(poll, old_rank, new_rank, batch);
+				}
+				if stopped.is_some() {
+					break
+				}
+			}
+			let complete = stopped.is_none();
+			if let Some((poll, id)) = stopped {
+				ClassRemapCursor::<T, I>::put((old_rank, new_rank, poll, id));
+			}
+			Self::deposit_event(Event::ClassRemapped { old_rank, new_rank, votes, complete });
+			Ok(Some(T::WeightInfo::remap_class(votes)).into())
+		}
+
+		/// Set the vote-weight scheme to a curve through `points`, or back to `VoteWeight` if
+		/// `None`.
+		///
+		/// Votes already cast keep the weight they were cast with.
+		///
+		/// - `origin`: Must be Root.
+		/// - `points`: Points of excess rank and votes, sorted by strictly increasing excess rank
+		///   and non-decreasing votes.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_vote_curve())]
+		pub fn set_vote_curve(
+			origin: OriginFor<T>,
+			points: Option<VoteCurvePoints>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let curve = match points {
+				Some(points) =>
+					Some(VoteCurve::new(points).ok_or(Error::<T, I>::InvalidVoteCurve)?),
+				None => None,
+			};
+			VoteWeightCurve::<T, I>::set(curve.clone());
+			Self::deposit_event(Event::VoteCurveSet { curve });
+			Ok(())
+		}
+
+		/// Introduce a new member on probation.
+		///
+		/// The member holds rank 0, but may not vote, veto, delegate or be promoted, and is not
+		/// counted among the voters of rank 0, until `confirm_member` ends their probation.
+		///
+		/// - `origin`: Must be the `PromoteOrigin`.
+		/// - `who`: Account of non-member which will become a member on probation.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::induct_member())]
+		pub fn induct_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			T::PromoteOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_induct_member(who)
+		}
+
+		/// End the probation of a member, who becomes a full member of rank 0.
+		///
+		/// - `origin`: Must be the `PromoteOrigin`.
+		/// - `who`: Account of a member on probation.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::confirm_member())]
+		pub fn confirm_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			T::PromoteOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let record = Self::ensure_member(&who)?;
+			ensure!(record.probationary, Error::<T, I>::NotProbationary);
+			let now = frame_system::Pallet::<T>::block_number();
+			Members::<T, I>::insert(
+				&who,
+				MemberRecord { probationary: false, tracked_since: Some(now), ..record.clone() },
+			);
+			Self::forget_probation(&record);
+			Self::deposit_event(Event::MemberConfirmed { who });
+			Ok(())
+		}
+
+		/// Export the members of ranks `min_rank` to `max_rank` into the `SplitTarget`, for
+		/// example to separate a smaller collective out of this one.
+		///
+		/// - `origin`: Must be Root.
+		/// - `min_rank`: The lowest rank of the members to export.
+		/// - `max_rank`: The highest rank of the members to export.
+		/// - `remove`: Whether the exported members are removed from this collective.
+		/// - `max_members`: Maximum number of members to visit in this call.
+		///
+		/// Each member is inducted into the `SplitTarget` if they are not yet a member of it, and
+		/// promoted there up to their rank here. A member who cannot be exported, or removed from
+		/// this collective, is left unchanged in both. Each member is reported by
+		/// `MemberExported` or `MemberNotExported`.
+		///
+		/// A call continues where the last call with the same arguments stopped, until
+		/// `MembersSplit` reports the walk to be complete. The collective keeps working
+		/// meanwhile; members added behind the walk are not exported.
+		///
+		/// Weight: `O(max_members * max_rank)`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(
+			T::WeightInfo::split_members(*max_members, ranked::rank_to_u32(*max_rank))
+		)]
+		pub fn split_members(
+			origin: OriginFor<T>,
+			min_rank: Rank,
+			max_rank: Rank,
+			remove: bool,
+			max_members: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let cursor = match SplitCursor::<T, I>::take() {
+				Some((min, max, rem, cursor))
+					if (min, max, rem) == (min_rank, max_rank, remove) =>
+					Some(cursor),
+				_ => None,
+			};
+			let (batch, cursor) = Self::scan_members(cursor, max_members);
+			let complete = cursor.is_none();
+			if let Some(cursor) = cursor {
+				SplitCursor::<T, I>::put((min_rank, max_rank, remove, cursor));
+			}
+
+			let visited = batch.len() as u32;
+			let mut exported = 0;
+			for (who, record) in batch {
+				let rank = record.rank;
+				if rank < min_rank || rank > max_rank {
+					continue
+				}
+				match with_storage_layer(|| Self::export_member(&who, rank, remove)) {
+					Ok(()) => {
+						exported += 1;
+						Self::deposit_event(Event::MemberExported { who, rank });
+					},
+					Err(error) =>
+						Self::deposit_event(Event::MemberNotExported { who, rank, error }),
+				}
+			}
+			Self::deposit_event(Event::MembersSplit { min_rank, max_rank, exported, complete });
+			Ok(Some(T::WeightInfo::split_members(visited, ranked::rank_to_u32(max_rank))).into())
+		}
+
+		/// Retract the vote of the sender on an ongoing poll, as if they had never voted on it.
+		///
+		/// The tally of the poll is adjusted and the vote record removed, along with the records
+		/// of the delegators whose votes were counted with it, who may then vote on the poll
+		/// themselves.
+		///
+		/// - `origin`: Must be `Signed` by an account which voted or abstained on `poll`.
+		/// - `poll`: Index of a poll which is ongoing.
+		///
+		/// Weight: `O(D)` where `D` is the number of members delegating to the sender on the
+		/// poll's class.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::retract_vote(T::MaxDelegators::get()))]
+		pub fn retract_vote(
+			origin: OriginFor<T>,
+			poll: PollIndexOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			use VoteRecord::*;
+			let (vote, delegated) = T::Polls::try_access_poll(
+				poll,
+				|mut status| -> Result<(VoteRecord, u32), DispatchError> {
+					match status {
+						PollStatus::None | PollStatus::Completed(..) =>
+							Err(Error::<T, I>::NotPolling)?,
+						PollStatus::Ongoing(ref mut tally, class) => {
+							ensure!(
+								!FrozenClasses::<T, I>::contains_key(&class),
+								Error::<T, I>::ClassFrozen
+							);
+							let vote =
+								Self::take_vote(poll, &who).ok_or(Error::<T, I>::NotVoted)?;
+							match vote {
+								Aye(votes) => {
+									tally.bare_ayes.saturating_dec();
+									tally.ayes.saturating_reduce(votes);
+								},
+								Nay(votes) => {
+									tally.bare_nays.saturating_dec();
+									tally.nays.saturating_reduce(votes);
+								},
+								Abstain => tally.bare_abstains.saturating_dec(),
+								Delegated(_) => Err(Error::<T, I>::VoteDelegated)?,
+							}
+							let delegators = Delegators::<T, I>::get(&class, &who);
+							for delegator in delegators.iter() {
+								if matches!(Self::vote_of(poll, delegator), Some(Delegated(_))) {
+									Self::take_vote(poll, delegator);
+								}
+							}
+							Ok((vote, delegators.len() as u32))
+						},
+					}
+				},
+			)?;
+			Self::deposit_event(Event::VoteRetracted { who, poll, vote });
+			Ok(Some(T::WeightInfo::retract_vote(delegated)).into())
+		}
+
+		/// Limit the poll classes on which the members of `rank` may vote to those with a
+		/// minimum rank of at most `limit`, or lift the limit if `None`.
+		///
+		/// This fences the classes reserved for other ranks beyond the minimum rank of each class.
+		/// The limit applies to the votes the members cast themselves and to those they delegate.
+		/// Votes already cast are left unchanged.
+		///
+		/// - `origin`: Must be Root.
+		/// - `rank`: The rank to limit.
+		/// - `limit`: The highest minimum rank of the classes on which the rank may vote.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_class_limit())]
+		pub fn set_class_limit(
+			origin: OriginFor<T>,
+			rank: Rank,
+			limit: Option<Rank>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ClassLimits::<T, I>::set(rank, limit);
+			Self::deposit_event(Event::ClassLimitSet { rank, limit });
+			Ok(())
+		}
+
+		/// Move a member directly to any rank, rather than through a promotion or demotion for
+		/// each rank in between.
+		///
+		/// - `origin`: Must be the `AdminOrigin`.
+		/// - `who`: Account of an existing member.
+		/// - `rank`: The rank which the member should have.
+		/// - `max_rank`: The higher of the current rank of the member and `rank`, or greater.
+		///
+		/// Members on probation may not be moved up and members under review may not be moved
+		/// down. A single `RankChanged` event is emitted.
+		///
+		/// Weight: `O(max_rank)`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_rank(ranked::rank_to_u32(*max_rank)))]
+		pub fn set_rank(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			rank: Rank,
+			max_rank: Rank,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let current = Self::ensure_member(&who)?.rank;
+			let highest = current.max(rank);
+			ensure!(max_rank >= highest, Error::<T, I>::InvalidWitness);
+			if rank < current {
+				Self::ensure_not_under_review(&who)?;
+			}
+			Self::do_set_rank(who, Some(current), rank)?;
+			Ok(Some(T::WeightInfo::set_rank(ranked::rank_to_u32(highest))).into())
+		}
+
+		/// Bring the rank of an account in line with its rank in the `RankSource`, as the
+		/// `RankSyncPolicy` resolves any difference between them.
+		///
+		/// - `origin`: Must be `Signed`.
+		/// - `who`: Account with a rank in the `RankSource`, who is added if not a member.
+		/// - `max_rank`: The higher of the current rank of the account and its rank in the
+		///   `RankSource`, or greater.
+		///
+		/// Members under review may not be moved down. Under the `Manual` policy, a
+		/// `RankConflict` is reported rather than any rank being changed.
+		///
+		/// Weight: `O(max_rank)`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::set_rank(ranked::rank_to_u32(*max_rank))
+				.saturating_add(T::WeightInfo::add_member())
+				.saturating_add(T::DbWeight::get().reads(1))
+		)]
+		pub fn sync_rank(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			max_rank: Rank,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let source_rank = T::RankSource::rank_of(&who).ok_or(Error::<T, I>::NotInSource)?;
+			let current = Members::<T, I>::get(&who).map(|record| record.rank);
+			let highest = current.unwrap_or(0).max(source_rank);
+			ensure!(max_rank >= highest, Error::<T, I>::InvalidWitness);
+
+			let mut weight = T::WeightInfo::set_rank(ranked::rank_to_u32(highest))
+				.saturating_add(T::DbWeight::get().reads(1));
+			if current.is_none() {
+				weight.saturating_accrue(T::WeightInfo::add_member());
+			}
+			ensure!(Self::do_sync_rank(who, current, source_rank)?, Error::<T, I>::AlreadySynced);
+			Ok(Some(weight).into())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Record the vote of `who` on `poll`: an aye or nay according to `aye`, or an abstention
+		/// if it is `None`.
+		fn do_vote(
+			who: T::AccountId,
+			poll: PollIndexOf<T, I>,
+			aye: Option<bool>,
+		) -> DispatchResultWithPostInfo {
+			let mut record = Self::ensure_member(&who)?;
+			ensure!(!record.probationary, Error::<T, I>::Probationary);
+			let panel = Panels::<T, I>::get(poll);
+			if let Some(ref panel) = panel {
+				ensure!(panel.contains(&who), Error::<T, I>::NotInPanel);
+			}
+			use VoteRecord::*;
+			let mut first = false;
+			let mut delegated = 0;
+
+			let ((tally, vote, rank_votes), poll_weight) = T::Polls::try_access_poll_metered(
+				poll,
+				T::DbWeight::get(),
+				|mut status, ops| -> Result<(TallyOf<T, I>, VoteRecord, Votes), DispatchError> {
+					match status {
+						PollStatus::None | PollStatus::Completed(..) =>
+							Err(Error::<T, I>::NotPolling)?,
+						PollStatus::Ongoing(ref mut tally, class) => {
+							ops.read(1);
+							ensure!(
+								!FrozenClasses::<T, I>::contains_key(&class),
+								Error::<T, I>::ClassFrozen
+							);
+							ops.read(1);
+							ensure!(
+								!Delegations::<T, I>::contains_key(&class, &who),
+								Error::<T, I>::VoteDelegated
+							);
+							ops.read(2);
+							let previous = Self::vote_of(poll, &who);
+							match previous {
+								Some(Aye(votes)) => {
+									tally.bare_ayes.saturating_dec();
+									tally.ayes.saturating_reduce(votes);
+								},
+								Some(Nay(votes)) => {
+									tally.bare_nays.saturating_dec();
+									tally.nays.saturating_reduce(votes);
+								},
+								Some(Abstain) => tally.bare_abstains.saturating_dec(),
+								Some(Delegated(_)) => Err(Error::<T, I>::VoteDelegated)?,
+								None => first = true,
+							}
+							let min_rank = T::MinRankOfClass::convert(class.clone());
+							if tally.max_voters.is_none() && T::SnapshotVoters::get() {
+								ops.read(2);
+								tally.max_voters = Some(Self::get_max_voters(min_rank));
+							}
+							let rank_votes = Self::rank_to_votes(record.rank, min_rank)?;
+							let mut votes = rank_votes;
+							ops.read(1);
+							let delegators = Delegators::<T, I>::get(&class, &who);
+							delegated = delegators.len() as u32;
+							for delegator in delegators {
+								if matches!(&panel, Some(p) if !p.contains(&delegator)) {
+									continue
+								}
+								// Delegators who voted themselves keep their own vote.
+								ops.read(3);
+								let counted = match Self::vote_of(poll, &delegator) {
+									None => false,
+									Some(Delegated(_)) => true,
+									Some(_) => continue,
+								};
+								let delegated_votes = aye
+									.and_then(|_| Members::<T, I>::get(&delegator))
+									.and_then(|r| Self::rank_to_votes(r.rank, min_rank).ok());
+								if let Some(delegated_votes) = delegated_votes {
+									ops.write(1);
+									Self::set_vote(poll, &delegator, Delegated(delegated_votes));
+									votes.saturating_accrue(delegated_votes);
+								} else if counted {
+									ops.write(1);
+									Self::take_vote(poll, &delegator);
+								}
+							}
+							let vote = match aye {
+								Some(aye) => VoteRecord::from((aye, votes)),
+								None => Abstain,
+							};
+							match vote {
+								Aye(votes) => {
+									tally.bare_ayes.saturating_inc();
+									tally.ayes.saturating_accrue(votes);
+								},
+								Nay(votes) => {
+									tally.bare_nays.saturating_inc();
+									tally.nays.saturating_accrue(votes);
+								},
+								Abstain => tally.bare_abstains.saturating_inc(),
+								Delegated(_) => {},
+							}
+							if previous != Some(vote) {
+								ops.write(1);
+								Self::set_vote(poll, &who, vote);
+							}
+							ops.read(1);
+							if !ActivePolls::<T, I>::contains_key(poll) {
+								ops.read(1);
+								ops.write(2);
+								ActivePolls::<T, I>::insert(poll, ());
+							}
+							Ok((tally.clone(), vote, rank_votes))
+						},
+					}
+				},
+			)?;
+			record.last_vote = Some(frame_system::Pallet::<T>::block_number());
+			Members::<T, I>::insert(&who, &record);
+			let pays = if first { Self::use_free_vote(&who) } else { Pays::Yes };
+			if T::CompactVoteEvents::get() {
+				Self::deposit_event(Event::VotedCompact { who, poll, vote });
+			} else {
+				Self::deposit_event(Event::Voted { who, poll, vote, rank_votes, tally });
+			}
+			let benchmarked = if first {
+				T::WeightInfo::vote_new(delegated)
+			} else {
+				T::WeightInfo::vote_existing(delegated)
+			};
+			let actual_weight = benchmarked
+				.saturating_sub(vote_poll_ops(delegated, first).weight(T::DbWeight::get()))
+				.saturating_add(poll_weight);
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: pays })
+		}
+
+		/// Re-weigh the `votes` on `poll` from `old_rank` to `new_rank`, if it is ongoing and of a
+		/// class whose minimum rank is `new_rank`. See `remap_class`.
+		fn remap_votes(
+			poll: PollIndexOf<T, I>,
+			old_rank: Rank,
+			new_rank: Rank,
+			votes: Vec<(T::AccountId, VoteRecord)>,
+		) {
+			T::Polls::access_poll(poll, |status| {
+				let tally = match status {
+					PollStatus::Ongoing(tally, class)
+						if T::MinRankOfClass::convert(class.clone()) == new_rank =>
+						tally,
+					_ => return,
+				};
+				for (who, vote) in votes {
+					let (aye, cast) = match vote {
+						VoteRecord::Aye(votes) => (true, votes),
+						VoteRecord::Nay(votes) => (false, votes),
+						_ => continue,
+					};
+					let rank = match Members::<T, I>::get(&who) {
+						Some(record) => record.rank,
+						None => continue,
+					};
+					if Self::rank_to_votes(rank, old_rank).ok() != Some(cast) {
+						continue
+					}
+					let (bare, total) = match aye {
+						true => (&mut tally.bare_ayes, &mut tally.ayes),
+						false => (&mut tally.bare_nays, &mut tally.nays),
+					};
+					total.saturating_reduce(cast);
+					match Self::rank_to_votes(rank, new_rank) {
+						Ok(votes) => {
+							total.saturating_accrue(votes);
+							Self::set_vote(poll, &who, VoteRecord::from((aye, votes)));
+						},
+						Err(_) => {
+							bare.saturating_dec();
+							Self::take_vote(poll, &who);
+						},
+					}
+				}
+			})
+		}
+
+		/// Induct `who` into the `SplitTarget` if needed and promote them there up to `rank`,
+		/// then remove them from this collective if `remove` is set.
+		fn export_member(who: &T::AccountId, rank: Rank, remove: bool) -> DispatchResult {
+			let current = match T::SplitTarget::rank_of(who) {
+				Some(current) => current,
+				None => {
+					T::SplitTarget::induct(who)?;
+					0
+				},
+			};
+			for _ in current..rank {
+				T::SplitTarget::promote(who)?;
+			}
+			if remove {
+				Self::ensure_not_under_review(who)?;
+				Self::do_remove_member(who.clone())?;
+			}
+			Ok(())
+		}
+
+		/// Fold the change of the rank of `who` to `rank` (`None` if they left) into the
+		/// `MembershipCommitment`.
+		fn note_membership_change(who: &T::AccountId, rank: Option<Rank>) {
+			MembershipCommitment::<T, I>::mutate(|commitment| {
+				*commitment = T::Hashing::hash_of(&(&*commitment, who, rank));
+			});
+		}
+
+		fn ensure_member(who: &T::AccountId) -> Result<MemberRecordOf<T>, DispatchError> {
+			Members::<T, I>::get(who).ok_or(Error::<T, I>::NotMember.into())
+		}
+
+		/// The index of `who` into the `VotePages`, given to them now if they never voted.
+		fn voter_id(who: &T::AccountId) -> VoterIndex {
+			VoterIds::<T, I>::get(who).unwrap_or_else(|| {
+				let id = NextVoterId::<T, I>::mutate(|next| {
+					let id = *next;
+					next.saturating_inc();
+					id
+				});
+				VoterIds::<T, I>::insert(who, id);
+				Voters::<T, I>::insert(id, who);
+				id
+			})
+		}
+
+		/// Record `vote` as the vote of `who` on `poll`, replacing any vote they cast before.
+		pub(crate) fn set_vote(poll: PollIndexOf<T, I>, who: &T::AccountId, vote: VoteRecord) {
+			let id = Self::voter_id(who);
+			VotePages::<T, I>::mutate(poll, id / VOTE_PAGE_SIZE, |page| {
+				page.get_or_insert_with(Default::default).insert(id % VOTE_PAGE_SIZE, vote)
+			});
+		}
+
+		/// Remove the vote of `who` on `poll`, returning it.
+		pub(crate) fn take_vote(poll: PollIndexOf<T, I>, who: &T::AccountId) -> Option<VoteRecord> {
+			let id = VoterIds::<T, I>::get(who)?;
+			VotePages::<T, I>::mutate_exists(poll, id / VOTE_PAGE_SIZE, |maybe_page| {
+				let page = maybe_page.as_mut()?;
+				let vote = page.remove(id % VOTE_PAGE_SIZE);
+				if page.is_empty() {
+					*maybe_page = None;
+				}
+				vote
+			})
+		}
+
+		/// Remove up to `max` pages of votes of the ended `poll`. Returns the number of pages
+		/// removed and whether none is left.
+		fn clear_votes(poll: PollIndexOf<T, I>, max: u32) -> (u32, bool) {
+			Panels::<T, I>::remove(poll);
+			Vetoes::<T, I>::remove(poll);
+			let mut pages = 0;
+			let mut votes = 0u32;
+			for (_, page) in VotePages::<T, I>::drain_prefix(poll).take(max as usize) {
+				pages += 1;
+				votes.saturating_accrue(page.len());
+			}
+			if pages == max && VotePages::<T, I>::iter_key_prefix(poll).next().is_some() {
+				VotesRemoved::<T, I>::mutate(poll, |n| n.saturating_accrue(votes));
+				return (pages, false)
+			}
+			CompletedPolls::<T, I>::remove(poll);
+			let votes_removed = VotesRemoved::<T, I>::take(poll).saturating_add(votes);
+			if votes_removed > 0 {
+				Self::deposit_event(Event::PollFinishedCleanup { poll, votes_removed });
+			}
+			(pages, true)
+		}
+
+		/// Remove the votes of the `CompletedPolls`, one poll after the other, as far as `limit`
+		/// allows. Returns the weight used.
+		fn cleanup_completed_polls(limit: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			let per_poll = T::WeightInfo::cleanup_poll(0).saturating_add(db.reads_writes(1, 1));
+			let per_page =
+				T::WeightInfo::cleanup_poll(1).saturating_sub(T::WeightInfo::cleanup_poll(0));
+			let mut used = Weight::zero();
+			while limit.all_gte(used.saturating_add(per_poll).saturating_add(per_page)) {
+				let poll = match CompletedPolls::<T, I>::iter_keys().next() {
+					Some(poll) => poll,
+					None => {
+						used.saturating_accrue(db.reads(1));
+						break
+					},
+				};
+				let budget = limit.saturating_sub(used).saturating_sub(per_poll);
+				let max = budget.ref_time() / per_page.ref_time().max(1);
+				let (removed, complete) =
+					Self::clear_votes(poll, max.try_into().unwrap_or(u32::MAX));
+				used.saturating_accrue(per_poll);
+				used.saturating_accrue(per_page.saturating_mul(removed.into()));
+				if !complete {
+					break
+				}
+			}
+			used
+		}
+
+		/// Check the activity of the next `max` members, continuing the walk over the members
+		/// where the last check stopped, and demote those inactive for longer than the
+		/// `DemotionPeriod` of their rank. Members on probation or under review are left alone, and
+		/// those without any recorded activity are recorded as active `now`. Returns the weight
+		/// used.
+		fn demote_inactive_members(now: T::BlockNumber, max: u32) -> Weight {
+			let db = T::DbWeight::get();
+			if max == 0 {
+				return Weight::zero()
+			}
+			let (batch, cursor) = Self::scan_members(InactivityCursor::<T, I>::take(), max);
+			if let Some(cursor) = cursor {
+				InactivityCursor::<T, I>::put(cursor);
+			}
+
+			let mut used = T::WeightInfo::scan_members(batch.len() as u32)
+				.saturating_add(db.reads_writes(1, 1));
+			for (who, record) in batch {
+				let period = T::DemotionPeriod::convert(record.rank);
+				if period.is_zero() || record.probationary {
+					continue
+				}
+				let last_activity = match record.last_activity() {
+					Some(last_activity) => last_activity,
+					None => {
+						// Count the inactivity of a member without any recorded activity from now.
+						Members::<T, I>::insert(
+							&who,
+							MemberRecord { tracked_since: Some(now), ..record },
+						);
+						used.saturating_accrue(db.writes(1));
+						continue
+					},
+				};
+				if now < last_activity.saturating_add(period) {
+					continue
+				}
+				used.saturating_accrue(db.reads(1));
+				let demoted = with_storage_layer(|| {
+					Self::ensure_not_under_review(&who)?;
+					Self::do_demote_member(who.clone())
+				});
+				if demoted.is_ok() {
+					used.saturating_accrue(T::WeightInfo::demote_member(ranked::rank_to_u32(
+						record.rank,
+					)));
+					Self::deposit_event(Event::DemotedForInactivity {
+						who,
+						rank: record.rank,
+						last_activity,
+					});
+				}
+			}
+			used
+		}
+
+		/// The next `max` members in storage order, starting after the `cursor` of an unfinished
+		/// walk over the members or from the first member. Also returns the cursor from which to
+		/// continue the walk, or `None` if it is complete.
+		///
+		/// Anything walking over the members does so through this, in bounded steps, and accounts
+		/// for `WeightInfo::scan_members` of the members returned.
+		pub(crate) fn scan_members(
+			cursor: Option<MembersCursorOf<T, I>>,
+			max: u32,
+		) -> (Vec<(T::AccountId, MemberRecordOf<T>)>, Option<MembersCursorOf<T, I>>) {
+			let mut members = match cursor {
+				Some(cursor) => Members::<T, I>::iter_from(cursor.into_inner()),
+				None => Members::<T, I>::iter(),
+			};
+			let mut batch = Vec::new();
+			while (batch.len() as u32) < max {
+				match members.next() {
+					Some(member) => batch.push(member),
+					None => return (batch, None),
+				}
+			}
+			(batch, Some(BoundedVec::truncate_from(members.last_raw_key().to_vec())))
+		}
+
+		fn is_probationary(who: &T::AccountId) -> bool {
+			matches!(Members::<T, I>::get(who), Some(record) if record.probationary)
+		}
+
+		/// Stop counting the member of `record` among those on probation, if they were one, as
+		/// they leave the collective or end their probation.
+		fn forget_probation(record: &MemberRecordOf<T>) {
+			if record.probationary {
+				ProbationaryCount::<T, I>::mutate(|count| count.saturating_dec());
+			}
+		}
+
+		/// Ensure that `who` is not the subject of an ongoing disciplinary poll, clearing their
+		/// review if its poll has ended.
+		fn ensure_not_under_review(who: &T::AccountId) -> DispatchResult {
+			if let Some(poll) = UnderReview::<T, I>::get(who) {
+				ensure!(T::Polls::as_ongoing(poll).is_none(), Error::<T, I>::MemberUnderReview);
+				UnderReview::<T, I>::remove(who);
+				Self::deposit_event(Event::ReviewEnded { who: who.clone(), poll });
+			}
+			Ok(())
+		}
+
+		/// Ensure that `origin` may perform `operation` on the account of `who`, returning it along
+		/// with the maximum rank the operation may be performed to (for additions and promotions)
+		/// or from (for demotions and removals).
+		///
+		/// `who` is only looked up once `origin` is found not to be the `PromoteOrigin` or
+		/// `DemoteOrigin`, as the `MembershipOrigin` needs the account.
+		fn ensure_membership_origin(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			operation: MemberOperation,
+		) -> Result<(T::AccountId, Rank), DispatchError> {
+			match Self::try_rank_origin(origin, operation) {
+				Ok(rank) => Ok((T::Lookup::lookup(who)?, rank)),
+				Err(origin) => {
+					let who = T::Lookup::lookup(who)?;
+					let rank =
+						T::MembershipOrigin::ensure_origin(origin, &(who.clone(), operation))?;
+					Ok((who, rank))
+				},
+			}
+		}
+
+		/// As `ensure_membership_origin`, for the same `operation` on each of the accounts `who`,
+		/// returning the maximum rank for each of them in turn.
+		fn ensure_membership_origins(
+			origin: OriginFor<T>,
+			who: &[T::AccountId],
+			operation: MemberOperation,
+		) -> Result<Vec<Rank>, DispatchError> {
+			match Self::try_rank_origin(origin, operation) {
+				Ok(rank) => Ok(vec![rank; who.len()]),
+				Err(origin) => {
+					ensure!(!who.is_empty(), DispatchError::BadOrigin);
+					who.iter()
+						.map(|who| {
+							T::MembershipOrigin::ensure_origin(
+								origin.clone(),
+								&(who.clone(), operation),
+							)
+							.map_err(Into::into)
+						})
+						.collect()
+				},
+			}
+		}
+
+		/// The maximum rank to or from which `origin` may perform `operation` on any member, if it
+		/// is the `PromoteOrigin` or `DemoteOrigin` as befits the operation.
+		fn try_rank_origin(
+			origin: OriginFor<T>,
+			operation: MemberOperation,
+		) -> Result<Rank, OriginFor<T>> {
+			match operation {
+				MemberOperation::Add | MemberOperation::Promote =>
+					T::PromoteOrigin::try_origin(origin),
+				MemberOperation::Demote | MemberOperation::Remove =>
+					T::DemoteOrigin::try_origin(origin),
+			}
+		}
+
+		/// Count a first vote of `who` against their fee-less quota of the current period,
+		/// returning whether it is to be paid for.
+		fn use_free_vote(who: &T::AccountId) -> Pays {
+			let now = frame_system::Pallet::<T>::block_number();
+			let period = now.checked_div(&T::FreeVotePeriod::get()).unwrap_or_else(Zero::zero);
+			let used = match FreeVotesUsed::<T, I>::get(who) {
+				Some((last, used)) if last == period => used,
+				_ => 0,
+			};
+			if used >= T::MaxFreeVotes::get() {
+				return Pays::Yes
+			}
+			FreeVotesUsed::<T, I>::insert(who, (period, used + 1));
+			Pays::No
+		}
+
+		fn rank_to_votes(rank: Rank, min: Rank) -> Result<Votes, DispatchError> {
+			let excess = ranked::rank_excess(rank, min).ok_or(Error::<T, I>::RankTooLow)?;
+			if let Some(limit) = ClassLimits::<T, I>::get(rank) {
+				ensure!(min <= limit, Error::<T, I>::ClassNotAllowed);
+			}
+			Ok(match VoteWeightCurve::<T, I>::get() {
+				Some(curve) => curve.votes(excess),
+				None => T::VoteWeight::convert(excess),
+			})
+		}
+
+		fn remove_from_rank(who: &T::AccountId, rank: Rank) -> DispatchResult {
+			MemberIndices::<T, I>::remove(&rank, who).map_err(|_| Error::<T, I>::Corruption)?;
+			Ok(())
+		}
+
+		fn add_to_rank(who: &T::AccountId, rank: Rank) -> DispatchResult {
+			ensure!(
+				MemberCount::<T, I>::get(rank) < T::MaxMembersPerRank::get(),
+				Error::<T, I>::TooManyMembers
+			);
+			MemberIndices::<T, I>::insert(&rank, who).map_err(|e| match e {
+				IndexedSetError::Overflow => DispatchError::from(Overflow),
+				_ => Error::<T, I>::Corruption.into(),
+			})?;
+			Ok(())
+		}
+
+		/// Adds a member into the ranked collective at level 0.
+		///
+		/// No origin checks are executed.
+		pub fn do_add_member(who: T::AccountId) -> DispatchResult {
+			Self::insert_member(who, false)
+		}
+
+		/// Adds a member on probation into the ranked collective at level 0.
+		///
+		/// No origin checks are executed.
+		pub fn do_induct_member(who: T::AccountId) -> DispatchResult {
+			Self::insert_member(who.clone(), true)?;
+			Self::deposit_event(Event::MemberInducted { who });
+			Ok(())
+		}
+
+		/// Adds a member into the ranked collective at level 0, on probation if `probationary`,
+		/// before notifying anything of the addition.
+		fn insert_member(who: T::AccountId, probationary: bool) -> DispatchResult {
+			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
+			if let Some(until) = ResignedUntil::<T, I>::get(&who) {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now >= until, Error::<T, I>::InCooldown);
+				ResignedUntil::<T, I>::remove(&who);
+			}
+			Self::add_to_rank(&who, 0)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			Members::<T, I>::insert(
+				&who,
+				MemberRecord { probationary, tracked_since: Some(now), ..MemberRecord::new(0) },
+			);
+			if probationary {
+				ProbationaryCount::<T, I>::mutate(|count| count.saturating_inc());
+			}
+			T::MembershipNft::on_added(&who, 0)?;
+			T::OnMemberAdded::on_member_added(&who, 0);
+			Self::note_membership_change(&who, Some(0));
+			Self::deposit_event(Event::MemberAdded { who });
+			Ok(())
+		}
+
+		/// Removes a member from the ranked collective, giving up all of their ranks.
+		///
+		/// No origin checks are executed.
+		pub fn do_remove_member(who: T::AccountId) -> DispatchResult {
+			let record = Self::ensure_member(&who)?;
+			Self::forget_member(&who, &record)?;
+			Self::deposit_event(Event::MemberRemoved { who, rank: record.rank });
+			Ok(())
+		}
+
+		/// Remove `who`, whose record is `record`, from all of their ranks and clear everything
+		/// kept about them as a member, without depositing an event.
+		fn forget_member(who: &T::AccountId, record: &MemberRecordOf<T>) -> DispatchResult {
+			for r in 0..=record.rank {
+				Self::remove_from_rank(who, r)?;
+			}
+			Members::<T, I>::remove(who);
+			Self::forget_probation(record);
+			FreeVotesUsed::<T, I>::remove(who);
+			UnderReview::<T, I>::remove(who);
+			Self::forget_delegations(who);
+			T::MembershipNft::on_removed(who)?;
+			T::OnMemberRemoved::on_member_removed(who, record.rank);
+			Self::note_membership_change(who, None);
+			Ok(())
+		}
+
+		/// End the delegations of `who` and those made to them on the polls of every class.
+		fn forget_delegations(who: &T::AccountId) {
+			for class in T::Polls::classes() {
+				if let Some(to) = Delegations::<T, I>::take(&class, who) {
+					Delegators::<T, I>::mutate(&class, &to, |delegators| {
+						delegators.retain(|delegator| delegator != who)
+					});
+					Self::deposit_event(Event::Undelegated {
+						who: who.clone(),
+						class: class.clone(),
+					});
+				}
+				for delegator in Delegators::<T, I>::take(&class, who) {
+					Delegations::<T, I>::remove(&class, &delegator);
+					Self::deposit_event(Event::Undelegated {
+						who: delegator,
+						class: class.clone(),
+					});
+				}
+			}
+		}
+
+		/// Take the vote weight of `who` back from the votes which `to` cast with it on the
+		/// `ActivePolls` of `class`, and remove the records of `who` delegating those votes.
+		fn withdraw_delegated_votes(who: &T::AccountId, to: &T::AccountId, class: &ClassOf<T, I>) {
+			use VoteRecord::*;
+			for poll in ActivePolls::<T, I>::iter_keys() {
+				let delegated = match Self::vote_of(poll, who) {
+					Some(Delegated(votes)) => votes,
+					_ => continue,
+				};
+				let _ = T::Polls::try_access_poll(poll, |mut status| -> DispatchResult {
+					if let PollStatus::Ongoing(ref mut tally, poll_class) = status {
+						if &poll_class != class {
+							return Ok(())
+						}
+						match Self::vote_of(poll, to) {
+							Some(Aye(votes)) => {
+								tally.ayes.saturating_reduce(delegated);
+								Self::set_vote(poll, to, Aye(votes.saturating_sub(delegated)));
+							},
+							Some(Nay(votes)) => {
+								tally.nays.saturating_reduce(delegated);
+								Self::set_vote(poll, to, Nay(votes.saturating_sub(delegated)));
+							},
+							_ => {},
+						}
+						Self::take_vote(poll, who);
+					}
+					Ok(())
+				});
+			}
+		}
+
+		/// Move the delegations of `who` and those made to them on the polls of every class to
+		/// `new_who`.
+		fn move_delegations(who: &T::AccountId, new_who: &T::AccountId) {
+			for class in T::Polls::classes() {
+				if let Some(to) = Delegations::<T, I>::take(&class, who) {
+					Delegators::<T, I>::mutate(&class, &to, |delegators| {
+						delegators
+							.iter_mut()
+							.filter(|d| *d == who)
+							.for_each(|d| *d = new_who.clone())
+					});
+					Delegations::<T, I>::insert(&class, new_who, to);
+				}
+				let delegators = Delegators::<T, I>::take(&class, who);
+				for delegator in delegators.iter() {
+					Delegations::<T, I>::insert(&class, delegator, new_who);
+				}
+				if !delegators.is_empty() {
+					Delegators::<T, I>::insert(&class, new_who, delegators);
+				}
+			}
+		}
+
+		/// Move the votes of `who`, their seats on panels and their vetoes on the `ActivePolls` to
+		/// `new_who`, who must not have voted on them.
+		pub(crate) fn move_poll_state(who: &T::AccountId, new_who: &T::AccountId) {
+			for poll in ActivePolls::<T, I>::iter_keys() {
+				if let Some(vote) = Self::take_vote(poll, who) {
+					Self::set_vote(poll, new_who, vote);
+				}
+				if let Some(mut panel) = Panels::<T, I>::get(poll) {
+					if let Some(seat) = panel.iter_mut().find(|m| *m == who) {
+						*seat = new_who.clone();
+						Panels::<T, I>::insert(poll, panel);
+					}
+				}
+				if let Some((since, mut vetoers)) = Vetoes::<T, I>::get(poll) {
+					if let Some(vetoer) = vetoers.iter_mut().find(|v| *v == who) {
+						*vetoer = new_who.clone();
+						Vetoes::<T, I>::insert(poll, (since, vetoers));
+					}
+				}
+			}
+		}
+
+		/// Exchanges the account of a member for `new_who`, keeping their rank and indices.
+		///
+		/// No origin checks are executed.
+		pub fn do_exchange_member(who: T::AccountId, new_who: T::AccountId) -> DispatchResult {
+			let record = Self::ensure_member(&who)?;
+			ensure!(!Members::<T, I>::contains_key(&new_who), Error::<T, I>::AlreadyMember);
+			// The votes of the member could not be moved without overwriting those of the new
+			// account, which are counted in the tallies.
+			ensure!(
+				ActivePolls::<T, I>::iter_keys()
+					.all(|poll| Self::vote_of(poll, &new_who).is_none()),
+				Error::<T, I>::NewAccountVoted
+			);
+			if let Some(until) = ResignedUntil::<T, I>::get(&new_who) {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now >= until, Error::<T, I>::InCooldown);
+				ResignedUntil::<T, I>::remove(&new_who);
+			}
+			for r in 0..=record.rank {
+				MemberIndices::<T, I>::replace(&r, &who, &new_who)
+					.map_err(|_| Error::<T, I>::Corruption)?;
+			}
+			Members::<T, I>::remove(&who);
+			Members::<T, I>::insert(&new_who, &record);
+			if let Some(used) = FreeVotesUsed::<T, I>::take(&who) {
+				FreeVotesUsed::<T, I>::insert(&new_who, used);
+			}
+			if let Some(poll) = UnderReview::<T, I>::take(&who) {
+				UnderReview::<T, I>::insert(&new_who, poll);
+			}
+			Self::move_delegations(&who, &new_who);
+			Self::move_poll_state(&who, &new_who);
+			T::MembershipNft::on_removed(&who)?;
+			T::MembershipNft::on_added(&new_who, record.rank)?;
+			T::OnMemberRemoved::on_member_removed(&who, record.rank);
+			T::OnMemberAdded::on_member_added(&new_who, record.rank);
+			Self::note_membership_change(&who, None);
+			Self::note_membership_change(&new_who, Some(record.rank));
+			Self::deposit_event(Event::MemberExchanged { who, new_who });
+			Ok(())
+		}
+
+		/// Promotes a member in the ranked collective into the next role.
+		///
+		/// A `maybe_max_rank` may be provided to check that the member does not get promoted beyond
+		/// a certain rank. Is `None` is provided, then the rank will be incremented without checks.
+		pub fn do_promote_member(
+			who: T::AccountId,
+			maybe_max_rank: Option<Rank>,
+		) -> DispatchResult {
+			let mut record = Self::ensure_member(&who)?;
+			ensure!(!record.probationary, Error::<T, I>::Probationary);
+			let rank = ranked::promoted(record.rank).ok_or(Overflow)?;
+			if let Some(max_rank) = maybe_max_rank {
+				ensure!(max_rank >= rank, Error::<T, I>::NoPermission);
+			}
+			Self::add_to_rank(&who, rank)?;
+			let old = record.rank;
+			record.rank = rank;
+			record.last_promotion = Some(frame_system::Pallet::<T>::block_number());
+			Members::<T, I>::insert(&who, &record);
+			T::MembershipNft::on_rank_changed(&who, rank)?;
+			T::OnRankChanged::on_rank_changed(&who, old, rank);
+			Self::note_membership_change(&who, Some(rank));
+			Self::deposit_event(Event::RankChanged { who, rank });
+			Ok(())
+		}
+
+		/// Demotes a member in the ranked collective by one rank, removing them if they were of
+		/// rank zero.
+		///
+		/// No origin checks are executed.
+		pub fn do_demote_member(who: T::AccountId) -> DispatchResult {
+			let mut record = Self::ensure_member(&who)?;
+			let rank = record.rank;
+			match ranked::demoted(rank) {
+				None => {
+					Self::forget_member(&who, &record)?;
+					Self::deposit_event(Event::MemberRemoved { who, rank: 0 });
+				},
+				Some(new_rank) => {
+					Self::remove_from_rank(&who, rank)?;
+					record.rank = new_rank;
+					record.last_demotion = Some(frame_system::Pallet::<T>::block_number());
+					Members::<T, I>::insert(&who, &record);
+					T::MembershipNft::on_rank_changed(&who, new_rank)?;
+					T::OnRankChanged::on_rank_changed(&who, rank, new_rank);
+					Self::note_membership_change(&who, Some(new_rank));
+					Self::deposit_event(Event::RankChanged { who, rank: new_rank });
+				},
+			}
+			Ok(())
+		}
+
+		/// Draws `size` distinct members of at least the minimum rank of `class` at random.
+		///
+		/// The result only depends on the `seed` and the output of `T::Randomness`, so it is
+		/// deterministic within a block. Nothing is written to storage.
+		pub fn draw_panel(
+			class: ClassOf<T, I>,
+			size: MemberIndex,
+			seed: u64,
+		) -> Result<BoundedVec<T::AccountId, T::MaxPanelSize>, DispatchError> {
+			let rank = T::MinRankOfClass::convert(class);
+			let count = MemberCount::<T, I>::get(rank);
+			ensure!(
+				size > 0 && size <= Self::get_max_voters(rank) && size <= T::MaxPanelSize::get(),
+				Error::<T, I>::InvalidPanelSize
+			);
+
+			let (random, _) = T::Randomness::random(&(b"panel", seed).encode());
+			let mut chosen = BTreeSet::new();
+			let mut panel = BoundedVec::default();
+			for i in 0..size {
+				let entropy = T::Hashing::hash_of(&(&random, i));
+				let mut index = <u32>::decode(&mut entropy.as_ref())
+					.expect("secure hashes should always be bigger than u32; qed") %
+					count;
+				// Probe linearly for the next member neither chosen yet nor on probation; `size`
+				// not exceeding the number of voters of the rank ensures there always is one.
+				let who = loop {
+					if chosen.insert(index) {
+						let who =
+							IndexToId::<T, I>::get(rank, index).ok_or(Error::<T, I>::Corruption)?;
+						if !Self::is_probationary(&who) {
+							break who
+						}
+					}
+					index = (index + 1) % count;
+				};
+				panel.try_push(who).map_err(|_| Error::<T, I>::InvalidPanelSize)?;
+			}
+			Ok(panel)
+		}
+
+		/// Add a member to the rank collective, and continue to promote them until a certain rank
+		/// is reached.
+		pub fn do_add_member_to_rank(who: T::AccountId, rank: Rank) -> DispatchResult {
+			Self::do_add_member(who.clone())?;
+			for _ in 0..rank {
+				Self::do_promote_member(who.clone(), None)?;
+			}
+			Ok(())
+		}
+
+		/// The hash of the current membership of the accounts in `changes`, to be passed as the
+		/// witness of `force_set_ranks`.
+		pub fn rank_changes_witness(changes: &[(T::AccountId, Rank)]) -> T::Hash {
+			let snapshot = changes
+				.iter()
+				.map(|(who, _)| (who, Members::<T, I>::get(who).map(|r| r.rank)))
+				.collect::<Vec<_>>();
+			T::Hashing::hash_of(&snapshot)
+		}
+
+		/// Move `who` from their `current` rank, if any, to `rank` at once, adding them if they
+		/// are not a member.
+		fn do_set_rank(who: T::AccountId, current: Option<Rank>, rank: Rank) -> DispatchResult {
+			let current = match current {
+				Some(current) => current,
+				None => {
+					Self::do_add_member(who.clone())?;
+					0
+				},
+			};
+			if rank != current {
+				let mut record = Self::ensure_member(&who)?;
+				let now = frame_system::Pallet::<T>::block_number();
+				if rank > current {
+					ensure!(!record.probationary, Error::<T, I>::Probationary);
+					for r in current + 1..=rank {
+						Self::add_to_rank(&who, r)?;
+					}
+					record.last_promotion = Some(now);
+				} else {
+					for r in (rank + 1..=current).rev() {
+						Self::remove_from_rank(&who, r)?;
+					}
+					record.last_demotion = Some(now);
+				}
+				record.rank = rank;
+				Members::<T, I>::insert(&who, &record);
+				T::MembershipNft::on_rank_changed(&who, rank)?;
+				T::OnRankChanged::on_rank_changed(&who, current, rank);
+				Self::note_membership_change(&who, Some(rank));
+				Self::deposit_event(Event::RankChanged { who, rank });
+			}
+			Ok(())
+		}
+
+		/// Resolve the difference between the `current` rank of `who`, if they are a member, and
+		/// their `source_rank` in the `RankSource` according to the `RankSyncPolicy`. Returns
+		/// whether there was a difference.
+		pub(crate) fn do_sync_rank(
+			who: T::AccountId,
+			current: Option<Rank>,
+			source_rank: Rank,
+		) -> Result<bool, DispatchError> {
+			let rank = match (T::RankSyncPolicy::get(), current) {
+				(_, Some(current)) if current == source_rank => return Ok(false),
+				(SyncPolicy::Max, Some(current)) if current > source_rank => return Ok(false),
+				(SyncPolicy::Manual, rank) => {
+					Self::deposit_event(Event::RankConflict { who, rank, source_rank });
+					return Ok(true)
+				},
+				_ => source_rank,
+			};
+			if matches!(current, Some(current) if rank < current) {
+				Self::ensure_not_under_review(&who)?;
+			}
+			Self::do_set_rank(who, current, rank)?;
+			Ok(true)
+		}
+
+		/// The number of members who have at least the given `rank`.
+		pub fn member_count(rank: Rank) -> MemberIndex {
+			MemberCount::<T, I>::get(rank)
+		}
+
+		/// The members who have at least the given `rank`, in the order of their index into it.
+		pub fn members_of_rank(rank: Rank) -> impl Iterator<Item = T::AccountId> {
+			(0..MemberCount::<T, I>::get(rank))
+				.filter_map(move |index| IndexToId::<T, I>::get(rank, index))
+		}
+
+		/// The record of `who`, if they are a member.
+		pub fn member_record(who: &T::AccountId) -> Option<MemberRecordOf<T>> {
+			Members::<T, I>::get(who)
+		}
+
+		/// The latest block in which `who` was promoted, demoted or voted, or from which their
+		/// activity is tracked, if they are a member and any of these is known.
+		pub fn last_activity(who: &T::AccountId) -> Option<T::BlockNumber> {
+			Members::<T, I>::get(who).and_then(|record| record.last_activity())
+		}
+
+		/// The vote of `who` on `poll`, if they have voted on it and it is ongoing.
+		pub fn vote_of(poll: PollIndexOf<T, I>, who: &T::AccountId) -> Option<VoteRecord> {
+			let id = VoterIds::<T, I>::get(who)?;
+			VotePages::<T, I>::get(poll, id / VOTE_PAGE_SIZE)?.get(id % VOTE_PAGE_SIZE)
+		}
+
+		/// The votes `who` would cast on the polls of `class`, including those delegated to them
+		/// for it, if they may vote on these.
+		///
+		/// Delegators who vote on a poll themselves keep their own votes on it, so the votes
+		/// actually cast by `who` may be fewer.
+		pub fn voting_power(who: &T::AccountId, class: ClassOf<T, I>) -> Option<Votes> {
+			let record = Members::<T, I>::get(who).filter(|r| !r.probationary)?;
+			let min_rank = T::MinRankOfClass::convert(class.clone());
+			let own = Self::rank_to_votes(record.rank, min_rank).ok()?;
+			let delegated = Delegators::<T, I>::get(&class, who)
+				.into_iter()
+				.filter_map(|delegator| Members::<T, I>::get(&delegator))
+				.filter_map(|r| Self::rank_to_votes(r.rank, min_rank).ok())
+				.fold(0, |votes: Votes, v| votes.saturating_add(v));
+			Some(own.saturating_add(delegated))
+		}
+
+		/// The ongoing polls, with their class and current tally, on which the rank of `who` lets
+		/// them vote, in no particular order.
+		///
+		/// Other reasons for which a vote may be rejected, such as a frozen class or a panel which
+		/// `who` is not on, are not checked; `dry_run_vote` does.
+		pub fn votable_polls(
+			who: &T::AccountId,
+		) -> Vec<(PollIndexOf<T, I>, ClassOf<T, I>, TallyOf<T, I>)> {
+			let rank = match Members::<T, I>::get(who) {
+				Some(record) if !record.probationary => record.rank,
+				_ => return Vec::new(),
+			};
+			T::Polls::ongoing_polls()
+				.into_iter()
+				.filter(|(_, _, class)| {
+					Self::rank_to_votes(rank, T::MinRankOfClass::convert(class.clone())).is_ok()
+				})
+				.map(|(poll, tally, class)| (poll, class, tally))
+				.collect()
+		}
+
+		/// The current tally of `poll`, if it is ongoing.
+		pub fn tally_of(poll: PollIndexOf<T, I>) -> Option<TallyOf<T, I>> {
+			T::Polls::as_ongoing(poll).map(|(tally, _)| tally)
+		}
+
+		/// The certificate of `poll`, if it has been decided.
+		pub fn tally_certificate(poll: PollIndexOf<T, I>) -> Option<TallyCertificateOf<T, I>> {
+			TallyCertificates::<T, I>::get(poll)
+		}
+
+		/// Check whether `who` may vote on `poll` with `aye`, without changing any state.
+		///
+		/// The vote is cast and then rolled back, so the result is exactly that of `vote`, with
+		/// its error turned into a `VoteRejection`.
+		pub fn dry_run_vote(
+			who: T::AccountId,
+			poll: PollIndexOf<T, I>,
+			aye: bool,
+		) -> Result<(), VoteRejection> {
+			let result = with_transaction(|| {
+				let result = Self::vote(frame_system::RawOrigin::Signed(who).into(), poll, aye);
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+			});
+			let error = match result {
+				Ok(Ok(_)) => return Ok(()),
+				Ok(Err(e)) => e.error,
+				Err(e) => e,
+			};
+			let is = |e: Error<T, I>| error == e.into();
+			Err(if is(Error::<T, I>::NotMember) {
+				VoteRejection::NotMember
+			} else if is(Error::<T, I>::RankTooLow) {
+				match T::Polls::as_ongoing(poll) {
+					Some((_, class)) =>
+						VoteRejection::RankTooLow { required: T::MinRankOfClass::convert(class) },
+					None => VoteRejection::NotPolling,
+				}
+			} else if is(Error::<T, I>::NotPolling) {
+				VoteRejection::NotPolling
+			} else if is(Error::<T, I>::ClassFrozen) {
+				VoteRejection::ClassFrozen
+			} else if is(Error::<T, I>::NotInPanel) {
+				VoteRejection::NotInPanel
+			} else if is(Error::<T, I>::ClassNotAllowed) {
+				VoteRejection::ClassNotAllowed
+			} else {
+				VoteRejection::Other(error)
+			})
+		}
+	}
+}
+
+/// Syncs the rank of a member who joined the `RankSource`, which names this pallet as its handler.
+impl<T: Config<I>, I: 'static> OnMemberAdded<T::AccountId> for Pallet<T, I> {
+	fn on_member_added(who: &T::AccountId, rank: Rank) {
+		let current = Members::<T, I>::get(who).map(|record| record.rank);
+		if let Err(e) = Self::do_sync_rank(who.clone(), current, rank) {
+			log::warn!(target: LOG_TARGET, "could not sync the rank of {:?}: {:?}", who, e);
+		}
+	}
+}
+
+/// Syncs the rank of a member whose rank changed in the `RankSource`, which names this pallet as
+/// its handler.
+impl<T: Config<I>, I: 'static> OnRankChanged<T::AccountId> for Pallet<T, I> {
+	fn on_rank_changed(who: &T::AccountId, _: Rank, new: Rank) {
+		let current = Members::<T, I>::get(who).map(|record| record.rank);
+		if let Err(e) = Self::do_sync_rank(who.clone(), current, new) {
+			log::warn!(target: LOG_TARGET, "could not sync the rank of {:?}: {:?}", who, e);
+		}
+	}
+}
+
+#[cfg(any(test, feature = "try-runtime"))]
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Check that `Members` and the indices of the members of each rank agree: every member is
+	/// indexed at each rank up to theirs, and `MemberCount` of each rank is the number of members
+	/// who have at least that rank, so that nobody else is indexed.
+	pub(crate) fn do_try_state() -> Result<(), &'static str> {
+		for rank in MemberCount::<T, I>::iter_keys() {
+			MemberIndices::<T, I>::try_state(&rank)?;
+		}
+		// The number of members who have at least each rank.
+		let mut counts = Vec::<MemberIndex>::new();
+		for (who, record) in Members::<T, I>::iter() {
+			for rank in 0..=record.rank {
+				ensure!(
+					MemberIndices::<T, I>::contains(&rank, &who),
+					"a member is not indexed at a rank up to theirs"
+				);
+			}
+			let ranks = record.rank as usize + 1;
+			if counts.len() < ranks {
+				counts.resize(ranks, 0);
+			}
+			counts[..ranks].iter_mut().for_each(|count| count.saturating_inc());
+		}
+		for (rank, count) in MemberCount::<T, I>::iter() {
+			ensure!(
+				counts.get(rank as usize).copied().unwrap_or_default() == count,
+				"the member count of a rank does not match its members"
+			);
+		}
+		for (rank, count) in counts.into_iter().enumerate() {
+			ensure!(
+				MemberCount::<T, I>::get(rank as Rank) == count,
+				"the member count of a rank does not match its members"
+			);
+		}
+		Ok(())
 	}
 }
@@ -0,0 +1,825 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the ranked collective pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+pub mod v1 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v1";
+
+	/// Aggregated votes for an ongoing poll, before the number of nay voters was tracked.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Tally {
+		pub bare_ayes: MemberIndex,
+		pub ayes: u32,
+		pub nays: u32,
+	}
+
+	/// The tallies of the ongoing polls, stored as `Old`.
+	///
+	/// These live in the storage of the polling system (e.g. the Referenda pallet) rather than in
+	/// this pallet, so the runtime must provide access to them.
+	pub trait TallyStore<T: Config<I>, I: 'static, Old = Tally, New = TallyOf<T, I>> {
+		/// Translate the tally of every ongoing poll with `f`, returning the number of polls
+		/// translated.
+		fn translate(f: impl FnMut(PollIndexOf<T, I>, Old) -> New) -> u32;
+	}
+
+	/// Adds the number of nay voters to the tallies of all ongoing polls.
+	pub struct MigrateToV1<T, I, S>(PhantomData<(T, I, S)>);
+	impl<T: Config<I>, I: 'static, S: TallyStore<T, I, Tally, v2::Tally>> OnRuntimeUpgrade
+		for MigrateToV1<T, I, S>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 0, "migration from version 0 to 1.");
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 0 {
+				log::warn!(target: TARGET, "skipping migration from v0 to v1.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut votes_read = 0u64;
+			let polls = S::translate(|poll, old| {
+				// Recover the number of nay voters from the votes recorded for the poll, which
+				// are still counted in `u32`.
+				let mut bare_nays: MemberIndex = 0;
+				for vote in v6::Voting::<T, I>::iter_prefix_values(poll) {
+					votes_read.saturating_inc();
+					if matches!(vote, v6::VoteRecord::Nay(..)) {
+						bare_nays.saturating_inc();
+					}
+				}
+				v2::Tally { bare_ayes: old.bare_ayes, bare_nays, ayes: old.ayes, nays: old.nays }
+			});
+			log::info!(target: TARGET, "migrated the tallies of {} ongoing polls.", polls);
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			T::DbWeight::get().reads_writes(
+				votes_read.saturating_add(polls as u64).saturating_add(1),
+				(polls as u64).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 1, "must upgrade from version 0 to 1.");
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v2";
+
+	/// Aggregated votes for an ongoing poll, before the number of abstaining members was tracked.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Tally {
+		pub bare_ayes: MemberIndex,
+		pub bare_nays: MemberIndex,
+		pub ayes: u32,
+		pub nays: u32,
+	}
+
+	/// Adds the number of abstaining members, none so far, to the tallies of all ongoing polls.
+	pub struct MigrateToV2<T, I, S>(PhantomData<(T, I, S)>);
+	impl<T: Config<I>, I: 'static, S: v1::TallyStore<T, I, Tally, v5::Tally>> OnRuntimeUpgrade
+		for MigrateToV2<T, I, S>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 1, "migration from version 1 to 2.");
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 1 {
+				log::warn!(target: TARGET, "skipping migration from v1 to v2.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let polls = S::translate(|_, old| v5::Tally {
+				bare_ayes: old.bare_ayes,
+				bare_nays: old.bare_nays,
+				bare_abstains: 0,
+				ayes: old.ayes,
+				nays: old.nays,
+			});
+			log::info!(target: TARGET, "migrated the tallies of {} ongoing polls.", polls);
+
+			StorageVersion::new(2).put::<Pallet<T, I>>();
+			T::DbWeight::get()
+				.reads_writes((polls as u64).saturating_add(1), (polls as u64).saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 2, "must upgrade from version 1 to 2.");
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v3";
+
+	/// Record of a member, before members could be on probation.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct MemberRecord {
+		pub rank: Rank,
+	}
+
+	/// Marks all existing members as full members, none of them being on probation.
+	pub struct MigrateToV3<T, I>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 2, "migration from version 2 to 3.");
+			Ok((Members::<T, I>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 2 {
+				log::warn!(target: TARGET, "skipping migration from v2 to v3.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut members = 0u64;
+			v4::Members::<T, I>::translate_values::<MemberRecord, _>(|old| {
+				members.saturating_inc();
+				Some(v4::MemberRecord { rank: old.rank, probationary: false })
+			});
+			log::info!(target: TARGET, "migrated the records of {} members.", members);
+
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+			T::DbWeight::get().reads_writes(members.saturating_add(1), members.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 3, "must upgrade from version 2 to 3.");
+			let members: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			ensure!(
+				Members::<T, I>::iter_keys().count() as u32 == members,
+				"no member should be lost."
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v4";
+
+	/// Record of a member, before their activity was tracked.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct MemberRecord {
+		pub rank: Rank,
+		pub probationary: bool,
+	}
+
+	/// The records of the members, before their activity was tracked.
+	#[frame_support::storage_alias]
+	pub type Members<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		MemberRecord,
+	>;
+
+	/// Adds the activity of the existing members to their records. None of it is known, so their
+	/// activity is tracked from the block of the upgrade, starting the clock of their inactivity
+	/// from there.
+	pub struct MigrateToV4<T, I>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 3, "migration from version 3 to 4.");
+			Ok((Members::<T, I>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 3 {
+				log::warn!(target: TARGET, "skipping migration from v3 to v4.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut members = 0u64;
+			crate::Members::<T, I>::translate_values::<MemberRecord, _>(|old| {
+				members.saturating_inc();
+				Some(crate::MemberRecord {
+					probationary: old.probationary,
+					tracked_since: Some(now),
+					..crate::MemberRecord::new(old.rank)
+				})
+			});
+			log::info!(target: TARGET, "migrated the records of {} members.", members);
+
+			StorageVersion::new(4).put::<Pallet<T, I>>();
+			T::DbWeight::get().reads_writes(members.saturating_add(1), members.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 4, "must upgrade from version 3 to 4.");
+			let members: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			ensure!(
+				Members::<T, I>::iter_keys().count() as u32 == members,
+				"no member should be lost."
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v5";
+
+	/// Aggregated votes for an ongoing poll, before the number of eligible voters could be
+	/// snapshotted.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Tally {
+		pub bare_ayes: MemberIndex,
+		pub bare_nays: MemberIndex,
+		pub bare_abstains: MemberIndex,
+		pub ayes: u32,
+		pub nays: u32,
+	}
+
+	impl From<Tally> for v6::Tally {
+		fn from(old: Tally) -> Self {
+			v6::Tally {
+				bare_ayes: old.bare_ayes,
+				bare_nays: old.bare_nays,
+				bare_abstains: old.bare_abstains,
+				ayes: old.ayes,
+				nays: old.nays,
+				max_voters: None,
+			}
+		}
+	}
+
+	/// The certificate of a decided poll, before the number of eligible voters could be
+	/// snapshotted.
+	pub type TallyCertificateOf<T, I> = TallyCertificate<
+		PollIndexOf<T, I>,
+		ClassOf<T, I>,
+		Tally,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// Adds an empty snapshot of the eligible voters to the tallies of all ongoing polls and of
+	/// the certificates of the decided ones.
+	pub struct MigrateToV5<T, I, S>(PhantomData<(T, I, S)>);
+	impl<T: Config<I>, I: 'static, S: v1::TallyStore<T, I, Tally, v6::Tally>> OnRuntimeUpgrade
+		for MigrateToV5<T, I, S>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 4, "migration from version 4 to 5.");
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 4 {
+				log::warn!(target: TARGET, "skipping migration from v4 to v5.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let polls = S::translate(|_, old| old.into()) as u64;
+			log::info!(target: TARGET, "migrated the tallies of {} ongoing polls.", polls);
+
+			let mut certificates = 0u64;
+			v6::TallyCertificates::<T, I>::translate_values::<TallyCertificateOf<T, I>, _>(|old| {
+				certificates.saturating_inc();
+				Some(TallyCertificate {
+					poll: old.poll,
+					class: old.class,
+					tally: old.tally.into(),
+					approved: old.approved,
+					eligible: old.eligible,
+					members: old.members,
+					at: old.at,
+				})
+			});
+			log::info!(target: TARGET, "migrated {} tally certificates.", certificates);
+
+			StorageVersion::new(5).put::<Pallet<T, I>>();
+			let migrated = polls.saturating_add(certificates).saturating_add(1);
+			T::DbWeight::get().reads_writes(migrated, migrated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 5, "must upgrade from version 4 to 5.");
+			Ok(())
+		}
+	}
+}
+
+pub mod v6 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v6";
+
+	/// Aggregated votes for an ongoing poll, while votes were counted in `u32`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Tally {
+		pub bare_ayes: MemberIndex,
+		pub bare_nays: MemberIndex,
+		pub bare_abstains: MemberIndex,
+		pub ayes: u32,
+		pub nays: u32,
+		pub max_voters: Option<MemberIndex>,
+	}
+
+	impl<T, I, M: GetMaxVoters> From<Tally> for crate::Tally<T, I, M> {
+		fn from(old: Tally) -> Self {
+			crate::Tally {
+				bare_ayes: old.bare_ayes,
+				bare_nays: old.bare_nays,
+				bare_abstains: old.bare_abstains,
+				ayes: old.ayes.into(),
+				nays: old.nays.into(),
+				max_voters: old.max_voters,
+				dummy: PhantomData,
+			}
+		}
+	}
+
+	/// The certificate of a decided poll, while votes were counted in `u32`.
+	pub type TallyCertificateOf<T, I> = TallyCertificate<
+		PollIndexOf<T, I>,
+		ClassOf<T, I>,
+		Tally,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// The certificates of the decided polls, while votes were counted in `u32`.
+	#[frame_support::storage_alias]
+	pub type TallyCertificates<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Blake2_128Concat, PollIndexOf<T, I>, TallyCertificateOf<T, I>>;
+
+	/// A vote, while votes were counted in `u32`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum VoteRecord {
+		Aye(u32),
+		Nay(u32),
+		Abstain,
+		Delegated(u32),
+	}
+
+	impl From<VoteRecord> for crate::VoteRecord {
+		fn from(old: VoteRecord) -> Self {
+			match old {
+				VoteRecord::Aye(votes) => crate::VoteRecord::Aye(votes.into()),
+				VoteRecord::Nay(votes) => crate::VoteRecord::Nay(votes.into()),
+				VoteRecord::Abstain => crate::VoteRecord::Abstain,
+				VoteRecord::Delegated(votes) => crate::VoteRecord::Delegated(votes.into()),
+			}
+		}
+	}
+
+	/// The votes on the ongoing polls, while votes were counted in `u32`.
+	#[frame_support::storage_alias]
+	pub type Voting<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		PollIndexOf<T, I>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		VoteRecord,
+	>;
+
+	/// The vote-weight scheme set on-chain, while votes were counted in `u32`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct VoteCurve(pub BoundedVec<(Rank, u32), ConstU32<MAX_VOTE_CURVE_POINTS>>);
+
+	/// The votes which the polling system holds of its own, such as those of the referenda queued
+	/// on each track of the Referenda pallet, while votes were counted in `u32`.
+	pub trait QueuedVotesStore {
+		/// Translate every such vote with `f`, returning the number of entries translated.
+		fn translate_votes(f: impl FnMut(u32) -> Votes) -> u32;
+	}
+
+	/// Widens the votes of the tallies of all ongoing polls and of the certificates of the
+	/// decided ones, of the votes cast, of the vote curve and of the votes queued by the polling
+	/// system from `u32` to `u64`.
+	pub struct MigrateToV6<T, I, S>(PhantomData<(T, I, S)>);
+	impl<T: Config<I>, I: 'static, S: v1::TallyStore<T, I, Tally> + QueuedVotesStore>
+		OnRuntimeUpgrade for MigrateToV6<T, I, S>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 5, "migration from version 5 to 6.");
+			Ok((v7::Voting::<T, I>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 5 {
+				log::warn!(target: TARGET, "skipping migration from v5 to v6.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let polls = S::translate(|_, old| old.into()) as u64;
+			log::info!(target: TARGET, "migrated the tallies of {} ongoing polls.", polls);
+			let queued = S::translate_votes(Into::into) as u64;
+			log::info!(target: TARGET, "migrated {} queues of the polling system.", queued);
+
+			let mut certificates = 0u64;
+			crate::TallyCertificates::<T, I>::translate_values::<TallyCertificateOf<T, I>, _>(
+				|old| {
+					certificates.saturating_inc();
+					Some(TallyCertificate {
+						poll: old.poll,
+						class: old.class,
+						tally: old.tally.into(),
+						approved: old.approved,
+						eligible: old.eligible,
+						members: old.members,
+						at: old.at,
+					})
+				},
+			);
+			log::info!(target: TARGET, "migrated {} tally certificates.", certificates);
+
+			let mut votes = 0u64;
+			v7::Voting::<T, I>::translate_values::<VoteRecord, _>(|old| {
+				votes.saturating_inc();
+				Some(old.into())
+			});
+			log::info!(target: TARGET, "migrated {} votes.", votes);
+
+			let curve = crate::VoteWeightCurve::<T, I>::translate::<VoteCurve, _>(|old| {
+				old.map(|VoteCurve(points)| {
+					let points = points.into_iter().map(|(rank, votes)| (rank, votes.into()));
+					crate::VoteCurve(VoteCurvePoints::truncate_from(points.collect()))
+				})
+			});
+			let curve = matches!(curve, Ok(Some(_))) as u64;
+
+			StorageVersion::new(6).put::<Pallet<T, I>>();
+			let migrated = polls
+				.saturating_add(queued)
+				.saturating_add(certificates)
+				.saturating_add(votes)
+				.saturating_add(curve + 1);
+			T::DbWeight::get().reads_writes(migrated, migrated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 6, "must upgrade from version 5 to 6.");
+			let votes: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			ensure!(
+				v7::Voting::<T, I>::iter_values().count() as u32 == votes,
+				"no vote should be lost."
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v7 {
+	use super::*;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v7";
+
+	/// The votes on each ongoing poll, one entry per voter, as they were kept before the votes
+	/// were paged.
+	#[frame_support::storage_alias]
+	pub type Voting<T: Config<I>, I: 'static> = StorageDoubleMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		PollIndexOf<T, I>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		VoteRecord,
+	>;
+
+	/// The account which a member held before their last exchange, whose votes they took over
+	/// lazily.
+	#[frame_support::storage_alias]
+	pub type ExchangedFrom<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	/// Records the ongoing polls for which the pallet holds votes, a panel or vetoes as
+	/// `ActivePolls`, and moves what is still held by the former accounts of exchanged members to
+	/// their current ones.
+	pub struct MigrateToV7<T, I>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 6, "migration from version 6 to 7.");
+			Ok((Voting::<T, I>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 6 {
+				log::warn!(target: TARGET, "skipping migration from v6 to v7.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 0u64;
+			let polls = Voting::<T, I>::iter_keys()
+				.map(|(poll, _)| poll)
+				.chain(crate::Panels::<T, I>::iter_keys())
+				.chain(crate::Vetoes::<T, I>::iter_keys())
+				.inspect(|_| reads.saturating_inc())
+				.filter(|poll| !crate::CompletedPolls::<T, I>::contains_key(poll))
+				.collect::<BTreeSet<_>>();
+			for poll in polls.iter() {
+				crate::ActivePolls::<T, I>::insert(poll, ());
+			}
+			log::info!(target: TARGET, "recorded {} active polls.", polls.len());
+
+			// Follow each former account to the account its member holds now.
+			let successors = ExchangedFrom::<T, I>::drain()
+				.map(|(new_who, who)| (who, new_who))
+				.collect::<BTreeMap<_, _>>();
+			for (who, new_who) in successors.iter() {
+				let mut current = new_who;
+				for _ in 0..successors.len() {
+					match successors.get(current) {
+						Some(next) => current = next,
+						None => break,
+					}
+				}
+				for poll in polls.iter() {
+					if let Some(vote) = Voting::<T, I>::take(poll, who) {
+						Voting::<T, I>::insert(poll, current, vote);
+					}
+				}
+				Pallet::<T, I>::move_poll_state(who, current);
+			}
+			log::info!(target: TARGET, "moved the polls of {} former accounts.", successors.len());
+
+			StorageVersion::new(7).put::<Pallet<T, I>>();
+			let moved = (successors.len() as u64).saturating_mul(polls.len() as u64 * 3);
+			T::DbWeight::get().reads_writes(
+				reads.saturating_add(polls.len() as u64).saturating_add(moved).saturating_add(1),
+				(polls.len() as u64).saturating_add(moved).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 7, "must upgrade from version 6 to 7.");
+			let votes: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			ensure!(Voting::<T, I>::iter_keys().count() as u32 == votes, "no vote should be lost.");
+			ensure!(ExchangedFrom::<T, I>::iter_keys().next().is_none(), "no former account left.");
+			Ok(())
+		}
+	}
+}
+
+pub mod v8 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::v8";
+
+	/// The last key of `v7::Voting` removed by the unfinished cleanup of a completed poll.
+	#[frame_support::storage_alias]
+	pub type VotingCleanup<T: Config<I>, I: 'static> =
+		StorageMap<Pallet<T, I>, Blake2_128Concat, PollIndexOf<T, I>, Vec<u8>>;
+
+	/// Moves the votes of every poll from one entry per voter into the `VotePages` of the poll,
+	/// giving each voter its index into them.
+	///
+	/// Unfinished cleanups continue on the pages, and an unfinished `remap_class` starts over.
+	///
+	/// All votes are moved in the block of the upgrade, so `MaxVotes` bounds the votes and the
+	/// unfinished cleanups it may move, and must be low enough for moving them to fit in a block.
+	/// With more of them, nothing is moved and the storage is left at version 7, so that a
+	/// runtime with too many votes on its ongoing polls needs to wait for some of them to
+	/// complete before upgrading. `pre_upgrade` fails in that case.
+	pub struct MigrateToV8<T, I, MaxVotes>(PhantomData<(T, I, MaxVotes)>);
+	impl<T: Config<I>, I: 'static, MaxVotes: Get<u32>> OnRuntimeUpgrade
+		for MigrateToV8<T, I, MaxVotes>
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 7, "migration from version 7 to 8.");
+			ensure!(
+				v7::Voting::<T, I>::iter_keys().count() as u32 <= MaxVotes::get() &&
+					VotingCleanup::<T, I>::iter_keys().count() as u32 <= MaxVotes::get(),
+				"too many votes to move in one block."
+			);
+			Ok(v7::Voting::<T, I>::iter().collect::<Vec<_>>().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version != 7 {
+				log::warn!(target: TARGET, "skipping migration from v7 to v8.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Count no further than one past the bound, so that too many votes cost no more than
+			// moving as many would.
+			let max_votes = MaxVotes::get() as usize;
+			let too_many = v7::Voting::<T, I>::iter_keys().take(max_votes + 1).count() > max_votes ||
+				VotingCleanup::<T, I>::iter_keys().take(max_votes + 1).count() > max_votes;
+			if too_many {
+				log::error!(target: TARGET, "skipping migration with more than {} votes.", max_votes);
+				return T::DbWeight::get()
+					.reads((max_votes as u64).saturating_add(1).saturating_mul(2))
+			}
+
+			let mut votes = 0u64;
+			for (poll, who, vote) in v7::Voting::<T, I>::drain() {
+				Pallet::<T, I>::set_vote(poll, &who, vote);
+				votes.saturating_inc();
+			}
+			log::info!(target: TARGET, "paged {} votes.", votes);
+			let cleanups = VotingCleanup::<T, I>::clear(MaxVotes::get(), None).unique as u64;
+			crate::ClassRemapCursor::<T, I>::kill();
+
+			StorageVersion::new(8).put::<Pallet<T, I>>();
+			// Counting the votes, reading and writing the page and, at most, giving the voter an
+			// index.
+			T::DbWeight::get().reads_writes(
+				votes
+					.saturating_mul(5)
+					.saturating_add(cleanups.saturating_mul(2))
+					.saturating_add(1),
+				votes.saturating_mul(5).saturating_add(cleanups).saturating_add(2),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			ensure!(onchain_version == 8, "must upgrade from version 7 to 8.");
+			let votes: Vec<(PollIndexOf<T, I>, T::AccountId, VoteRecord)> =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			for (poll, who, vote) in votes.iter() {
+				ensure!(
+					Pallet::<T, I>::vote_of(*poll, who) == Some(*vote),
+					"every vote should be paged."
+				);
+			}
+			ensure!(
+				crate::VotePages::<T, I>::iter_values()
+					.map(|page| page.len() as usize)
+					.sum::<usize>() == votes.len(),
+				"no vote should be added."
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Import of the membership of a `pallet-collective` instance.
+pub mod from_collective {
+	use super::*;
+	use frame_support::{storage::migration::get_storage_value, traits::PalletInfoAccess};
+
+	/// The log target.
+	const TARGET: &str = "runtime::ranked-collective::migration::from_collective";
+
+	/// The members of the collective instance `P`, in the layout of `pallet-collective`.
+	fn collective_members<P: PalletInfoAccess, AccountId: Decode>() -> Vec<AccountId> {
+		get_storage_value(P::name().as_bytes(), b"Members", &[]).unwrap_or_default()
+	}
+
+	/// The prime member of the collective instance `P`, in the layout of `pallet-collective`.
+	fn collective_prime<P: PalletInfoAccess, AccountId: Decode>() -> Option<AccountId> {
+		get_storage_value(P::name().as_bytes(), b"Prime", &[])
+	}
+
+	/// Adds every member of the collective instance `P` at rank 0, and its prime member at
+	/// rank 1.
+	///
+	/// `P` is the collective pallet as named in the runtime, e.g. `Council`. The collective's
+	/// storage is only read, not removed. Nothing is imported into a collective which already
+	/// has members, so the migration may stay in place for more than one upgrade.
+	pub struct ImportCollective<T, I, P>(PhantomData<(T, I, P)>);
+	impl<T: Config<I>, I: 'static, P: PalletInfoAccess> OnRuntimeUpgrade for ImportCollective<T, I, P> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let members = collective_members::<P, T::AccountId>();
+			let prime = collective_prime::<P, T::AccountId>();
+			let empty = Members::<T, I>::iter_keys().next().is_none();
+			Ok((empty, members, prime).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if Members::<T, I>::iter_keys().next().is_some() {
+				log::warn!(target: TARGET, "skipping import into a collective with members.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let members = collective_members::<P, T::AccountId>();
+			let prime = collective_prime::<P, T::AccountId>();
+			let mut weight = T::DbWeight::get().reads(3);
+			for who in members.iter() {
+				let rank = if prime.as_ref() == Some(who) { 1 } else { 0 };
+				match Pallet::<T, I>::do_add_member_to_rank(who.clone(), rank) {
+					Ok(()) => {
+						weight.saturating_accrue(T::WeightInfo::add_member());
+						if rank > 0 {
+							weight.saturating_accrue(T::WeightInfo::promote_member(0));
+						}
+					},
+					Err(e) => log::warn!(target: TARGET, "could not import {:?}: {:?}", who, e),
+				}
+			}
+			log::info!(target: TARGET, "imported {} members of {}.", members.len(), P::name());
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (empty, members, prime): (bool, Vec<T::AccountId>, Option<T::AccountId>) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "the state of the pre-upgrade should decode.")?;
+			if empty {
+				for who in members.iter() {
+					let rank = if prime.as_ref() == Some(who) { 1 } else { 0 };
+					ensure!(
+						Members::<T, I>::get(who).map(|r| r.rank) == Some(rank),
+						"every member of the collective should be imported at its rank."
+					);
+				}
+				ensure!(
+					MemberCount::<T, I>::get(0) as usize == members.len(),
+					"only the members of the collective should be imported."
+				);
+			}
+			Pallet::<T, I>::do_try_state()
+		}
+	}
+}
@@ -22,10 +22,15 @@ use std::collections::BTreeMap;
 use frame_support::{
 	assert_noop, assert_ok,
 	error::BadOrigin,
-	pallet_prelude::Weight,
+	pallet_prelude::{Pays, Weight},
 	parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64, EitherOf, Everything, MapSuccess, Polling},
+	traits::{
+		ChangeMembers, ConstU16, ConstU32, ConstU64, EitherOf, EnsureOriginWithArg, Everything,
+		GenesisBuild, InitializeMembers, MapSuccess, Polling, StorageVersion,
+	},
+	BoundedVec,
 };
+use frame_support_test::TestRandomness;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -57,7 +62,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type Index = u64;
 	type BlockNumber = u64;
@@ -88,10 +93,26 @@ pub enum TestPollState {
 use TestPollState::*;
 
 parameter_types! {
+	pub static SupportIncludesNays: bool = false;
+	pub static SnapshotVoters: bool = false;
+	pub static CompactVoteEvents: bool = false;
+	pub static IdleCleanupWeight: Weight = Weight::zero();
+	pub static AllowSelfDemotion: bool = false;
+	pub static UnrelatedReviews: Vec<(u64, u8)> = vec![];
+	pub static FrozenNotes: Vec<(Rank, bool)> = vec![];
+	pub static HookCalls: Vec<(u64, Option<Rank>, Option<Rank>)> = vec![];
+	pub storage Badges: BTreeMap<u64, Rank> = BTreeMap::new();
+	pub storage SplitMembers: BTreeMap<u64, Rank> = BTreeMap::new();
+	pub static SplitRefused: Vec<u64> = vec![];
+	pub static DemotionPeriods: BTreeMap<Rank, u64> = BTreeMap::new();
+	pub static MaxInactivityChecks: u32 = 0;
+	pub static MaxMembersPerRank: u32 = u32::MAX;
+	pub static SourceRanks: BTreeMap<u64, Rank> = BTreeMap::new();
+	pub static RankSyncPolicy: SyncPolicy = SyncPolicy::SourceWins;
 	pub static Polls: BTreeMap<u8, TestPollState> = vec![
 		(1, Completed(1, true)),
 		(2, Completed(2, false)),
-		(3, Ongoing(Tally::from_parts(0, 0, 0), 1)),
+		(3, Ongoing(Tally::from_parts(0, 0, 0, 0), 1)),
 	].into_iter().collect();
 }
 
@@ -113,6 +134,15 @@ impl Polling<TallyOf<Test>> for TestPolls {
 			}
 		})
 	}
+	fn ongoing_polls() -> Vec<(u8, TallyOf<Test>, Self::Class)> {
+		Polls::get()
+			.into_iter()
+			.filter_map(|(i, x)| match x {
+				TestPollState::Ongoing(t, c) => Some((i, t, c)),
+				_ => None,
+			})
+			.collect()
+	}
 	fn access_poll<R>(
 		index: Self::Index,
 		f: impl FnOnce(PollStatus<&mut TallyOf<Test>, Self::Moment, Self::Class>) -> R,
@@ -145,6 +175,17 @@ impl Polling<TallyOf<Test>> for TestPolls {
 		Polls::set(polls);
 		Ok(r)
 	}
+	fn on_class_frozen(class: &Self::Class, frozen: bool) {
+		let mut notes = FrozenNotes::get();
+		notes.push((*class, frozen));
+		FrozenNotes::set(notes);
+	}
+	fn on_vetoed(index: u8) {
+		let mut polls = Polls::get();
+		let now = frame_system::Pallet::<Test>::block_number();
+		polls.insert(index, Completed(now, false));
+		Polls::set(polls);
+	}
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
@@ -169,6 +210,127 @@ impl Polling<TallyOf<Test>> for TestPolls {
 	}
 }
 
+pub struct TestBadges;
+impl MembershipNft<u64> for TestBadges {
+	fn on_added(who: &u64, rank: Rank) -> DispatchResult {
+		let mut badges = Badges::get();
+		ensure!(badges.insert(*who, rank).is_none(), DispatchError::Other("badge exists"));
+		Badges::set(&badges);
+		Ok(())
+	}
+	fn on_rank_changed(who: &u64, rank: Rank) -> DispatchResult {
+		let mut badges = Badges::get();
+		*badges.get_mut(who).ok_or(DispatchError::Other("no badge"))? = rank;
+		Badges::set(&badges);
+		Ok(())
+	}
+	fn on_removed(who: &u64) -> DispatchResult {
+		let mut badges = Badges::get();
+		badges.remove(who).ok_or(DispatchError::Other("no badge"))?;
+		Badges::set(&badges);
+		Ok(())
+	}
+}
+
+/// Logs each call of a membership hook as the member with their old and new rank.
+pub struct TestHooks;
+impl TestHooks {
+	fn log(who: &u64, old: Option<Rank>, new: Option<Rank>) {
+		let mut calls = HookCalls::get();
+		calls.push((*who, old, new));
+		HookCalls::set(calls);
+	}
+}
+impl ranked::OnMemberAdded<u64> for TestHooks {
+	fn on_member_added(who: &u64, rank: Rank) {
+		Self::log(who, None, Some(rank));
+	}
+}
+impl ranked::OnRankChanged<u64> for TestHooks {
+	fn on_rank_changed(who: &u64, old: Rank, new: Rank) {
+		Self::log(who, Some(old), Some(new));
+	}
+}
+impl ranked::OnMemberRemoved<u64> for TestHooks {
+	fn on_member_removed(who: &u64, rank: Rank) {
+		Self::log(who, Some(rank), None);
+	}
+}
+
+pub struct TestRankSource;
+impl ranked::RankedMembers for TestRankSource {
+	type AccountId = u64;
+	fn rank_of(who: &u64) -> Option<Rank> {
+		SourceRanks::get().get(who).copied()
+	}
+	fn induct(_: &u64) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+	fn promote(_: &u64) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+	fn demote(_: &u64) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+}
+
+pub struct TestSplitTarget;
+impl ranked::RankedMembers for TestSplitTarget {
+	type AccountId = u64;
+	fn rank_of(who: &u64) -> Option<Rank> {
+		SplitMembers::get().get(who).copied()
+	}
+	fn induct(who: &u64) -> DispatchResult {
+		let mut members = SplitMembers::get();
+		ensure!(!SplitRefused::get().contains(who), DispatchError::Unavailable);
+		ensure!(members.insert(*who, 0).is_none(), DispatchError::Other("already a member"));
+		SplitMembers::set(&members);
+		Ok(())
+	}
+	fn promote(who: &u64) -> DispatchResult {
+		let mut members = SplitMembers::get();
+		*members.get_mut(who).ok_or(DispatchError::Other("not a member"))? += 1;
+		SplitMembers::set(&members);
+		Ok(())
+	}
+	fn demote(_: &u64) -> DispatchResult {
+		Err(DispatchError::Other("not supported"))
+	}
+}
+
+pub struct TestDemotionPeriod;
+impl Convert<Rank, u64> for TestDemotionPeriod {
+	fn convert(rank: Rank) -> u64 {
+		DemotionPeriods::get().get(&rank).copied().unwrap_or(0)
+	}
+}
+
+pub struct TestMembershipOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, (u64, MemberOperation)> for TestMembershipOrigin {
+	type Success = Rank;
+	fn try_origin(
+		o: RuntimeOrigin,
+		a: &(u64, MemberOperation),
+	) -> Result<Self::Success, RuntimeOrigin> {
+		match AllowSelfDemotion::get() {
+			true => EnsureSelfDemotion::<Test, ()>::try_origin(o, a),
+			false => Err(o),
+		}
+	}
+}
+
+/// Passes any signed origin, unless the poll is registered as not being about the member.
+pub struct TestReviewOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, (u64, u8)> for TestReviewOrigin {
+	type Success = u64;
+	fn try_origin(o: RuntimeOrigin, a: &(u64, u8)) -> Result<Self::Success, RuntimeOrigin> {
+		match UnrelatedReviews::get().contains(a) {
+			true => Err(o),
+			false => frame_system::EnsureSigned::try_origin(o),
+		}
+	}
+}
+
 impl Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
@@ -184,9 +346,38 @@ impl Config for Test {
 		// Members can demote up to the rank of 3 below them.
 		MapSuccess<EnsureRanked<Test, (), 3>, ReduceBy<ConstU16<3>>>,
 	>;
+	type MembershipOrigin = TestMembershipOrigin;
 	type Polls = TestPolls;
 	type MinRankOfClass = Identity;
 	type VoteWeight = Geometric;
+	type SupportIncludesNays = SupportIncludesNays;
+	type SnapshotVoters = SnapshotVoters;
+	type CompactVoteEvents = CompactVoteEvents;
+	type Randomness = TestRandomness<Self>;
+	type PanelOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxPanelSize = ConstU32<10>;
+	type IdleCleanupWeight = IdleCleanupWeight;
+	type ResignationCooldown = ConstU64<10>;
+	type MaxRankChanges = ConstU32<16>;
+	type MaxFreeVotes = ConstU32<3>;
+	type FreeVotePeriod = ConstU64<10>;
+	type VetoRank = ConstU16<2>;
+	type VetoThreshold = ConstU32<2>;
+	type VetoPeriod = ConstU64<5>;
+	type MembershipNft = TestBadges;
+	type ReviewOrigin = TestReviewOrigin;
+	type MaxDelegators = ConstU32<4>;
+	type ExchangeOrigin = frame_system::EnsureRoot<u64>;
+	type SplitTarget = TestSplitTarget;
+	type DemotionPeriod = TestDemotionPeriod;
+	type MaxInactivityChecks = MaxInactivityChecks;
+	type MaxMembersPerRank = MaxMembersPerRank;
+	type OnMemberAdded = TestHooks;
+	type OnRankChanged = TestHooks;
+	type OnMemberRemoved = TestHooks;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type RankSource = TestRankSource;
+	type RankSyncPolicy = RankSyncPolicy;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -232,7 +423,7 @@ fn completed_poll_should_panic() {
 #[test]
 fn basic_stuff() {
 	new_test_ext().execute_with(|| {
-		assert_eq!(tally(3), Tally::from_parts(0, 0, 0));
+		assert_eq!(tally(3), Tally::from_parts(0, 0, 0, 0));
 	});
 }
 
@@ -248,6 +439,87 @@ fn member_lifecycle_works() {
 	});
 }
 
+#[test]
+fn genesis_config_works() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	GenesisBuild::<Test>::assimilate_storage(
+		&pallet_ranked_collective::GenesisConfig::<Test> {
+			members: vec![(1, 0), (2, 2), (3, 1)],
+			..Default::default()
+		},
+		&mut t,
+	)
+	.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(Members::<Test>::get(2), Some(MemberRecord::new(2)));
+		assert_eq!(member_count(0), 3);
+		assert_eq!(member_count(1), 2);
+		assert_eq!(member_count(2), 1);
+		assert_eq!(member_count(3), 0);
+		assert_eq!(IdToIndex::<Test>::get(1, 3), Some(1));
+		assert_eq!(IndexToId::<Test>::get(2, 0), Some(2));
+		for r in 0..=2 {
+			assert_ok!(MemberIndices::<Test, ()>::try_state(&r));
+		}
+		assert_noop!(Club::add_member(RuntimeOrigin::root(), 3), Error::<Test>::AlreadyMember);
+	});
+}
+
+#[test]
+fn try_state_detects_inconsistent_members() {
+	new_test_ext().execute_with(|| {
+		for (who, rank) in [(1, 2), (2, 0), (3, 1), (4, 2)] {
+			assert_ok!(Club::do_add_member_to_rank(who, rank));
+		}
+		// Removing a member moves the last member of each of their ranks into their index.
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 2));
+		assert_ok!(Club::do_try_state());
+
+		let record = Members::<Test>::get(2).unwrap();
+		Members::<Test>::insert(2, MemberRecord::new(1));
+		assert_eq!(Club::do_try_state(), Err("a member is not indexed at a rank up to theirs"));
+		Members::<Test>::insert(2, record);
+
+		Members::<Test>::remove(3);
+		assert_eq!(
+			Club::do_try_state(),
+			Err("the member count of a rank does not match its members")
+		);
+		Members::<Test>::insert(3, MemberRecord::new(1));
+		assert_ok!(Club::do_try_state());
+
+		IndexToId::<Test>::remove(1, 0);
+		assert_eq!(Club::do_try_state(), Err("no value at an index below the count"));
+	});
+}
+
+#[test]
+#[should_panic(expected = "Members cannot contain duplicate accounts.")]
+fn genesis_config_rejects_duplicates() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let _ = GenesisBuild::<Test>::assimilate_storage(
+		&pallet_ranked_collective::GenesisConfig::<Test> {
+			members: vec![(1, 0), (1, 2)],
+			..Default::default()
+		},
+		&mut t,
+	);
+}
+
+#[test]
+#[should_panic(expected = "Members cannot be more than `MaxMembersPerRank`.")]
+fn genesis_config_rejects_too_many_members() {
+	MaxMembersPerRank::set(2);
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let _ = GenesisBuild::<Test>::assimilate_storage(
+		&pallet_ranked_collective::GenesisConfig::<Test> {
+			members: vec![(1, 0), (2, 2), (3, 1)],
+			..Default::default()
+		},
+		&mut t,
+	);
+}
+
 #[test]
 fn add_remove_works() {
 	new_test_ext().execute_with(|| {
@@ -272,12 +544,98 @@ fn add_remove_works() {
 
 		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
 		assert_eq!(member_count(0), 1);
+		// The indices of removed members are cleared.
+		assert_eq!(IndexToId::<Test>::iter_prefix(0).collect::<Vec<_>>(), vec![(0, 2)]);
+		assert_eq!(IdToIndex::<Test>::iter_prefix(0).collect::<Vec<_>>(), vec![(2, 0)]);
+		assert_ok!(MemberIndices::<Test, ()>::try_state(&0));
 
 		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
 		assert_eq!(member_count(0), 0);
 	});
 }
 
+#[test]
+fn ranked_members_trait_works() {
+	use ranked::RankedMembers;
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(Club::rank_of(&1), None);
+		assert_noop!(Club::promote(&1), Error::<Test>::NotMember);
+		assert_ok!(Club::induct(&1));
+		assert_noop!(Club::induct(&1), Error::<Test>::AlreadyMember);
+		assert_eq!(Club::rank_of(&1), Some(0));
+		assert_ok!(Club::promote(&1));
+		assert_ok!(Club::promote(&1));
+		assert_eq!(Club::rank_of(&1), Some(2));
+		assert_eq!(member_count(2), 1);
+
+		assert_ok!(Club::demote(&1));
+		assert_eq!(Club::rank_of(&1), Some(1));
+		System::assert_last_event(Event::RankChanged { who: 1, rank: 1 }.into());
+		assert_ok!(Club::demote(&1));
+		assert_ok!(Club::demote(&1));
+		assert_eq!(Club::rank_of(&1), None);
+		assert_eq!(member_count(0), 0);
+		assert_noop!(Club::demote(&1), Error::<Test>::NotMember);
+	});
+}
+
+#[test]
+fn batch_membership_operations_work() {
+	new_test_ext().execute_with(|| {
+		let batch = |who: Vec<u64>| BoundedVec::truncate_from(who);
+		assert_noop!(Club::add_members(RuntimeOrigin::signed(1), batch(vec![1, 2])), BadOrigin);
+		assert_ok!(Club::add_members(RuntimeOrigin::root(), batch(vec![1, 2, 3])));
+		assert_eq!(member_count(0), 3);
+		// A failure anywhere in the batch reverts all of it.
+		assert_noop!(
+			Club::add_members(RuntimeOrigin::root(), batch(vec![4, 3])),
+			Error::<Test>::AlreadyMember
+		);
+
+		assert_ok!(Club::promote_members(RuntimeOrigin::root(), batch(vec![1, 2])));
+		assert_eq!(member_count(1), 2);
+		assert_noop!(
+			Club::promote_members(RuntimeOrigin::root(), batch(vec![1, 4])),
+			Error::<Test>::NotMember
+		);
+
+		assert_noop!(
+			Club::remove_members(RuntimeOrigin::root(), batch(vec![3, 1]), 0),
+			Error::<Test>::InvalidWitness
+		);
+		assert_ok!(Club::remove_members(RuntimeOrigin::root(), batch(vec![3, 1]), 1));
+		assert_eq!(member_count(0), 1);
+		assert_eq!(member_count(1), 1);
+		System::assert_last_event(Event::MemberRemoved { who: 1, rank: 1 }.into());
+	});
+}
+
+#[test]
+fn members_per_rank_are_bounded() {
+	new_test_ext().execute_with(|| {
+		MaxMembersPerRank::set(2);
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+		assert_noop!(Club::add_member(RuntimeOrigin::root(), 3), Error::<Test>::TooManyMembers);
+		assert_noop!(Club::induct_member(RuntimeOrigin::root(), 3), Error::<Test>::TooManyMembers);
+
+		// The cap counts the members of higher ranks too.
+		MaxMembersPerRank::set(1);
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_noop!(Club::promote_member(RuntimeOrigin::root(), 2), Error::<Test>::TooManyMembers);
+
+		// Making room admits the next member.
+		MaxMembersPerRank::set(2);
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
+		assert_eq!(member_count(0), 2);
+		MaxMembersPerRank::set(u32::MAX);
+	});
+}
+
 #[test]
 fn promote_demote_works() {
 	new_test_ext().execute_with(|| {
@@ -376,6 +734,90 @@ fn promote_demote_by_rank_works() {
 	});
 }
 
+#[test]
+fn membership_origin_may_allow_self_demotion() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+
+		assert_noop!(Club::demote_member(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+
+		AllowSelfDemotion::set(true);
+		// Only the member themselves may step down, and never step up.
+		assert_noop!(Club::demote_member(RuntimeOrigin::signed(2), 1), DispatchError::BadOrigin);
+		assert_noop!(Club::promote_member(RuntimeOrigin::signed(2), 2), DispatchError::BadOrigin);
+		assert_noop!(Club::add_member(RuntimeOrigin::signed(3), 3), DispatchError::BadOrigin);
+
+		assert_ok!(Club::demote_member(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(1));
+		assert_ok!(Club::remove_member(RuntimeOrigin::signed(1), 1, 1));
+		assert_eq!(Members::<Test>::get(1), None);
+		assert_eq!(member_count(0), 1);
+
+		// The same holds for each account of a batch.
+		let batch = |who: Vec<u64>| BoundedVec::truncate_from(who);
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
+		assert_noop!(
+			Club::remove_members(RuntimeOrigin::signed(2), batch(vec![2, 3]), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Club::remove_members(RuntimeOrigin::signed(2), batch(vec![]), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Club::promote_members(RuntimeOrigin::signed(2), batch(vec![2])),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Club::add_members(RuntimeOrigin::signed(4), batch(vec![4])),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Club::remove_members(RuntimeOrigin::signed(2), batch(vec![2]), 0));
+		assert_eq!(Members::<Test>::get(2), None);
+		AllowSelfDemotion::set(false);
+	});
+}
+
+#[test]
+fn resign_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+
+		assert_noop!(Club::resign(RuntimeOrigin::signed(3), 0, false), Error::<Test>::NotMember);
+		assert_noop!(
+			Club::resign(RuntimeOrigin::signed(1), 0, false),
+			Error::<Test>::InvalidWitness
+		);
+
+		// Resigning without a cooldown allows being added back straight away.
+		assert_ok!(Club::resign(RuntimeOrigin::signed(2), 0, false));
+		System::assert_last_event(
+			Event::MemberResigned { who: 2, rank: 0, cooldown_until: None }.into(),
+		);
+		assert_eq!(Members::<Test>::get(2), None);
+		assert_eq!(member_count(0), 1);
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+
+		// Resigning with a cooldown removes all ranks and bars re-adding until it ends.
+		assert_ok!(Club::resign(RuntimeOrigin::signed(1), 1, true));
+		System::assert_last_event(
+			Event::MemberResigned { who: 1, rank: 1, cooldown_until: Some(11) }.into(),
+		);
+		assert_eq!(Members::<Test>::get(1), None);
+		assert_eq!(member_count(0), 1);
+		assert_eq!(member_count(1), 0);
+		assert_noop!(Club::add_member(RuntimeOrigin::root(), 1), Error::<Test>::InCooldown);
+		run_to(11);
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_eq!(ResignedUntil::<Test>::get(1), None);
+	});
+}
+
 #[test]
 fn voting_works() {
 	new_test_ext().execute_with(|| {
@@ -391,116 +833,1959 @@ fn voting_works() {
 		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
 
 		assert_noop!(Club::vote(RuntimeOrigin::signed(0), 3, true), Error::<Test>::RankTooLow);
-		assert_eq!(tally(3), Tally::from_parts(0, 0, 0));
+		assert_eq!(tally(3), Tally::from_parts(0, 0, 0, 0));
 
 		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
-		assert_eq!(tally(3), Tally::from_parts(1, 1, 0));
+		assert_eq!(tally(3), Tally::from_parts(1, 0, 1, 0));
 		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
-		assert_eq!(tally(3), Tally::from_parts(0, 0, 1));
+		assert_eq!(tally(3), Tally::from_parts(0, 1, 0, 1));
 
 		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, true));
-		assert_eq!(tally(3), Tally::from_parts(1, 3, 1));
+		assert_eq!(tally(3), Tally::from_parts(1, 1, 3, 1));
 		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
-		assert_eq!(tally(3), Tally::from_parts(0, 0, 4));
+		assert_eq!(tally(3), Tally::from_parts(0, 2, 0, 4));
 
 		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, true));
-		assert_eq!(tally(3), Tally::from_parts(1, 6, 4));
+		assert_eq!(tally(3), Tally::from_parts(1, 2, 6, 4));
 		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, false));
-		assert_eq!(tally(3), Tally::from_parts(0, 0, 10));
+		assert_eq!(tally(3), Tally::from_parts(0, 3, 0, 10));
 	});
 }
 
 #[test]
-fn cleanup_works() {
+fn abstaining_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
-		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
-		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 0));
+		for who in 1..=3 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), who));
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), who));
+		}
+
+		// Abstaining needs the same rank as voting.
+		assert_noop!(Club::abstain(RuntimeOrigin::signed(0), 3), Error::<Test>::RankTooLow);
+		assert_noop!(Club::abstain(RuntimeOrigin::signed(1), 4), Error::<Test>::NotPolling);
 
+		assert_ok!(Club::abstain(RuntimeOrigin::signed(1), 3));
+		System::assert_last_event(
+			Event::Voted {
+				who: 1,
+				poll: 3,
+				vote: VoteRecord::Abstain,
+				rank_votes: 1,
+				tally: tally(3),
+			}
+			.into(),
+		);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, false));
+		assert_eq!(tally(3).bare_abstains(), 1);
+		assert_eq!(tally(3).turnout(), 3);
+		assert_eq!(Club::vote_of(3, &1), Some(VoteRecord::Abstain));
+
+		// The abstention counts towards neither approval nor support.
+		let t = tally(3);
+		assert_eq!(t.approval(1), Perbill::from_percent(50));
+		assert_eq!(t.support(1), Perbill::from_rational(1u32, 3u32));
+
+		// Abstaining replaces a vote, and a vote replaces an abstention.
+		assert_ok!(Club::abstain(RuntimeOrigin::signed(2), 3));
 		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
-		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
-		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, true));
+		let t = tally(3);
+		assert_eq!((t.ayes(1), t.bare_nays(), t.bare_abstains()), (1, 1, 1));
+		assert_eq!(t.turnout(), 3);
 
-		assert_noop!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10), Error::<Test>::Ongoing);
-		Polls::set(
-			vec![(1, Completed(1, true)), (2, Completed(2, false)), (3, Completed(3, true))]
-				.into_iter()
-				.collect(),
+		// A poll with abstentions has votes.
+		assert_noop!(
+			Club::select_panel(RuntimeOrigin::root(), 3, 2, 0),
+			Error::<Test>::PollHasVotes
 		);
-		assert_ok!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10));
-		// NOTE: This will fail until #10016 is merged.
-		//		assert_noop!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10),
-		// Error::<Test>::NoneRemaining);
 	});
 }
 
 #[test]
-fn ensure_ranked_works() {
+fn delegated_votes_are_counted_with_the_delegate() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
-		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
-		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
-		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		assert_ok!(Club::do_add_member_to_rank(3, 2));
+		for who in 4..=6 {
+			assert_ok!(Club::do_add_member_to_rank(who, 0));
+		}
+		// Member 2 votes before delegating, so keeps their own vote.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
 
-		use frame_support::traits::OriginTrait;
-		type Rank1 = EnsureRanked<Test, (), 1>;
-		type Rank2 = EnsureRanked<Test, (), 2>;
-		type Rank3 = EnsureRanked<Test, (), 3>;
-		type Rank4 = EnsureRanked<Test, (), 4>;
-		assert_eq!(Rank1::try_origin(RuntimeOrigin::signed(1)).unwrap(), 1);
-		assert_eq!(Rank1::try_origin(RuntimeOrigin::signed(2)).unwrap(), 2);
-		assert_eq!(Rank1::try_origin(RuntimeOrigin::signed(3)).unwrap(), 3);
-		assert_eq!(
-			Rank2::try_origin(RuntimeOrigin::signed(1)).unwrap_err().as_signed().unwrap(),
-			1
+		assert_noop!(Club::delegate(RuntimeOrigin::signed(7), 1, 1), Error::<Test>::NotMember);
+		assert_noop!(
+			Club::delegate(RuntimeOrigin::signed(1), 1, 1),
+			Error::<Test>::InvalidDelegate
 		);
-		assert_eq!(Rank2::try_origin(RuntimeOrigin::signed(2)).unwrap(), 2);
-		assert_eq!(Rank2::try_origin(RuntimeOrigin::signed(3)).unwrap(), 3);
-		assert_eq!(
-			Rank3::try_origin(RuntimeOrigin::signed(1)).unwrap_err().as_signed().unwrap(),
-			1
+		for who in 2..=5 {
+			assert_ok!(Club::delegate(RuntimeOrigin::signed(who), 1, 1));
+		}
+		System::assert_last_event(Event::Delegated { who: 5, class: 1, to: 1 }.into());
+		assert_noop!(
+			Club::delegate(RuntimeOrigin::signed(6), 1, 1),
+			Error::<Test>::TooManyDelegators
 		);
-		assert_eq!(
-			Rank3::try_origin(RuntimeOrigin::signed(2)).unwrap_err().as_signed().unwrap(),
-			2
+		assert_noop!(
+			Club::delegate(RuntimeOrigin::signed(2), 1, 6),
+			Error::<Test>::AlreadyDelegating
 		);
-		assert_eq!(Rank3::try_origin(RuntimeOrigin::signed(3)).unwrap(), 3);
-		assert_eq!(
-			Rank4::try_origin(RuntimeOrigin::signed(1)).unwrap_err().as_signed().unwrap(),
-			1
+		assert_noop!(
+			Club::delegate(RuntimeOrigin::signed(6), 1, 2),
+			Error::<Test>::InvalidDelegate
 		);
-		assert_eq!(
-			Rank4::try_origin(RuntimeOrigin::signed(2)).unwrap_err().as_signed().unwrap(),
-			2
+		assert_noop!(
+			Club::delegate(RuntimeOrigin::signed(1), 1, 6),
+			Error::<Test>::InvalidDelegate
 		);
-		assert_eq!(
-			Rank4::try_origin(RuntimeOrigin::signed(3)).unwrap_err().as_signed().unwrap(),
-			3
+		assert_noop!(Club::vote(RuntimeOrigin::signed(2), 3, true), Error::<Test>::VoteDelegated);
+
+		// Member 3's votes are added to member 1's, the members of rank 0 have no votes on the
+		// class, and member 2 voted themselves.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		System::assert_last_event(
+			Event::Voted {
+				who: 1,
+				poll: 3,
+				vote: VoteRecord::Aye(4),
+				rank_votes: 1,
+				tally: tally(3),
+			}
+			.into(),
+		);
+		assert_eq!(Club::vote_of(3, &1), Some(VoteRecord::Aye(4)));
+		assert_eq!(Club::vote_of(3, &3), Some(VoteRecord::Delegated(3)));
+		assert_eq!(Club::vote_of(3, &4), None);
+		assert_eq!(tally(3), Tally::from_parts(1, 1, 4, 1));
+
+		// Undelegating takes back the votes already counted with the delegate's.
+		assert_noop!(
+			Club::undelegate(RuntimeOrigin::signed(3), 1, 0),
+			Error::<Test>::InvalidWitness
+		);
+		assert_ok!(Club::undelegate(RuntimeOrigin::signed(3), 1, 1));
+		System::assert_last_event(Event::Undelegated { who: 3, class: 1 }.into());
+		assert_noop!(
+			Club::undelegate(RuntimeOrigin::signed(3), 1, 1),
+			Error::<Test>::NotDelegating
 		);
+		assert_eq!(Delegators::<Test>::get(1, 1).into_inner(), vec![2, 4, 5]);
+		assert_eq!(Club::vote_of(3, &1), Some(VoteRecord::Aye(1)));
+		assert_eq!(Club::vote_of(3, &3), None);
+		assert_eq!(tally(3), Tally::from_parts(1, 1, 1, 1));
+
+		// The former delegator may then vote themselves.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, true));
+		assert_eq!(Club::vote_of(3, &3), Some(VoteRecord::Aye(3)));
+		assert_eq!(tally(3), Tally::from_parts(2, 1, 4, 1));
+
+		// A new vote of the delegate counts their current delegators.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
+		assert_eq!(Club::vote_of(3, &3), Some(VoteRecord::Aye(3)));
+		assert_eq!(tally(3), Tally::from_parts(1, 2, 3, 2));
 	});
 }
 
 #[test]
-fn do_add_member_to_rank_works() {
+fn retracting_votes_works() {
 	new_test_ext().execute_with(|| {
-		let max_rank = 9u16;
-		assert_ok!(Club::do_add_member_to_rank(69, max_rank / 2));
-		assert_ok!(Club::do_add_member_to_rank(1337, max_rank));
-		for i in 0..=max_rank {
-			if i <= max_rank / 2 {
-				assert_eq!(member_count(i), 2);
-			} else {
-				assert_eq!(member_count(i), 1);
-			}
+		for who in 1..=3 {
+			assert_ok!(Club::do_add_member_to_rank(who, 1));
 		}
-		assert_eq!(member_count(max_rank + 1), 0);
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(3), 1, 1));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
+		assert_eq!(tally(3), Tally::from_parts(1, 1, 2, 1));
+
+		assert_noop!(Club::retract_vote(RuntimeOrigin::signed(1), 4), Error::<Test>::NotPolling);
+		assert_noop!(Club::retract_vote(RuntimeOrigin::signed(4), 3), Error::<Test>::NotVoted);
+		assert_noop!(Club::retract_vote(RuntimeOrigin::signed(3), 3), Error::<Test>::VoteDelegated);
+
+		// The delegated votes are retracted with the vote of the delegate.
+		assert_ok!(Club::retract_vote(RuntimeOrigin::signed(1), 3));
+		System::assert_last_event(
+			Event::VoteRetracted { who: 1, poll: 3, vote: VoteRecord::Aye(2) }.into(),
+		);
+		assert_eq!(tally(3), Tally::from_parts(0, 1, 0, 1));
+		assert_eq!(Club::vote_of(3, &1), None);
+		assert_eq!(Club::vote_of(3, &3), None);
+		assert_noop!(Club::retract_vote(RuntimeOrigin::signed(1), 3), Error::<Test>::NotVoted);
+
+		// Abstentions may be retracted too, and the member may vote again.
+		assert_ok!(Club::abstain(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Club::retract_vote(RuntimeOrigin::signed(1), 3));
+		assert_eq!(tally(3).turnout(), 1);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
+		assert_eq!(tally(3), Tally::from_parts(0, 2, 0, 3));
+
+		// Votes on completed polls stay.
+		assert_noop!(Club::retract_vote(RuntimeOrigin::signed(1), 1), Error::<Test>::NotPolling);
+	});
+}
+
+#[test]
+fn delegations_end_with_membership() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=4 {
+			assert_ok!(Club::do_add_member_to_rank(who, 1));
+		}
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(2), 1, 1));
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(1), 2, 4));
+
+		// Removing a member ends their delegations and those made to them.
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 1));
+		System::assert_has_event(Event::Undelegated { who: 2, class: 1 }.into());
+		assert_eq!(Delegations::<Test>::get(1, 2), None);
+		assert!(Delegators::<Test>::get(1, 1).is_empty());
+		assert_eq!(Delegations::<Test>::get(2, 1), None);
+		assert!(Delegators::<Test>::get(2, 4).is_empty());
+
+		// So does resigning.
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(2), 1, 3));
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(3), 2, 4));
+		assert_ok!(Club::resign(RuntimeOrigin::signed(3), 1, false));
+		assert_eq!(Delegations::<Test>::get(1, 2), None);
+		assert!(Delegators::<Test>::get(1, 3).is_empty());
+		assert!(Delegators::<Test>::get(2, 4).is_empty());
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, true));
+
+		// And being demoted out of the collective.
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(4), 1, 2));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 4));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 4));
+		assert_eq!(Delegations::<Test>::get(1, 4), None);
+		assert!(Delegators::<Test>::get(1, 2).is_empty());
+	});
+}
+
+#[test]
+fn exchange_member_moves_delegations() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		assert_ok!(Club::do_add_member_to_rank(3, 1));
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(2), 1, 1));
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(1), 2, 3));
+
+		assert_ok!(Club::exchange_member(RuntimeOrigin::signed(1), 1, 10, 2, 0));
+		assert_eq!(Delegations::<Test>::get(1, 2), Some(10));
+		assert_eq!(Delegators::<Test>::get(1, 10).into_inner(), vec![2]);
+		assert!(Delegators::<Test>::get(1, 1).is_empty());
+		assert_eq!(Delegations::<Test>::get(2, 1), None);
+		assert_eq!(Delegations::<Test>::get(2, 10), Some(3));
+		assert_eq!(Delegators::<Test>::get(2, 3).into_inner(), vec![10]);
+
+		// The delegated votes are counted with the vote of the new account.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(10), 3, true));
+		assert_eq!(Club::vote_of(3, &2), Some(VoteRecord::Delegated(1)));
+	});
+}
+
+#[test]
+fn exchange_member_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		let indices = |who| (0..=2).map(|r| IdToIndex::<Test>::get(r, who)).collect::<Vec<_>>();
+		let old_indices = indices(1);
+
+		assert_noop!(
+			Club::exchange_member(RuntimeOrigin::signed(2), 1, 10, 2, 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Club::exchange_member(RuntimeOrigin::signed(1), 1, 2, 2, 1),
+			Error::<Test>::AlreadyMember
+		);
+		assert_noop!(
+			Club::exchange_member(RuntimeOrigin::signed(1), 1, 10, 1, 1),
+			Error::<Test>::InvalidWitness
+		);
+		assert_noop!(
+			Club::exchange_member(RuntimeOrigin::signed(1), 1, 10, 2, 0),
+			Error::<Test>::InvalidWitness
+		);
+		assert_ok!(Club::exchange_member(RuntimeOrigin::signed(1), 1, 10, 2, 1));
+		System::assert_last_event(Event::MemberExchanged { who: 1, new_who: 10 }.into());
+
+		// The new account has the rank and indices of the old one.
+		assert!(!Members::<Test>::contains_key(1));
+		assert_eq!(Members::<Test>::get(10).map(|r| r.rank()), Some(2));
+		assert_eq!(indices(10), old_indices);
+		assert_eq!(member_count(2), 1);
+
+		// The vote of the old account is moved rather than counted twice.
+		assert_eq!(Club::vote_of(3, &1), None);
+		assert_eq!(Club::vote_of(3, &10), Some(VoteRecord::Aye(3)));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(10), 3, false));
+		assert_eq!(tally(3), Tally::from_parts(0, 1, 0, 3));
+
+		// The `ExchangeOrigin` may exchange any member.
+		assert_ok!(Club::exchange_member(RuntimeOrigin::root(), 2, 20, 1, 1));
+		assert_ok!(MemberIndices::<Test, ()>::try_state(&1));
+	});
+}
+
+#[test]
+fn exchange_member_moves_votes_panel_seats_and_vetoes() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=3 {
+			assert_ok!(Club::do_add_member_to_rank(who, 2));
+		}
+		Polls::mutate(|p| p.insert(4, Ongoing(Tally::from_parts(0, 0, 0, 0), 1)));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_ok!(Club::veto(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Club::select_panel(RuntimeOrigin::root(), 4, 3, 7));
+		assert_eq!(ActivePolls::<Test>::count(), 2);
+
+		assert_ok!(Club::exchange_member(RuntimeOrigin::signed(1), 1, 10, 2, 2));
+		assert_eq!(Club::vote_of(3, &10), Some(VoteRecord::Aye(3)));
+		assert_eq!(Vetoes::<Test>::get(3).map(|(_, v)| v.into_inner()), Some(vec![10]));
+		assert!(Panels::<Test>::get(4).unwrap().contains(&10));
+		assert_noop!(Club::veto(RuntimeOrigin::signed(10), 3), Error::<Test>::AlreadyVetoed);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(10), 4, true));
+
+		// The old account, once back in the collective, neither votes with nor retracts the
+		// moved vote.
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_noop!(Club::retract_vote(RuntimeOrigin::signed(1), 3), Error::<Test>::NotVoted);
+		assert_noop!(Club::vote(RuntimeOrigin::signed(1), 4, true), Error::<Test>::NotInPanel);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
+		assert_eq!(tally(3), Tally::from_parts(1, 1, 3, 3));
+
+		// A vote moved over several exchanges may be retracted by the latest account.
+		assert_ok!(Club::exchange_member(RuntimeOrigin::signed(10), 10, 11, 2, 2));
+		assert_ok!(Club::retract_vote(RuntimeOrigin::signed(11), 3));
+		assert_eq!(Club::vote_of(3, &11), None);
+
+		// Completed polls no longer count towards the witness.
+		<Club as OnPollCompleted<_, _, _>>::on_poll_completed(3, 1, &tally(3), false);
+		assert_eq!(ActivePolls::<Test>::count(), 1);
+	});
+}
+
+#[test]
+fn exchange_member_rejects_accounts_which_voted() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=2 {
+			assert_ok!(Club::do_add_member_to_rank(who, 2));
+		}
+		// Member 2 leaves the collective, keeping their vote on the ongoing poll.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
+		assert_ok!(Club::do_remove_member(2));
+		assert_eq!(tally(3), Tally::from_parts(0, 1, 0, 3));
+
+		assert_noop!(
+			Club::exchange_member(RuntimeOrigin::signed(1), 1, 2, 2, 1),
+			Error::<Test>::NewAccountVoted
+		);
+
+		// Once the poll is over, the account may take over the membership.
+		<Club as OnPollCompleted<_, _, _>>::on_poll_completed(3, 1, &tally(3), false);
+		assert_ok!(Club::exchange_member(RuntimeOrigin::signed(1), 1, 2, 2, 0));
+		assert_eq!(Members::<Test>::get(2).map(|r| r.rank()), Some(2));
+	});
+}
+
+#[test]
+fn votes_are_paged_by_voter_index() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(Club::do_add_member_to_rank(i, 1));
+		}
+		NextVoterId::<Test>::put(VOTE_PAGE_SIZE - 1);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, true));
+		// The first voter takes the last slot of a page, the others share the next one.
+		assert_eq!(VoterIds::<Test>::get(1), Some(VOTE_PAGE_SIZE - 1));
+		assert_eq!(Voters::<Test>::get(VOTE_PAGE_SIZE), Some(2));
+		assert_eq!(VotePages::<Test>::get(3, 0).map(|page| page.len()), Some(1));
+		assert_eq!(VotePages::<Test>::get(3, 1).map(|page| page.len()), Some(2));
+		assert!(matches!(Club::vote_of(3, &2), Some(VoteRecord::Nay(_))));
+
+		// A page goes with its last vote.
+		assert_ok!(Club::retract_vote(RuntimeOrigin::signed(1), 3));
+		assert!(!VotePages::<Test>::contains_key(3, 0));
+		assert_ok!(Club::retract_vote(RuntimeOrigin::signed(3), 3));
+		assert_eq!(VotePages::<Test>::get(3, 1).map(|page| page.len()), Some(1));
+
+		// A member who leaves keeps their index, so that their vote is found again as they
+		// rejoin.
+		assert_ok!(Club::do_remove_member(2));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		assert_eq!(VoterIds::<Test>::get(2), Some(VOTE_PAGE_SIZE));
+		assert!(matches!(Club::vote_of(3, &2), Some(VoteRecord::Nay(_))));
+		assert_eq!(NextVoterId::<Test>::get(), VOTE_PAGE_SIZE + 2);
+	});
+}
+
+#[test]
+fn remap_class_reweighs_votes() {
+	new_test_ext().execute_with(|| {
+		for (who, rank) in [(1, 0), (2, 1), (3, 3), (4, 2)] {
+			assert_ok!(Club::do_add_member_to_rank(who, rank));
+		}
+		// Votes cast while class 1 had the minimum rank 0.
+		let mut polls = Polls::get();
+		polls.insert(3, Ongoing(Tally::from_parts(2, 1, 4, 10), 1));
+		polls.insert(4, Ongoing(Tally::from_parts(0, 1, 0, 3), 1));
+		polls.insert(5, Ongoing(Tally::from_parts(1, 0, 10, 0), 2));
+		Polls::set(polls);
+		Club::set_vote(3, &1, VoteRecord::Aye(1));
+		Club::set_vote(3, &2, VoteRecord::Aye(3));
+		Club::set_vote(3, &3, VoteRecord::Nay(10));
+		Club::set_vote(4, &2, VoteRecord::Nay(3));
+		Club::set_vote(5, &3, VoteRecord::Aye(10));
+		// A vote cast since the upgrade.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(4), 3, true));
+		assert_eq!(tally(3), Tally::from_parts(3, 1, 7, 10));
+
+		assert_noop!(Club::remap_class(RuntimeOrigin::signed(1), 0, 1, 2), BadOrigin);
+		// The six votes are visited two at a time, whether or not they are on the same poll.
+		for _ in 0..2 {
+			assert_ok!(Club::remap_class(RuntimeOrigin::root(), 0, 1, 2));
+			System::assert_last_event(
+				Event::ClassRemapped { old_rank: 0, new_rank: 1, votes: 2, complete: false }.into(),
+			);
+			assert!(ClassRemapCursor::<Test>::exists());
+		}
+		assert_ok!(Club::remap_class(RuntimeOrigin::root(), 0, 1, 0));
+		assert!(ClassRemapCursor::<Test>::exists());
+		assert_ok!(Club::remap_class(RuntimeOrigin::root(), 0, 1, 3));
+		System::assert_last_event(
+			Event::ClassRemapped { old_rank: 0, new_rank: 1, votes: 2, complete: true }.into(),
+		);
+		assert!(!ClassRemapCursor::<Test>::exists());
+
+		// The vote of the member below the new rank is cancelled, the others are re-weighed.
+		assert_eq!(Club::vote_of(3, &1), None);
+		assert_eq!(Club::vote_of(3, &2), Some(VoteRecord::Aye(1)));
+		assert_eq!(Club::vote_of(3, &3), Some(VoteRecord::Nay(6)));
+		assert_eq!(Club::vote_of(3, &4), Some(VoteRecord::Aye(3)));
+		assert_eq!(tally(3), Tally::from_parts(2, 1, 4, 6));
+		assert_eq!(tally(4), Tally::from_parts(0, 1, 0, 1));
+		// Polls of other classes are skipped.
+		assert_eq!(Club::vote_of(5, &3), Some(VoteRecord::Aye(10)));
+		assert_eq!(tally(5), Tally::from_parts(1, 0, 10, 0));
+
+		// Remapping again changes nothing.
+		assert_ok!(Club::remap_class(RuntimeOrigin::root(), 0, 1, 10));
+		assert_eq!(tally(3), Tally::from_parts(2, 1, 4, 6));
+		assert_eq!(tally(4), Tally::from_parts(0, 1, 0, 1));
+	});
+}
+
+#[test]
+fn unchanged_votes_are_refunded_their_write() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		let weight =
+			|aye| Club::vote(RuntimeOrigin::signed(1), 3, aye).unwrap().actual_weight.unwrap();
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let write = db.writes(1);
+		let full = <() as WeightInfo>::vote_existing(0);
+
+		assert_eq!(weight(true), <() as WeightInfo>::vote_new(0));
+		assert_eq!(weight(true), full - write);
+		assert_eq!(weight(false), full);
+		assert_eq!(tally(3), Tally::from_parts(0, 1, 0, 1));
+	});
+}
+
+#[test]
+fn free_votes_are_limited_per_period() {
+	new_test_ext().execute_with(|| {
+		let mut polls = Polls::get();
+		for index in 4..=8 {
+			polls.insert(index, Ongoing(Tally::from_parts(0, 0, 0, 0), 0));
+		}
+		Polls::set(polls);
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		let pays = |poll, aye| Club::vote(RuntimeOrigin::signed(1), poll, aye).unwrap().pays_fee;
+
+		assert_eq!(pays(4, true), Pays::No);
+		assert_eq!(pays(5, true), Pays::No);
+		// Changing a vote is never free and does not use up the quota.
+		assert_eq!(pays(5, false), Pays::Yes);
+		assert_eq!(pays(6, true), Pays::No);
+		assert_eq!(pays(7, true), Pays::Yes);
+		assert_eq!(FreeVotesUsed::<Test>::get(1), Some((0, 3)));
+
+		// The quota is refreshed in the next period.
+		run_to(10);
+		assert_eq!(pays(8, true), Pays::No);
+		assert_eq!(FreeVotesUsed::<Test>::get(1), Some((1, 1)));
+
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 0));
+		assert_eq!(FreeVotesUsed::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn frozen_classes_reject_votes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+
+		assert_noop!(Club::freeze_class(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+		assert_noop!(Club::unfreeze_class(RuntimeOrigin::root(), 1), Error::<Test>::NotFrozen);
+		assert_ok!(Club::freeze_class(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::ClassFrozen { class: 1 }.into());
+		assert_noop!(Club::freeze_class(RuntimeOrigin::root(), 1), Error::<Test>::ClassFrozen);
+
+		// Votes, including changes to earlier votes, are rejected while the class is frozen.
+		assert_noop!(Club::vote(RuntimeOrigin::signed(1), 3, false), Error::<Test>::ClassFrozen);
+		assert_eq!(tally(3), Tally::from_parts(1, 0, 1, 0));
+
+		assert_ok!(Club::unfreeze_class(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::ClassUnfrozen { class: 1 }.into());
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
+		assert_eq!(tally(3), Tally::from_parts(0, 1, 0, 1));
+
+		assert_eq!(FrozenNotes::get(), vec![(1, true), (1, false)]);
+	});
+}
+
+#[test]
+fn vetoes_within_the_window_veto_polls() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=3 {
+			assert_ok!(Club::do_add_member_to_rank(who, 2));
+		}
+		assert_ok!(Club::do_add_member_to_rank(4, 1));
+
+		assert_noop!(Club::veto(RuntimeOrigin::signed(4), 3), Error::<Test>::RankTooLow);
+		assert_noop!(Club::veto(RuntimeOrigin::signed(5), 3), Error::<Test>::NotMember);
+		assert_noop!(Club::veto(RuntimeOrigin::signed(1), 1), Error::<Test>::NotPolling);
+
+		assert_ok!(Club::veto(RuntimeOrigin::signed(1), 3));
+		System::assert_last_event(Event::VetoRegistered { who: 1, poll: 3, vetoes: 1 }.into());
+		assert_noop!(Club::veto(RuntimeOrigin::signed(1), 3), Error::<Test>::AlreadyVetoed);
+
+		// The first veto lapses once the window has closed, and the next one opens a new window.
+		run_to(6);
+		assert_ok!(Club::veto(RuntimeOrigin::signed(2), 3));
+		System::assert_has_event(Event::VetoesLapsed { poll: 3, vetoes: 1 }.into());
+		System::assert_last_event(Event::VetoRegistered { who: 2, poll: 3, vetoes: 1 }.into());
+		assert_eq!(
+			Vetoes::<Test>::get(3).map(|(since, v)| (since, v.into_inner())),
+			Some((6, vec![2]))
+		);
+
+		assert_ok!(Club::veto(RuntimeOrigin::signed(3), 3));
+		System::assert_last_event(Event::PollVetoed { poll: 3, vetoers: vec![2, 3] }.into());
+		assert_eq!(Vetoes::<Test>::get(3), None);
+		assert_eq!(Polls::get().get(&3), Some(&Completed(6, false)));
+		assert_noop!(Club::veto(RuntimeOrigin::signed(1), 3), Error::<Test>::NotPolling);
+	});
+}
+
+#[test]
+fn dry_run_vote_reports_rejections() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 0));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+
+		assert_eq!(Club::dry_run_vote(3, 3, true), Err(VoteRejection::NotMember));
+		assert_eq!(Club::dry_run_vote(1, 3, true), Err(VoteRejection::RankTooLow { required: 1 }));
+		assert_eq!(Club::dry_run_vote(2, 1, true), Err(VoteRejection::NotPolling));
+		assert_ok!(Club::freeze_class(RuntimeOrigin::root(), 1));
+		assert_eq!(Club::dry_run_vote(2, 3, true), Err(VoteRejection::ClassFrozen));
+		assert_ok!(Club::unfreeze_class(RuntimeOrigin::root(), 1));
+
+		// A successful dry run leaves no trace.
+		System::reset_events();
+		assert_eq!(Club::dry_run_vote(2, 3, true), Ok(()));
+		assert_eq!(Club::vote_of(3, &2), None);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn class_limits_fence_ranks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		assert_ok!(Club::do_add_member_to_rank(2, 3));
+		assert_ok!(Club::do_add_member_to_rank(3, 3));
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(3), 1, 1));
+
+		assert_noop!(
+			Club::set_class_limit(RuntimeOrigin::signed(1), 3, Some(0)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Club::set_class_limit(RuntimeOrigin::root(), 3, Some(0)));
+		System::assert_last_event(Event::ClassLimitSet { rank: 3, limit: Some(0) }.into());
+
+		// Members of rank 3 may no longer vote on class 1, themselves or through their delegate.
+		assert_noop!(Club::vote(RuntimeOrigin::signed(2), 3, true), Error::<Test>::ClassNotAllowed);
+		assert_eq!(Club::dry_run_vote(2, 3, true), Err(VoteRejection::ClassNotAllowed));
+		assert_eq!(Club::voting_power(&2, 1), None);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_eq!(Club::vote_of(3, &1), Some(VoteRecord::Aye(1)));
+		assert_eq!(Club::vote_of(3, &3), None);
+
+		// Lifting the limit lets them vote again.
+		assert_ok!(Club::set_class_limit(RuntimeOrigin::root(), 3, None));
+		assert_eq!(ClassLimits::<Test>::get(3), None);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_eq!(Club::vote_of(3, &1), Some(VoteRecord::Aye(1 + 6)));
+	});
+}
+
+#[test]
+fn completed_polls_are_certified() {
+	new_test_ext().execute_with(|| {
+		let genesis = MembershipCommitment::<Test>::get();
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		assert_ok!(Club::do_add_member_to_rank(2, 0));
+		let members = MembershipCommitment::<Test>::get();
+		assert_ne!(members, genesis);
+
+		let tally = Tally::from_parts(1, 0, 1, 0);
+		<Club as OnPollCompleted<_, _, _>>::on_poll_completed(5, 1, &tally, true);
+		System::assert_last_event(Event::TallyCertified { poll: 5, approved: true }.into());
+		let certificate = TallyCertificate {
+			poll: 5,
+			class: 1,
+			tally,
+			approved: true,
+			eligible: 1,
+			members,
+			at: 1,
+		};
+		assert_eq!(Club::tally_certificate(5), Some(certificate.clone()));
+
+		// Later membership changes move the commitment but leave the certificate untouched.
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
+		assert_ne!(MembershipCommitment::<Test>::get(), members);
+		assert_eq!(Club::tally_certificate(5), Some(certificate));
+		assert_eq!(Club::tally_certificate(6), None);
+	});
+}
+
+#[test]
+fn support_may_include_nays() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=4 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), i));
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), i));
+		}
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, false));
+
+		let t = tally(3);
+		assert_eq!(t.bare_nays(), 2);
+		assert_eq!(t.support(1), Perbill::from_percent(25));
+		assert_eq!(t.approval(1), Perbill::from_rational(1u32, 3u32));
+
+		assert!(t.meets_support(1, Perbill::from_percent(25)));
+		assert!(!t.meets_support(1, Perbill::from_parts(250_000_001)));
+		assert!(t.meets_approval(1, Perbill::from_parts(333_333_333)));
+		assert!(!t.meets_approval(1, Perbill::from_parts(333_333_334)));
+
+		SupportIncludesNays::set(true);
+		assert_eq!(t.support(1), Perbill::from_percent(75));
+		assert_eq!(t.approval(1), Perbill::from_rational(1u32, 3u32));
+		assert!(t.meets_support(1, Perbill::from_percent(75)));
+		assert!(!t.meets_support(1, Perbill::from_parts(750_000_001)));
+		SupportIncludesNays::set(false);
+	});
+}
+
+#[test]
+fn vote_curve_interpolates() {
+	let curve = |points: Vec<(Rank, Votes)>| VoteCurve::new(BoundedVec::truncate_from(points));
+	assert_eq!(curve(vec![]), None);
+	assert_eq!(curve(vec![(1, 1), (1, 2)]), None);
+	assert_eq!(curve(vec![(1, 2), (2, 1)]), None);
+
+	let c = curve(vec![(1, 2), (3, 6), (5, 7)]).unwrap();
+	let votes = (0..=6).map(|excess| c.votes(excess)).collect::<Vec<_>>();
+	assert_eq!(votes, vec![2, 2, 4, 6, 6, 7, 7]);
+}
+
+#[test]
+fn vote_curve_may_be_set() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		for _ in 0..3 {
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		}
+		let points = BoundedVec::truncate_from(vec![(0, 1), (2, 9)]);
+		assert_noop!(
+			Club::set_vote_curve(RuntimeOrigin::signed(1), Some(points.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			Club::set_vote_curve(RuntimeOrigin::root(), Some(BoundedVec::default())),
+			Error::<Test>::InvalidVoteCurve
+		);
+
+		assert_ok!(Club::set_vote_curve(RuntimeOrigin::root(), Some(points.clone())));
+		let curve = VoteCurve::new(points).unwrap();
+		System::assert_last_event(Event::VoteCurveSet { curve: Some(curve) }.into());
+		// Rank 3 is an excess rank of 2 on the polls of class 1.
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_eq!(tally(3), Tally::from_parts(1, 0, 9, 0));
+
+		// The votes already cast keep their weight.
+		assert_ok!(Club::set_vote_curve(RuntimeOrigin::root(), None));
+		System::assert_last_event(Event::VoteCurveSet { curve: None }.into());
+		assert_eq!(tally(3), Tally::from_parts(1, 0, 9, 0));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_eq!(tally(3), Tally::from_parts(1, 0, 6, 0));
+	});
+}
+
+#[test]
+fn inactive_members_are_demoted() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		DemotionPeriods::set(vec![(0, 100), (1, 10)].into_iter().collect());
+		MaxInactivityChecks::set(10);
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		assert_ok!(Club::do_add_member_to_rank(3, 0));
+		assert_ok!(Club::induct_member(RuntimeOrigin::root(), 4));
+
+		System::set_block_number(5);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, true));
+
+		// Only the member of rank 1 who has done nothing for 10 blocks is demoted.
+		Club::on_initialize(10);
+		assert_eq!(member_count(1), 2);
+		System::set_block_number(11);
+		Club::on_initialize(11);
+		System::assert_last_event(
+			Event::DemotedForInactivity { who: 1, rank: 1, last_activity: 1 }.into(),
+		);
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(0));
+		assert_eq!(Club::member_record(&2).map(|r| r.rank()), Some(1));
+
+		// A member under review is not demoted until the review ends.
+		assert_ok!(Club::mark_under_review(RuntimeOrigin::signed(9), 2, 3));
+		System::set_block_number(15);
+		Club::on_initialize(15);
+		assert_eq!(Club::member_record(&2).map(|r| r.rank()), Some(1));
+		let mut polls = Polls::get();
+		polls.insert(3, Completed(1, true));
+		Polls::set(polls);
+
+		// With one check per block, the walk over the four members takes four blocks.
+		MaxInactivityChecks::set(1);
+		for n in 16..20 {
+			Club::on_initialize(n);
+		}
+		assert_eq!(Club::member_record(&2).map(|r| r.rank()), Some(0));
+		assert!(!UnderReview::<Test>::contains_key(2));
+
+		// Members of rank 0 are removed, except those on probation, once the walk restarts.
+		MaxInactivityChecks::set(10);
+		System::set_block_number(101);
+		Club::on_initialize(101);
+		assert!(!InactivityCursor::<Test>::exists());
+		assert!(Members::<Test>::contains_key(3));
+		Club::on_initialize(101);
+		assert_eq!(Members::<Test>::get(3), None);
+		assert!(Members::<Test>::get(4).unwrap().is_probationary());
+		assert_eq!(member_count(0), 3);
+
+		DemotionPeriods::set(BTreeMap::new());
+		MaxInactivityChecks::set(0);
+	});
+}
+
+#[test]
+fn members_without_activity_are_not_demoted() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		DemotionPeriods::set(vec![(1, 10)].into_iter().collect());
+		MaxInactivityChecks::set(10);
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		Members::<Test>::insert(1, MemberRecord::new(1));
+
+		// The clock of a member without any recorded activity starts when it is first checked.
+		System::set_block_number(50);
+		Club::on_initialize(50);
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(1));
+		assert_eq!(Club::last_activity(&1), Some(50));
+		assert_eq!(Club::member_record(&1).unwrap().last_promotion(), None);
+
+		Club::on_initialize(59);
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(1));
+		Club::on_initialize(60);
+		System::assert_last_event(
+			Event::DemotedForInactivity { who: 1, rank: 1, last_activity: 50 }.into(),
+		);
+
+		DemotionPeriods::set(BTreeMap::new());
+		MaxInactivityChecks::set(0);
+	});
+}
+
+#[test]
+fn split_members_works() {
+	new_test_ext().execute_with(|| {
+		for (who, rank) in [(1, 0), (2, 1), (3, 2), (4, 3)] {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), who));
+			for _ in 0..rank {
+				assert_ok!(Club::promote_member(RuntimeOrigin::root(), who));
+			}
+		}
+		SplitMembers::set(&vec![(3, 1)].into_iter().collect());
+		SplitRefused::set(vec![4]);
+		assert_noop!(Club::split_members(RuntimeOrigin::signed(4), 2, 3, true, 10), BadOrigin);
+
+		// The walk stops after two members, and the next call carries on from there.
+		assert_ok!(Club::split_members(RuntimeOrigin::root(), 2, 3, true, 2));
+		assert!(SplitCursor::<Test>::exists());
+		assert_ok!(Club::split_members(RuntimeOrigin::root(), 2, 3, true, 10));
+		assert!(!SplitCursor::<Test>::exists());
+		System::assert_has_event(Event::MemberExported { who: 3, rank: 2 }.into());
+		System::assert_has_event(
+			Event::MemberNotExported { who: 4, rank: 3, error: DispatchError::Unavailable }.into(),
+		);
+		// The exported member was promoted to their rank and removed, the refused one kept.
+		assert_eq!(SplitMembers::get(), vec![(3, 2)].into_iter().collect());
+		assert_eq!(member_count(2), 1);
+		assert_eq!(Club::member_record(&4).map(|r| r.rank()), Some(3));
+		assert_eq!(Club::member_record(&3), None);
+
+		// Members may be exported without being removed.
+		assert_ok!(Club::split_members(RuntimeOrigin::root(), 0, 0, false, 10));
+		System::assert_last_event(
+			Event::MembersSplit { min_rank: 0, max_rank: 0, exported: 1, complete: true }.into(),
+		);
+		assert_eq!(SplitMembers::get(), vec![(1, 0), (3, 2)].into_iter().collect());
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(0));
+	});
+}
+
+#[test]
+fn compact_vote_events_leave_out_the_tally() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		let verbose = System::events().pop().unwrap().event;
+		assert_eq!(
+			verbose,
+			Event::Voted {
+				who: 1,
+				poll: 3,
+				vote: VoteRecord::Aye(1),
+				rank_votes: 1,
+				tally: tally(3)
+			}
+			.into()
+		);
+
+		CompactVoteEvents::set(true);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
+		let compact = System::events().pop().unwrap().event;
+		assert_eq!(
+			compact,
+			Event::VotedCompact { who: 1, poll: 3, vote: VoteRecord::Nay(1) }.into()
+		);
+		assert!(compact.encoded_size() < verbose.encoded_size());
+		// The tally is still available from the pallet.
+		assert_eq!(Club::tally_of(3), Some(Tally::from_parts(0, 1, 0, 1)));
+		assert_eq!(Club::tally_of(1), None);
+		CompactVoteEvents::set(false);
+	});
+}
+
+#[test]
+fn tally_migration_recovers_bare_nays() {
+	use crate::migration::{v1, v2, v6};
+	use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+	parameter_types! {
+		pub static OldTallies: BTreeMap<u8, v1::Tally> = BTreeMap::new();
+	}
+
+	struct TestTallies;
+	impl v1::TallyStore<Test, (), v1::Tally, v2::Tally> for TestTallies {
+		fn translate(mut f: impl FnMut(u8, v1::Tally) -> v2::Tally) -> u32 {
+			let mut polls = Polls::get();
+			let old = OldTallies::take();
+			for (index, tally) in old.iter() {
+				let class = match polls.get(index) {
+					Some(Ongoing(_, class)) => *class,
+					_ => continue,
+				};
+				let new = f(*index, tally.clone());
+				let tally = Tally::from_parts(
+					new.bare_ayes,
+					new.bare_nays,
+					new.ayes.into(),
+					new.nays.into(),
+				);
+				polls.insert(*index, Ongoing(tally, class));
+			}
+			Polls::set(polls);
+			old.len() as u32
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		// Pretend the tally and the votes were stored before nay voters were counted, while
+		// votes were still counted in `u32`.
+		let old_vote = |who: u64, vote: v6::VoteRecord| {
+			unhashed::put(&v6::Voting::<Test, ()>::hashed_key_for(3, who), &vote);
+		};
+		old_vote(1, v6::VoteRecord::Aye(1));
+		old_vote(2, v6::VoteRecord::Nay(1));
+		old_vote(3, v6::VoteRecord::Nay(3));
+		old_vote(4, v6::VoteRecord::Aye(3));
+		OldTallies::set(
+			vec![(3, v1::Tally { bare_ayes: 2, ayes: 4, nays: 4 })].into_iter().collect(),
+		);
+		Polls::mutate(|p| p.insert(3, Ongoing(Tally::from_parts(0, 0, 0, 0), 1)));
+
+		assert_eq!(StorageVersion::get::<Club>(), 0);
+		v1::MigrateToV1::<Test, (), TestTallies>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 1);
+		assert_eq!(tally(3), Tally::from_parts(2, 2, 4, 4));
+
+		// Running it again is a no-op.
+		OldTallies::set(
+			vec![(3, v1::Tally { bare_ayes: 0, ayes: 0, nays: 0 })].into_iter().collect(),
+		);
+		v1::MigrateToV1::<Test, (), TestTallies>::on_runtime_upgrade();
+		assert_eq!(tally(3), Tally::from_parts(2, 2, 4, 4));
+	});
+}
+
+#[test]
+fn tally_migration_adds_bare_abstains() {
+	use crate::migration::{v1, v2, v5, v6};
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	parameter_types! {
+		pub static OldTallies: BTreeMap<u8, v2::Tally> = BTreeMap::new();
+	}
+
+	struct TestTallies;
+	impl v1::TallyStore<Test, (), v2::Tally, v5::Tally> for TestTallies {
+		fn translate(mut f: impl FnMut(u8, v2::Tally) -> v5::Tally) -> u32 {
+			let mut polls = Polls::get();
+			let old = OldTallies::take();
+			for (index, tally) in old.iter() {
+				if let Some(Ongoing(_, class)) = polls.get(index).cloned() {
+					polls.insert(
+						*index,
+						Ongoing(v6::Tally::from(f(*index, tally.clone())).into(), class),
+					);
+				}
+			}
+			Polls::set(polls);
+			old.len() as u32
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		OldTallies::set(
+			vec![(3, v2::Tally { bare_ayes: 1, bare_nays: 2, ayes: 1, nays: 4 })]
+				.into_iter()
+				.collect(),
+		);
+		StorageVersion::new(1).put::<Club>();
+		v2::MigrateToV2::<Test, (), TestTallies>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 2);
+		assert_eq!(tally(3), Tally::from_parts(1, 2, 1, 4));
+		assert_eq!(tally(3).bare_abstains(), 0);
+	});
+}
+
+#[test]
+fn tally_migration_adds_no_voter_snapshot() {
+	use crate::migration::{v1, v5, v6};
+	use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+	parameter_types! {
+		pub static OldTallies: BTreeMap<u8, v5::Tally> = BTreeMap::new();
+	}
+
+	struct TestTallies;
+	impl v1::TallyStore<Test, (), v5::Tally, v6::Tally> for TestTallies {
+		fn translate(mut f: impl FnMut(u8, v5::Tally) -> v6::Tally) -> u32 {
+			let mut polls = Polls::get();
+			let old = OldTallies::take();
+			for (index, tally) in old.iter() {
+				if let Some(Ongoing(_, class)) = polls.get(index).cloned() {
+					polls.insert(*index, Ongoing(f(*index, tally.clone()).into(), class));
+				}
+			}
+			Polls::set(polls);
+			old.len() as u32
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		let old = v5::Tally { bare_ayes: 1, bare_nays: 2, bare_abstains: 1, ayes: 1, nays: 4 };
+		OldTallies::set(vec![(3, old.clone())].into_iter().collect());
+		let certificate = v5::TallyCertificateOf::<Test, ()> {
+			poll: 1,
+			class: 1,
+			tally: old.clone(),
+			approved: true,
+			eligible: 3,
+			members: Default::default(),
+			at: 1,
+		};
+		unhashed::put(&TallyCertificates::<Test>::hashed_key_for(1), &certificate);
+		StorageVersion::new(4).put::<Club>();
+
+		v5::MigrateToV5::<Test, (), TestTallies>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 5);
+		let expected = v6::Tally::from(old);
+		assert_eq!(tally(3), expected.clone().into());
+		assert_eq!(tally(3).bare_abstains(), 1);
+		assert_eq!(tally(3).max_voters(), None);
+		let migrated = v6::TallyCertificates::<Test, ()>::get(1).unwrap();
+		assert_eq!(migrated.tally, expected);
+		assert_eq!(migrated.eligible, 3);
+	});
+}
+
+#[test]
+fn vote_migration_widens_votes() {
+	use crate::migration::{v1, v6, v7};
+	use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+	parameter_types! {
+		pub static OldTallies: BTreeMap<u8, v6::Tally> = BTreeMap::new();
+		pub static OldQueued: Vec<u32> = vec![];
+		pub static NewQueued: Vec<Votes> = vec![];
+	}
+
+	struct TestTallies;
+	impl v1::TallyStore<Test, (), v6::Tally> for TestTallies {
+		fn translate(mut f: impl FnMut(u8, v6::Tally) -> TallyOf<Test>) -> u32 {
+			let mut polls = Polls::get();
+			let old = OldTallies::take();
+			for (index, tally) in old.iter() {
+				if let Some(Ongoing(_, class)) = polls.get(index).cloned() {
+					polls.insert(*index, Ongoing(f(*index, tally.clone()), class));
+				}
+			}
+			Polls::set(polls);
+			old.len() as u32
+		}
+	}
+	impl v6::QueuedVotesStore for TestTallies {
+		fn translate_votes(mut f: impl FnMut(u32) -> Votes) -> u32 {
+			let queued = OldQueued::take();
+			NewQueued::set(queued.iter().map(|votes| f(*votes)).collect());
+			queued.len() as u32
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		let old = v6::Tally {
+			bare_ayes: 1,
+			bare_nays: 1,
+			bare_abstains: 0,
+			ayes: u32::MAX,
+			nays: 3,
+			max_voters: Some(4),
+		};
+		OldTallies::set(vec![(3, old.clone())].into_iter().collect());
+		let certificate = v6::TallyCertificateOf::<Test, ()> {
+			poll: 1,
+			class: 1,
+			tally: old.clone(),
+			approved: true,
+			eligible: 4,
+			members: Default::default(),
+			at: 1,
+		};
+		v6::TallyCertificates::<Test, ()>::insert(1, certificate);
+		v6::Voting::<Test, ()>::insert(3, 1, v6::VoteRecord::Aye(u32::MAX));
+		v6::Voting::<Test, ()>::insert(3, 2, v6::VoteRecord::Nay(3));
+		v6::Voting::<Test, ()>::insert(3, 3, v6::VoteRecord::Abstain);
+		let points = BoundedVec::truncate_from(vec![(0, 1), (2, 5)]);
+		unhashed::put(&VoteWeightCurve::<Test>::hashed_key(), &v6::VoteCurve(points));
+		OldQueued::set(vec![u32::MAX, 2]);
+		StorageVersion::new(5).put::<Club>();
+
+		v6::MigrateToV6::<Test, (), TestTallies>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 6);
+		assert_eq!(NewQueued::get(), vec![u32::MAX as Votes, 2]);
+		let mut expected = Tally::from_parts(1, 1, u32::MAX as Votes, 3);
+		expected.max_voters = Some(4);
+		assert_eq!(tally(3), expected);
+		assert_eq!(TallyCertificates::<Test>::get(1).unwrap().tally, expected);
+		assert_eq!(v7::Voting::<Test, ()>::get(3, 1), Some(VoteRecord::Aye(u32::MAX as Votes)));
+		assert_eq!(v7::Voting::<Test, ()>::get(3, 2), Some(VoteRecord::Nay(3)));
+		assert_eq!(v7::Voting::<Test, ()>::get(3, 3), Some(VoteRecord::Abstain));
+		assert_eq!(VoteWeightCurve::<Test>::get().unwrap().points(), &[(0, 1), (2, 5)]);
+
+		// Tallies may now go beyond `u32`.
+		let t = tally(3);
+		assert_ok!(Club::do_add_member_to_rank(4, 1));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(4), 3, true));
+		assert_eq!(tally(3).ayes, t.ayes + 1);
+	});
+}
+
+#[test]
+fn support_is_measured_against_voter_snapshot() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=4 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), i));
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), i));
+		}
+		SnapshotVoters::set(true);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_eq!(tally(3).max_voters(), Some(4));
+		assert_eq!(tally(3).support(1), Perbill::from_percent(25));
+
+		// Members joining or leaving no longer move the support of the poll.
+		for i in 5..=8 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), i));
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), i));
+		}
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, true));
+		assert_eq!(tally(3).max_voters(), Some(4));
+		assert_eq!(tally(3).support(1), Perbill::from_percent(50));
+		SnapshotVoters::set(false);
+
+		// Polls voted on without the snapshot follow the membership.
+		Polls::mutate(|p| p.insert(4, Ongoing(Tally::from_parts(0, 0, 0, 0), 1)));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 4, true));
+		assert_eq!(tally(4).max_voters(), None);
+		assert_eq!(tally(4).support(1), Perbill::from_rational(1u32, 8u32));
+	});
+}
+
+#[test]
+fn member_migration_ends_no_probation() {
+	use crate::migration::{v3, v4};
+	use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		unhashed::put(&Members::<Test>::hashed_key_for(1), &v3::MemberRecord { rank: 2 });
+		unhashed::put(&Members::<Test>::hashed_key_for(2), &v3::MemberRecord { rank: 0 });
+		StorageVersion::new(2).put::<Club>();
+
+		v3::MigrateToV3::<Test, ()>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 3);
+		let record = |rank| v4::MemberRecord { rank, probationary: false };
+		assert_eq!(v4::Members::<Test, ()>::get(1), Some(record(2)));
+		assert_eq!(v4::Members::<Test, ()>::get(2), Some(record(0)));
+	});
+}
+
+#[test]
+fn member_migration_stamps_activity() {
+	use crate::migration::v4;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		v4::Members::<Test, ()>::insert(1, v4::MemberRecord { rank: 2, probationary: false });
+		v4::Members::<Test, ()>::insert(2, v4::MemberRecord { rank: 0, probationary: true });
+		StorageVersion::new(3).put::<Club>();
+		System::set_block_number(7);
+
+		v4::MigrateToV4::<Test, ()>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 4);
+		assert_eq!(Members::<Test>::get(1).map(|r| r.rank()), Some(2));
+		assert!(Members::<Test>::get(2).unwrap().is_probationary());
+		// The activity of the members is counted from the upgrade, which is not a promotion.
+		assert_eq!(Club::last_activity(&1), Some(7));
+		assert_eq!(Members::<Test>::get(1).unwrap().last_promotion(), None);
+	});
+}
+
+#[test]
+fn active_poll_migration_moves_former_accounts() {
+	use crate::migration::v7;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(30, 2));
+		v7::Voting::<Test, ()>::insert(3, 10, VoteRecord::Aye(3));
+		v7::Voting::<Test, ()>::insert(1, 10, VoteRecord::Nay(3));
+		CompletedPolls::<Test>::insert(1, ());
+		Vetoes::<Test>::insert(3, (1, BoundedVec::truncate_from(vec![10])));
+		// The member exchanged 10 for 20, then 20 for 30.
+		v7::ExchangedFrom::<Test, ()>::insert(20, 10);
+		v7::ExchangedFrom::<Test, ()>::insert(30, 20);
+		StorageVersion::new(6).put::<Club>();
+
+		v7::MigrateToV7::<Test, ()>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 7);
+		assert_eq!(ActivePolls::<Test>::iter_keys().collect::<Vec<_>>(), vec![3]);
+		assert_eq!(ActivePolls::<Test>::count(), 1);
+		assert_eq!(v7::Voting::<Test, ()>::get(3, 30), Some(VoteRecord::Aye(3)));
+		assert_eq!(v7::Voting::<Test, ()>::get(3, 10), None);
+		assert_eq!(Vetoes::<Test>::get(3).map(|(_, v)| v.into_inner()), Some(vec![30]));
+		// Votes on completed polls are left for their cleanup.
+		assert_eq!(v7::Voting::<Test, ()>::get(1, 10), Some(VoteRecord::Nay(3)));
+		assert!(v7::ExchangedFrom::<Test, ()>::iter_keys().next().is_none());
+	});
+}
+
+#[test]
+fn vote_migration_pages_votes() {
+	use crate::migration::{v7, v8};
+	use frame_support::traits::{ConstU32, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		v7::Voting::<Test, ()>::insert(3, 10, VoteRecord::Aye(3));
+		v7::Voting::<Test, ()>::insert(3, 20, VoteRecord::Delegated(1));
+		v7::Voting::<Test, ()>::insert(1, 10, VoteRecord::Nay(3));
+		v8::VotingCleanup::<Test, ()>::insert(1, vec![1, 2, 3]);
+		ClassRemapCursor::<Test>::put((0, 1, 3, 0));
+		StorageVersion::new(7).put::<Club>();
+
+		// Nothing is moved with more votes than the bound.
+		v8::MigrateToV8::<Test, (), ConstU32<2>>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 7);
+		assert_eq!(v7::Voting::<Test, ()>::iter().count(), 3);
+		assert!(VotePages::<Test>::iter().next().is_none());
+
+		v8::MigrateToV8::<Test, (), ConstU32<3>>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Club>(), 8);
+		assert_eq!(Club::vote_of(3, &10), Some(VoteRecord::Aye(3)));
+		assert_eq!(Club::vote_of(3, &20), Some(VoteRecord::Delegated(1)));
+		assert_eq!(Club::vote_of(1, &10), Some(VoteRecord::Nay(3)));
+		// Each voter gets one index for the votes on all polls.
+		assert_eq!(VoterIds::<Test>::iter().count(), 2);
+		assert_eq!(NextVoterId::<Test>::get(), 2);
+		assert_eq!(VotePages::<Test>::get(3, 0).map(|page| page.len()), Some(2));
+		assert!(v7::Voting::<Test, ()>::iter().next().is_none());
+		assert!(v8::VotingCleanup::<Test, ()>::iter().next().is_none());
+		// An unfinished remap starts over.
+		assert!(!ClassRemapCursor::<Test>::exists());
+	});
+}
+
+#[test]
+fn collective_import_ranks_members_and_prime() {
+	use crate::migration::from_collective::ImportCollective;
+	use frame_support::{
+		storage::migration::put_storage_value,
+		traits::{CrateVersion, OnRuntimeUpgrade, PalletInfoAccess},
+	};
+	use ranked::RankedMembers;
+
+	struct Council;
+	impl PalletInfoAccess for Council {
+		fn index() -> usize {
+			42
+		}
+		fn name() -> &'static str {
+			"Council"
+		}
+		fn module_name() -> &'static str {
+			"pallet_collective"
+		}
+		fn crate_version() -> CrateVersion {
+			CrateVersion::new(4, 0, 0)
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		put_storage_value(b"Council", b"Members", &[], vec![1u64, 2, 3]);
+		put_storage_value(b"Council", b"Prime", &[], 2u64);
+
+		ImportCollective::<Test, (), Council>::on_runtime_upgrade();
+		assert_eq!(Club::rank_of(&1), Some(0));
+		assert_eq!(Club::rank_of(&2), Some(1));
+		assert_eq!(Club::rank_of(&3), Some(0));
+		assert_eq!(member_count(0), 3);
+		assert_eq!(member_count(1), 1);
+		assert_ok!(Club::do_try_state());
+
+		// A collective with members is left alone.
+		put_storage_value(b"Council", b"Members", &[], vec![4u64]);
+		ImportCollective::<Test, (), Council>::on_runtime_upgrade();
+		assert_eq!(Club::rank_of(&4), None);
+	});
+}
+
+#[test]
+fn scan_members_walks_in_bounded_steps() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=5 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), who));
+		}
+		let (first, cursor) = Club::scan_members(None, 2);
+		assert_eq!(first.len(), 2);
+		let (second, cursor) = Club::scan_members(cursor, 2);
+		assert_eq!(second.len(), 2);
+		let (last, cursor) = Club::scan_members(cursor, 2);
+		assert_eq!(last.len(), 1);
+		assert_eq!(cursor, None);
+
+		let mut seen: Vec<u64> =
+			first.into_iter().chain(second).chain(last).map(|(w, _)| w).collect();
+		seen.sort();
+		assert_eq!(seen, vec![1, 2, 3, 4, 5]);
+	});
+}
+
+#[test]
+fn sync_rank_works() {
+	use ranked::{OnMemberAdded, OnRankChanged, RankedMembers};
+
+	new_test_ext().execute_with(|| {
+		let source = |ranks: Vec<(u64, Rank)>| SourceRanks::set(ranks.into_iter().collect());
+		let sync = |who, max_rank| Club::sync_rank(RuntimeOrigin::signed(9), who, max_rank);
+		source(vec![(1, 2), (2, 1), (3, 1), (4, 2)]);
+		assert_ok!(Club::do_add_member_to_rank(2, 3));
+		assert_ok!(Club::do_add_member_to_rank(3, 2));
+		assert_ok!(Club::do_add_member_to_rank(4, 0));
+
+		assert_noop!(sync(5, 5), Error::<Test>::NotInSource);
+		assert_noop!(sync(1, 1), Error::<Test>::InvalidWitness);
+
+		// Under `SourceWins`, accounts are added and moved up or down to the rank of the source.
+		assert_ok!(sync(1, 2));
+		assert_eq!(Club::rank_of(&1), Some(2));
+		assert_noop!(sync(1, 2), Error::<Test>::AlreadySynced);
+		assert_ok!(sync(2, 3));
+		assert_eq!(Club::rank_of(&2), Some(1));
+		System::assert_last_event(Event::RankChanged { who: 2, rank: 1 }.into());
+
+		// Under `Max`, members are only moved up.
+		RankSyncPolicy::set(SyncPolicy::Max);
+		assert_noop!(sync(3, 2), Error::<Test>::AlreadySynced);
+		source(vec![(3, 4)]);
+		assert_ok!(sync(3, 4));
+		assert_eq!(Club::rank_of(&3), Some(4));
+
+		// Under `Manual`, differences are only reported.
+		RankSyncPolicy::set(SyncPolicy::Manual);
+		source(vec![(4, 2)]);
+		assert_ok!(sync(4, 2));
+		assert_eq!(Club::rank_of(&4), Some(0));
+		System::assert_last_event(
+			Event::RankConflict { who: 4, rank: Some(0), source_rank: 2 }.into(),
+		);
+
+		// The source may push its changes instead.
+		RankSyncPolicy::set(SyncPolicy::SourceWins);
+		<Club as OnRankChanged<u64>>::on_rank_changed(&4, 0, 2);
+		assert_eq!(Club::rank_of(&4), Some(2));
+		<Club as OnMemberAdded<u64>>::on_member_added(&6, 1);
+		assert_eq!(Club::rank_of(&6), Some(1));
+		assert_ok!(Club::do_try_state());
+	});
+}
+
+#[test]
+fn member_activity_is_tracked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_eq!(Club::last_activity(&1), Some(1));
+		assert_eq!(Club::member_record(&1).unwrap().last_promotion(), None);
+
+		System::set_block_number(2);
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		System::set_block_number(3);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		System::set_block_number(4);
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
+
+		let record = Club::member_record(&1).unwrap();
+		assert_eq!(record.last_promotion(), Some(2));
+		assert_eq!(record.last_vote(), Some(3));
+		assert_eq!(record.last_demotion(), Some(4));
+		assert_eq!(Club::last_activity(&1), Some(4));
+
+		// Lowering the rank through `force_set_ranks` counts as a demotion.
+		System::set_block_number(5);
+		let changes = vec![(1, 0)];
+		let witness = Club::rank_changes_witness(&changes);
+		assert_ok!(Club::force_set_ranks(
+			RuntimeOrigin::root(),
+			BoundedVec::truncate_from(changes),
+			1,
+			witness,
+		));
+		assert_eq!(Club::member_record(&1).unwrap().last_demotion(), Some(5));
+		assert_eq!(Club::last_activity(&2), None);
+	});
+}
+
+#[test]
+fn cleanup_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
+
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 3, false));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 3, true));
+
+		assert_noop!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10), Error::<Test>::Ongoing);
+		Polls::set(
+			vec![(1, Completed(1, true)), (2, Completed(2, false)), (3, Completed(3, true))]
+				.into_iter()
+				.collect(),
+		);
+		assert_ok!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10));
+		// NOTE: This will fail until #10016 is merged.
+		//		assert_noop!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10),
+		// Error::<Test>::NoneRemaining);
+	});
+}
+
+#[test]
+fn idle_cleanup_works() {
+	use frame_support::traits::Hooks;
+
+	let db = <Test as frame_system::Config>::DbWeight::get();
+	let per_page = <() as WeightInfo>::cleanup_poll(1) - <() as WeightInfo>::cleanup_poll(0);
+	let limit = <() as WeightInfo>::cleanup_poll(0) + db.reads_writes(1, 1) + per_page * 2;
+
+	// Each step runs in a block of its own, with the votes removed before in the backend.
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		// Each voter gets an index into a page of their own.
+		for i in 1..=3 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), i));
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), i));
+			NextVoterId::<Test>::put(i as VoterIndex * VOTE_PAGE_SIZE);
+			assert_ok!(Club::vote(RuntimeOrigin::signed(i), 3, true));
+		}
+		let final_tally = tally(3);
+		Polls::mutate(|p| p.insert(3, Completed(3, true)));
+		<Club as OnPollCompleted<_, _, _>>::on_poll_completed(3, 1, &final_tally, true);
+		assert!(CompletedPolls::<Test>::contains_key(3));
+
+		// Nothing is removed while the cleanup weight is zero.
+		assert_eq!(Club::on_idle(1, Weight::MAX), Weight::zero());
+		assert_eq!(VotePages::<Test>::iter_prefix(3).count(), 3);
+	});
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		// Only as many pages of votes are removed as the weight allows.
+		IdleCleanupWeight::set(limit);
+		assert_eq!(Club::on_idle(2, Weight::MAX), limit);
+		assert_eq!(VotePages::<Test>::iter_prefix(3).count(), 1);
+		assert_eq!(VotesRemoved::<Test>::get(3), 2);
+		assert!(CompletedPolls::<Test>::contains_key(3));
+
+		// And no more than remains of the block.
+		assert_eq!(Club::on_idle(2, per_page), Weight::zero());
+	});
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		assert!(Club::on_idle(3, Weight::MAX).all_lte(limit));
+		assert_eq!(VotePages::<Test>::iter_prefix(3).count(), 0);
+		assert!(!CompletedPolls::<Test>::contains_key(3));
+		assert!(!VotesRemoved::<Test>::contains_key(3));
+		System::assert_last_event(Event::PollFinishedCleanup { poll: 3, votes_removed: 3 }.into());
+		IdleCleanupWeight::set(Weight::zero());
+	});
+}
+
+#[test]
+fn probation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::mutate(|p| p.insert(4, Ongoing(Tally::from_parts(0, 0, 0, 0), 0)));
+		assert_noop!(Club::induct_member(RuntimeOrigin::signed(1), 1), BadOrigin);
+		assert_ok!(Club::induct_member(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::MemberInducted { who: 1 }.into());
+		assert!(Members::<Test>::get(1).unwrap().is_probationary());
+		assert_eq!(Members::<Test>::get(1).unwrap().tracked_since(), Some(1));
+		assert_eq!(member_count(0), 1);
+		assert_eq!(<Club as GetMaxVoters>::get_max_voters(0), 0);
+
+		// Members on probation may neither vote nor be promoted.
+		assert_noop!(Club::vote(RuntimeOrigin::signed(1), 4, true), Error::<Test>::Probationary);
+		assert_noop!(Club::promote_member(RuntimeOrigin::root(), 1), Error::<Test>::Probationary);
+
+		// Nor are they drawn into panels.
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+		assert_noop!(Club::draw_panel(0, 2, 7), Error::<Test>::InvalidPanelSize);
+		assert_eq!(Club::draw_panel(0, 1, 7).unwrap().into_inner(), vec![2]);
+
+		assert_noop!(Club::confirm_member(RuntimeOrigin::signed(1), 1), BadOrigin);
+		assert_noop!(
+			Club::confirm_member(RuntimeOrigin::root(), 2),
+			Error::<Test>::NotProbationary
+		);
+		System::set_block_number(5);
+		assert_ok!(Club::confirm_member(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::MemberConfirmed { who: 1 }.into());
+		// Ending the probation is not a promotion, but the activity is tracked from there.
+		let record = Members::<Test>::get(1).unwrap();
+		assert_eq!((record.last_promotion(), record.tracked_since()), (None, Some(5)));
+		assert_eq!(<Club as GetMaxVoters>::get_max_voters(0), 2);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 4, true));
+		assert_eq!(tally(4), Tally::from_parts(1, 0, 1, 0));
+
+		// Members leaving while on probation are no longer counted.
+		assert_ok!(Club::induct_member(RuntimeOrigin::root(), 3));
+		assert_eq!(ProbationaryCount::<Test>::get(), 1);
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 3));
+		assert_eq!(ProbationaryCount::<Test>::get(), 0);
+		assert_eq!(<Club as GetMaxVoters>::get_max_voters(0), 2);
+	});
+}
+
+#[test]
+fn panel_selection_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 0));
+		for i in 1..=5 {
+			assert_ok!(Club::add_member(RuntimeOrigin::root(), i));
+			assert_ok!(Club::promote_member(RuntimeOrigin::root(), i));
+		}
+		Polls::mutate(|p| p.insert(4, Ongoing(Tally::from_parts(0, 0, 0, 0), 1)));
+
+		assert_noop!(Club::select_panel(RuntimeOrigin::signed(1), 3, 3, 7), BadOrigin);
+		assert_noop!(Club::select_panel(RuntimeOrigin::root(), 1, 3, 7), Error::<Test>::NotPolling);
+		assert_noop!(
+			Club::select_panel(RuntimeOrigin::root(), 3, 0, 7),
+			Error::<Test>::InvalidPanelSize
+		);
+		// Only five members are of rank 1 or above.
+		assert_noop!(
+			Club::select_panel(RuntimeOrigin::root(), 3, 6, 7),
+			Error::<Test>::InvalidPanelSize
+		);
+
+		let expected = Club::draw_panel(1, 3, 7).unwrap();
+		assert_ok!(Club::select_panel(RuntimeOrigin::root(), 3, 3, 7));
+		let panel = Panels::<Test>::get(3).unwrap();
+		assert_eq!(panel, expected);
+		let unique = panel.iter().collect::<std::collections::BTreeSet<_>>();
+		assert_eq!(unique.len(), 3);
+		assert!(!panel.contains(&0));
+		System::assert_last_event(Event::PanelSelected { poll: 3, panel: panel.clone() }.into());
+		assert_noop!(
+			Club::select_panel(RuntimeOrigin::root(), 3, 3, 7),
+			Error::<Test>::PanelAlreadySelected
+		);
+
+		// Only panel members may vote on the poll, while other polls are unaffected.
+		let outsider = (1..=5).find(|i| !panel.contains(i)).unwrap();
+		assert_noop!(
+			Club::vote(RuntimeOrigin::signed(outsider), 3, true),
+			Error::<Test>::NotInPanel
+		);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(panel[0]), 3, true));
+		assert_eq!((tally(3).ayes(1), tally(3).max_voters()), (1, Some(3)));
+		// The support is measured against the panel rather than all members of the rank.
+		assert_eq!(tally(3).support(1), Perbill::from_rational(1u32, 3u32));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(outsider), 4, true));
+
+		assert_noop!(
+			Club::select_panel(RuntimeOrigin::root(), 4, 3, 7),
+			Error::<Test>::PollHasVotes
+		);
+
+		Polls::mutate(|p| p.insert(3, Completed(1, true)));
+		assert_ok!(Club::cleanup_poll(RuntimeOrigin::signed(4), 3, 10));
+		assert!(Panels::<Test>::get(3).is_none());
+	});
+}
+
+#[test]
+fn ensure_ranked_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 3));
+
+		use frame_support::traits::OriginTrait;
+		type Rank1 = EnsureRanked<Test, (), 1>;
+		type Rank2 = EnsureRanked<Test, (), 2>;
+		type Rank3 = EnsureRanked<Test, (), 3>;
+		type Rank4 = EnsureRanked<Test, (), 4>;
+		assert_eq!(Rank1::try_origin(RuntimeOrigin::signed(1)).unwrap(), 1);
+		assert_eq!(Rank1::try_origin(RuntimeOrigin::signed(2)).unwrap(), 2);
+		assert_eq!(Rank1::try_origin(RuntimeOrigin::signed(3)).unwrap(), 3);
+		assert_eq!(
+			Rank2::try_origin(RuntimeOrigin::signed(1)).unwrap_err().as_signed().unwrap(),
+			1
+		);
+		assert_eq!(Rank2::try_origin(RuntimeOrigin::signed(2)).unwrap(), 2);
+		assert_eq!(Rank2::try_origin(RuntimeOrigin::signed(3)).unwrap(), 3);
+		assert_eq!(
+			Rank3::try_origin(RuntimeOrigin::signed(1)).unwrap_err().as_signed().unwrap(),
+			1
+		);
+		assert_eq!(
+			Rank3::try_origin(RuntimeOrigin::signed(2)).unwrap_err().as_signed().unwrap(),
+			2
+		);
+		assert_eq!(Rank3::try_origin(RuntimeOrigin::signed(3)).unwrap(), 3);
+		assert_eq!(
+			Rank4::try_origin(RuntimeOrigin::signed(1)).unwrap_err().as_signed().unwrap(),
+			1
+		);
+		assert_eq!(
+			Rank4::try_origin(RuntimeOrigin::signed(2)).unwrap_err().as_signed().unwrap(),
+			2
+		);
+		assert_eq!(
+			Rank4::try_origin(RuntimeOrigin::signed(3)).unwrap_err().as_signed().unwrap(),
+			3
+		);
+	});
+}
+
+#[test]
+fn ensure_origins_reject_probationary_members() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::induct_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+
+		use frame_support::traits::OriginTrait;
+		let rejected = |o: RuntimeOrigin| o.as_signed().unwrap();
+		assert_eq!(
+			rejected(
+				EnsureRanked::<Test, (), 0>::try_origin(RuntimeOrigin::signed(1)).unwrap_err()
+			),
+			1
+		);
+		assert_eq!(
+			rejected(
+				EnsureMember::<Test, (), 0>::try_origin(RuntimeOrigin::signed(1)).unwrap_err()
+			),
+			1
+		);
+		assert_eq!(
+			rejected(
+				EnsureRankedMember::<Test, (), 0>::try_origin(RuntimeOrigin::signed(1))
+					.unwrap_err()
+			),
+			1
+		);
+		assert_eq!(
+			rejected(
+				EnsureMemberOfClass::<Test, ()>::try_origin(RuntimeOrigin::signed(1), &0)
+					.unwrap_err()
+			),
+			1
+		);
+		assert_eq!(EnsureRanked::<Test, (), 0>::try_origin(RuntimeOrigin::signed(2)).unwrap(), 0);
+		assert_eq!(EnsureMember::<Test, (), 0>::try_origin(RuntimeOrigin::signed(2)).unwrap(), 2);
+		assert_eq!(
+			EnsureRankedMember::<Test, (), 0>::try_origin(RuntimeOrigin::signed(2)).unwrap(),
+			(2, 0)
+		);
+		assert_eq!(
+			EnsureMemberOfClass::<Test, ()>::try_origin(RuntimeOrigin::signed(2), &0).unwrap(),
+			2
+		);
+
+		// Once confirmed, the member passes the guards.
+		assert_ok!(Club::confirm_member(RuntimeOrigin::root(), 1));
+		assert_eq!(EnsureRanked::<Test, (), 0>::try_origin(RuntimeOrigin::signed(1)).unwrap(), 0);
+		assert_eq!(EnsureMember::<Test, (), 0>::try_origin(RuntimeOrigin::signed(1)).unwrap(), 1);
+		assert_eq!(
+			EnsureRankedMember::<Test, (), 0>::try_origin(RuntimeOrigin::signed(1)).unwrap(),
+			(1, 0)
+		);
+		assert_eq!(
+			EnsureMemberOfClass::<Test, ()>::try_origin(RuntimeOrigin::signed(1), &0).unwrap(),
+			1
+		);
+	});
+}
+
+#[test]
+fn do_add_member_to_rank_works() {
+	new_test_ext().execute_with(|| {
+		let max_rank = 9u16;
+		assert_ok!(Club::do_add_member_to_rank(69, max_rank / 2));
+		assert_ok!(Club::do_add_member_to_rank(1337, max_rank));
+		for i in 0..=max_rank {
+			if i <= max_rank / 2 {
+				assert_eq!(member_count(i), 2);
+			} else {
+				assert_eq!(member_count(i), 1);
+			}
+		}
+		assert_eq!(member_count(max_rank + 1), 0);
 	})
 }
+
+#[test]
+fn read_accessors_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_ok!(Club::do_add_member_to_rank(2, 0));
+		assert_eq!(Club::member_count(0), 2);
+		assert_eq!(Club::member_count(2), 1);
+		assert_eq!(Club::member_count(3), 0);
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(2));
+		assert_eq!(Club::member_record(&3), None);
+		assert_eq!(Club::members_of_rank(0).collect::<Vec<_>>(), vec![1, 2]);
+		assert_eq!(Club::members_of_rank(2).collect::<Vec<_>>(), vec![1]);
+		assert_eq!(Club::members_of_rank(3).count(), 0);
+
+		assert_eq!(Club::vote_of(3, &1), None);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, false));
+		let vote = Club::vote_of(3, &1).unwrap();
+		assert!(!vote.is_aye());
+		assert_eq!(vote.votes(), 3);
+
+		assert_eq!(Club::voting_power(&1, 1), Some(3));
+		assert_eq!(Club::voting_power(&2, 1), None);
+		assert_eq!(Club::voting_power(&3, 0), None);
+		assert_eq!(Club::voting_power(&1, 0), Some(6));
+		assert_ok!(Club::delegate(RuntimeOrigin::signed(2), 0, 1));
+		assert_eq!(Club::voting_power(&1, 0), Some(7));
+
+		let mut polls = Polls::get();
+		polls.insert(4, Ongoing(Tally::from_parts(0, 0, 0, 0), 2));
+		polls.insert(5, Ongoing(Tally::from_parts(0, 0, 0, 0), 3));
+		Polls::set(polls);
+		assert_eq!(
+			Club::votable_polls(&1),
+			vec![(3, 1, Tally::from_parts(0, 1, 0, 3)), (4, 2, Tally::from_parts(0, 0, 0, 0))]
+		);
+		assert!(Club::votable_polls(&2).is_empty());
+		assert!(Club::votable_polls(&3).is_empty());
+	});
+}
+
+#[test]
+fn set_rank_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 0));
+		assert_ok!(Club::do_add_member_to_rank(2, 3));
+		assert_noop!(Club::set_rank(RuntimeOrigin::signed(1), 1, 3, 3), BadOrigin);
+		assert_noop!(Club::set_rank(RuntimeOrigin::root(), 1, 3, 2), Error::<Test>::InvalidWitness);
+		assert_noop!(Club::set_rank(RuntimeOrigin::root(), 5, 1, 1), Error::<Test>::NotMember);
+
+		System::reset_events();
+		assert_ok!(Club::set_rank(RuntimeOrigin::root(), 1, 3, 3));
+		assert_eq!(System::events().len(), 1);
+		System::assert_last_event(Event::RankChanged { who: 1, rank: 3 }.into());
+		assert_eq!(member_count(3), 2);
+		assert_ok!(Club::do_try_state());
+
+		assert_ok!(Club::set_rank(RuntimeOrigin::root(), 1, 1, 3));
+		System::assert_last_event(Event::RankChanged { who: 1, rank: 1 }.into());
+		assert_eq!((member_count(1), member_count(2), member_count(3)), (2, 1, 1));
+		assert_ok!(Club::do_try_state());
+
+		// Members under review may only be moved up.
+		assert_ok!(Club::mark_under_review(RuntimeOrigin::signed(9), 1, 3));
+		assert_noop!(
+			Club::set_rank(RuntimeOrigin::root(), 1, 0, 1),
+			Error::<Test>::MemberUnderReview
+		);
+		assert_ok!(Club::set_rank(RuntimeOrigin::root(), 1, 2, 2));
+
+		// Members on probation may not be moved up.
+		assert_ok!(Club::do_induct_member(6));
+		assert_noop!(Club::set_rank(RuntimeOrigin::root(), 6, 1, 1), Error::<Test>::Probationary);
+	});
+}
+
+#[test]
+fn force_set_ranks_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 3));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		let changes: BoundedVec<_, _> = vec![(1, 1), (2, 2), (3, 2)].try_into().unwrap();
+		let witness = Club::rank_changes_witness(&changes);
+
+		assert_noop!(
+			Club::force_set_ranks(RuntimeOrigin::signed(1), changes.clone(), 3, witness),
+			BadOrigin
+		);
+		assert_noop!(
+			Club::force_set_ranks(RuntimeOrigin::root(), changes.clone(), 2, witness),
+			Error::<Test>::InvalidWitness
+		);
+
+		// The membership drifts after the witness was taken.
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 2));
+		assert_noop!(
+			Club::force_set_ranks(RuntimeOrigin::root(), changes.clone(), 3, witness),
+			Error::<Test>::InvalidWitness
+		);
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
+
+		assert_ok!(Club::force_set_ranks(RuntimeOrigin::root(), changes, 3, witness));
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(1));
+		assert_eq!(Club::member_record(&2).map(|r| r.rank()), Some(2));
+		assert_eq!(Club::member_record(&3).map(|r| r.rank()), Some(2));
+		assert_eq!(member_count(0), 3);
+		assert_eq!(member_count(1), 3);
+		assert_eq!(member_count(2), 2);
+		assert_eq!(member_count(3), 0);
+		System::assert_has_event(Event::RankChanged { who: 1, rank: 1 }.into());
+		System::assert_has_event(Event::MemberAdded { who: 3 }.into());
+	});
+}
+
+#[test]
+fn ranked_members_adapter_works() {
+	type Adapter = ChangeMembersAdapter<Test, (), ConstU16<2>>;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 4));
+		Adapter::initialize_members(&[1, 2, 3]);
+		assert_eq!(Club::member_record(&1).map(|r| r.rank()), Some(4));
+		assert_eq!(Club::member_record(&2).map(|r| r.rank()), Some(2));
+		assert_eq!(Club::member_record(&3).map(|r| r.rank()), Some(2));
+
+		Adapter::set_members_sorted(&[2, 4], &[1, 2, 3]);
+		assert_eq!(Club::member_record(&1), None);
+		assert_eq!(Club::member_record(&2).map(|r| r.rank()), Some(2));
+		assert_eq!(Club::member_record(&3), None);
+		assert_eq!(Club::member_record(&4).map(|r| r.rank()), Some(2));
+		assert_eq!(member_count(0), 2);
+		assert_eq!(member_count(2), 2);
+		assert_eq!(member_count(4), 0);
+		System::assert_has_event(Event::MemberRemoved { who: 1, rank: 4 }.into());
+	});
+}
+
+#[test]
+fn membership_nft_mirrors_membership() {
+	new_test_ext().execute_with(|| {
+		let badges = || Badges::get().into_iter().collect::<Vec<_>>();
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 3));
+		assert_eq!(badges(), vec![(1, 0), (2, 0), (3, 0)]);
+
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
+		assert_eq!(badges(), vec![(1, 1), (2, 0), (3, 0)]);
+
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::resign(RuntimeOrigin::signed(3), 0, false));
+		assert_eq!(badges(), vec![(1, 1)]);
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 1));
+		assert!(badges().is_empty());
+
+		// A failing mirror reverts the membership change.
+		Badges::set(&vec![(4, 0)].into_iter().collect());
+		assert_noop!(
+			Club::add_member(RuntimeOrigin::root(), 4),
+			DispatchError::Other("badge exists")
+		);
+		assert!(!Members::<Test>::contains_key(4));
+	});
+}
+
+#[test]
+fn membership_hooks_are_called() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::add_member(RuntimeOrigin::root(), 2));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::promote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
+		assert_ok!(Club::exchange_member(RuntimeOrigin::root(), 1, 10, 1, 0));
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
+		assert_eq!(
+			HookCalls::take(),
+			vec![
+				(1, None, Some(0)),
+				(2, None, Some(0)),
+				(1, Some(0), Some(1)),
+				(1, Some(1), Some(2)),
+				(1, Some(2), Some(1)),
+				(1, Some(1), None),
+				(10, None, Some(1)),
+				(2, Some(0), None),
+			]
+		);
+
+		assert_ok!(Club::resign(RuntimeOrigin::signed(10), 1, false));
+		assert_eq!(HookCalls::take(), vec![(10, Some(1), None)]);
+
+		// Failed changes call no hook.
+		assert_noop!(Club::demote_member(RuntimeOrigin::root(), 10), Error::<Test>::NotMember);
+		assert!(HookCalls::get().is_empty());
+	});
+}
+
+#[test]
+fn members_under_review_cannot_be_demoted_or_removed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_ok!(Club::do_add_member_to_rank(2, 1));
+		assert_noop!(
+			Club::mark_under_review(RuntimeOrigin::signed(9), 1, 1),
+			Error::<Test>::NotPolling
+		);
+		// The poll must be about the member.
+		UnrelatedReviews::set(vec![(1, 3)]);
+		assert_noop!(Club::mark_under_review(RuntimeOrigin::signed(9), 1, 3), BadOrigin);
+		UnrelatedReviews::set(vec![]);
+		assert_ok!(Club::mark_under_review(RuntimeOrigin::signed(9), 1, 3));
+		System::assert_last_event(Event::MarkedUnderReview { who: 1, poll: 3 }.into());
+		assert_noop!(
+			Club::mark_under_review(RuntimeOrigin::signed(9), 1, 3),
+			Error::<Test>::MemberUnderReview
+		);
+
+		// The review bars the demotion and removal of the member, but of no one else.
+		assert_noop!(
+			Club::demote_member(RuntimeOrigin::root(), 1),
+			Error::<Test>::MemberUnderReview
+		);
+		assert_noop!(
+			Club::remove_member(RuntimeOrigin::root(), 1, 2),
+			Error::<Test>::MemberUnderReview
+		);
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 2));
+
+		// Once the poll has ended, the review is cleared by the next demotion.
+		let mut polls = Polls::get();
+		polls.insert(3, Completed(1, true));
+		Polls::set(polls);
+		assert_ok!(Club::demote_member(RuntimeOrigin::root(), 1));
+		System::assert_has_event(Event::ReviewEnded { who: 1, poll: 3 }.into());
+		assert!(!UnderReview::<Test>::contains_key(1));
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 1));
+	});
+}
+
+#[test]
+fn removed_members_cannot_be_put_under_review() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 1));
+		assert_noop!(
+			Club::mark_under_review(RuntimeOrigin::signed(9), 1, 3),
+			Error::<Test>::NotMember
+		);
+
+		// A member who leaves while under review does not keep a stale review.
+		assert_ok!(Club::do_add_member_to_rank(2, 0));
+		assert_ok!(Club::mark_under_review(RuntimeOrigin::signed(9), 2, 3));
+		assert_ok!(Club::resign(RuntimeOrigin::signed(2), 0, false));
+		assert!(!UnderReview::<Test>::contains_key(2));
+	});
+}
@@ -15,14 +15,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_ranked_collective
+//! Weights for pallet_ranked_collective
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! NOT FULLY GENERATED: the functions marked as placeholders were written by hand after the
+//! last run of the benchmark CLI and have not been benchmarked. Regenerate this file with the
+//! command below before these weights are relied upon.
+//!
+//! Last run of the Substrate benchmark CLI version 4.0.0-dev:
 //! DATE: 2022-11-07, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: `bm2`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
 
-// Executed Command:
+// Command to regenerate:
 // ./target/production/substrate
 // benchmark
 // pallet
@@ -51,161 +55,773 @@ pub trait WeightInfo {
 	fn remove_member(r: u32, ) -> Weight;
 	fn promote_member(r: u32, ) -> Weight;
 	fn demote_member(r: u32, ) -> Weight;
-	fn vote() -> Weight;
+	fn vote_new(d: u32, ) -> Weight;
+	fn vote_existing(d: u32, ) -> Weight;
 	fn cleanup_poll(n: u32, ) -> Weight;
+	fn select_panel(n: u32, ) -> Weight;
+	fn resign(r: u32, ) -> Weight;
+	fn force_set_ranks(n: u32, r: u32, ) -> Weight;
+	fn freeze_class() -> Weight;
+	fn unfreeze_class() -> Weight;
+	fn veto() -> Weight;
+	fn mark_under_review() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate(p: u32, ) -> Weight;
+	fn exchange_member(r: u32, p: u32, ) -> Weight;
+	fn remap_class(v: u32, ) -> Weight;
+	fn induct_member() -> Weight;
+	fn confirm_member() -> Weight;
+	fn set_vote_curve() -> Weight;
+	fn split_members(n: u32, r: u32, ) -> Weight;
+	fn retract_vote(d: u32, ) -> Weight;
+	fn set_class_limit() -> Weight;
+	fn set_rank(r: u32, ) -> Weight;
+	fn scan_members(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_ranked_collective using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:0 w:1)
 	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	fn add_member() -> Weight {
-		// Minimum execution time: 24_344 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(24_856_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IdToIndex (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective FreeVotesUsed (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	/// The range of component `r` is `[0, 10]`.
 	fn remove_member(r: u32, ) -> Weight {
-		// Minimum execution time: 36_881 nanoseconds.
-		Weight::from_ref_time(39_284_238 as u64)
-			// Standard Error: 16_355
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(46_917_420 as u64)
 			.saturating_add(Weight::from_ref_time(11_385_424 as u64).saturating_mul(r as u64))
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
 			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(r as u64)))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
 	}
 	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:0 w:1)
 	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	/// The range of component `r` is `[0, 10]`.
 	fn promote_member(r: u32, ) -> Weight {
-		// Minimum execution time: 27_444 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(28_576_394 as u64)
-			// Standard Error: 4_818
 			.saturating_add(Weight::from_ref_time(519_056 as u64).saturating_mul(r as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IdToIndex (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	/// The range of component `r` is `[0, 10]`.
 	fn demote_member(r: u32, ) -> Weight {
-		// Minimum execution time: 36_539 nanoseconds.
-		Weight::from_ref_time(39_339_893 as u64)
-			// Standard Error: 16_526
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(46_902_115 as u64)
 			.saturating_add(Weight::from_ref_time(807_457 as u64).saturating_mul(r as u64))
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
-	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedPolls ReferendumInfoFor (r:1 w:1)
-	// Storage: RankedCollective Voting (r:1 w:1)
+	// Storage: RankedCollective FrozenClasses (r:1 w:0)
+	// Storage: RankedCollective Delegations (r:1 w:0)
+	// Storage: RankedCollective VoterIds (r:1 w:1)
+	// Storage: RankedCollective NextVoterId (r:1 w:1)
+	// Storage: RankedCollective Voters (r:0 w:1)
+	// Storage: RankedCollective VotePages (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:1 w:1)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:1)
+	// Storage: RankedCollective VoteWeightCurve (r:1 w:0)
+	// Storage: RankedCollective Delegators (r:1 w:0)
 	// Storage: Scheduler Agenda (r:2 w:2)
-	fn vote() -> Weight {
-		// Minimum execution time: 50_548 nanoseconds.
-		Weight::from_ref_time(51_276_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	// Storage: RankedCollective FreeVotesUsed (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn vote_new(d: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(62_417_000 as u64)
+			.saturating_add(Weight::from_ref_time(8_689_914 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:1)
+	// Storage: RankedCollective FrozenClasses (r:1 w:0)
+	// Storage: RankedCollective Delegations (r:1 w:0)
+	// Storage: RankedCollective VoterIds (r:1 w:0)
+	// Storage: RankedCollective VotePages (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:1 w:0)
+	// Storage: RankedCollective VoteWeightCurve (r:1 w:0)
+	// Storage: RankedCollective Delegators (r:1 w:0)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	/// The range of component `d` is `[0, 16]`.
+	fn vote_existing(d: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(63_950_000 as u64)
+			.saturating_add(Weight::from_ref_time(8_702_355 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
 	}
 	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
-	// Storage: RankedCollective VotingCleanup (r:1 w:0)
-	// Storage: RankedCollective Voting (r:0 w:2)
+	// Storage: RankedCollective VotePages (r:101 w:100)
+	// Storage: RankedCollective VotesRemoved (r:1 w:0)
 	/// The range of component `n` is `[0, 100]`.
 	fn cleanup_poll(n: u32, ) -> Weight {
-		// Minimum execution time: 16_222 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(22_982_955 as u64)
-			// Standard Error: 3_863
 			.saturating_add(Weight::from_ref_time(1_074_054 as u64).saturating_mul(n as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	// Storage: RankedCollective Panels (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: RankedCollective ProbationaryCount (r:1 w:0)
+	// Storage: RankedCollective IndexToId (r:100 w:0)
+	// Storage: RankedCollective Members (r:100 w:0)
+	// Storage: RankedCollective ActivePolls (r:1 w:1)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn select_panel(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(26_013_519 as u64)
+			.saturating_add(Weight::from_ref_time(6_518_744 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective IdToIndex (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:0 w:1)
+	// Storage: RankedCollective FreeVotesUsed (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `r` is `[0, 10]`.
+	fn resign(r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(39_781_513 as u64)
+			.saturating_add(Weight::from_ref_time(11_402_870 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:0 w:1)
+	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `n` is `[1, 16]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn force_set_ranks(n: u32, r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(12_806_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_318_113 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(146_377_302 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((16 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((64 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedCollective FrozenClasses (r:1 w:1)
+	fn freeze_class() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(17_731_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective FrozenClasses (r:1 w:1)
+	fn unfreeze_class() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(18_102_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective Vetoes (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:1 w:1)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:1)
+	fn veto() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(34_027_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedPolls ReferendumInfoFor (r:2 w:0)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	fn mark_under_review() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(28_640_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective Members (r:2 w:0)
+	// Storage: RankedCollective Delegations (r:2 w:1)
+	// Storage: RankedCollective Delegators (r:2 w:1)
+	fn delegate() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(38_204_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:0)
+	// Storage: RankedCollective Delegations (r:1 w:1)
+	// Storage: RankedCollective Delegators (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:11 w:0)
+	// Storage: RankedCollective VoterIds (r:20 w:0)
+	// Storage: RankedCollective VotePages (r:30 w:20)
+	// Storage: RankedPolls ReferendumInfoFor (r:10 w:10)
+	/// The range of component `p` is `[0, 10]`.
+	fn undelegate(p: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(30_917_000 as u64)
+			.saturating_add(Weight::from_ref_time(14_310_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: RankedCollective Members (r:2 w:2)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
+	// Storage: RankedCollective IdToIndex (r:1 w:2)
+	// Storage: RankedCollective IndexToId (r:0 w:1)
+	// Storage: RankedCollective FreeVotesUsed (r:1 w:2)
+	// Storage: RankedCollective UnderReview (r:1 w:2)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:0)
+	// Storage: RankedCollective ActivePolls (r:11 w:0)
+	// Storage: RankedCollective VoterIds (r:2 w:1)
+	// Storage: RankedCollective NextVoterId (r:1 w:1)
+	// Storage: RankedCollective Voters (r:0 w:1)
+	// Storage: RankedCollective VotePages (r:10 w:10)
+	// Storage: RankedCollective Panels (r:10 w:10)
+	// Storage: RankedCollective Vetoes (r:10 w:10)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `r` is `[0, 10]`.
+	/// The range of component `p` is `[0, 10]`.
+	fn exchange_member(r: u32, p: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(49_263_118 as u64)
+			.saturating_add(Weight::from_ref_time(7_814_530 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(9_402_000 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: RankedCollective ClassRemapCursor (r:1 w:1)
+	// Storage: RankedCollective VotePages (r:20 w:10)
+	// Storage: RankedCollective Voters (r:10 w:0)
+	// Storage: RankedCollective VoterIds (r:10 w:0)
+	// Storage: RankedPolls ReferendumInfoFor (r:10 w:10)
+	// Storage: RankedCollective Members (r:10 w:0)
+	/// The range of component `v` is `[0, 10]`.
+	fn remap_class(v: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(15_338_260 as u64)
+			.saturating_add(Weight::from_ref_time(27_906_113 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective ProbationaryCount (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:0 w:1)
+	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	fn induct_member() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(26_793_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ProbationaryCount (r:1 w:1)
+	fn confirm_member() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(13_962_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: RankedCollective VoteWeightCurve (r:0 w:1)
+	fn set_vote_curve() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(10_291_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective SplitCursor (r:1 w:1)
+	// Storage: RankedCollective Members (r:101 w:100)
+	// Storage: RankedCollective MemberCount (r:11 w:11)
+	// Storage: RankedCollective IdToIndex (r:1100 w:1100)
+	// Storage: RankedCollective IndexToId (r:1100 w:1100)
+	// Storage: RankedCollective UnderReview (r:100 w:100)
+	// Storage: RankedCollective FreeVotesUsed (r:0 w:100)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn split_members(n: u32, r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(19_032_000 as u64)
+			.saturating_add(Weight::from_ref_time(58_203_117 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(1_138_106_954 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((300 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((300 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:1)
+	// Storage: RankedCollective FrozenClasses (r:1 w:0)
+	// Storage: RankedCollective VoterIds (r:1 w:0)
+	// Storage: RankedCollective VotePages (r:1 w:1)
+	// Storage: RankedCollective Delegators (r:1 w:0)
+	/// The range of component `d` is `[0, 16]`.
+	fn retract_vote(d: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(32_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_215_874 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: RankedCollective ClassLimits (r:0 w:1)
+	fn set_class_limit() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(10_580_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective IdToIndex (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `r` is `[0, 10]`.
+	fn set_rank(r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(29_804_377 as u64)
+			.saturating_add(Weight::from_ref_time(10_966_210 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedCollective Members (r:100 w:0)
+	/// The range of component `n` is `[0, 100]`.
+	fn scan_members(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(3_546_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_207_313 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:0 w:1)
 	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	fn add_member() -> Weight {
-		// Minimum execution time: 24_344 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(24_856_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IdToIndex (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective FreeVotesUsed (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	/// The range of component `r` is `[0, 10]`.
 	fn remove_member(r: u32, ) -> Weight {
-		// Minimum execution time: 36_881 nanoseconds.
-		Weight::from_ref_time(39_284_238 as u64)
-			// Standard Error: 16_355
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(46_917_420 as u64)
 			.saturating_add(Weight::from_ref_time(11_385_424 as u64).saturating_mul(r as u64))
-			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
 			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(r as u64)))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
 	}
 	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:0 w:1)
 	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	/// The range of component `r` is `[0, 10]`.
 	fn promote_member(r: u32, ) -> Weight {
-		// Minimum execution time: 27_444 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(28_576_394 as u64)
-			// Standard Error: 4_818
 			.saturating_add(Weight::from_ref_time(519_056 as u64).saturating_mul(r as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedCollective MemberCount (r:1 w:1)
 	// Storage: RankedCollective IdToIndex (r:1 w:1)
 	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
 	/// The range of component `r` is `[0, 10]`.
 	fn demote_member(r: u32, ) -> Weight {
-		// Minimum execution time: 36_539 nanoseconds.
-		Weight::from_ref_time(39_339_893 as u64)
-			// Standard Error: 16_526
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(46_902_115 as u64)
 			.saturating_add(Weight::from_ref_time(807_457 as u64).saturating_mul(r as u64))
-			.saturating_add(RocksDbWeight::get().reads(4 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
-	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedCollective Members (r:1 w:1)
 	// Storage: RankedPolls ReferendumInfoFor (r:1 w:1)
-	// Storage: RankedCollective Voting (r:1 w:1)
+	// Storage: RankedCollective FrozenClasses (r:1 w:0)
+	// Storage: RankedCollective Delegations (r:1 w:0)
+	// Storage: RankedCollective VoterIds (r:1 w:1)
+	// Storage: RankedCollective NextVoterId (r:1 w:1)
+	// Storage: RankedCollective Voters (r:0 w:1)
+	// Storage: RankedCollective VotePages (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:1 w:1)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:1)
+	// Storage: RankedCollective VoteWeightCurve (r:1 w:0)
+	// Storage: RankedCollective Delegators (r:1 w:0)
 	// Storage: Scheduler Agenda (r:2 w:2)
-	fn vote() -> Weight {
-		// Minimum execution time: 50_548 nanoseconds.
-		Weight::from_ref_time(51_276_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	// Storage: RankedCollective FreeVotesUsed (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn vote_new(d: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(62_417_000 as u64)
+			.saturating_add(Weight::from_ref_time(8_689_914 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:1)
+	// Storage: RankedCollective FrozenClasses (r:1 w:0)
+	// Storage: RankedCollective Delegations (r:1 w:0)
+	// Storage: RankedCollective VoterIds (r:1 w:0)
+	// Storage: RankedCollective VotePages (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:1 w:0)
+	// Storage: RankedCollective VoteWeightCurve (r:1 w:0)
+	// Storage: RankedCollective Delegators (r:1 w:0)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	/// The range of component `d` is `[0, 16]`.
+	fn vote_existing(d: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(63_950_000 as u64)
+			.saturating_add(Weight::from_ref_time(8_702_355 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
 	}
 	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
-	// Storage: RankedCollective VotingCleanup (r:1 w:0)
-	// Storage: RankedCollective Voting (r:0 w:2)
+	// Storage: RankedCollective VotePages (r:101 w:100)
+	// Storage: RankedCollective VotesRemoved (r:1 w:0)
 	/// The range of component `n` is `[0, 100]`.
 	fn cleanup_poll(n: u32, ) -> Weight {
-		// Minimum execution time: 16_222 nanoseconds.
+		// Placeholder: written by hand, not yet benchmarked.
 		Weight::from_ref_time(22_982_955 as u64)
-			// Standard Error: 3_863
 			.saturating_add(Weight::from_ref_time(1_074_054 as u64).saturating_mul(n as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	// Storage: RankedCollective Panels (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: RankedCollective ProbationaryCount (r:1 w:0)
+	// Storage: RankedCollective IndexToId (r:100 w:0)
+	// Storage: RankedCollective Members (r:100 w:0)
+	// Storage: RankedCollective ActivePolls (r:1 w:1)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn select_panel(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(26_013_519 as u64)
+			.saturating_add(Weight::from_ref_time(6_518_744 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective IdToIndex (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:0 w:1)
+	// Storage: RankedCollective FreeVotesUsed (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `r` is `[0, 10]`.
+	fn resign(r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(39_781_513 as u64)
+			.saturating_add(Weight::from_ref_time(11_402_870 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:0 w:1)
+	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `n` is `[1, 16]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn force_set_ranks(n: u32, r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(12_806_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_318_113 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(146_377_302 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().reads((16 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((64 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedCollective FrozenClasses (r:1 w:1)
+	fn freeze_class() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(17_731_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective FrozenClasses (r:1 w:1)
+	fn unfreeze_class() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(18_102_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective Vetoes (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:1 w:1)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:1)
+	fn veto() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(34_027_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedPolls ReferendumInfoFor (r:2 w:0)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	fn mark_under_review() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(28_640_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective Members (r:2 w:0)
+	// Storage: RankedCollective Delegations (r:2 w:1)
+	// Storage: RankedCollective Delegators (r:2 w:1)
+	fn delegate() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(38_204_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:0)
+	// Storage: RankedCollective Delegations (r:1 w:1)
+	// Storage: RankedCollective Delegators (r:1 w:1)
+	// Storage: RankedCollective ActivePolls (r:11 w:0)
+	// Storage: RankedCollective VoterIds (r:20 w:0)
+	// Storage: RankedCollective VotePages (r:30 w:20)
+	// Storage: RankedPolls ReferendumInfoFor (r:10 w:10)
+	/// The range of component `p` is `[0, 10]`.
+	fn undelegate(p: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(30_917_000 as u64)
+			.saturating_add(Weight::from_ref_time(14_310_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: RankedCollective Members (r:2 w:2)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
+	// Storage: RankedCollective IdToIndex (r:1 w:2)
+	// Storage: RankedCollective IndexToId (r:0 w:1)
+	// Storage: RankedCollective FreeVotesUsed (r:1 w:2)
+	// Storage: RankedCollective UnderReview (r:1 w:2)
+	// Storage: RankedCollective CounterForActivePolls (r:1 w:0)
+	// Storage: RankedCollective ActivePolls (r:11 w:0)
+	// Storage: RankedCollective VoterIds (r:2 w:1)
+	// Storage: RankedCollective NextVoterId (r:1 w:1)
+	// Storage: RankedCollective Voters (r:0 w:1)
+	// Storage: RankedCollective VotePages (r:10 w:10)
+	// Storage: RankedCollective Panels (r:10 w:10)
+	// Storage: RankedCollective Vetoes (r:10 w:10)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `r` is `[0, 10]`.
+	/// The range of component `p` is `[0, 10]`.
+	fn exchange_member(r: u32, p: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(49_263_118 as u64)
+			.saturating_add(Weight::from_ref_time(7_814_530 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(9_402_000 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: RankedCollective ClassRemapCursor (r:1 w:1)
+	// Storage: RankedCollective VotePages (r:20 w:10)
+	// Storage: RankedCollective Voters (r:10 w:0)
+	// Storage: RankedCollective VoterIds (r:10 w:0)
+	// Storage: RankedPolls ReferendumInfoFor (r:10 w:10)
+	// Storage: RankedCollective Members (r:10 w:0)
+	/// The range of component `v` is `[0, 10]`.
+	fn remap_class(v: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(15_338_260 as u64)
+			.saturating_add(Weight::from_ref_time(27_906_113 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ResignedUntil (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective ProbationaryCount (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:0 w:1)
+	// Storage: RankedCollective IdToIndex (r:0 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	fn induct_member() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(26_793_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective ProbationaryCount (r:1 w:1)
+	fn confirm_member() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(13_962_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: RankedCollective VoteWeightCurve (r:0 w:1)
+	fn set_vote_curve() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(10_291_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective SplitCursor (r:1 w:1)
+	// Storage: RankedCollective Members (r:101 w:100)
+	// Storage: RankedCollective MemberCount (r:11 w:11)
+	// Storage: RankedCollective IdToIndex (r:1100 w:1100)
+	// Storage: RankedCollective IndexToId (r:1100 w:1100)
+	// Storage: RankedCollective UnderReview (r:100 w:100)
+	// Storage: RankedCollective FreeVotesUsed (r:0 w:100)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	/// The range of component `r` is `[0, 10]`.
+	fn split_members(n: u32, r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(19_032_000 as u64)
+			.saturating_add(Weight::from_ref_time(58_203_117 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(1_138_106_954 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().reads((300 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((300 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:1)
+	// Storage: RankedCollective FrozenClasses (r:1 w:0)
+	// Storage: RankedCollective VoterIds (r:1 w:0)
+	// Storage: RankedCollective VotePages (r:1 w:1)
+	// Storage: RankedCollective Delegators (r:1 w:0)
+	/// The range of component `d` is `[0, 16]`.
+	fn retract_vote(d: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(32_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_215_874 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: RankedCollective ClassLimits (r:0 w:1)
+	fn set_class_limit() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(10_580_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: RankedCollective Members (r:1 w:1)
+	// Storage: RankedCollective UnderReview (r:1 w:1)
+	// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
+	// Storage: RankedCollective MemberCount (r:1 w:1)
+	// Storage: RankedCollective IdToIndex (r:1 w:1)
+	// Storage: RankedCollective IndexToId (r:1 w:1)
+	// Storage: RankedCollective MembershipCommitment (r:1 w:1)
+	/// The range of component `r` is `[0, 10]`.
+	fn set_rank(r: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(29_804_377 as u64)
+			.saturating_add(Weight::from_ref_time(10_966_210 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: RankedCollective Members (r:100 w:0)
+	/// The range of component `n` is `[0, 100]`.
+	fn scan_members(n: u32, ) -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(3_546_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_207_313 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+	}
 }
@@ -0,0 +1,71 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ranked motions pallet benchmarking.
+
+use super::*;
+#[allow(unused_imports)]
+use crate::Pallet as RankedMotions;
+
+use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
+use frame_support::{assert_ok, dispatch::UnfilteredDispatchable};
+use frame_system::RawOrigin as SystemOrigin;
+
+fn first_class<T: Config<I>, I: 'static>() -> T::Class {
+	T::Classes::get().into_iter().next().expect("Always one class")
+}
+
+fn dummy_proposal<T: Config<I>, I: 'static>() -> BoundedCallOf<T, I> {
+	let call = Call::<T, I>::close { index: 0, proposal_weight_bound: Weight::zero() };
+	T::Preimages::bound(CallOf::<T, I>::from(call)).expect("call is small enough to bound")
+}
+
+benchmarks_instance_pallet! {
+	open {
+		let class = first_class::<T, I>();
+		let origin = T::OpenOrigin::successful_origin(&class);
+		let proposal = dummy_proposal::<T, I>();
+		let call = Call::<T, I>::open { class, proposal };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(MotionCount::<T, I>::get(), 1);
+		assert!(MotionFor::<T, I>::contains_key(0));
+	}
+
+	close {
+		let class = first_class::<T, I>();
+		assert_ok!(RankedMotions::<T, I>::open(
+			T::OpenOrigin::successful_origin(&class),
+			class,
+			dummy_proposal::<T, I>(),
+		));
+		MotionFor::<T, I>::mutate(0, |motion| {
+			if let Some(motion) = motion {
+				motion.tally = T::Tally::unanimity(class);
+			}
+		});
+		let end = MotionFor::<T, I>::get(0).unwrap().end;
+		frame_system::Pallet::<T>::set_block_number(end);
+		let caller: T::AccountId = whitelisted_caller();
+		let weight_bound = T::WeightInfo::close();
+	}: _(SystemOrigin::Signed(caller), 0, weight_bound)
+	verify {
+		assert!(!MotionFor::<T, I>::contains_key(0));
+	}
+
+	impl_benchmark_test_suite!(RankedMotions, crate::tests::new_test_ext(), crate::tests::Test);
+}
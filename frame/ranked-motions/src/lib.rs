@@ -0,0 +1,426 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Ranked Motions Pallet
+//!
+//! A minimal `Polling` implementation for chains which want ranked voting, e.g. through the
+//! Ranked Collective pallet, without the machinery of the Referenda pallet.
+//!
+//! A motion is opened with `open` by an origin passing `OpenOrigin` for the motion's class,
+//! usually a member of a sufficient rank. It may be voted on for the `Duration` of its class,
+//! after which anyone may `close` it. A motion is approved if its tally reaches both the
+//! `ApprovalThreshold` and the `SupportThreshold` of its class, in which case its proposal is
+//! dispatched right away from the `ClassOrigin` of its class.
+//!
+//! Proposals are given as `Bounded` calls, so they may be placed inline or noted with the
+//! `Preimages` provider beforehand. The preimage of an open motion is requested until the motion
+//! is closed.
+//!
+//! There are no deposits, queues or decision periods: a motion is ongoing from the moment it is
+//! opened until its duration has passed, and it is removed from storage once closed. Instead, each
+//! proposer may have at most `MaxMotionsPerProposer` motions which have not been closed or vetoed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::{
+		extract_actual_weight, DispatchResult, DispatchResultWithPostInfo, Dispatchable,
+		GetDispatchInfo,
+	},
+	traits::{
+		Bounded, EnsureOriginWithArg, Get, OnPollCompleted, OriginTrait, PollStatus, Polling,
+		QueryPreimage, StorePreimage, VoteTally,
+	},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_arithmetic::traits::AtLeast32BitUnsigned;
+use sp_runtime::{
+	traits::{Convert, Saturating},
+	DispatchError, Perbill, RuntimeDebug,
+};
+use sp_std::{fmt::Debug, prelude::*};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The index of a motion.
+pub type MotionIndex = u32;
+
+pub type CallOf<T, I> = <T as Config<I>>::RuntimeCall;
+pub type BoundedCallOf<T, I> = Bounded<<T as Config<I>>::RuntimeCall>;
+pub type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+pub type MotionOf<T, I> = Motion<
+	<T as frame_system::Config>::AccountId,
+	<T as Config<I>>::Class,
+	BoundedCallOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config<I>>::Tally,
+>;
+
+/// Information about an open motion.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Motion<AccountId, Class, Call, BlockNumber, Tally> {
+	/// The account which opened the motion.
+	pub proposer: AccountId,
+	/// The class of the motion.
+	pub class: Class,
+	/// The call to be dispatched if the motion is approved.
+	pub proposal: Call,
+	/// The block from which the motion may no longer be voted on and may be closed.
+	pub end: BlockNumber,
+	/// The current tally of votes on the motion.
+	pub tally: Tally,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The runtime event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The runtime call type, of which proposals are.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ GetDispatchInfo
+			+ From<Call<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The counting type for votes.
+		type Votes: AtLeast32BitUnsigned + Copy + Parameter + Member + MaxEncodedLen;
+
+		/// The class of a motion, determining who may open it, how long it runs, what it takes to
+		/// be approved and the origin its proposal is dispatched from.
+		type Class: Parameter + Member + Ord + PartialOrd + Copy + MaxEncodedLen;
+
+		/// The tallying type.
+		type Tally: VoteTally<Self::Votes, Self::Class>
+			+ Clone
+			+ Codec
+			+ Eq
+			+ Debug
+			+ TypeInfo
+			+ MaxEncodedLen;
+
+		/// The origin which may open a motion of a given class. The success value is the
+		/// proposer.
+		type OpenOrigin: EnsureOriginWithArg<
+			Self::RuntimeOrigin,
+			Self::Class,
+			Success = Self::AccountId,
+		>;
+
+		/// The classes of motions which may be opened.
+		type Classes: Get<Vec<Self::Class>>;
+
+		/// The number of blocks a motion of a given class may be voted on for.
+		type Duration: Convert<Self::Class, Self::BlockNumber>;
+
+		/// The approval a motion of a given class must reach to be approved.
+		type ApprovalThreshold: Convert<Self::Class, Perbill>;
+
+		/// The support a motion of a given class must reach to be approved.
+		type SupportThreshold: Convert<Self::Class, Perbill>;
+
+		/// The origin from which the proposal of an approved motion of a given class is
+		/// dispatched.
+		type ClassOrigin: Convert<Self::Class, PalletsOriginOf<Self>>;
+
+		/// The preimage provider.
+		type Preimages: QueryPreimage + StorePreimage;
+
+		/// Handler called with the final tally of each closed motion.
+		type OnPollCompleted: OnPollCompleted<MotionIndex, Self::Class, Self::Tally>;
+
+		/// The maximum number of motions an account may have opened which have not yet been
+		/// closed or vetoed.
+		#[pallet::constant]
+		type MaxMotionsPerProposer: Get<u32>;
+	}
+
+	/// The number of motions which have been opened so far.
+	#[pallet::storage]
+	pub type MotionCount<T: Config<I>, I: 'static = ()> = StorageValue<_, MotionIndex, ValueQuery>;
+
+	/// The motions which have not been closed yet.
+	#[pallet::storage]
+	pub type MotionFor<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, MotionIndex, MotionOf<T, I>>;
+
+	/// The number of motions opened by an account which have not been closed or vetoed yet.
+	#[pallet::storage]
+	pub type MotionsOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A motion has been opened by `proposer`; it may be voted on until `end`.
+		Opened { index: MotionIndex, class: T::Class, proposer: T::AccountId, end: T::BlockNumber },
+		/// A motion has been approved and its proposal dispatched with the given `result`.
+		Approved { index: MotionIndex, tally: T::Tally, result: DispatchResult },
+		/// A motion has been rejected.
+		Rejected { index: MotionIndex, tally: T::Tally },
+		/// A motion has been vetoed by the voters and removed without its proposal being
+		/// dispatched.
+		Vetoed { index: MotionIndex, tally: T::Tally },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The class is not one of `Classes`.
+		BadClass,
+		/// The motion is unknown or has been closed.
+		UnknownMotion,
+		/// The motion may still be voted on.
+		Ongoing,
+		/// The weight of the proposal is greater than the given bound.
+		WrongProposalWeight,
+		/// The proposer already has `MaxMotionsPerProposer` motions which have not been closed.
+		TooManyMotions,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Open a motion to dispatch `proposal`.
+		///
+		/// - `origin`: Must pass `OpenOrigin` for `class`.
+		/// - `class`: The class of the motion.
+		/// - `proposal`: The call to be dispatched from the `ClassOrigin` of `class` if the motion
+		///   is approved.
+		///
+		/// Emits `Opened`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::open())]
+		pub fn open(
+			origin: OriginFor<T>,
+			class: T::Class,
+			proposal: BoundedCallOf<T, I>,
+		) -> DispatchResult {
+			let proposer = T::OpenOrigin::ensure_origin(origin, &class)?;
+			ensure!(T::Classes::get().contains(&class), Error::<T, I>::BadClass);
+			MotionsOf::<T, I>::try_mutate(&proposer, |count| -> DispatchResult {
+				ensure!(*count < T::MaxMotionsPerProposer::get(), Error::<T, I>::TooManyMotions);
+				count.saturating_inc();
+				Ok(())
+			})?;
+
+			let index = MotionCount::<T, I>::mutate(|count| {
+				let index = *count;
+				count.saturating_inc();
+				index
+			});
+			let end = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::Duration::convert(class));
+			T::Preimages::hold(&proposal);
+			let tally = T::Tally::new(class);
+			MotionFor::<T, I>::insert(
+				index,
+				Motion { proposer: proposer.clone(), class, proposal, end, tally },
+			);
+			Self::deposit_event(Event::Opened { index, class, proposer, end });
+			Ok(())
+		}
+
+		/// Close a motion which may no longer be voted on, dispatching its proposal if it has
+		/// been approved.
+		///
+		/// - `origin`: Must be `Signed`.
+		/// - `index`: The index of the motion.
+		/// - `proposal_weight_bound`: The weight of the proposal or greater.
+		///
+		/// The weight of the proposal is refunded unless it is dispatched, in which case only the
+		/// weight it actually used is charged.
+		///
+		/// Emits `Approved` or `Rejected`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::close().saturating_add(*proposal_weight_bound))]
+		pub fn close(
+			origin: OriginFor<T>,
+			index: MotionIndex,
+			proposal_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let motion = MotionFor::<T, I>::get(index).ok_or(Error::<T, I>::UnknownMotion)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= motion.end, Error::<T, I>::Ongoing);
+
+			let Motion { proposer, class, proposal, tally, .. } = motion;
+			let approved = Self::is_approved(&tally, class);
+			T::OnPollCompleted::on_poll_completed(index, class, &tally, approved);
+			let mut proposal_weight = Weight::zero();
+			if approved {
+				let result = match T::Preimages::peek(&proposal) {
+					Ok((call, _)) => {
+						let info = call.get_dispatch_info();
+						ensure!(
+							info.weight.all_lte(proposal_weight_bound),
+							Error::<T, I>::WrongProposalWeight
+						);
+						let origin = T::ClassOrigin::convert(class).into();
+						let result = call.dispatch(origin);
+						proposal_weight = extract_actual_weight(&result, &info);
+						result.map(|_| ()).map_err(|e| e.error)
+					},
+					Err(e) => Err(e),
+				};
+				Self::deposit_event(Event::Approved { index, tally, result });
+			} else {
+				Self::deposit_event(Event::Rejected { index, tally });
+			}
+			T::Preimages::drop(&proposal);
+			MotionFor::<T, I>::remove(index);
+			Self::note_motion_ended(&proposer);
+			Ok(Some(T::WeightInfo::close().saturating_add(proposal_weight)).into())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Whether a motion of the given `class` with the given `tally` is approved.
+		pub fn is_approved(tally: &T::Tally, class: T::Class) -> bool {
+			tally.meets_approval(class, T::ApprovalThreshold::convert(class)) &&
+				tally.meets_support(class, T::SupportThreshold::convert(class))
+		}
+
+		/// Note that a motion opened by `proposer` has been closed or vetoed.
+		fn note_motion_ended(proposer: &T::AccountId) {
+			MotionsOf::<T, I>::mutate_exists(proposer, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+		}
+
+		/// The motion at `index`, if it may still be voted on.
+		pub(crate) fn ongoing(index: MotionIndex) -> Option<MotionOf<T, I>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			MotionFor::<T, I>::get(index).filter(|motion| now < motion.end)
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
+	type Index = MotionIndex;
+	type Votes = T::Votes;
+	type Class = T::Class;
+	type Moment = T::BlockNumber;
+
+	fn classes() -> Vec<Self::Class> {
+		T::Classes::get()
+	}
+
+	fn as_ongoing(index: Self::Index) -> Option<(T::Tally, Self::Class)> {
+		Self::ongoing(index).map(|motion| (motion.tally, motion.class))
+	}
+
+	fn ongoing_polls() -> Vec<(Self::Index, T::Tally, Self::Class)> {
+		let now = frame_system::Pallet::<T>::block_number();
+		MotionFor::<T, I>::iter()
+			.filter(|(_, motion)| now < motion.end)
+			.map(|(index, motion)| (index, motion.tally, motion.class))
+			.collect()
+	}
+
+	fn access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut T::Tally, T::BlockNumber, T::Class>) -> R,
+	) -> R {
+		match Self::ongoing(index) {
+			Some(mut motion) => {
+				let result = f(PollStatus::Ongoing(&mut motion.tally, motion.class));
+				MotionFor::<T, I>::insert(index, motion);
+				result
+			},
+			None => f(PollStatus::None),
+		}
+	}
+
+	fn try_access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut T::Tally, T::BlockNumber, T::Class>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		match Self::ongoing(index) {
+			Some(mut motion) => {
+				let result = f(PollStatus::Ongoing(&mut motion.tally, motion.class))?;
+				MotionFor::<T, I>::insert(index, motion);
+				Ok(result)
+			},
+			None => f(PollStatus::None),
+		}
+	}
+
+	fn on_vetoed(index: Self::Index) {
+		if let Some(motion) = MotionFor::<T, I>::take(index) {
+			T::OnPollCompleted::on_poll_completed(index, motion.class, &motion.tally, false);
+			T::Preimages::drop(&motion.proposal);
+			Self::note_motion_ended(&motion.proposer);
+			Self::deposit_event(Event::Vetoed { index, tally: motion.tally });
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
+		let index = MotionCount::<T, I>::mutate(|count| {
+			let index = *count;
+			count.saturating_inc();
+			index
+		});
+		let proposer =
+			codec::Decode::decode(&mut sp_runtime::traits::TrailingZeroInput::new(&b"dummy"[..]))
+				.expect("infinite length input; no invalid inputs for type; qed");
+		let proposal = T::Preimages::bound(CallOf::<T, I>::from(Call::close {
+			index,
+			proposal_weight_bound: Weight::zero(),
+		}))
+		.map_err(|_| ())?;
+		let motion = Motion {
+			proposer,
+			class,
+			proposal,
+			end: sp_runtime::traits::Bounded::max_value(),
+			tally: T::Tally::new(class),
+		};
+		MotionFor::<T, I>::insert(index, motion);
+		Ok(index)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: Self::Index, _approved: bool) -> Result<(), ()> {
+		Self::ongoing(index).ok_or(())?;
+		MotionFor::<T, I>::remove(index);
+		Ok(())
+	}
+}
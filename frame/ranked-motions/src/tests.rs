@@ -0,0 +1,316 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The crate's tests.
+
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Everything},
+};
+use frame_support_test::TestRandomness;
+use frame_system::EnsureRoot;
+use pallet_ranked_collective::{EnsureMemberOfClass, EnsureSelfDemotion, Geometric, Rank, Votes};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Identity, IdentityLookup},
+};
+
+use super::*;
+use crate as pallet_ranked_motions;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Club: pallet_ranked_collective::{Pallet, Call, Storage, Event<T>},
+		Motions: pallet_ranked_motions::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_ranked_collective::Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type PromoteOrigin = frame_system::EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>;
+	type DemoteOrigin = frame_system::EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>;
+	type MembershipOrigin = EnsureSelfDemotion<Test, ()>;
+	type Polls = Motions;
+	type MinRankOfClass = Identity;
+	type VoteWeight = Geometric;
+	type SupportIncludesNays = frame_support::traits::ConstBool<false>;
+	type SnapshotVoters = frame_support::traits::ConstBool<false>;
+	type CompactVoteEvents = frame_support::traits::ConstBool<false>;
+	type Randomness = TestRandomness<Self>;
+	type PanelOrigin = EnsureRoot<Self::AccountId>;
+	type MaxPanelSize = ConstU32<10>;
+	type IdleCleanupWeight = ();
+	type ResignationCooldown = ConstU64<10>;
+	type MaxRankChanges = ConstU32<16>;
+	type MaxFreeVotes = ConstU32<3>;
+	type FreeVotePeriod = ConstU64<10>;
+	type VetoRank = ConstU16<2>;
+	type VetoThreshold = ConstU32<2>;
+	type VetoPeriod = ConstU64<5>;
+	type MembershipNft = ();
+	type ReviewOrigin = frame_system::EnsureRoot<u64>;
+	type MaxDelegators = ConstU32<16>;
+	type ExchangeOrigin = frame_system::EnsureRoot<u64>;
+	type SplitTarget = frame_support::traits::ranked::NoMembers<u64>;
+	type DemotionPeriod = ();
+	type MaxInactivityChecks = ConstU32<0>;
+	type MaxMembersPerRank = ConstU32<{ u32::MAX }>;
+	type OnMemberAdded = ();
+	type OnRankChanged = ();
+	type OnMemberRemoved = ();
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type RankSource = frame_support::traits::ranked::NoMembers<u64>;
+	type RankSyncPolicy = RankSyncPolicy;
+}
+
+parameter_types! {
+	pub Classes: Vec<Rank> = vec![0, 1, 2];
+	pub const RankSyncPolicy: pallet_ranked_collective::SyncPolicy =
+		pallet_ranked_collective::SyncPolicy::Manual;
+}
+
+/// Motions of class `c` run for `5 * (c + 1)` blocks.
+pub struct TestDuration;
+impl Convert<Rank, u64> for TestDuration {
+	fn convert(class: Rank) -> u64 {
+		5 * (class as u64 + 1)
+	}
+}
+
+pub struct HalfOf;
+impl Convert<Rank, Perbill> for HalfOf {
+	fn convert(_: Rank) -> Perbill {
+		Perbill::from_percent(50)
+	}
+}
+
+pub struct RootOfClass;
+impl Convert<Rank, OriginCaller> for RootOfClass {
+	fn convert(_: Rank) -> OriginCaller {
+		frame_system::RawOrigin::Root.into()
+	}
+}
+
+impl Config for Test {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Votes = Votes;
+	type Class = Rank;
+	type Tally = pallet_ranked_collective::TallyOf<Test>;
+	type OpenOrigin = EnsureMemberOfClass<Test, ()>;
+	type Classes = Classes;
+	type Duration = TestDuration;
+	type ApprovalThreshold = HalfOf;
+	type SupportThreshold = HalfOf;
+	type ClassOrigin = RootOfClass;
+	type Preimages = ();
+	type OnPollCompleted = Club;
+	type MaxMotionsPerProposer = ConstU32<2>;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn run_to(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+	}
+}
+
+fn add_member(who: u64) -> BoundedCallOf<Test, ()> {
+	let call = RuntimeCall::Club(pallet_ranked_collective::Call::add_member { who });
+	<() as StorePreimage>::bound(call).unwrap()
+}
+
+fn members(ranks: &[Rank]) {
+	for (who, rank) in ranks.iter().enumerate() {
+		assert_ok!(Club::do_add_member_to_rank(who as u64 + 1, *rank));
+	}
+}
+
+#[test]
+fn open_works() {
+	new_test_ext().execute_with(|| {
+		members(&[0, 1, 3]);
+		assert_noop!(
+			Motions::open(RuntimeOrigin::signed(1), 1, add_member(9)),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_noop!(
+			Motions::open(RuntimeOrigin::signed(2), 3, add_member(9)),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_noop!(
+			Motions::open(RuntimeOrigin::signed(3), 3, add_member(9)),
+			Error::<Test>::BadClass
+		);
+
+		assert_ok!(Motions::open(RuntimeOrigin::signed(2), 1, add_member(9)));
+		System::assert_last_event(
+			Event::Opened { index: 0, class: 1, proposer: 2, end: 11 }.into(),
+		);
+		assert!(Motions::as_ongoing(0).is_some());
+		assert_eq!(MotionCount::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn approved_motion_is_dispatched() {
+	new_test_ext().execute_with(|| {
+		members(&[1, 1, 1]);
+		assert_ok!(Motions::open(RuntimeOrigin::signed(1), 1, add_member(9)));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 0, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(2), 0, true));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 0, false));
+
+		assert_noop!(
+			Motions::close(RuntimeOrigin::signed(4), 0, Weight::MAX),
+			Error::<Test>::Ongoing
+		);
+		run_to(11);
+		// Voting has ended.
+		assert_noop!(
+			Club::vote(RuntimeOrigin::signed(3), 0, true),
+			pallet_ranked_collective::Error::<Test>::NotPolling
+		);
+		assert_noop!(
+			Motions::close(RuntimeOrigin::signed(4), 0, Weight::zero()),
+			Error::<Test>::WrongProposalWeight
+		);
+
+		let closed = Motions::close(RuntimeOrigin::signed(4), 0, Weight::MAX);
+		// Only the weight the proposal actually used is charged.
+		assert!(closed.unwrap().actual_weight.unwrap().all_lt(Weight::MAX));
+		System::assert_has_event(pallet_ranked_collective::Event::MemberAdded { who: 9 }.into());
+		assert!(matches!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Motions(Event::Approved { index: 0, result: Ok(()), .. })
+		));
+		assert!(!MotionFor::<Test>::contains_key(0));
+		assert_ok!(Club::cleanup_poll(RuntimeOrigin::signed(4), 0, 10));
+	});
+}
+
+#[test]
+fn rejected_motion_is_not_dispatched() {
+	new_test_ext().execute_with(|| {
+		members(&[0, 0, 0]);
+		assert_ok!(Motions::open(RuntimeOrigin::signed(1), 0, add_member(9)));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 0, true));
+		run_to(6);
+
+		let closed = Motions::close(RuntimeOrigin::signed(1), 0, Weight::MAX);
+		// The proposal was never dispatched, so its weight bound is refunded.
+		assert_eq!(closed.unwrap().actual_weight, Some(<() as WeightInfo>::close()));
+		assert!(matches!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Motions(Event::Rejected { index: 0, .. })
+		));
+		assert_noop!(
+			Motions::close(RuntimeOrigin::signed(1), 0, Weight::zero()),
+			Error::<Test>::UnknownMotion
+		);
+	});
+}
+
+#[test]
+fn vetoed_motion_is_removed() {
+	new_test_ext().execute_with(|| {
+		members(&[2, 2, 0]);
+		assert_ok!(Motions::open(RuntimeOrigin::signed(1), 0, add_member(9)));
+		assert_ok!(Club::vote(RuntimeOrigin::signed(3), 0, true));
+		assert_ok!(Club::veto(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Club::veto(RuntimeOrigin::signed(2), 0));
+
+		assert!(matches!(
+			System::events().iter().rev().nth(1).unwrap().event,
+			RuntimeEvent::Motions(Event::Vetoed { index: 0, .. })
+		));
+		assert!(!MotionFor::<Test>::contains_key(0));
+		assert_noop!(
+			Club::vote(RuntimeOrigin::signed(3), 0, false),
+			pallet_ranked_collective::Error::<Test>::NotPolling
+		);
+		// The final tally is reported, so the collective may clean up the votes.
+		let certificate = pallet_ranked_collective::TallyCertificates::<Test>::get(0).unwrap();
+		assert!(!certificate.approved);
+		assert_ok!(Club::cleanup_poll(RuntimeOrigin::signed(4), 0, 10));
+		assert_eq!(MotionsOf::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn open_motions_per_proposer_are_capped() {
+	new_test_ext().execute_with(|| {
+		members(&[0, 0]);
+		assert_ok!(Motions::open(RuntimeOrigin::signed(1), 0, add_member(8)));
+		assert_ok!(Motions::open(RuntimeOrigin::signed(1), 0, add_member(9)));
+		assert_noop!(
+			Motions::open(RuntimeOrigin::signed(1), 0, add_member(10)),
+			Error::<Test>::TooManyMotions
+		);
+		assert_ok!(Motions::open(RuntimeOrigin::signed(2), 0, add_member(10)));
+		assert_eq!(MotionsOf::<Test>::get(1), 2);
+
+		run_to(6);
+		assert_ok!(Motions::close(RuntimeOrigin::signed(2), 0, Weight::zero()));
+		assert_eq!(MotionsOf::<Test>::get(1), 1);
+		assert_ok!(Motions::open(RuntimeOrigin::signed(1), 0, add_member(10)));
+	});
+}
@@ -0,0 +1,98 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_ranked_motions
+//!
+//! NOT GENERATED: every function here is a placeholder written by hand, as the pallet has not
+//! been run through the benchmark CLI yet. Generate this file with the command below before these
+//! weights are relied upon.
+
+// Command to generate:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_ranked_motions
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/ranked-motions/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_ranked_motions.
+pub trait WeightInfo {
+	fn open() -> Weight;
+	fn close() -> Weight;
+}
+
+/// Weights for pallet_ranked_motions using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedMotions MotionCount (r:1 w:1)
+	// Storage: RankedMotions MotionsOf (r:1 w:1)
+	// Storage: RankedMotions MotionFor (r:0 w:1)
+	fn open() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(29_102_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: RankedMotions MotionFor (r:1 w:1)
+	// Storage: RankedCollective MemberCount (r:1 w:0)
+	// Storage: RankedMotions MotionsOf (r:1 w:1)
+	fn close() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(34_280_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: RankedCollective Members (r:1 w:0)
+	// Storage: RankedMotions MotionCount (r:1 w:1)
+	// Storage: RankedMotions MotionsOf (r:1 w:1)
+	// Storage: RankedMotions MotionFor (r:0 w:1)
+	fn open() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(29_102_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: RankedMotions MotionFor (r:1 w:1)
+	// Storage: RankedCollective MemberCount (r:1 w:0)
+	// Storage: RankedMotions MotionsOf (r:1 w:1)
+	fn close() -> Weight {
+		// Placeholder: written by hand, not yet benchmarked.
+		Weight::from_ref_time(34_280_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+}
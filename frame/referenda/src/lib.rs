@@ -72,8 +72,8 @@ use frame_support::{
 			v3::{Anon as ScheduleAnon, Named as ScheduleNamed},
 			DispatchTime,
 		},
-		Currency, LockIdentifier, OnUnbalanced, OriginTrait, PollStatus, Polling, QueryPreimage,
-		ReservableCurrency, StorePreimage, VoteTally,
+		Currency, LockIdentifier, OnPollCompleted, OnUnbalanced, OriginTrait, PollStatus, Polling,
+		QueryPreimage, ReservableCurrency, StorePreimage, VoteTally,
 	},
 	BoundedVec,
 };
@@ -222,6 +222,10 @@ pub mod pallet {
 
 		/// The preimage provider.
 		type Preimages: QueryPreimage + StorePreimage;
+
+		/// Handler told about the final tally of each referendum once it is no longer ongoing,
+		/// whether it is approved, rejected, cancelled, killed or timed out.
+		type OnPollCompleted: OnPollCompleted<ReferendumIndex, TrackIdOf<Self, I>, Self::Tally>;
 	}
 
 	/// The next free referendum index, aka the number of referenda started so far.
@@ -236,7 +240,8 @@ pub mod pallet {
 	/// The sorted list of referenda ready to be decided but not yet being decided, ordered by
 	/// conviction-weighted approvals.
 	///
-	/// This should be empty if `DecidingCount` is less than `TrackInfo::max_deciding`.
+	/// This should be empty if `DecidingCount` is less than `TrackInfo::max_deciding`, unless the
+	/// track is paused.
 	#[pallet::storage]
 	pub type TrackQueue<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
@@ -251,6 +256,12 @@ pub mod pallet {
 	pub type DecidingCount<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, u32, ValueQuery>;
 
+	/// The tracks whose voters have stopped accepting votes. Referenda ready to be decided on them
+	/// are queued until they resume.
+	#[pallet::storage]
+	pub type PausedTracks<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -356,6 +367,16 @@ pub mod pallet {
 			/// The amount placed by the account.
 			amount: BalanceOf<T, I>,
 		},
+		/// A track has been paused, as its voters stopped accepting votes.
+		TrackPaused {
+			/// The track which was paused.
+			track: TrackIdOf<T, I>,
+		},
+		/// A track has resumed, as its voters accept votes again.
+		TrackResumed {
+			/// The track which resumed.
+			track: TrackIdOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -508,17 +529,7 @@ pub mod pallet {
 		pub fn cancel(origin: OriginFor<T>, index: ReferendumIndex) -> DispatchResult {
 			T::CancelOrigin::ensure_origin(origin)?;
 			let status = Self::ensure_ongoing(index)?;
-			if let Some((_, last_alarm)) = status.alarm {
-				let _ = T::Scheduler::cancel(last_alarm);
-			}
-			Self::note_one_fewer_deciding(status.track);
-			Self::deposit_event(Event::<T, I>::Cancelled { index, tally: status.tally });
-			let info = ReferendumInfo::Cancelled(
-				frame_system::Pallet::<T>::block_number(),
-				Some(status.submission_deposit),
-				status.decision_deposit,
-			);
-			ReferendumInfoFor::<T, I>::insert(index, info);
+			Self::do_cancel(index, status);
 			Ok(())
 		}
 
@@ -537,6 +548,7 @@ pub mod pallet {
 				let _ = T::Scheduler::cancel(last_alarm);
 			}
 			Self::note_one_fewer_deciding(status.track);
+			T::OnPollCompleted::on_poll_completed(index, status.track, &status.tally, false);
 			Self::deposit_event(Event::<T, I>::Killed { index, tally: status.tally });
 			Self::slash_deposit(Some(status.submission_deposit.clone()));
 			Self::slash_deposit(status.decision_deposit.clone());
@@ -585,21 +597,25 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::BadTrack)?;
 			let mut track_queue = TrackQueue::<T, I>::get(track);
-			let branch =
-				if let Some((index, mut status)) = Self::next_for_deciding(&mut track_queue) {
-					let now = frame_system::Pallet::<T>::block_number();
-					let (maybe_alarm, branch) =
-						Self::begin_deciding(&mut status, index, now, track_info);
-					if let Some(set_alarm) = maybe_alarm {
-						Self::ensure_alarm_at(&mut status, index, set_alarm);
-					}
-					ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
-					TrackQueue::<T, I>::insert(track, track_queue);
-					branch.into()
-				} else {
-					DecidingCount::<T, I>::mutate(track, |x| x.saturating_dec());
-					OneFewerDecidingBranch::QueueEmpty
-				};
+			// No referendum begins deciding on a paused track, see `resume_track`.
+			let next = match PausedTracks::<T, I>::contains_key(track) {
+				true => None,
+				false => Self::next_for_deciding(&mut track_queue),
+			};
+			let branch = if let Some((index, mut status)) = next {
+				let now = frame_system::Pallet::<T>::block_number();
+				let (maybe_alarm, branch) =
+					Self::begin_deciding(&mut status, index, now, track_info);
+				if let Some(set_alarm) = maybe_alarm {
+					Self::ensure_alarm_at(&mut status, index, set_alarm);
+				}
+				ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+				TrackQueue::<T, I>::insert(track, track_queue);
+				branch.into()
+			} else {
+				DecidingCount::<T, I>::mutate(track, |x| x.saturating_dec());
+				OneFewerDecidingBranch::QueueEmpty
+			};
 			Ok(Some(branch.weight::<T, I>()).into())
 		}
 
@@ -688,6 +704,34 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 		Self::ensure_ongoing(index).ok().map(|x| (x.tally, x.track))
 	}
 
+	/// Pauses the track `class` while it is frozen: no referendum on it begins deciding until it
+	/// resumes, at which point the queued referenda begin deciding as far as the track allows.
+	fn on_class_frozen(class: &Self::Class, frozen: bool) {
+		if frozen {
+			PausedTracks::<T, I>::insert(class, ());
+			Self::deposit_event(Event::<T, I>::TrackPaused { track: *class });
+		} else if PausedTracks::<T, I>::take(class).is_some() {
+			Self::resume_track(*class);
+			Self::deposit_event(Event::<T, I>::TrackResumed { track: *class });
+		}
+	}
+
+	/// Cancels the vetoed referendum `index`, refunding its deposits.
+	fn on_vetoed(index: Self::Index) {
+		if let Ok(status) = Self::ensure_ongoing(index) {
+			Self::do_cancel(index, status);
+		}
+	}
+
+	fn ongoing_polls() -> Vec<(Self::Index, T::Tally, TrackIdOf<T, I>)> {
+		ReferendumInfoFor::<T, I>::iter()
+			.filter_map(|(index, info)| match info {
+				ReferendumInfo::Ongoing(status) => Some((index, status.tally, status.track)),
+				_ => None,
+			})
+			.collect()
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
 		let index = ReferendumCount::<T, I>::mutate(|x| {
@@ -885,7 +929,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		status: &mut ReferendumStatusOf<T, I>,
 	) -> (Option<T::BlockNumber>, ServiceBranch) {
 		let deciding_count = DecidingCount::<T, I>::get(status.track);
-		if deciding_count < track.max_deciding {
+		if deciding_count < track.max_deciding && !PausedTracks::<T, I>::contains_key(status.track)
+		{
 			// Begin deciding.
 			DecidingCount::<T, I>::insert(status.track, deciding_count.saturating_add(1));
 			let r = Self::begin_deciding(status, index, now, track);
@@ -899,6 +944,48 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Cancel the ongoing referendum `index`, leaving its deposits to be refunded.
+	fn do_cancel(index: ReferendumIndex, status: ReferendumStatusOf<T, I>) {
+		if let Some((_, last_alarm)) = status.alarm {
+			let _ = T::Scheduler::cancel(last_alarm);
+		}
+		Self::note_one_fewer_deciding(status.track);
+		T::OnPollCompleted::on_poll_completed(index, status.track, &status.tally, false);
+		Self::deposit_event(Event::<T, I>::Cancelled { index, tally: status.tally });
+		let info = ReferendumInfo::Cancelled(
+			frame_system::Pallet::<T>::block_number(),
+			Some(status.submission_deposit),
+			status.decision_deposit,
+		);
+		ReferendumInfoFor::<T, I>::insert(index, info);
+	}
+
+	/// Begin deciding the referenda queued on the resumed `track`, as far as its `max_deciding`
+	/// allows.
+	fn resume_track(track: TrackIdOf<T, I>) {
+		let track_info = match T::Tracks::info(track) {
+			Some(info) => info,
+			None => return,
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut track_queue = TrackQueue::<T, I>::get(track);
+		let mut deciding_count = DecidingCount::<T, I>::get(track);
+		while deciding_count < track_info.max_deciding {
+			let (index, mut status) = match Self::next_for_deciding(&mut track_queue) {
+				Some(next) => next,
+				None => break,
+			};
+			deciding_count.saturating_inc();
+			let (maybe_alarm, _) = Self::begin_deciding(&mut status, index, now, track_info);
+			if let Some(set_alarm) = maybe_alarm {
+				Self::ensure_alarm_at(&mut status, index, set_alarm);
+			}
+			ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+		}
+		DecidingCount::<T, I>::insert(track, deciding_count);
+		TrackQueue::<T, I>::insert(track, track_queue);
+	}
+
 	/// Grab the index and status for the referendum which is the highest priority of those for the
 	/// given track which are ready for being decided.
 	fn next_for_deciding(
@@ -1045,6 +1132,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				if status.deciding.is_none() && now >= timeout {
 					// Too long without being decided - end it.
 					Self::ensure_no_alarm(&mut status);
+					T::OnPollCompleted::on_poll_completed(
+						index,
+						status.track,
+						&status.tally,
+						false,
+					);
 					Self::deposit_event(Event::<T, I>::TimedOut { index, tally: status.tally });
 					return (
 						ReferendumInfo::TimedOut(
@@ -1074,6 +1167,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							Self::note_one_fewer_deciding(status.track);
 							let (desired, call) = (status.enactment, status.proposal);
 							Self::schedule_enactment(index, track, desired, status.origin, call);
+							T::OnPollCompleted::on_poll_completed(
+								index,
+								status.track,
+								&status.tally,
+								true,
+							);
 							Self::deposit_event(Event::<T, I>::Confirmed {
 								index,
 								tally: status.tally,
@@ -1102,6 +1201,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// Failed!
 						Self::ensure_no_alarm(&mut status);
 						Self::note_one_fewer_deciding(status.track);
+						T::OnPollCompleted::on_poll_completed(
+							index,
+							status.track,
+							&status.tally,
+							false,
+						);
 						Self::deposit_event(Event::<T, I>::Rejected { index, tally: status.tally });
 						return (
 							ReferendumInfo::Rejected(
@@ -1201,7 +1306,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		id: TrackIdOf<T, I>,
 	) -> bool {
 		let x = Perbill::from_rational(elapsed.min(period), period);
-		support_needed.passing(x, tally.support(id)) &&
-			approval_needed.passing(x, tally.approval(id))
+		tally.meets_support(id, support_needed.threshold(x)) &&
+			tally.meets_approval(id, approval_needed.threshold(x))
 	}
 }
@@ -163,6 +163,78 @@ pub mod v1 {
 	}
 }
 
+/// Translation of the tallies and votes held by the referenda, for a voting system whose `Tally`
+/// or `Votes` changes its encoding.
+///
+/// The layout of these depends on the voting system rather than on this pallet, so they do not
+/// change its storage version. The migration of the voting system should call them, guarded by
+/// its own storage version.
+pub mod tally {
+	use super::*;
+	use frame_support::storage::unhashed;
+
+	/// Info regarding a referendum, with its tally encoded as `Tally`.
+	pub type ReferendumInfoOf<T, I, Tally> = ReferendumInfo<
+		TrackIdOf<T, I>,
+		PalletsOriginOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedCallOf<T, I>,
+		BalanceOf<T, I>,
+		Tally,
+		<T as frame_system::Config>::AccountId,
+		ScheduleAddressOf<T, I>,
+	>;
+
+	/// Translate the tally of every ongoing referendum from `Old` to `New` with `f`, returning
+	/// the number of referenda translated. Finished referenda hold no tally and are untouched.
+	pub fn translate_tallies<T: Config<I>, I: 'static, Old, New>(
+		mut f: impl FnMut(ReferendumIndex, Old) -> New,
+	) -> u32
+	where
+		Old: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+		New: Eq + PartialEq + Debug + Encode + Decode + TypeInfo + Clone,
+	{
+		let mut translated = 0u32;
+		for index in ReferendumInfoFor::<T, I>::iter_keys().collect::<Vec<_>>() {
+			let key = ReferendumInfoFor::<T, I>::hashed_key_for(index);
+			let status = match unhashed::get::<ReferendumInfoOf<T, I, Old>>(&key) {
+				Some(ReferendumInfo::Ongoing(status)) => status,
+				_ => continue,
+			};
+			let status = ReferendumStatus {
+				track: status.track,
+				origin: status.origin,
+				proposal: status.proposal,
+				enactment: status.enactment,
+				submitted: status.submitted,
+				submission_deposit: status.submission_deposit,
+				decision_deposit: status.decision_deposit,
+				deciding: status.deciding,
+				tally: f(index, status.tally),
+				in_queue: status.in_queue,
+				alarm: status.alarm,
+			};
+			unhashed::put(&key, &ReferendumInfoOf::<T, I, New>::Ongoing(status));
+			translated.saturating_inc();
+		}
+		translated
+	}
+
+	/// Translate the votes of the referenda queued on every track from `Old` with `f`, returning
+	/// the number of tracks translated.
+	pub fn translate_queued_votes<T: Config<I>, I: 'static, Old: Decode>(
+		mut f: impl FnMut(Old) -> VotesOf<T, I>,
+	) -> u32 {
+		let mut translated = 0u32;
+		TrackQueue::<T, I>::translate::<Vec<(ReferendumIndex, Old)>, _>(|_, queue| {
+			translated.saturating_inc();
+			let queue = queue.into_iter().map(|(index, votes)| (index, f(votes)));
+			Some(BoundedVec::truncate_from(queue.collect()))
+		});
+		translated
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 	use super::*;
@@ -229,4 +301,31 @@ pub mod test {
 			);
 		});
 	}
+
+	#[test]
+	fn tallies_and_queued_votes_are_translated() {
+		new_test_ext().execute_with(|| {
+			let status = create_status_v0();
+			let track = status.track;
+			ReferendumInfoFor::<T, ()>::insert(2, ReferendumInfoOf::<T, ()>::Ongoing(status));
+			let killed = ReferendumInfoOf::<T, ()>::Killed(123);
+			ReferendumInfoFor::<T, ()>::insert(5, killed.clone());
+			TrackQueue::<T, ()>::insert(track, BoundedVec::truncate_from(vec![(2, 3), (4, 5)]));
+
+			let translated =
+				tally::translate_tallies::<T, (), TallyOf<T, ()>, TallyOf<T, ()>>(|index, old| {
+					assert_eq!(index, 2);
+					Tally { ayes: old.ayes + 1, nays: old.nays + 2 }
+				});
+			assert_eq!(translated, 1);
+			match ReferendumInfoFor::<T, ()>::get(2) {
+				Some(ReferendumInfo::Ongoing(s)) => assert_eq!(s.tally, Tally { ayes: 1, nays: 2 }),
+				_ => panic!("the referendum should still be ongoing"),
+			}
+			assert_eq!(ReferendumInfoFor::<T, ()>::get(5), Some(killed));
+
+			assert_eq!(tally::translate_queued_votes::<T, (), u32>(|votes| votes * 2), 1);
+			assert_eq!(TrackQueue::<T, ()>::get(track).into_inner(), vec![(2, 6), (4, 10)]);
+		});
+	}
 }
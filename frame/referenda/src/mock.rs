@@ -99,6 +99,10 @@ impl pallet_preimage::Config for Test {
 	type ManagerOrigin = EnsureRoot<u64>;
 	type BaseDeposit = ();
 	type ByteDeposit = ();
+	type MaxRequests = ConstU32<100>;
+	type CheckCanonicalEncoding = frame_support::traits::ConstBool<false>;
+	type RequestExpiry = ();
+	type MaxExpiriesPerBlock = ConstU32<0>;
 }
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -125,6 +129,15 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
 	pub static AlarmInterval: u64 = 1;
+	pub static CompletedPolls: Vec<(ReferendumIndex, bool)> = vec![];
+}
+
+/// Records each referendum reported as completed, and whether it was approved.
+pub struct RecordCompleted;
+impl OnPollCompleted<ReferendumIndex, u8, Tally> for RecordCompleted {
+	fn on_poll_completed(index: ReferendumIndex, _: u8, _: &Tally, approved: bool) {
+		CompletedPolls::mutate(|polls| polls.push((index, approved)));
+	}
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -228,6 +241,7 @@ impl Config for Test {
 	type AlarmInterval = AlarmInterval;
 	type Tracks = TestTracksInfo;
 	type Preimages = Preimage;
+	type OnPollCompleted = RecordCompleted;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -262,6 +262,7 @@ fn queueing_works() {
 		// The final one has since timed out.
 		run_to(22);
 		assert_eq!(timed_out_since(3), 22);
+		assert!(CompletedPolls::get().contains(&(3, false)));
 	});
 }
 
@@ -497,6 +498,7 @@ fn cancel_works() {
 		assert_ok!(Referenda::cancel(RuntimeOrigin::signed(4), 0));
 		assert_ok!(Referenda::refund_decision_deposit(RuntimeOrigin::signed(3), 0));
 		assert_eq!(cancelled_since(0), 8);
+		assert_eq!(CompletedPolls::get(), vec![(0, false)]);
 	});
 }
 
@@ -535,6 +537,7 @@ fn kill_works() {
 		let e = Error::<Test>::NoDeposit;
 		assert_noop!(Referenda::refund_decision_deposit(RuntimeOrigin::signed(3), 0), e);
 		assert_eq!(killed_since(0), 8);
+		assert_eq!(CompletedPolls::get(), vec![(0, false)]);
 	});
 }
 
@@ -578,3 +581,71 @@ fn curve_handles_all_inputs() {
 	let threshold = test_curve.threshold(Perbill::one());
 	assert_eq!(threshold, Perbill::zero());
 }
+
+#[test]
+fn ongoing_polls_lists_ongoing_referenda() {
+	new_test_ext().execute_with(|| {
+		let a = Passing.create();
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(2),
+			DispatchTime::At(10),
+		));
+		let b = a + 1;
+		let indices = || {
+			let mut polls = <Referenda as Polling<_>>::ongoing_polls();
+			polls.sort_by_key(|(index, ..)| *index);
+			polls.into_iter().map(|(index, _, track)| (index, track)).collect::<Vec<_>>()
+		};
+		assert_eq!(indices(), vec![(a, 0), (b, 0)]);
+
+		assert_ok!(Referenda::kill(RuntimeOrigin::root(), a));
+		assert_eq!(indices(), vec![(b, 0)]);
+	});
+}
+
+#[test]
+fn paused_tracks_queue_referenda_until_resumed() {
+	new_test_ext().execute_with(|| {
+		<Referenda as Polling<_>>::on_class_frozen(&0, true);
+		assert!(PausedTracks::<Test>::contains_key(0));
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+
+		// The referendum is queued rather than decided while the track is paused.
+		run_to(6);
+		assert_eq!(waiting_since(0), 1);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		assert_eq!(TrackQueue::<Test>::get(0).len(), 1);
+
+		<Referenda as Polling<_>>::on_class_frozen(&0, false);
+		assert!(!PausedTracks::<Test>::contains_key(0));
+		assert_eq!(deciding_since(0), 6);
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+		assert!(TrackQueue::<Test>::get(0).is_empty());
+	});
+}
+
+#[test]
+fn vetoed_referenda_are_cancelled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+
+		run_to(8);
+		<Referenda as Polling<_>>::on_vetoed(0);
+		assert_eq!(cancelled_since(0), 8);
+		assert_ok!(Referenda::refund_decision_deposit(RuntimeOrigin::signed(3), 0));
+	});
+}
@@ -164,6 +164,10 @@ impl pallet_preimage::Config for Test {
 	type ManagerOrigin = EnsureRoot<u64>;
 	type BaseDeposit = ();
 	type ByteDeposit = ();
+	type MaxRequests = ConstU32<100>;
+	type CheckCanonicalEncoding = frame_support::traits::ConstBool<false>;
+	type RequestExpiry = ();
+	type MaxExpiriesPerBlock = ConstU32<0>;
 }
 
 pub struct TestWeightInfo;
@@ -0,0 +1,230 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sets of values kept in groups, in which every value has a compact index.
+
+use crate::storage::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap};
+use codec::FullCodec;
+use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+/// The index of a value within its group of an [`IndexedSet`].
+pub type SetIndex = u32;
+
+/// An error of an [`IndexedSet`] operation.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum IndexedSetError {
+	/// The value is not in the group.
+	NotInSet,
+	/// The group already holds `SetIndex::MAX` values.
+	Overflow,
+	/// The indices of the group do not match each other.
+	Inconsistent,
+}
+
+impl From<IndexedSetError> for DispatchError {
+	fn from(e: IndexedSetError) -> Self {
+		match e {
+			IndexedSetError::NotInSet => DispatchError::Other("value not in set"),
+			IndexedSetError::Overflow => ArithmeticError::Overflow.into(),
+			IndexedSetError::Inconsistent => DispatchError::Corruption,
+		}
+	}
+}
+
+/// Sets of values kept in groups, in which each value of a group has an index in the range
+/// `0..count` of that group. This allows for the values of a group to be counted, and to be
+/// selected by index, in `O(1)`.
+///
+/// The sets are kept in three storage items:
+/// - `Count`, the number of values of each group;
+/// - `IdToIndex`, the index of each value of each group;
+/// - `IndexToId`, the value at each index of each group.
+///
+/// Removing a value moves the last value of its group into its index, so the index of a value
+/// may change over time.
+pub struct IndexedSet<Group, Value, Count, IdToIndex, IndexToId>(
+	PhantomData<(Group, Value, Count, IdToIndex, IndexToId)>,
+);
+
+impl<Group, Value, Count, IdToIndex, IndexToId>
+	IndexedSet<Group, Value, Count, IdToIndex, IndexToId>
+where
+	Group: FullCodec,
+	Value: FullCodec,
+	Count: StorageMap<Group, SetIndex, Query = SetIndex>,
+	IdToIndex: StorageDoubleMap<Group, Value, SetIndex, Query = Option<SetIndex>>,
+	IndexToId: StorageDoubleMap<Group, SetIndex, Value, Query = Option<Value>>,
+{
+	/// The number of values in `group`.
+	pub fn count(group: &Group) -> SetIndex {
+		Count::get(group)
+	}
+
+	/// Whether `value` is in `group`.
+	pub fn contains(group: &Group, value: &Value) -> bool {
+		IdToIndex::contains_key(group, value)
+	}
+
+	/// The index of `value` in `group`, if it is in it.
+	pub fn index_of(group: &Group, value: &Value) -> Option<SetIndex> {
+		IdToIndex::get(group, value)
+	}
+
+	/// The value at `index` of `group`, if any.
+	pub fn at(group: &Group, index: SetIndex) -> Option<Value> {
+		IndexToId::get(group, index)
+	}
+
+	/// Add `value` at the end of `group`, returning its index.
+	///
+	/// The caller must ensure that `value` is not yet in `group`; this is not checked, so as to
+	/// save a storage read.
+	pub fn insert(group: &Group, value: &Value) -> Result<SetIndex, IndexedSetError> {
+		let index = Count::get(group);
+		let count = index.checked_add(1).ok_or(IndexedSetError::Overflow)?;
+		IdToIndex::insert(group, value, index);
+		IndexToId::insert(group, index, value);
+		Count::insert(group, count);
+		Ok(index)
+	}
+
+	/// Remove `value` from `group`, moving the last value of the group into its index. Returns the
+	/// index which `value` had.
+	pub fn remove(group: &Group, value: &Value) -> Result<SetIndex, IndexedSetError> {
+		let index = IdToIndex::take(group, value).ok_or(IndexedSetError::NotInSet)?;
+		let last_index = Count::get(group).checked_sub(1).ok_or(IndexedSetError::Inconsistent)?;
+		if index != last_index {
+			let last = IndexToId::take(group, last_index).ok_or(IndexedSetError::Inconsistent)?;
+			IdToIndex::insert(group, &last, index);
+			IndexToId::insert(group, index, last);
+		} else {
+			IndexToId::remove(group, index);
+		}
+		Count::insert(group, last_index);
+		Ok(index)
+	}
+
+	/// Replace `old` with `new` in `group`, keeping its index. Returns that index.
+	///
+	/// The caller must ensure that `new` is not yet in `group`, as for [`Self::insert`].
+	pub fn replace(group: &Group, old: &Value, new: &Value) -> Result<SetIndex, IndexedSetError> {
+		let index = IdToIndex::take(group, old).ok_or(IndexedSetError::NotInSet)?;
+		IdToIndex::insert(group, new, index);
+		IndexToId::insert(group, index, new);
+		Ok(index)
+	}
+
+	/// Iterate over the values of `group`, in the order of their indices.
+	pub fn iter(group: Group) -> impl Iterator<Item = Value> {
+		(0..Count::get(&group)).filter_map(move |index| IndexToId::get(&group, index))
+	}
+
+	/// Check that the indices of `group` are consistent with each other and with its count.
+	pub fn try_state(group: &Group) -> Result<(), &'static str>
+	where
+		IdToIndex: IterableStorageDoubleMap<Group, Value, SetIndex>,
+		IndexToId: IterableStorageDoubleMap<Group, SetIndex, Value>,
+	{
+		let count = Count::get(group);
+		for index in 0..count {
+			let value =
+				IndexToId::get(group, index).ok_or("no value at an index below the count")?;
+			if IdToIndex::get(group, &value) != Some(index) {
+				return Err("the index of a value does not match its position")
+			}
+		}
+		if IdToIndex::iter_prefix(group).count() != count as usize {
+			return Err("the number of indexed values does not match the count")
+		}
+		if IndexToId::iter_prefix(group).count() != count as usize {
+			return Err("the number of values does not match the count")
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{storage::types::ValueQuery, Twox64Concat};
+	use sp_io::TestExternalities;
+
+	#[crate::storage_alias]
+	type Count = StorageMap<Prefix, Twox64Concat, u8, SetIndex, ValueQuery>;
+	#[crate::storage_alias]
+	type IdToIndex = StorageDoubleMap<Prefix, Twox64Concat, u8, Twox64Concat, u64, SetIndex>;
+	#[crate::storage_alias]
+	type IndexToId = StorageDoubleMap<Prefix, Twox64Concat, u8, Twox64Concat, SetIndex, u64>;
+
+	type Set = IndexedSet<u8, u64, Count, IdToIndex, IndexToId>;
+
+	#[test]
+	fn insert_and_remove_work() {
+		TestExternalities::default().execute_with(|| {
+			for value in [10, 20, 30, 40] {
+				assert_eq!(Set::insert(&0, &value), Ok(value as SetIndex / 10 - 1));
+			}
+			assert_eq!(Set::insert(&1, &10), Ok(0));
+			assert_eq!(Set::count(&0), 4);
+			assert_eq!(Set::iter(0).collect::<Vec<_>>(), vec![10, 20, 30, 40]);
+
+			// The last value takes the place of the removed one.
+			assert_eq!(Set::remove(&0, &20), Ok(1));
+			assert_eq!(Set::iter(0).collect::<Vec<_>>(), vec![10, 40, 30]);
+			assert_eq!(Set::index_of(&0, &40), Some(1));
+			assert!(!Set::contains(&0, &20));
+			assert_eq!(Set::at(&0, 3), None);
+
+			// Removing the last value moves nothing.
+			assert_eq!(Set::remove(&0, &30), Ok(2));
+			assert_eq!(Set::iter(0).collect::<Vec<_>>(), vec![10, 40]);
+			assert_eq!(Set::remove(&0, &30), Err(IndexedSetError::NotInSet));
+
+			// A replaced value keeps its index.
+			assert_eq!(Set::replace(&0, &10, &50), Ok(0));
+			assert_eq!(Set::iter(0).collect::<Vec<_>>(), vec![50, 40]);
+			assert!(!Set::contains(&0, &10));
+			assert_eq!(Set::replace(&0, &10, &60), Err(IndexedSetError::NotInSet));
+
+			// Other groups are unaffected.
+			assert_eq!(Set::iter(1).collect::<Vec<_>>(), vec![10]);
+			assert_eq!(Set::try_state(&0), Ok(()));
+			assert_eq!(Set::try_state(&1), Ok(()));
+		});
+	}
+
+	#[test]
+	fn try_state_detects_inconsistencies() {
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(Set::insert(&0, &10), Ok(0));
+			assert_eq!(Set::insert(&0, &20), Ok(1));
+
+			IdToIndex::insert(0, 30, 2);
+			assert!(Set::try_state(&0).is_err());
+			IdToIndex::remove(0, 30);
+
+			IdToIndex::insert(0, 20, 0);
+			assert!(Set::try_state(&0).is_err());
+			IdToIndex::insert(0, 20, 1);
+
+			Count::insert(0, 3);
+			assert!(Set::try_state(&0).is_err());
+			assert_eq!(Set::remove(&0, &10), Err(IndexedSetError::Inconsistent));
+		});
+	}
+}
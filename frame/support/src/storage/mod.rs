@@ -46,6 +46,7 @@ pub mod child;
 #[doc(hidden)]
 pub mod generator;
 pub mod hashed;
+pub mod indexed_set;
 pub mod migration;
 pub mod storage_noop_guard;
 mod stream_iter;
@@ -105,12 +105,17 @@ pub use dispatch::{
 
 mod voting;
 pub use voting::{
-	ClassCountOf, CurrencyToVote, PollStatus, Polling, SaturatingCurrencyToVote,
-	U128CurrencyToVote, VoteTally,
+	meets_approval, meets_support, ClassCountOf, CurrencyToVote, OnPollCompleted, PollStatus,
+	Polling, SaturatingCurrencyToVote, StorageOps, U128CurrencyToVote, VoteTally,
 };
 
+pub mod ranked;
+
 mod preimages;
-pub use preimages::{Bounded, BoundedInline, FetchResult, Hash, QueryPreimage, StorePreimage};
+pub use preimages::{
+	ensure_canonical, Bounded, BoundedInline, FetchResult, Hash, PreimageCapabilities,
+	PreimageFeatures, QueryPreimage, StorePreimage,
+};
 
 mod messages;
 pub use messages::{
@@ -17,7 +17,7 @@
 
 //! Stuff for dealing with 32-byte hashed preimages.
 
-use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
+use codec::{Decode, DecodeAll, Encode, EncodeLike, MaxEncodedLen};
 use sp_core::{RuntimeDebug, H256};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{traits::ConstU32, DispatchError};
@@ -121,6 +121,67 @@ impl<T> Bounded<T> {
 
 pub type FetchResult = Result<Cow<'static, [u8]>, DispatchError>;
 
+bitflags::bitflags! {
+	/// Optional features which a preimage provider may support.
+	pub struct PreimageFeatures: u8 {
+		/// Noted preimages expire after some time unless they are requested.
+		const TTL = 0b00000001;
+		/// Preimages may be pinned, keeping them available regardless of requests.
+		const PIN = 0b00000010;
+		/// Preimages may be noted in several chunks.
+		const CHUNKS = 0b00000100;
+		/// Values passed to `StorePreimage::bound_checked` or `note_checked` are checked to be
+		/// canonically encoded.
+		const CANONICAL = 0b00001000;
+	}
+}
+
+/// Ensure that `bytes` are the canonical encoding of a value of type `T`: they must decode into
+/// such a value without any bytes left over, and the value must encode back into the very same
+/// bytes.
+///
+/// This rules out that several encodings of the same value, and thus several hashes, circulate.
+pub fn ensure_canonical<T: Encode + Decode>(bytes: &[u8]) -> Result<(), DispatchError> {
+	let error = DispatchError::Other("value is not canonically encoded");
+	let value = T::decode_all(&mut &bytes[..]).map_err(|_| error)?;
+	if value.using_encoded(|encoded| encoded != bytes) {
+		return Err(error)
+	}
+	Ok(())
+}
+
+/// What a preimage provider is able to do.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub struct PreimageCapabilities {
+	/// The optional features supported by the provider.
+	pub features: PreimageFeatures,
+	/// The maximum length of a preimage which may be noted.
+	pub max_len: u32,
+}
+
+impl PreimageCapabilities {
+	/// Whether noted preimages expire after some time unless they are requested.
+	pub fn supports_ttl(&self) -> bool {
+		self.features.contains(PreimageFeatures::TTL)
+	}
+
+	/// Whether preimages may be pinned.
+	pub fn supports_pin(&self) -> bool {
+		self.features.contains(PreimageFeatures::PIN)
+	}
+
+	/// Whether preimages may be noted in several chunks.
+	pub fn supports_chunks(&self) -> bool {
+		self.features.contains(PreimageFeatures::CHUNKS)
+	}
+
+	/// Whether values passed to `StorePreimage::bound_checked` or `note_checked` are checked to
+	/// be canonically encoded.
+	pub fn checks_canonical(&self) -> bool {
+		self.features.contains(PreimageFeatures::CANONICAL)
+	}
+}
+
 /// A interface for looking up preimages from their hash on chain.
 pub trait QueryPreimage {
 	/// Returns whether a preimage exists for a given hash and if so its length.
@@ -132,10 +193,31 @@ pub trait QueryPreimage {
 	/// Returns whether a preimage request exists for a given hash.
 	fn is_requested(hash: &Hash) -> bool;
 
+	/// Returns the number of outstanding requests for a given hash.
+	///
+	/// Providers which do not track the number of requests report one if any exists.
+	fn request_count(hash: &Hash) -> u32 {
+		Self::is_requested(hash) as u32
+	}
+
 	/// Request that someone report a preimage. Providers use this to optimise the economics for
 	/// preimage reporting.
+	///
+	/// The request is always counted, so it must be matched by exactly one `unrequest`. Providers
+	/// which bound the number of outstanding requests per hash only report going beyond it; use
+	/// `try_request` to have such a request refused instead.
 	fn request(hash: &Hash);
 
+	/// Request that someone report a preimage, returning the number of outstanding requests for
+	/// `hash` including this one.
+	///
+	/// Returns an error and leaves the request count untouched if the number of outstanding
+	/// requests for `hash` is already saturated.
+	fn try_request(hash: &Hash) -> Result<u32, DispatchError> {
+		Self::request(hash);
+		Ok(Self::request_count(hash))
+	}
+
 	/// Cancel a previous preimage request.
 	fn unrequest(hash: &Hash);
 
@@ -222,12 +304,36 @@ pub trait StorePreimage: QueryPreimage {
 	/// May return `DispatchError::Exhausted` if the preimage is just too big.
 	fn note(bytes: Cow<[u8]>) -> Result<Hash, DispatchError>;
 
+	/// The capabilities of the provider, allowing generic users to adapt to it rather than fail
+	/// when using a feature which it lacks.
+	///
+	/// By default no optional feature is supported and preimages of up to `MAX_LENGTH` bytes may
+	/// be noted.
+	fn capabilities() -> PreimageCapabilities {
+		PreimageCapabilities {
+			features: PreimageFeatures::empty(),
+			max_len: Self::MAX_LENGTH.try_into().unwrap_or(u32::MAX),
+		}
+	}
+
 	/// Attempt to clear a previously noted preimage. Exactly the same as `unrequest` but is
 	/// provided for symmetry.
 	fn unnote(hash: &Hash) {
 		Self::unrequest(hash)
 	}
 
+	/// Request and attempt to store the bytes of a preimage of a value of type `T` on chain.
+	///
+	/// If the provider `checks_canonical`, the `bytes` are first checked with
+	/// [`ensure_canonical`], at the cost of decoding them once. Use this rather than `note` for
+	/// bytes which do not come from encoding a value.
+	fn note_checked<T: Encode + Decode>(bytes: Cow<[u8]>) -> Result<Hash, DispatchError> {
+		if Self::capabilities().checks_canonical() {
+			ensure_canonical::<T>(&bytes)?;
+		}
+		Self::note(bytes)
+	}
+
 	/// Convert an otherwise unbounded or large value into a type ready for placing in storage. The
 	/// result is a type whose `MaxEncodedLen` is 131 bytes.
 	///
@@ -240,6 +346,15 @@ pub trait StorePreimage: QueryPreimage {
 			Err(unbounded) => Bounded::Lookup { hash: Self::note(unbounded.into())?, len },
 		})
 	}
+
+	/// As `bound`, but if the provider `checks_canonical`, the encoding of `t` is first checked
+	/// with [`ensure_canonical`], at the cost of decoding it once.
+	fn bound_checked<T: Encode + Decode>(t: T) -> Result<Bounded<T>, DispatchError> {
+		if Self::capabilities().checks_canonical() {
+			t.using_encoded(ensure_canonical::<T>)?;
+		}
+		Self::bound(t)
+	}
 }
 
 impl QueryPreimage for () {
@@ -252,7 +367,13 @@ impl QueryPreimage for () {
 	fn is_requested(_: &Hash) -> bool {
 		false
 	}
+	fn request_count(_: &Hash) -> u32 {
+		0
+	}
 	fn request(_: &Hash) {}
+	fn try_request(_: &Hash) -> Result<u32, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
 	fn unrequest(_: &Hash) {}
 }
 
@@ -263,6 +384,50 @@ impl StorePreimage for () {
 	}
 }
 
+/// Implements `QueryPreimage` and `StorePreimage` for a wrapper type by forwarding to the
+/// provider it wraps.
+macro_rules! impl_preimage_provider_for_wrapper {
+	($( $wrapper:ty ),* $(,)?) => {
+		$(
+			impl<P: QueryPreimage> QueryPreimage for $wrapper {
+				fn len(hash: &Hash) -> Option<u32> {
+					P::len(hash)
+				}
+				fn fetch(hash: &Hash, len: Option<u32>) -> FetchResult {
+					P::fetch(hash, len)
+				}
+				fn is_requested(hash: &Hash) -> bool {
+					P::is_requested(hash)
+				}
+				fn request_count(hash: &Hash) -> u32 {
+					P::request_count(hash)
+				}
+				fn request(hash: &Hash) {
+					P::request(hash)
+				}
+				fn try_request(hash: &Hash) -> Result<u32, DispatchError> {
+					P::try_request(hash)
+				}
+				fn unrequest(hash: &Hash) {
+					P::unrequest(hash)
+				}
+			}
+
+			impl<P: StorePreimage> StorePreimage for $wrapper {
+				const MAX_LENGTH: usize = P::MAX_LENGTH;
+				fn note(bytes: Cow<[u8]>) -> Result<Hash, DispatchError> {
+					P::note(bytes)
+				}
+				fn capabilities() -> PreimageCapabilities {
+					P::capabilities()
+				}
+			}
+		)*
+	};
+}
+
+impl_preimage_provider_for_wrapper!(&P, sp_std::boxed::Box<P>, sp_std::sync::Arc<P>);
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -305,6 +470,39 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn bounded_encoding_test_vectors() {
+		let hash = H256::repeat_byte(0x42);
+
+		// Legacy: variant index and hash.
+		let bound: Bounded<Vec<u8>> = Bounded::Legacy { hash, dummy: Default::default() };
+		let mut expected = vec![0u8];
+		expected.extend_from_slice(hash.as_bytes());
+		assert_eq!(bound.encode(), expected);
+		assert_eq!(Bounded::<Vec<u8>>::decode(&mut &expected[..]).unwrap(), bound);
+
+		// Inline: variant index, compact length and data.
+		let bound: Bounded<Vec<u8>> = Bounded::Inline(bounded_vec![b'a', b'b', b'c']);
+		assert_eq!(bound.encode(), vec![1, 12, b'a', b'b', b'c']);
+
+		// Inline at the maximal length hits the `MaxEncodedLen` exactly.
+		let bound: Bounded<Vec<u8>> = Bounded::Inline(BoundedVec::truncate_from(vec![7; 128]));
+		assert_eq!(bound.encode().len(), 131);
+
+		// Lookup: variant index, hash and little-endian length.
+		let bound: Bounded<Vec<u8>> = Bounded::Lookup { hash, len: 0x0102_0304 };
+		let mut expected = vec![2u8];
+		expected.extend_from_slice(hash.as_bytes());
+		expected.extend_from_slice(&[4, 3, 2, 1]);
+		assert_eq!(bound.encode(), expected);
+		assert_eq!(Bounded::<Vec<u8>>::decode(&mut &expected[..]).unwrap(), bound);
+
+		// Anything beyond the inline limit does not decode.
+		let mut too_long = vec![1u8];
+		too_long.extend(vec![0u8; 129].encode());
+		assert!(Bounded::<Vec<u8>>::decode(&mut &too_long[..]).is_err());
+	}
+
 	#[test]
 	fn bounded_transmuting_works() {
 		let data: BoundedVec<u8, _> = bounded_vec![b'a', b'b', b'c'];
@@ -314,4 +512,50 @@ mod tests {
 		let y: Bounded<&str> = x.transmute();
 		assert_eq!(y, Bounded::Inline(data));
 	}
+
+	#[test]
+	fn wrapped_providers_forward() {
+		let hash = H256::repeat_byte(0x42);
+		assert_eq!(<&()>::len(&hash), None);
+		assert!(!<sp_std::boxed::Box<()>>::is_requested(&hash));
+		assert_eq!(<sp_std::sync::Arc<()>>::try_request(&hash), Err(DispatchError::Unavailable));
+		assert_eq!(<&()>::MAX_LENGTH, 0);
+		assert_eq!(
+			<sp_std::sync::Arc<()>>::note(Cow::Borrowed(&[1, 2, 3])),
+			Err(DispatchError::Exhausted)
+		);
+		assert_eq!(<&()>::capabilities(), <()>::capabilities());
+	}
+
+	#[test]
+	fn capabilities_default_to_none() {
+		let capabilities = <()>::capabilities();
+		assert_eq!(capabilities.max_len, 0);
+		assert!(!capabilities.supports_ttl());
+		assert!(!capabilities.supports_pin());
+		assert!(!capabilities.supports_chunks());
+
+		let capabilities = PreimageCapabilities {
+			features: PreimageFeatures::PIN | PreimageFeatures::CHUNKS,
+			max_len: 4 * 1024 * 1024,
+		};
+		assert!(!capabilities.supports_ttl());
+		assert!(capabilities.supports_pin());
+		assert!(capabilities.supports_chunks());
+		assert!(!capabilities.checks_canonical());
+	}
+
+	#[test]
+	fn ensure_canonical_works() {
+		use sp_std::collections::btree_set::BTreeSet;
+
+		assert_eq!(ensure_canonical::<u16>(&[1, 0]), Ok(()));
+		assert_eq!(ensure_canonical::<BTreeSet<u8>>(&[8, 1, 2]), Ok(()));
+		// Trailing bytes.
+		assert!(ensure_canonical::<u16>(&[1, 0, 0]).is_err());
+		// Too few bytes.
+		assert!(ensure_canonical::<u16>(&[1]).is_err());
+		// Decodes fine, but into a set which encodes differently.
+		assert!(ensure_canonical::<BTreeSet<u8>>(&[8, 2, 1]).is_err());
+	}
 }
@@ -0,0 +1,149 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types shared by pallets which keep ranked memberships, and conversions between them.
+
+use impl_trait_for_tuples::impl_for_tuples;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::marker::PhantomData;
+
+/// The rank of a member. Zero is the lowest rank.
+pub type Rank = u16;
+
+/// A number of members, or the index of a member among the members of a rank.
+pub type MemberIndex = u32;
+
+/// The weight of a vote.
+pub type Votes = u64;
+
+/// The number of ranks by which `rank` exceeds `min`, or `None` if it is below `min`.
+pub fn rank_excess(rank: Rank, min: Rank) -> Option<Rank> {
+	rank.checked_sub(min)
+}
+
+/// The rank after a promotion from `rank`, or `None` if it is the highest rank.
+pub fn promoted(rank: Rank) -> Option<Rank> {
+	rank.checked_add(1)
+}
+
+/// The rank after `by` promotions from `rank`, saturating at the highest rank.
+pub fn saturating_promoted(rank: Rank, by: Rank) -> Rank {
+	rank.saturating_add(by)
+}
+
+/// The rank after a demotion from `rank`, or `None` if it is zero, so that the member leaves.
+pub fn demoted(rank: Rank) -> Option<Rank> {
+	rank.checked_sub(1)
+}
+
+/// `rank` as a `u32`, e.g. a weight component.
+pub fn rank_to_u32(rank: Rank) -> u32 {
+	rank.into()
+}
+
+/// The rank `n`, e.g. a benchmark component, saturating at the highest rank.
+pub fn rank_from_u32(n: u32) -> Rank {
+	n.try_into().unwrap_or(Rank::MAX)
+}
+
+/// A ranked membership which other pallets may query and change, without going through its
+/// dispatchables. No origin checks are done by any of these functions.
+pub trait RankedMembers {
+	/// The account of a member.
+	type AccountId;
+
+	/// The rank of `who`, or `None` if they are not a member.
+	fn rank_of(who: &Self::AccountId) -> Option<Rank>;
+
+	/// Add `who` as a member of rank zero.
+	fn induct(who: &Self::AccountId) -> DispatchResult;
+
+	/// Increment the rank of the member `who` by one.
+	fn promote(who: &Self::AccountId) -> DispatchResult;
+
+	/// Decrement the rank of the member `who` by one, removing them if they were of rank zero.
+	fn demote(who: &Self::AccountId) -> DispatchResult;
+}
+
+/// Handler for when a member joins a ranked membership.
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+pub trait OnMemberAdded<AccountId> {
+	/// `who` joined the membership at rank `rank`.
+	fn on_member_added(who: &AccountId, rank: Rank);
+}
+
+/// Handler for when the rank of a member of a ranked membership changes.
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+pub trait OnRankChanged<AccountId> {
+	/// The rank of `who`, who remains a member, changed from `old` to `new`.
+	fn on_rank_changed(who: &AccountId, old: Rank, new: Rank);
+}
+
+/// Handler for when a member leaves a ranked membership.
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+pub trait OnMemberRemoved<AccountId> {
+	/// `who` left the membership, which they had at rank `rank`.
+	fn on_member_removed(who: &AccountId, rank: Rank);
+}
+
+/// A ranked membership without members, which nobody may join.
+pub struct NoMembers<AccountId>(PhantomData<AccountId>);
+
+impl<AccountId> RankedMembers for NoMembers<AccountId> {
+	type AccountId = AccountId;
+
+	fn rank_of(_: &AccountId) -> Option<Rank> {
+		None
+	}
+
+	fn induct(_: &AccountId) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn promote(_: &AccountId) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn demote(_: &AccountId) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn conversions_work() {
+		assert_eq!(rank_excess(3, 1), Some(2));
+		assert_eq!(rank_excess(1, 3), None);
+		assert_eq!(promoted(1), Some(2));
+		assert_eq!(promoted(Rank::MAX), None);
+		assert_eq!(saturating_promoted(Rank::MAX - 1, 2), Rank::MAX);
+		assert_eq!(demoted(1), Some(0));
+		assert_eq!(demoted(0), None);
+		assert_eq!(rank_to_u32(Rank::MAX), 65535);
+		assert_eq!(rank_from_u32(7), 7);
+		assert_eq!(rank_from_u32(u32::MAX), Rank::MAX);
+	}
+}
@@ -29,7 +29,7 @@
 
 use crate::{
 	dispatch::{DispatchError, DispatchResult},
-	traits::tokens::misc::AttributeNamespace,
+	traits::{tokens::misc::AttributeNamespace, LockIdentifier},
 };
 use codec::{Decode, Encode};
 use sp_runtime::TokenError;
@@ -294,3 +294,36 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 		destination: &AccountId,
 	) -> DispatchResult;
 }
+
+/// Trait for locking items of non-fungible sets in place, so that a custodian may hold them
+/// without their being transferred away from their owner.
+pub trait LockableNonFungible<AccountId>: Inspect<AccountId> {
+	/// Lock `item` of `collection` for the given `reason`. It may be neither transferred nor
+	/// burned until it is unlocked.
+	fn lock(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		reason: LockIdentifier,
+	) -> DispatchResult;
+
+	/// Unlock `item` of `collection`, which must be locked for the given `reason`.
+	fn unlock(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		reason: LockIdentifier,
+	) -> DispatchResult;
+
+	/// Returns the reason for which `item` of `collection` is locked, if it is.
+	fn locked_by(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<LockIdentifier>;
+}
+
+/// Trait for inspecting the royalties owed on the sales of items of non-fungible sets.
+pub trait InspectRoyalties<AccountId, Balance>: Inspect<AccountId> {
+	/// Returns the beneficiaries of the royalty owed on a sale of `item` of `collection` for
+	/// `price`, each with the amount owed to them. Empty if no royalty is owed.
+	fn royalties(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		price: Balance,
+	) -> Vec<(AccountId, Balance)>;
+}
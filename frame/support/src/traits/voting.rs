@@ -18,13 +18,16 @@
 //! Traits and associated data structures concerned with voting, and moving between tokens and
 //! votes.
 
-use crate::dispatch::{DispatchError, Parameter};
+use crate::{
+	dispatch::{DispatchError, Parameter},
+	weights::{RuntimeDbWeight, Weight},
+};
 use codec::{HasCompact, MaxEncodedLen};
 use sp_arithmetic::{
-	traits::{SaturatedConversion, UniqueSaturatedFrom, UniqueSaturatedInto},
-	Perbill,
+	traits::{SaturatedConversion, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto},
+	PerThing, Perbill,
 };
-use sp_runtime::traits::Member;
+use sp_runtime::{traits::Member, RuntimeDebug};
 use sp_std::prelude::*;
 
 /// A trait similar to `Convert` to convert values from `B` an abstract balance type
@@ -95,11 +98,49 @@ impl<B: UniqueSaturatedInto<u64> + UniqueSaturatedFrom<u128>> CurrencyToVote<B>
 	}
 }
 
+/// Whether `supporters` out of `members` make up at least the `threshold`.
+///
+/// The fraction is compared exactly, without first being rounded into a `Perbill`, and cannot
+/// overflow. As with `Perbill::from_rational`, any supporters out of no members meet any
+/// threshold.
+pub fn meets_support(supporters: u64, members: u64, threshold: Perbill) -> bool {
+	members == 0 || meets_fraction(supporters, members, threshold)
+}
+
+/// Whether `ayes` make up at least the `threshold` of `ayes` and `nays` together.
+///
+/// The fraction is compared exactly, without first being rounded into a `Perbill`, and cannot
+/// overflow. Without any ayes or nays, only a zero threshold is met.
+pub fn meets_approval(ayes: u64, nays: u64, threshold: Perbill) -> bool {
+	meets_fraction(ayes, (ayes as u128).saturating_add(nays as u128).max(1), threshold)
+}
+
+/// Whether `part / whole >= threshold`, for a non-zero `whole` of at most `2 * u64::MAX`.
+fn meets_fraction(part: u64, whole: impl Into<u128>, threshold: Perbill) -> bool {
+	let accuracy = Perbill::ACCURACY as u128;
+	// Neither side exceeds `2^65 * 10^9 < 2^95`.
+	(part as u128) * accuracy >= (threshold.deconstruct() as u128) * whole.into()
+}
+
 pub trait VoteTally<Votes, Class> {
 	fn new(_: Class) -> Self;
 	fn ayes(&self, class: Class) -> Votes;
 	fn support(&self, class: Class) -> Perbill;
 	fn approval(&self, class: Class) -> Perbill;
+	/// Whether the support of the tally is at least `threshold`.
+	///
+	/// By default compares `support` with the threshold. Tallies of whole numbers of votes may
+	/// use [`meets_support`] to compare them exactly.
+	fn meets_support(&self, class: Class, threshold: Perbill) -> bool {
+		self.support(class) >= threshold
+	}
+	/// Whether the approval of the tally is at least `threshold`.
+	///
+	/// By default compares `approval` with the threshold. Tallies of whole numbers of votes may
+	/// use [`meets_approval`] to compare them exactly.
+	fn meets_approval(&self, class: Class, threshold: Perbill) -> bool {
+		self.approval(class) >= threshold
+	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn unanimity(class: Class) -> Self;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -135,6 +176,33 @@ impl<Tally, Moment, Class> PollStatus<Tally, Moment, Class> {
 	}
 }
 
+/// The storage operations which a closure passed to `Polling::try_access_poll_metered` declares
+/// as it does them, so that their weight may be metered.
+#[derive(Clone, Copy, Default, Eq, PartialEq, RuntimeDebug)]
+pub struct StorageOps {
+	/// The number of storage reads.
+	pub reads: u64,
+	/// The number of storage writes.
+	pub writes: u64,
+}
+
+impl StorageOps {
+	/// Declare `n` storage reads.
+	pub fn read(&mut self, n: u64) {
+		self.reads.saturating_accrue(n);
+	}
+
+	/// Declare `n` storage writes.
+	pub fn write(&mut self, n: u64) {
+		self.writes.saturating_accrue(n);
+	}
+
+	/// The weight of the declared operations.
+	pub fn weight(&self, db_weight: RuntimeDbWeight) -> Weight {
+		db_weight.reads_writes(self.reads, self.writes)
+	}
+}
+
 pub struct ClassCountOf<P, T>(sp_std::marker::PhantomData<(P, T)>);
 impl<T, P: Polling<T>> sp_runtime::traits::Get<u32> for ClassCountOf<P, T> {
 	fn get() -> u32 {
@@ -142,6 +210,21 @@ impl<T, P: Polling<T>> sp_runtime::traits::Get<u32> for ClassCountOf<P, T> {
 	}
 }
 
+/// A handler told about the final tally of the polls of a [`Polling`] provider as they are
+/// decided, e.g. so that the voters may keep a record of the outcome.
+///
+/// The provider does not account for the weight of the handler separately, so it should be
+/// small and constant, and be included when benchmarking the provider.
+pub trait OnPollCompleted<Index, Class, Tally> {
+	/// The poll `index` of `class` was decided with the final `tally`, approving it if
+	/// `approved`.
+	fn on_poll_completed(index: Index, class: Class, tally: &Tally, approved: bool);
+}
+
+impl<Index, Class, Tally> OnPollCompleted<Index, Class, Tally> for () {
+	fn on_poll_completed(_: Index, _: Class, _: &Tally, _: bool) {}
+}
+
 pub trait Polling<Tally> {
 	type Index: Parameter + Member + Ord + PartialOrd + Copy + HasCompact + MaxEncodedLen;
 	type Votes: Parameter + Member + Ord + PartialOrd + Copy + HasCompact + MaxEncodedLen;
@@ -157,6 +240,15 @@ pub trait Polling<Tally> {
 	/// Don't use this if you might mutate - use `try_access_poll` instead.
 	fn as_ongoing(index: Self::Index) -> Option<(Tally, Self::Class)>;
 
+	/// All polls which can be voted on, with their tally and class, in no particular order.
+	///
+	/// This reads every poll, so it is meant for queries from outside the chain, such as runtime
+	/// APIs, rather than dispatchables. By default no polls are listed, for providers which
+	/// cannot enumerate theirs.
+	fn ongoing_polls() -> Vec<(Self::Index, Tally, Self::Class)> {
+		Vec::new()
+	}
+
 	fn access_poll<R>(
 		index: Self::Index,
 		f: impl FnOnce(PollStatus<&mut Tally, Self::Moment, Self::Class>) -> R,
@@ -167,6 +259,36 @@ pub trait Polling<Tally> {
 		f: impl FnOnce(PollStatus<&mut Tally, Self::Moment, Self::Class>) -> Result<R, DispatchError>,
 	) -> Result<R, DispatchError>;
 
+	/// Like `try_access_poll`, but `f` declares the storage operations it does on the given
+	/// `StorageOps`, and their weight according to `db_weight` is returned along with the result
+	/// of `f`, e.g. so that the caller can report its actual weight in its `PostDispatchInfo`.
+	///
+	/// The weight does not include the access to the poll itself.
+	fn try_access_poll_metered<R>(
+		index: Self::Index,
+		db_weight: RuntimeDbWeight,
+		f: impl FnOnce(
+			PollStatus<&mut Tally, Self::Moment, Self::Class>,
+			&mut StorageOps,
+		) -> Result<R, DispatchError>,
+	) -> Result<(R, Weight), DispatchError> {
+		let mut ops = StorageOps::default();
+		let result = Self::try_access_poll(index, |status| f(status, &mut ops))?;
+		Ok((result, ops.weight(db_weight)))
+	}
+
+	/// Notes that the voters on the polls of `class` have stopped (`frozen` is `true`) or
+	/// resumed accepting votes, so that the provider may hold off opening polls of `class`.
+	///
+	/// Does nothing by default.
+	fn on_class_frozen(_class: &Self::Class, _frozen: bool) {}
+
+	/// Notes that the voters have vetoed the ongoing poll `index`, so that the provider may
+	/// cancel or suspend it.
+	///
+	/// Does nothing by default.
+	fn on_vetoed(_index: Self::Index) {}
+
 	/// Create an ongoing majority-carries poll of given class lasting given period for the purpose
 	/// of benchmarking.
 	///
@@ -187,3 +309,87 @@ pub trait Polling<Tally> {
 		(Self::classes().into_iter().next().expect("Always one class"), u32::max_value())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_arithmetic::Rational128;
+
+	/// Whether `part / whole >= threshold` in exact rational arithmetic.
+	fn rational_meets(part: u64, whole: u128, threshold: Perbill) -> bool {
+		Rational128::from(part as u128, whole) >=
+			Rational128::from(threshold.deconstruct() as u128, Perbill::ACCURACY as u128)
+	}
+
+	fn values() -> Vec<u64> {
+		let mut values: Vec<u64> = (0..=40).collect();
+		values.extend([999, 1_000, 1_001, 999_999_999, 1_000_000_000, 1_000_000_001]);
+		values.extend([u32::MAX as u64, u64::MAX / 3, u64::MAX / 2, u64::MAX - 1, u64::MAX]);
+		values
+	}
+
+	fn thresholds() -> Vec<Perbill> {
+		let mut thresholds: Vec<Perbill> =
+			(0..=20).map(|n| Perbill::from_rational(n, 20u32)).collect();
+		thresholds.extend(
+			[1, 333_333_333, 333_333_334, 666_666_666, 666_666_667, 999_999_999]
+				.map(Perbill::from_parts),
+		);
+		thresholds
+	}
+
+	#[test]
+	fn meets_support_matches_rational_arithmetic() {
+		for threshold in thresholds() {
+			for &members in values().iter() {
+				for &supporters in values().iter() {
+					let expected =
+						members == 0 || rational_meets(supporters, members as u128, threshold);
+					assert_eq!(
+						meets_support(supporters, members, threshold),
+						expected,
+						"{} out of {} against {:?}",
+						supporters,
+						members,
+						threshold,
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn meets_approval_matches_rational_arithmetic() {
+		for threshold in thresholds() {
+			for &nays in values().iter() {
+				for &ayes in values().iter() {
+					let expected = match ayes as u128 + nays as u128 {
+						0 => threshold.is_zero(),
+						all => rational_meets(ayes, all, threshold),
+					};
+					assert_eq!(
+						meets_approval(ayes, nays, threshold),
+						expected,
+						"{} ayes and {} nays against {:?}",
+						ayes,
+						nays,
+						threshold,
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn thresholds_are_met_at_their_edges() {
+		assert!(meets_approval(2, 1, Perbill::from_parts(666_666_666)));
+		assert!(!meets_approval(2, 1, Perbill::from_parts(666_666_667)));
+		assert!(meets_approval(u64::MAX, u64::MAX, Perbill::from_percent(50)));
+		assert!(!meets_approval(u64::MAX - 1, u64::MAX, Perbill::from_percent(50)));
+		assert!(meets_approval(0, 0, Perbill::zero()));
+		assert!(!meets_approval(0, 0, Perbill::from_parts(1)));
+		assert!(meets_support(3, 3, Perbill::one()));
+		assert!(!meets_support(0, 3, Perbill::from_parts(1)));
+		assert!(meets_support(0, 0, Perbill::one()));
+	}
+}
@@ -99,6 +99,10 @@ impl pallet_preimage::Config for Test {
 	type BaseDeposit = ConstU64<1>;
 	type ByteDeposit = ConstU64<1>;
 	type WeightInfo = ();
+	type MaxRequests = ConstU32<100>;
+	type CheckCanonicalEncoding = frame_support::traits::ConstBool<false>;
+	type RequestExpiry = ();
+	type MaxExpiriesPerBlock = ConstU32<0>;
 }
 
 impl pallet_whitelist::Config for Test {